@@ -0,0 +1,29 @@
+-- Full-text index over blog posts (external content table, kept in sync by triggers)
+CREATE VIRTUAL TABLE blog_fts USING fts5(
+    title,
+    excerpt,
+    content,
+    content = 'blog',
+    content_rowid = 'id',
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+-- Index rows that already exist
+INSERT INTO blog_fts(blog_fts) VALUES ('rebuild');
+
+CREATE TRIGGER blog_fts_after_insert AFTER INSERT ON blog BEGIN
+    INSERT INTO blog_fts(rowid, title, excerpt, content)
+    VALUES (new.id, new.title, new.excerpt, new.content);
+END;
+
+CREATE TRIGGER blog_fts_after_delete AFTER DELETE ON blog BEGIN
+    INSERT INTO blog_fts(blog_fts, rowid, title, excerpt, content)
+    VALUES ('delete', old.id, old.title, old.excerpt, old.content);
+END;
+
+CREATE TRIGGER blog_fts_after_update AFTER UPDATE OF title, excerpt, content ON blog BEGIN
+    INSERT INTO blog_fts(blog_fts, rowid, title, excerpt, content)
+    VALUES ('delete', old.id, old.title, old.excerpt, old.content);
+    INSERT INTO blog_fts(rowid, title, excerpt, content)
+    VALUES (new.id, new.title, new.excerpt, new.content);
+END;
@@ -0,0 +1,14 @@
+CREATE TABLE blog_revisions (
+    id INTEGER NOT NULL PRIMARY KEY,
+    blog_id INTEGER NOT NULL REFERENCES blog(id) ON DELETE CASCADE,
+    revision_number INTEGER NOT NULL,
+    title TEXT NOT NULL,
+    excerpt TEXT,
+    content TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (blog_id, revision_number)
+);
+
+-- Seed history with the current state of every existing post
+INSERT INTO blog_revisions (blog_id, revision_number, title, excerpt, content, created_at)
+SELECT id, 1, title, excerpt, content, updated_at FROM blog;
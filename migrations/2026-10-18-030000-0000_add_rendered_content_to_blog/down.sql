@@ -0,0 +1,4 @@
+ALTER TABLE blog DROP COLUMN reading_time_minutes;
+ALTER TABLE blog DROP COLUMN word_count;
+ALTER TABLE blog DROP COLUMN toc;
+ALTER TABLE blog DROP COLUMN content_html;
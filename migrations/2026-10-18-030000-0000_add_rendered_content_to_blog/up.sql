@@ -0,0 +1,5 @@
+-- Cached Markdown rendering; content_html stays NULL until the post is rendered
+ALTER TABLE blog ADD COLUMN content_html TEXT;
+ALTER TABLE blog ADD COLUMN toc TEXT;
+ALTER TABLE blog ADD COLUMN word_count INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE blog ADD COLUMN reading_time_minutes INTEGER NOT NULL DEFAULT 0;
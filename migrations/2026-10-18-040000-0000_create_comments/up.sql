@@ -0,0 +1,14 @@
+CREATE TABLE comments (
+    id INTEGER NOT NULL PRIMARY KEY,
+    blog_id INTEGER NOT NULL REFERENCES blog(id) ON DELETE CASCADE,
+    parent_id INTEGER REFERENCES comments(id) ON DELETE CASCADE,
+    author_name TEXT NOT NULL,
+    author_email TEXT NOT NULL,
+    content TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'PENDING',
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_comments_blog_status ON comments(blog_id, status);
+CREATE INDEX idx_comments_parent ON comments(parent_id);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_blog_status_published_at;
+DROP INDEX IF EXISTS idx_blog_tags_tag;
@@ -0,0 +1,4 @@
+-- Related-post lookups go from a tag to the posts carrying it, and only
+-- consider published posts.
+CREATE INDEX idx_blog_tags_tag ON blog_tags(tag_id);
+CREATE INDEX idx_blog_status_published_at ON blog(status, published_at);
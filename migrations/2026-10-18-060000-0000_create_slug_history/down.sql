@@ -0,0 +1,10 @@
+DROP TRIGGER IF EXISTS portofolio_slug_history_after_delete;
+DROP TRIGGER IF EXISTS portofolio_slug_history_after_update;
+DROP TRIGGER IF EXISTS portofolio_slug_history_after_insert;
+DROP TRIGGER IF EXISTS project_slug_history_after_delete;
+DROP TRIGGER IF EXISTS project_slug_history_after_update;
+DROP TRIGGER IF EXISTS project_slug_history_after_insert;
+DROP TRIGGER IF EXISTS blog_slug_history_after_delete;
+DROP TRIGGER IF EXISTS blog_slug_history_after_update;
+DROP TRIGGER IF EXISTS blog_slug_history_after_insert;
+DROP TABLE IF EXISTS slug_history;
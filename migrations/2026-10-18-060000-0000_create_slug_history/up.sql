@@ -0,0 +1,60 @@
+-- Retired slugs of blogs, projects and portofolios, kept so old URLs can be
+-- redirected to the current one. Rows are maintained by the triggers below.
+CREATE TABLE slug_history (
+    id INTEGER NOT NULL PRIMARY KEY,
+    entity_type TEXT NOT NULL,
+    entity_id INTEGER NOT NULL,
+    slug TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX idx_slug_history_entity_slug ON slug_history(entity_type, slug);
+CREATE INDEX idx_slug_history_entity_id ON slug_history(entity_type, entity_id);
+
+-- blog
+CREATE TRIGGER blog_slug_history_after_insert AFTER INSERT ON blog BEGIN
+    DELETE FROM slug_history WHERE entity_type = 'blog' AND slug = new.slug;
+END;
+
+CREATE TRIGGER blog_slug_history_after_update AFTER UPDATE OF slug ON blog
+WHEN old.slug <> new.slug BEGIN
+    DELETE FROM slug_history WHERE entity_type = 'blog' AND slug = new.slug;
+    INSERT OR REPLACE INTO slug_history(entity_type, entity_id, slug)
+    VALUES ('blog', old.id, old.slug);
+END;
+
+CREATE TRIGGER blog_slug_history_after_delete AFTER DELETE ON blog BEGIN
+    DELETE FROM slug_history WHERE entity_type = 'blog' AND entity_id = old.id;
+END;
+
+-- projects
+CREATE TRIGGER project_slug_history_after_insert AFTER INSERT ON projects BEGIN
+    DELETE FROM slug_history WHERE entity_type = 'project' AND slug = new.slug;
+END;
+
+CREATE TRIGGER project_slug_history_after_update AFTER UPDATE OF slug ON projects
+WHEN old.slug <> new.slug BEGIN
+    DELETE FROM slug_history WHERE entity_type = 'project' AND slug = new.slug;
+    INSERT OR REPLACE INTO slug_history(entity_type, entity_id, slug)
+    VALUES ('project', old.id, old.slug);
+END;
+
+CREATE TRIGGER project_slug_history_after_delete AFTER DELETE ON projects BEGIN
+    DELETE FROM slug_history WHERE entity_type = 'project' AND entity_id = old.id;
+END;
+
+-- portofolios
+CREATE TRIGGER portofolio_slug_history_after_insert AFTER INSERT ON portofolios BEGIN
+    DELETE FROM slug_history WHERE entity_type = 'portofolio' AND slug = new.slug;
+END;
+
+CREATE TRIGGER portofolio_slug_history_after_update AFTER UPDATE OF slug ON portofolios
+WHEN old.slug <> new.slug BEGIN
+    DELETE FROM slug_history WHERE entity_type = 'portofolio' AND slug = new.slug;
+    INSERT OR REPLACE INTO slug_history(entity_type, entity_id, slug)
+    VALUES ('portofolio', old.id, old.slug);
+END;
+
+CREATE TRIGGER portofolio_slug_history_after_delete AFTER DELETE ON portofolios BEGIN
+    DELETE FROM slug_history WHERE entity_type = 'portofolio' AND entity_id = old.id;
+END;
@@ -0,0 +1,12 @@
+CREATE TABLE media (
+    id INTEGER NOT NULL PRIMARY KEY,
+    filename TEXT NOT NULL UNIQUE,
+    original_name TEXT NOT NULL,
+    mime_type TEXT NOT NULL,
+    size BIGINT NOT NULL,
+    checksum TEXT NOT NULL,
+    alt_text TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX idx_media_checksum ON media(checksum);
@@ -0,0 +1,5 @@
+DROP TABLE media_variants;
+
+ALTER TABLE media DROP COLUMN blurhash;
+ALTER TABLE media DROP COLUMN height;
+ALTER TABLE media DROP COLUMN width;
@@ -0,0 +1,15 @@
+ALTER TABLE media ADD COLUMN width INTEGER;
+ALTER TABLE media ADD COLUMN height INTEGER;
+ALTER TABLE media ADD COLUMN blurhash TEXT;
+
+CREATE TABLE media_variants (
+    id INTEGER NOT NULL PRIMARY KEY,
+    media_id INTEGER NOT NULL REFERENCES media(id) ON DELETE CASCADE,
+    filename TEXT NOT NULL UNIQUE,
+    mime_type TEXT NOT NULL,
+    width INTEGER NOT NULL,
+    height INTEGER NOT NULL,
+    size BIGINT NOT NULL
+);
+
+CREATE INDEX idx_media_variants_media_id ON media_variants(media_id);
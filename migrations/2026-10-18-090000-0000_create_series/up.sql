@@ -0,0 +1,18 @@
+CREATE TABLE series (
+    id INTEGER NOT NULL PRIMARY KEY,
+    title TEXT NOT NULL,
+    slug TEXT NOT NULL UNIQUE,
+    description TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- A post belongs to at most one series
+CREATE TABLE series_posts (
+    series_id INTEGER NOT NULL REFERENCES series(id) ON DELETE CASCADE,
+    blog_id INTEGER NOT NULL UNIQUE REFERENCES blog(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (series_id, blog_id)
+);
+
+CREATE UNIQUE INDEX idx_series_posts_position ON series_posts(series_id, position);
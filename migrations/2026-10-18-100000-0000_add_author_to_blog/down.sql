@@ -0,0 +1,3 @@
+DROP INDEX idx_blog_author_id;
+
+ALTER TABLE blog DROP COLUMN author_id;
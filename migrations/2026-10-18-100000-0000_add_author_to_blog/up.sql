@@ -0,0 +1,6 @@
+ALTER TABLE blog ADD COLUMN author_id INTEGER REFERENCES users(id) ON DELETE SET NULL;
+
+-- Posts written before authors existed belong to the original account
+UPDATE blog SET author_id = (SELECT MIN(id) FROM users);
+
+CREATE INDEX idx_blog_author_id ON blog(author_id);
@@ -0,0 +1,11 @@
+-- The signed token itself is never stored; its `jti` claim is this row's id,
+-- which is what revocation is checked against.
+CREATE TABLE blog_preview_tokens (
+    id INTEGER NOT NULL PRIMARY KEY,
+    blog_id INTEGER NOT NULL REFERENCES blog(id) ON DELETE CASCADE,
+    expires_at TIMESTAMP NOT NULL,
+    revoked_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_blog_preview_tokens_blog_id ON blog_preview_tokens(blog_id);
@@ -0,0 +1,2 @@
+DROP TABLE blog_views_daily;
+DROP TABLE blog_view_fingerprints;
@@ -0,0 +1,19 @@
+-- Visitors counted recently, keyed by a salted hash of their IP and user agent.
+-- Rows older than the de-duplication window are pruned as new views come in.
+CREATE TABLE blog_view_fingerprints (
+    blog_id INTEGER NOT NULL REFERENCES blog(id) ON DELETE CASCADE,
+    fingerprint TEXT NOT NULL,
+    counted_at TIMESTAMP NOT NULL,
+    PRIMARY KEY (blog_id, fingerprint)
+);
+
+CREATE INDEX idx_blog_view_fingerprints_counted_at ON blog_view_fingerprints(counted_at);
+
+CREATE TABLE blog_views_daily (
+    blog_id INTEGER NOT NULL REFERENCES blog(id) ON DELETE CASCADE,
+    day DATE NOT NULL,
+    views INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (blog_id, day)
+);
+
+CREATE INDEX idx_blog_views_daily_day ON blog_views_daily(day);
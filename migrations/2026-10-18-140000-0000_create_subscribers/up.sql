@@ -0,0 +1,24 @@
+-- status is PENDING until the confirmation link is followed, then CONFIRMED;
+-- UNSUBSCRIBED rows are kept so a later subscribe reuses them.
+CREATE TABLE subscribers (
+    id INTEGER NOT NULL PRIMARY KEY,
+    email TEXT NOT NULL UNIQUE,
+    status TEXT NOT NULL DEFAULT 'PENDING',
+    confirmed_at TIMESTAMP,
+    unsubscribed_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_subscribers_status ON subscribers(status);
+
+-- One row per post announced to subscribers, so a post is only mailed the
+-- first time it goes live.
+CREATE TABLE newsletter_issues (
+    blog_id INTEGER NOT NULL PRIMARY KEY REFERENCES blog(id) ON DELETE CASCADE,
+    recipients INTEGER NOT NULL DEFAULT 0,
+    sent_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Posts published before the newsletter existed are not announced
+INSERT INTO newsletter_issues (blog_id) SELECT id FROM blog WHERE status = 'PUBLISHED';
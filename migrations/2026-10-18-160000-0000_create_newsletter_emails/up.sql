@@ -0,0 +1,17 @@
+-- Outgoing subscriber emails, sent by the background newsletter sender so no
+-- request waits on SMTP. kind is CONFIRMATION or ISSUE (blog_id set); status
+-- is PENDING until the send is attempted, then SENT or FAILED, or SKIPPED
+-- when the subscriber changed status in the meantime.
+CREATE TABLE newsletter_emails (
+    id INTEGER NOT NULL PRIMARY KEY,
+    subscriber_id INTEGER NOT NULL REFERENCES subscribers(id) ON DELETE CASCADE,
+    kind TEXT NOT NULL,
+    blog_id INTEGER REFERENCES blog(id) ON DELETE CASCADE,
+    status TEXT NOT NULL DEFAULT 'PENDING',
+    error TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_newsletter_emails_status ON newsletter_emails(status);
+CREATE INDEX idx_newsletter_emails_subscriber_id ON newsletter_emails(subscriber_id, kind);
@@ -0,0 +1,5 @@
+DROP TRIGGER IF EXISTS users_public_name_after_insert;
+DROP INDEX users_slug_unique;
+
+ALTER TABLE users DROP COLUMN slug;
+ALTER TABLE users DROP COLUMN display_name;
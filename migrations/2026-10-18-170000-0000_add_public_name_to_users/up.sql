@@ -0,0 +1,18 @@
+-- The username is the login id, so authors are shown and linked by these instead
+ALTER TABLE users ADD COLUMN display_name TEXT;
+ALTER TABLE users ADD COLUMN slug TEXT;
+
+UPDATE users SET display_name = 'Author ' || id, slug = 'author-' || id;
+
+CREATE UNIQUE INDEX users_slug_unique ON users(slug);
+
+-- Accounts created without them get the same placeholders
+CREATE TRIGGER users_public_name_after_insert
+AFTER INSERT ON users
+WHEN NEW.display_name IS NULL OR NEW.slug IS NULL
+BEGIN
+    UPDATE users
+    SET display_name = COALESCE(NEW.display_name, 'Author ' || NEW.id),
+        slug = COALESCE(NEW.slug, 'author-' || NEW.id)
+    WHERE id = NEW.id;
+END;
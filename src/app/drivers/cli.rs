@@ -0,0 +1,93 @@
+use crate::app::features::importer::domain::entity::ImportFile;
+use crate::utils::di::Container;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const IMPORT_USAGE: &str =
+    "usage: myblog import [--dry-run] [--author <username>] <file or directory>...";
+
+fn is_importable(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| matches!(e.to_lowercase().as_str(), "xml" | "md" | "markdown"))
+}
+
+fn collect_files(dir: &Path, found: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(&path, found)?;
+        } else if is_importable(&path) {
+            found.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Files are read as given; directories are searched recursively for
+/// `.xml`, `.md` and `.markdown` files, in path order.
+pub fn read_import_files(paths: &[PathBuf]) -> io::Result<Vec<ImportFile>> {
+    let mut found = Vec::new();
+    for path in paths {
+        if path.is_dir() {
+            let mut in_dir = Vec::new();
+            collect_files(path, &mut in_dir)?;
+            in_dir.sort();
+            found.extend(in_dir);
+        } else {
+            found.push(path.clone());
+        }
+    }
+    found
+        .into_iter()
+        .map(|path| {
+            Ok(ImportFile {
+                name: path.display().to_string(),
+                content: String::from_utf8_lossy(&fs::read(&path)?).into_owned(),
+            })
+        })
+        .collect()
+}
+
+/// `myblog import ...`: imports straight into the configured database
+/// without starting the server, and prints the report as JSON. Posts are
+/// credited to `--author`, or to `DEFAULT_USERNAME`.
+pub fn import(container: &Container, args: &[String]) -> io::Result<()> {
+    let mut dry_run = false;
+    let mut author = container.config.default_username.clone();
+    let mut paths = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--dry-run" => dry_run = true,
+            "--author" => {
+                author = args
+                    .next()
+                    .ok_or_else(|| io::Error::other(IMPORT_USAGE))?
+                    .clone()
+            }
+            _ if arg.starts_with("--") => return Err(io::Error::other(IMPORT_USAGE)),
+            _ => paths.push(PathBuf::from(arg)),
+        }
+    }
+    if paths.is_empty() {
+        return Err(io::Error::other(IMPORT_USAGE));
+    }
+
+    let files = read_import_files(&paths)?;
+    let report = container
+        .import_posts_usecase
+        .execute(files, dry_run, Some(author))
+        .map_err(|e| io::Error::other(format!("import failed: {:?}", e)))?;
+
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    eprintln!(
+        "{}{} created, {} skipped, {} failed",
+        if report.dry_run { "dry run: " } else { "" },
+        report.created,
+        report.skipped,
+        report.failed
+    );
+    Ok(())
+}
@@ -2,7 +2,7 @@ use crate::utils::di::Container;
 use crate::utils::error_response::map_string_error;
 use crate::utils::token::verify_token;
 use actix_web::{
-    Error,
+    Error, HttpMessage,
     dev::{Service, ServiceRequest, ServiceResponse, Transform},
     web,
 };
@@ -66,8 +66,9 @@ where
 
         if let Some(token) = token_value {
             if let Some(container) = req.app_data::<web::Data<Container>>() {
-                if let Ok(_) = verify_token(&token, &container.config.jwt_secret) {
-                    // Token is valid
+                if let Ok(claims) = verify_token(&token, &container.config.jwt_secret) {
+                    // Token is valid; handlers read the caller from `ReqData<Claims>`
+                    req.extensions_mut().insert(claims);
                     let fut = self.service.call(req);
                     return Box::pin(async move {
                         let res = fut.await?;
@@ -1,3 +1,5 @@
+pub mod cli;
 pub mod middlewares;
 pub mod openapi;
 pub mod routes;
+pub mod scheduler;
@@ -18,6 +18,7 @@ use utoipa::{
         // Blog Categories
         crate::app::features::blog::interface::controller::create_category,
         crate::app::features::blog::interface::controller::get_categories,
+        crate::app::features::blog::interface::controller::get_category_tree,
         crate::app::features::blog::interface::controller::get_category,
         crate::app::features::blog::interface::controller::update_category,
         crate::app::features::blog::interface::controller::delete_category,
@@ -27,12 +28,89 @@ use utoipa::{
         crate::app::features::blog::interface::controller::get_tag,
         crate::app::features::blog::interface::controller::update_tag,
         crate::app::features::blog::interface::controller::delete_tag,
+        crate::app::features::blog::interface::controller::merge_tag,
         // Blogs
         crate::app::features::blog::interface::controller::create_blog,
         crate::app::features::blog::interface::controller::get_blogs,
+        crate::app::features::blog::interface::controller::search_blogs,
+        crate::app::features::blog::interface::controller::get_blog_revisions,
+        crate::app::features::blog::interface::controller::diff_blog_revisions,
+        crate::app::features::blog::interface::controller::get_blog_revision,
+        crate::app::features::blog::interface::controller::restore_blog_revision,
+        crate::app::features::blog::interface::controller::get_related_blogs,
+        crate::app::features::blog::interface::controller::get_public_related_blogs,
+        crate::app::features::blog::interface::controller::get_public_tag_cloud,
+        // Comments
+        crate::app::features::comments::interface::controller::get_comments,
+        crate::app::features::comments::interface::controller::moderate_comments,
+        crate::app::features::comments::interface::controller::delete_comment,
+        crate::app::features::comments::interface::controller::get_public_comments,
+        crate::app::features::comments::interface::controller::create_public_comment,
+        // Feeds
+        crate::app::features::feed::interface::controller::rss_feed,
+        crate::app::features::feed::interface::controller::atom_feed,
+        crate::app::features::feed::interface::controller::json_feed,
+        crate::app::features::feed::interface::controller::category_rss_feed,
+        crate::app::features::feed::interface::controller::category_atom_feed,
+        crate::app::features::feed::interface::controller::category_json_feed,
+        crate::app::features::feed::interface::controller::tag_rss_feed,
+        crate::app::features::feed::interface::controller::tag_atom_feed,
+        crate::app::features::feed::interface::controller::tag_json_feed,
+        // SEO
+        crate::app::features::sitemap::interface::controller::get_sitemap,
+        crate::app::features::sitemap::interface::controller::get_sitemap_page,
+        crate::app::features::sitemap::interface::controller::get_robots_txt,
+        // Slug History
+        crate::app::features::slug_history::interface::controller::get_slug_history,
+        crate::app::features::slug_history::interface::controller::prune_slug_history,
+        crate::app::features::slug_history::interface::controller::delete_slug_history,
+        // Media
+        crate::app::features::media::interface::controller::upload_media,
+        crate::app::features::media::interface::controller::get_all_media,
+        crate::app::features::media::interface::controller::get_media,
+        crate::app::features::media::interface::controller::delete_media,
+        crate::app::features::media::interface::controller::serve_media,
+        // Series
+        crate::app::features::series::interface::controller::create_series,
+        crate::app::features::series::interface::controller::get_all_series,
+        crate::app::features::series::interface::controller::get_series,
+        crate::app::features::series::interface::controller::update_series,
+        crate::app::features::series::interface::controller::delete_series,
+        crate::app::features::series::interface::controller::get_public_series,
+        // Authors
+        crate::app::features::authors::interface::controller::get_public_authors,
+        crate::app::features::authors::interface::controller::get_public_author,
+        crate::app::features::authors::interface::controller::update_current_author,
+        // Previews
+        crate::app::features::previews::interface::controller::create_preview_token,
+        crate::app::features::previews::interface::controller::get_preview_tokens,
+        crate::app::features::previews::interface::controller::revoke_preview_token,
+        crate::app::features::previews::interface::controller::get_preview,
+        // Analytics
+        crate::app::features::analytics::interface::controller::get_blog_views,
+        crate::app::features::analytics::interface::controller::get_top_posts,
+        // Dashboard
+        crate::app::features::dashboard::interface::controller::get_dashboard,
+        // Newsletter
+        crate::app::features::subscribers::interface::controller::get_subscribers,
+        crate::app::features::subscribers::interface::controller::subscribe,
+        crate::app::features::subscribers::interface::controller::confirm_subscription,
+        crate::app::features::subscribers::interface::controller::unsubscribe,
+        crate::app::features::subscribers::interface::controller::unsubscribe_one_click,
+        // Webhooks
+        crate::app::features::webhooks::interface::controller::create_webhook,
+        crate::app::features::webhooks::interface::controller::get_webhooks,
+        crate::app::features::webhooks::interface::controller::get_webhook,
+        crate::app::features::webhooks::interface::controller::update_webhook,
+        crate::app::features::webhooks::interface::controller::delete_webhook,
+        crate::app::features::webhooks::interface::controller::get_webhook_deliveries,
+        crate::app::features::webhooks::interface::controller::redeliver_webhook,
+        // Import
+        crate::app::features::importer::interface::controller::import_posts,
         crate::app::features::blog::interface::controller::get_blog,
         crate::app::features::blog::interface::controller::update_blog,
         crate::app::features::blog::interface::controller::delete_blog,
+        crate::app::features::blog::interface::controller::bulk_blogs,
         // Projects
         crate::app::features::projects::interface::controller::create_project,
         crate::app::features::projects::interface::controller::get_all_projects,
@@ -54,6 +132,15 @@ use utoipa::{
         // Profile
         crate::app::features::profile::interface::controller::get_profile,
         crate::app::features::profile::interface::controller::upsert_profile,
+        // Public
+        crate::app::features::blog::interface::controller::get_public_blogs,
+        crate::app::features::blog::interface::controller::get_public_blog,
+        crate::app::features::blog::interface::controller::search_public_blogs,
+        crate::app::features::projects::interface::controller::get_public_projects,
+        crate::app::features::projects::interface::controller::get_public_project,
+        crate::app::features::portofolio::interface::controller::get_public_portfolios,
+        crate::app::features::portofolio::interface::controller::get_public_portfolio,
+        crate::app::features::profile::interface::controller::get_public_profile,
     ),
     components(
         schemas(
@@ -69,10 +156,76 @@ use utoipa::{
             // Blog
             crate::app::features::blog::interface::dto::CreateCategoryRequestDto,
             crate::app::features::blog::interface::dto::CategoryResponseDto,
+            crate::app::features::blog::interface::dto::CategoryBreadcrumbDto,
+            crate::app::features::blog::interface::dto::CategoryTreeNodeDto,
+            crate::app::features::blog::interface::dto::BulkBlogRequestDto,
+            crate::app::features::blog::interface::dto::BulkBlogResultDto,
+            crate::app::features::blog::interface::dto::BulkBlogResponseDto,
             crate::app::features::blog::interface::dto::CreateTagRequestDto,
             crate::app::features::blog::interface::dto::TagResponseDto,
+            crate::app::features::blog::interface::dto::MergeTagRequestDto,
+            crate::app::features::blog::interface::dto::MergeTagResponseDto,
+            crate::app::features::blog::interface::dto::TagCloudEntryDto,
             crate::app::features::blog::interface::dto::CreateBlogRequestDto,
             crate::app::features::blog::interface::dto::BlogResponseDto,
+            crate::app::features::blog::interface::dto::SearchBlogRequestDto,
+            crate::app::features::blog::interface::dto::BlogSearchResultDto,
+            crate::app::features::blog::interface::dto::BlogRevisionResponseDto,
+            crate::app::features::blog::interface::dto::BlogRevisionDiffResponseDto,
+            crate::app::features::blog::interface::dto::DiffLineDto,
+            crate::app::features::blog::interface::dto::RelatedBlogResponseDto,
+            crate::app::features::comments::interface::dto::CreateCommentRequestDto,
+            crate::app::features::comments::interface::dto::ModerateCommentsRequestDto,
+            crate::app::features::comments::interface::dto::ModerateCommentsResponseDto,
+            crate::app::features::comments::interface::dto::CommentResponseDto,
+            crate::app::features::comments::interface::dto::PublicCommentDto,
+            crate::app::features::feed::interface::dto::JsonFeedDto,
+            crate::app::features::feed::interface::dto::JsonFeedAuthorDto,
+            crate::app::features::feed::interface::dto::JsonFeedItemDto,
+            crate::app::features::feed::interface::dto::JsonFeedItemAuthorDto,
+            crate::app::features::slug_history::interface::dto::SlugHistoryResponseDto,
+            crate::app::features::slug_history::interface::dto::PruneSlugHistoryRequestDto,
+            crate::app::features::slug_history::interface::dto::PruneSlugHistoryResponseDto,
+            crate::app::features::media::interface::dto::UploadMediaForm,
+            crate::app::features::media::interface::dto::MediaResponseDto,
+            crate::app::features::media::interface::dto::ImageSourceDto,
+            crate::app::features::media::interface::dto::ResponsiveImageDto,
+            crate::app::features::series::interface::dto::CreateSeriesRequestDto,
+            crate::app::features::series::interface::dto::UpdateSeriesRequestDto,
+            crate::app::features::series::interface::dto::SeriesResponseDto,
+            crate::app::features::series::interface::dto::SeriesPostDto,
+            crate::app::features::series::interface::dto::BlogSeriesDto,
+            crate::app::features::series::interface::dto::SeriesPostLinkDto,
+            crate::app::features::authors::interface::dto::AuthorDto,
+            crate::app::features::authors::interface::dto::AuthorPostDto,
+            crate::app::features::authors::interface::dto::AuthorResponseDto,
+            crate::app::features::authors::interface::dto::UpdateAuthorRequestDto,
+            crate::app::features::previews::interface::dto::CreatePreviewTokenRequestDto,
+            crate::app::features::previews::interface::dto::PreviewTokenResponseDto,
+            crate::app::features::analytics::interface::dto::DailyViewsDto,
+            crate::app::features::analytics::interface::dto::BlogViewsResponseDto,
+            crate::app::features::analytics::interface::dto::TopPostDto,
+            crate::app::features::analytics::interface::dto::TopPostsResponseDto,
+            crate::app::features::dashboard::interface::dto::StatusCountDto,
+            crate::app::features::dashboard::interface::dto::PostStatsDto,
+            crate::app::features::dashboard::interface::dto::ProjectStatusStatsDto,
+            crate::app::features::dashboard::interface::dto::ProjectStatsDto,
+            crate::app::features::dashboard::interface::dto::PortofolioStatsDto,
+            crate::app::features::dashboard::interface::dto::DashboardPostDto,
+            crate::app::features::dashboard::interface::dto::RecentItemDto,
+            crate::app::features::dashboard::interface::dto::StaleDraftDto,
+            crate::app::features::dashboard::interface::dto::StaleDraftsDto,
+            crate::app::features::dashboard::interface::dto::DashboardResponseDto,
+            crate::app::features::subscribers::interface::dto::SubscribeRequestDto,
+            crate::app::features::subscribers::interface::dto::SubscriberResponseDto,
+            crate::app::features::subscribers::interface::dto::SubscriptionStatusDto,
+            crate::app::features::webhooks::interface::dto::CreateWebhookDto,
+            crate::app::features::webhooks::interface::dto::UpdateWebhookDto,
+            crate::app::features::webhooks::interface::dto::WebhookResponseDto,
+            crate::app::features::webhooks::interface::dto::WebhookDeliveryDto,
+            crate::app::features::importer::interface::dto::ImportForm,
+            crate::app::features::importer::interface::dto::ImportItemDto,
+            crate::app::features::importer::interface::dto::ImportReportDto,
             // Projects
             crate::app::features::projects::interface::dto::CreateProjectRequestDto,
             crate::app::features::projects::interface::dto::UpdateProjectRequestDto,
@@ -102,6 +255,20 @@ use utoipa::{
         (name = "Projects", description = "Project portfolio management"),
         (name = "Portofolios", description = "Individual portofolio items"),
         (name = "Profile", description = "User profile management"),
+        (name = "Comments", description = "Comment moderation"),
+        (name = "Feeds", description = "RSS, Atom and JSON feeds of published posts"),
+        (name = "SEO", description = "Sitemap and robots.txt"),
+        (name = "Slug History", description = "Retired slugs and their redirects"),
+        (name = "Media", description = "Uploaded images and files"),
+        (name = "Series", description = "Multi-part posts read in order"),
+        (name = "Authors", description = "People who write the posts"),
+        (name = "Previews", description = "Signed links for reading unpublished posts"),
+        (name = "Analytics", description = "Unique post views per day"),
+        (name = "Dashboard", description = "Content overview for the admin home page"),
+        (name = "Newsletter", description = "Email subscribers and new post announcements"),
+        (name = "Webhooks", description = "Signed HTTP callbacks for content changes"),
+        (name = "Import", description = "WordPress and Markdown importer"),
+        (name = "Public", description = "Public read-only content (no authentication)"),
     ),
     info(
         title = "MyBlog API",
@@ -7,6 +7,19 @@ pub fn routes(cfg: &mut web::ServiceConfig) {
         web::JsonConfig::default().error_handler(crate::utils::error_response::json_error_handler),
     )
     .service(app::features::home::interface::controller::index)
+    .service(app::features::sitemap::interface::controller::get_sitemap)
+    .service(app::features::sitemap::interface::controller::get_sitemap_page)
+    .service(app::features::sitemap::interface::controller::get_robots_txt)
+    .service(app::features::media::interface::controller::serve_media)
+    .service(app::features::feed::interface::controller::rss_feed)
+    .service(app::features::feed::interface::controller::atom_feed)
+    .service(app::features::feed::interface::controller::json_feed)
+    .service(app::features::feed::interface::controller::category_rss_feed)
+    .service(app::features::feed::interface::controller::category_atom_feed)
+    .service(app::features::feed::interface::controller::category_json_feed)
+    .service(app::features::feed::interface::controller::tag_rss_feed)
+    .service(app::features::feed::interface::controller::tag_atom_feed)
+    .service(app::features::feed::interface::controller::tag_json_feed)
     .service(
         web::scope("/app")
             .wrap(app::drivers::middlewares::auth::Auth)
@@ -14,6 +27,7 @@ pub fn routes(cfg: &mut web::ServiceConfig) {
             .service(app::features::home::interface::controller::send_email)
             .service(app::features::blog::interface::controller::create_category)
             .service(app::features::blog::interface::controller::get_categories)
+            .service(app::features::blog::interface::controller::get_category_tree)
             .service(app::features::blog::interface::controller::get_category)
             .service(app::features::blog::interface::controller::update_category)
             .service(app::features::blog::interface::controller::delete_category)
@@ -22,12 +36,50 @@ pub fn routes(cfg: &mut web::ServiceConfig) {
             .service(app::features::blog::interface::controller::get_tag)
             .service(app::features::blog::interface::controller::update_tag)
             .service(app::features::blog::interface::controller::delete_tag)
+            .service(app::features::blog::interface::controller::merge_tag)
             .service(app::features::blog::interface::controller::create_blog)
             .service(app::features::blog::interface::controller::get_blogs)
+            .service(app::features::blog::interface::controller::search_blogs)
             .service(app::features::blog::interface::controller::get_blog)
+            .service(app::features::blog::interface::controller::get_related_blogs)
+            .service(app::features::blog::interface::controller::get_blog_revisions)
+            .service(app::features::blog::interface::controller::diff_blog_revisions)
+            .service(app::features::blog::interface::controller::get_blog_revision)
+            .service(app::features::blog::interface::controller::restore_blog_revision)
+            .service(app::features::comments::interface::controller::get_comments)
+            .service(app::features::comments::interface::controller::moderate_comments)
+            .service(app::features::comments::interface::controller::delete_comment)
+            .service(app::features::slug_history::interface::controller::get_slug_history)
+            .service(app::features::slug_history::interface::controller::prune_slug_history)
+            .service(app::features::slug_history::interface::controller::delete_slug_history)
+            .service(app::features::media::interface::controller::upload_media)
+            .service(app::features::media::interface::controller::get_all_media)
+            .service(app::features::media::interface::controller::get_media)
+            .service(app::features::media::interface::controller::delete_media)
+            .service(app::features::previews::interface::controller::create_preview_token)
+            .service(app::features::previews::interface::controller::get_preview_tokens)
+            .service(app::features::previews::interface::controller::revoke_preview_token)
+            .service(app::features::analytics::interface::controller::get_blog_views)
+            .service(app::features::analytics::interface::controller::get_top_posts)
+            .service(app::features::dashboard::interface::controller::get_dashboard)
+            .service(app::features::subscribers::interface::controller::get_subscribers)
+            .service(app::features::webhooks::interface::controller::create_webhook)
+            .service(app::features::webhooks::interface::controller::get_webhooks)
+            .service(app::features::webhooks::interface::controller::redeliver_webhook)
+            .service(app::features::webhooks::interface::controller::get_webhook)
+            .service(app::features::webhooks::interface::controller::update_webhook)
+            .service(app::features::webhooks::interface::controller::delete_webhook)
+            .service(app::features::webhooks::interface::controller::get_webhook_deliveries)
+            .service(app::features::importer::interface::controller::import_posts)
+            .service(app::features::series::interface::controller::create_series)
+            .service(app::features::series::interface::controller::get_all_series)
+            .service(app::features::series::interface::controller::get_series)
+            .service(app::features::series::interface::controller::update_series)
+            .service(app::features::series::interface::controller::delete_series)
             .service(app::features::blog::interface::controller::update_blog)
             .service(app::features::blog::interface::controller::update_blog)
             .service(app::features::blog::interface::controller::delete_blog)
+            .service(app::features::blog::interface::controller::bulk_blogs)
             .service(app::features::projects::interface::controller::create_project)
             .service(app::features::projects::interface::controller::get_all_projects)
             .service(app::features::projects::interface::controller::get_project)
@@ -45,8 +97,32 @@ pub fn routes(cfg: &mut web::ServiceConfig) {
             .service(app::features::portofolio::interface::controller::delete_portfolio)
             .service(app::features::profile::interface::controller::get_profile)
             .service(app::features::profile::interface::controller::upsert_profile)
+            .service(app::features::authors::interface::controller::update_current_author)
             .service(app::features::auth::interface::controller::is_login),
     )
+    .service(
+        web::scope("/api/public")
+            .service(app::features::blog::interface::controller::get_public_blogs)
+            .service(app::features::blog::interface::controller::search_public_blogs)
+            .service(app::features::blog::interface::controller::get_public_blog)
+            .service(app::features::blog::interface::controller::get_public_related_blogs)
+            .service(app::features::blog::interface::controller::get_public_tag_cloud)
+            .service(app::features::series::interface::controller::get_public_series)
+            .service(app::features::authors::interface::controller::get_public_authors)
+            .service(app::features::authors::interface::controller::get_public_author)
+            .service(app::features::previews::interface::controller::get_preview)
+            .service(app::features::subscribers::interface::controller::subscribe)
+            .service(app::features::subscribers::interface::controller::confirm_subscription)
+            .service(app::features::subscribers::interface::controller::unsubscribe)
+            .service(app::features::subscribers::interface::controller::unsubscribe_one_click)
+            .service(app::features::comments::interface::controller::get_public_comments)
+            .service(app::features::comments::interface::controller::create_public_comment)
+            .service(app::features::projects::interface::controller::get_public_projects)
+            .service(app::features::projects::interface::controller::get_public_project)
+            .service(app::features::portofolio::interface::controller::get_public_portfolios)
+            .service(app::features::portofolio::interface::controller::get_public_portfolio)
+            .service(app::features::profile::interface::controller::get_public_profile),
+    )
     .service(app::features::auth::interface::controller::login)
     .service(app::features::auth::interface::controller::logout)
     .service(app::features::auth::interface::controller::forgot_password)
@@ -0,0 +1,76 @@
+use crate::app::features::webhooks::domain::entity::WebhookEvent;
+use crate::app::features::webhooks::interface::controller::emit_event;
+use crate::utils::di::Container;
+use actix_web::{rt, web};
+use std::time::Duration;
+
+/// Runs `publish_scheduled_blogs_usecase` every `publish_interval_secs` on the
+/// actix runtime so SCHEDULED posts go live without a request triggering it,
+/// then announces them to webhooks and newsletter subscribers.
+pub fn spawn_scheduled_publisher(container: Container) {
+    let period = Duration::from_secs(container.config.publish_interval_secs.max(1));
+
+    rt::spawn(async move {
+        let mut interval = rt::time::interval(period);
+        loop {
+            interval.tick().await;
+            match container.publish_scheduled_blogs_usecase.execute().await {
+                Ok(published) => {
+                    for blog in published {
+                        match container.get_blog_usecase.execute(blog.id).await {
+                            Ok(data) => {
+                                emit_event(&container, WebhookEvent::BlogPublished, &data).await
+                            }
+                            Err(e) => eprintln!("webhook blog.published failed: {:?}", e),
+                        }
+                        if let Err(e) = container.notify_subscribers_usecase.execute(blog.id).await
+                        {
+                            eprintln!("newsletter notification failed: {:?}", e);
+                        }
+                    }
+                }
+                Err(e) => eprintln!("scheduled publisher failed: {:?}", e),
+            }
+        }
+    });
+}
+
+/// Sends queued confirmation and newsletter emails every
+/// `newsletter_interval_secs`.
+pub fn spawn_newsletter_sender(container: Container) {
+    let period = Duration::from_secs(container.config.newsletter_interval_secs.max(1));
+
+    rt::spawn(async move {
+        let mut interval = rt::time::interval(period);
+        loop {
+            interval.tick().await;
+            // SMTP blocks; keep it off the async workers
+            let usecase = container.send_newsletter_emails_usecase.clone();
+            match web::block(move || usecase.execute()).await {
+                Ok(Ok(_)) => {}
+                Ok(Err(e)) => eprintln!("newsletter sender failed: {:?}", e),
+                Err(e) => eprintln!("newsletter sender failed: {}", e),
+            }
+        }
+    });
+}
+
+/// Sends due webhook deliveries every `webhook_interval_secs`, including
+/// retries whose backoff has elapsed.
+pub fn spawn_webhook_deliverer(container: Container) {
+    let period = Duration::from_secs(container.config.webhook_interval_secs.max(1));
+
+    rt::spawn(async move {
+        let mut interval = rt::time::interval(period);
+        loop {
+            interval.tick().await;
+            // Webhook endpoints may be slow; keep the HTTP calls off the async workers
+            let usecase = container.deliver_webhooks_usecase.clone();
+            match web::block(move || usecase.execute()).await {
+                Ok(Ok(_)) => {}
+                Ok(Err(e)) => eprintln!("webhook deliverer failed: {:?}", e),
+                Err(e) => eprintln!("webhook deliverer failed: {}", e),
+            }
+        }
+    });
+}
@@ -0,0 +1,69 @@
+use crate::app::features::analytics::application::usecase::range::resolve_range;
+use crate::app::features::analytics::domain::error::AnalyticsError;
+use crate::app::features::analytics::domain::repository::AnalyticsRepository;
+use crate::app::features::analytics::interface::dto::{
+    BlogViewsResponseDto, DailyViewsDto, ViewsRangeQueryDto,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+use validator::ValidationErrors;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn AnalyticsRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn AnalyticsRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        blog_id: i32,
+        query: ViewsRangeQueryDto,
+    ) -> Result<BlogViewsResponseDto, AnalyticsError> {
+        let mut validation_errors = ValidationErrors::new();
+        let range = resolve_range(
+            query.from.as_deref(),
+            query.to.as_deref(),
+            &mut validation_errors,
+        );
+        let Some((from, to)) = range else {
+            return Err(AnalyticsError::Validation(validation_errors));
+        };
+
+        if !self
+            .repository
+            .blog_exists(blog_id)
+            .map_err(|e| AnalyticsError::System(e.to_string()))?
+        {
+            return Err(AnalyticsError::NotFound("Blog not found".to_string()));
+        }
+
+        let counted: HashMap<_, _> = self
+            .repository
+            .get_daily_views(blog_id, from, to)
+            .map_err(|e| AnalyticsError::System(e.to_string()))?
+            .into_iter()
+            .map(|d| (d.day, d.views as i64))
+            .collect();
+
+        let days: Vec<DailyViewsDto> = from
+            .iter_days()
+            .take_while(|day| *day <= to)
+            .map(|day| DailyViewsDto {
+                date: day.to_string(),
+                views: counted.get(&day).copied().unwrap_or(0),
+            })
+            .collect();
+
+        Ok(BlogViewsResponseDto {
+            blog_id,
+            from: from.to_string(),
+            to: to.to_string(),
+            total_views: days.iter().map(|d| d.views).sum(),
+            days,
+        })
+    }
+}
@@ -0,0 +1,4 @@
+pub mod daily_views;
+pub mod range;
+pub mod record_view;
+pub mod top_posts;
@@ -0,0 +1,51 @@
+use chrono::{Days, NaiveDate, Utc};
+use validator::{ValidationError, ValidationErrors};
+
+/// Days covered when the caller gives no `from`.
+pub const DEFAULT_RANGE_DAYS: u64 = 30;
+/// Longest range a single request may ask for.
+pub const MAX_RANGE_DAYS: i64 = 366;
+
+/// Parses an inclusive `from`..=`to` day range, defaulting to the last
+/// `DEFAULT_RANGE_DAYS` days up to today (UTC).
+pub fn resolve_range(
+    from: Option<&str>,
+    to: Option<&str>,
+    errors: &mut ValidationErrors,
+) -> Option<(NaiveDate, NaiveDate)> {
+    let to = match to {
+        Some(raw) => parse_day("to", raw, errors),
+        None => Some(Utc::now().date_naive()),
+    };
+    let from = match from {
+        Some(raw) => parse_day("from", raw, errors),
+        None => to.and_then(|to| to.checked_sub_days(Days::new(DEFAULT_RANGE_DAYS - 1))),
+    };
+    let (from, to) = (from?, to?);
+
+    if from > to {
+        errors.add("to", ValidationError::new("to must not be before from"));
+        return None;
+    }
+    if (to - from).num_days() >= MAX_RANGE_DAYS {
+        errors.add(
+            "from",
+            ValidationError::new("Range must not exceed 366 days"),
+        );
+        return None;
+    }
+    Some((from, to))
+}
+
+fn parse_day(field: &'static str, raw: &str, errors: &mut ValidationErrors) -> Option<NaiveDate> {
+    match NaiveDate::parse_from_str(raw, "%Y-%m-%d") {
+        Ok(date) => Some(date),
+        Err(_) => {
+            errors.add(
+                field,
+                ValidationError::new("Invalid date format (YYYY-MM-DD)"),
+            );
+            None
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::app::features::analytics::domain::entity::visitor_fingerprint;
+use crate::app::features::analytics::domain::error::AnalyticsError;
+use crate::app::features::analytics::domain::repository::AnalyticsRepository;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn AnalyticsRepository + Send + Sync>,
+    secret: String,
+    window_minutes: i64,
+}
+
+impl Execute {
+    pub fn new(
+        repository: Arc<dyn AnalyticsRepository + Send + Sync>,
+        secret: String,
+        window_minutes: i64,
+    ) -> Self {
+        Self {
+            repository,
+            secret,
+            window_minutes,
+        }
+    }
+
+    /// Counts a read of `blog_id` once per visitor per window. Returns whether
+    /// this read was counted.
+    pub async fn execute(
+        &self,
+        blog_id: i32,
+        ip: &str,
+        user_agent: &str,
+    ) -> Result<bool, AnalyticsError> {
+        let now = Utc::now().naive_utc();
+        let window_start = now - Duration::minutes(self.window_minutes);
+
+        self.repository
+            .record_view(
+                blog_id,
+                visitor_fingerprint(&self.secret, ip, user_agent),
+                now,
+                window_start,
+            )
+            .map_err(|e| AnalyticsError::System(e.to_string()))
+    }
+}
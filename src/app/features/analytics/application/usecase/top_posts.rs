@@ -0,0 +1,57 @@
+use crate::app::features::analytics::application::usecase::range::resolve_range;
+use crate::app::features::analytics::domain::error::AnalyticsError;
+use crate::app::features::analytics::domain::repository::AnalyticsRepository;
+use crate::app::features::analytics::interface::dto::{
+    TopPostDto, TopPostsQueryDto, TopPostsResponseDto,
+};
+use std::sync::Arc;
+use validator::Validate;
+
+pub const DEFAULT_TOP_POSTS_LIMIT: i64 = 10;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn AnalyticsRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn AnalyticsRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        query: TopPostsQueryDto,
+    ) -> Result<TopPostsResponseDto, AnalyticsError> {
+        let mut validation_errors = query.validate().err().unwrap_or_default();
+        let range = resolve_range(
+            query.from.as_deref(),
+            query.to.as_deref(),
+            &mut validation_errors,
+        );
+        let (from, to) = match range {
+            Some(range) if validation_errors.is_empty() => range,
+            _ => return Err(AnalyticsError::Validation(validation_errors)),
+        };
+
+        let items = self
+            .repository
+            .get_top_posts(from, to, query.limit.unwrap_or(DEFAULT_TOP_POSTS_LIMIT))
+            .map_err(|e| AnalyticsError::System(e.to_string()))?
+            .into_iter()
+            .map(|p| TopPostDto {
+                id: p.id,
+                title: p.title,
+                slug: p.slug,
+                status: p.status,
+                views: p.views.unwrap_or(0),
+            })
+            .collect();
+
+        Ok(TopPostsResponseDto {
+            from: from.to_string(),
+            to: to.to_string(),
+            items,
+        })
+    }
+}
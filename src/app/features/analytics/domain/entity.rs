@@ -0,0 +1,31 @@
+use chrono::NaiveDate;
+use diesel::prelude::*;
+use sha2::{Digest, Sha256};
+
+#[derive(Queryable, Debug, Clone, PartialEq)]
+pub struct DailyViews {
+    pub day: NaiveDate,
+    pub views: i32,
+}
+
+/// A post ranked by the views it got in a date range.
+#[derive(Queryable, Debug, Clone, PartialEq)]
+pub struct TopPost {
+    pub id: i32,
+    pub title: String,
+    pub slug: String,
+    pub status: String,
+    pub views: Option<i64>,
+}
+
+/// Identifies a visitor without storing their IP or user agent. The secret
+/// keeps the hash from being reversed by trying every IP address.
+pub fn visitor_fingerprint(secret: &str, ip: &str, user_agent: &str) -> String {
+    let mut hasher = Sha256::new();
+    for part in [secret, ip, user_agent] {
+        hasher.update(part.as_bytes());
+        // Separator, so ("ab", "c") and ("a", "bc") hash differently
+        hasher.update([0u8]);
+    }
+    format!("{:x}", hasher.finalize())
+}
@@ -0,0 +1,14 @@
+use validator::ValidationErrors;
+
+#[derive(Debug)]
+pub enum AnalyticsError {
+    Validation(ValidationErrors),
+    System(String),
+    NotFound(String),
+}
+
+impl From<String> for AnalyticsError {
+    fn from(s: String) -> Self {
+        AnalyticsError::System(s)
+    }
+}
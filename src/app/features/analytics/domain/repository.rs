@@ -0,0 +1,30 @@
+use crate::app::features::analytics::domain::entity::{DailyViews, TopPost};
+use chrono::{NaiveDate, NaiveDateTime};
+use diesel::QueryResult;
+
+pub trait AnalyticsRepository {
+    /// Counts a view unless `fingerprint` was already counted for `blog_id`
+    /// after `window_start`. Returns whether the view was counted.
+    fn record_view(
+        &self,
+        blog_id: i32,
+        fingerprint: String,
+        now: NaiveDateTime,
+        window_start: NaiveDateTime,
+    ) -> QueryResult<bool>;
+    fn blog_exists(&self, blog_id: i32) -> QueryResult<bool>;
+    /// Days with at least one view between `from` and `to` (inclusive), oldest first.
+    fn get_daily_views(
+        &self,
+        blog_id: i32,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> QueryResult<Vec<DailyViews>>;
+    /// Most viewed posts between `from` and `to` (inclusive).
+    fn get_top_posts(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        limit: i64,
+    ) -> QueryResult<Vec<TopPost>>;
+}
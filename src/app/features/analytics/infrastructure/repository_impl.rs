@@ -0,0 +1,115 @@
+use crate::app::features::analytics::domain::entity::{DailyViews, TopPost};
+use crate::app::features::analytics::domain::repository::AnalyticsRepository;
+use crate::schema::{blog, blog_view_fingerprints, blog_views_daily};
+use crate::utils::db::DbPool;
+use chrono::{NaiveDate, NaiveDateTime};
+use diesel::dsl::sum;
+use diesel::prelude::*;
+
+#[derive(Clone)]
+pub struct AnalyticsRepositoryImpl {
+    pub pool: DbPool,
+}
+
+impl AnalyticsRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl AnalyticsRepository for AnalyticsRepositoryImpl {
+    fn record_view(
+        &self,
+        blog_id: i32,
+        fingerprint: String,
+        now: NaiveDateTime,
+        window_start: NaiveDateTime,
+    ) -> QueryResult<bool> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        conn.transaction(|conn| {
+            // Older entries can no longer suppress a view, so any row left is a repeat
+            diesel::delete(
+                blog_view_fingerprints::table
+                    .filter(blog_view_fingerprints::counted_at.le(window_start)),
+            )
+            .execute(conn)?;
+            let inserted = diesel::insert_or_ignore_into(blog_view_fingerprints::table)
+                .values((
+                    blog_view_fingerprints::blog_id.eq(blog_id),
+                    blog_view_fingerprints::fingerprint.eq(fingerprint),
+                    blog_view_fingerprints::counted_at.eq(now),
+                ))
+                .execute(conn)?;
+            if inserted == 0 {
+                return Ok(false);
+            }
+
+            diesel::insert_into(blog_views_daily::table)
+                .values((
+                    blog_views_daily::blog_id.eq(blog_id),
+                    blog_views_daily::day.eq(now.date()),
+                    blog_views_daily::views.eq(1),
+                ))
+                .on_conflict((blog_views_daily::blog_id, blog_views_daily::day))
+                .do_update()
+                .set(blog_views_daily::views.eq(blog_views_daily::views + 1))
+                .execute(conn)?;
+            diesel::update(blog::table.find(blog_id))
+                .set(blog::view_count.eq(blog::view_count + 1))
+                .execute(conn)?;
+            Ok(true)
+        })
+    }
+    fn blog_exists(&self, blog_id: i32) -> QueryResult<bool> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::select(diesel::dsl::exists(blog::table.find(blog_id))).get_result(&mut conn)
+    }
+    fn get_daily_views(
+        &self,
+        blog_id: i32,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> QueryResult<Vec<DailyViews>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        blog_views_daily::table
+            .filter(blog_views_daily::blog_id.eq(blog_id))
+            .filter(blog_views_daily::day.between(from, to))
+            .order(blog_views_daily::day.asc())
+            .select((blog_views_daily::day, blog_views_daily::views))
+            .load::<DailyViews>(&mut conn)
+    }
+    fn get_top_posts(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        limit: i64,
+    ) -> QueryResult<Vec<TopPost>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        blog_views_daily::table
+            .inner_join(blog::table)
+            .filter(blog_views_daily::day.between(from, to))
+            .group_by((blog::id, blog::title, blog::slug, blog::status))
+            .select((
+                blog::id,
+                blog::title,
+                blog::slug,
+                blog::status,
+                sum(blog_views_daily::views),
+            ))
+            .order((sum(blog_views_daily::views).desc(), blog::id.asc()))
+            .limit(limit)
+            .load::<TopPost>(&mut conn)
+    }
+}
@@ -0,0 +1,81 @@
+use crate::app::features::analytics::domain::error::AnalyticsError;
+use crate::app::features::analytics::interface::dto::{
+    BlogViewsResponseDto, TopPostsQueryDto, TopPostsResponseDto, ViewsRangeQueryDto,
+};
+use crate::utils::di::Container;
+use crate::utils::error_response::{ErrorResponse, map_string_error, map_validation_error};
+use crate::utils::success_response::map_success_with_data;
+use actix_web::{HttpResponse, Responder, get, web};
+
+fn analytics_error_response(e: AnalyticsError) -> HttpResponse {
+    match e {
+        AnalyticsError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+        AnalyticsError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+        AnalyticsError::System(msg) => {
+            HttpResponse::InternalServerError().json(map_string_error(msg))
+        }
+    }
+}
+
+#[utoipa::path(
+    path = "/app/analytics/blogs/{id}/views",
+    tag = "Analytics",
+    params(
+        ("id" = i32, Path, description = "Blog id"),
+        ViewsRangeQueryDto
+    ),
+    responses(
+        (status = 200, description = "Unique views of the post per day", body = crate::utils::success_response::SuccessResponse<BlogViewsResponseDto>),
+        (status = 400, description = "Invalid date range", body = ErrorResponse),
+        (status = 404, description = "Blog not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[get("/analytics/blogs/{id}/views")]
+pub async fn get_blog_views(
+    container: web::Data<Container>,
+    id: web::Path<i32>,
+    query: web::Query<ViewsRangeQueryDto>,
+) -> impl Responder {
+    match container
+        .get_blog_views_usecase
+        .execute(id.into_inner(), query.into_inner())
+        .await
+    {
+        Ok(views) => HttpResponse::Ok().json(map_success_with_data(
+            "Blog views fetched successfully".to_string(),
+            views,
+        )),
+        Err(e) => analytics_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    path = "/app/analytics/top-posts",
+    tag = "Analytics",
+    params(
+        TopPostsQueryDto
+    ),
+    responses(
+        (status = 200, description = "Most viewed posts in the range", body = crate::utils::success_response::SuccessResponse<TopPostsResponseDto>),
+        (status = 400, description = "Invalid date range or limit", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[get("/analytics/top-posts")]
+pub async fn get_top_posts(
+    container: web::Data<Container>,
+    query: web::Query<TopPostsQueryDto>,
+) -> impl Responder {
+    match container
+        .get_top_posts_usecase
+        .execute(query.into_inner())
+        .await
+    {
+        Ok(top) => HttpResponse::Ok().json(map_success_with_data(
+            "Top posts fetched successfully".to_string(),
+            top,
+        )),
+        Err(e) => analytics_error_response(e),
+    }
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Deserialize, Serialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ViewsRangeQueryDto {
+    /// First day (YYYY-MM-DD, UTC); 29 days before `to` when omitted
+    pub from: Option<String>,
+    /// Last day (YYYY-MM-DD, UTC), inclusive; today when omitted
+    pub to: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TopPostsQueryDto {
+    /// First day (YYYY-MM-DD, UTC); 29 days before `to` when omitted
+    pub from: Option<String>,
+    /// Last day (YYYY-MM-DD, UTC), inclusive; today when omitted
+    pub to: Option<String>,
+    /// Defaults to 10
+    #[validate(range(min = 1, max = 100, message = "Must be between 1 and 100"))]
+    pub limit: Option<i64>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct DailyViewsDto {
+    /// YYYY-MM-DD
+    pub date: String,
+    pub views: i64,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct BlogViewsResponseDto {
+    pub blog_id: i32,
+    pub from: String,
+    pub to: String,
+    pub total_views: i64,
+    /// Every day of the range, oldest first, including days without views
+    pub days: Vec<DailyViewsDto>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct TopPostDto {
+    pub id: i32,
+    pub title: String,
+    pub slug: String,
+    pub status: String,
+    /// Unique views within the range
+    pub views: i64,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct TopPostsResponseDto {
+    pub from: String,
+    pub to: String,
+    pub items: Vec<TopPostDto>,
+}
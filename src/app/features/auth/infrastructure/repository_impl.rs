@@ -25,7 +25,8 @@ impl UserRepository for UserRepositoryImpl {
             .expect("couldn't get db connection from pool");
         users::table
             .filter(users::id.eq(get_id))
-            .first::<User>(&mut conn)
+            .select(User::as_select())
+            .first(&mut conn)
             .optional()
     }
     fn get_where(&self, name: String, pass: String) -> QueryResult<Option<User>> {
@@ -36,7 +37,8 @@ impl UserRepository for UserRepositoryImpl {
         users::table
             .filter(users::username.eq(name))
             .filter(users::password.eq(pass))
-            .first::<User>(&mut conn)
+            .select(User::as_select())
+            .first(&mut conn)
             .optional()
     }
     fn reset_password(&self, name: String, pass: String) -> QueryResult<User> {
@@ -47,6 +49,7 @@ impl UserRepository for UserRepositoryImpl {
 
         diesel::update(users::table.filter(users::username.eq(name)))
             .set(users::password.eq(pass))
+            .returning(User::as_returning())
             .get_result(&mut conn)
     }
 
@@ -56,8 +59,9 @@ impl UserRepository for UserRepositoryImpl {
             .get()
             .expect("couldn't get db connection from pool");
 
+        // SQLite assigns the id, so the first account still gets 1
         let new_user = User {
-            id: Some(1),
+            id: None,
             username: name,
             email: mail,
             password: pass,
@@ -65,6 +69,7 @@ impl UserRepository for UserRepositoryImpl {
 
         diesel::insert_into(users::table)
             .values(&new_user)
+            .returning(User::as_returning())
             .get_result(&mut conn)
     }
 
@@ -75,7 +80,8 @@ impl UserRepository for UserRepositoryImpl {
             .expect("couldn't get db connection from pool");
         users::table
             .filter(users::email.eq(mail))
-            .first::<User>(&mut conn)
+            .select(User::as_select())
+            .first(&mut conn)
             .optional()
     }
 }
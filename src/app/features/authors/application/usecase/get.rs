@@ -0,0 +1,53 @@
+use crate::app::features::authors::domain::error::AuthorError;
+use crate::app::features::authors::domain::repository::AuthorRepository;
+use crate::app::features::authors::interface::dto::{
+    AuthorDto, AuthorPostDto, AuthorPostsQueryDto, AuthorPostsResponseDto, MetaDto,
+};
+use chrono::Utc;
+use std::sync::Arc;
+use validator::Validate;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn AuthorRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn AuthorRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        slug: String,
+        query: AuthorPostsQueryDto,
+    ) -> Result<AuthorPostsResponseDto, AuthorError> {
+        query.validate().map_err(AuthorError::Validation)?;
+        let page = query.page.unwrap_or(1);
+        let per_page = query.per_page.unwrap_or(10);
+        let offset = (page - 1).saturating_mul(per_page);
+
+        let author = self
+            .repository
+            .get_author_by_slug(slug)
+            .map_err(|e| AuthorError::System(e.to_string()))?
+            .ok_or_else(|| AuthorError::NotFound("Author not found".to_string()))?;
+        let (posts, total_items) = self
+            .repository
+            .get_author_posts(author.id, Utc::now().naive_utc(), per_page, offset)
+            .map_err(|e| AuthorError::System(e.to_string()))?;
+
+        let total_pages = (total_items as f64 / per_page as f64).ceil() as i64;
+
+        Ok(AuthorPostsResponseDto {
+            author: AuthorDto::from(author),
+            items: posts.into_iter().map(AuthorPostDto::from).collect(),
+            meta: MetaDto {
+                page,
+                per_page,
+                total_pages,
+                total_items,
+            },
+        })
+    }
+}
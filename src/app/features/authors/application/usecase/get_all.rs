@@ -0,0 +1,44 @@
+use crate::app::features::authors::domain::error::AuthorError;
+use crate::app::features::authors::domain::repository::AuthorRepository;
+use crate::app::features::authors::interface::dto::{AuthorPostDto, AuthorResponseDto};
+use chrono::Utc;
+use std::sync::Arc;
+
+/// How many posts each author shows in the listing.
+pub const RECENT_POSTS_PER_AUTHOR: i64 = 5;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn AuthorRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn AuthorRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Authors with published posts, by display name.
+    pub async fn execute(&self) -> Result<Vec<AuthorResponseDto>, AuthorError> {
+        let now = Utc::now().naive_utc();
+        let authors = self
+            .repository
+            .get_authors(now)
+            .map_err(|e| AuthorError::System(e.to_string()))?;
+
+        let mut items = Vec::with_capacity(authors.len());
+        for (author, post_count) in authors {
+            let (posts, _) = self
+                .repository
+                .get_author_posts(author.id, now, RECENT_POSTS_PER_AUTHOR, 0)
+                .map_err(|e| AuthorError::System(e.to_string()))?;
+            items.push(AuthorResponseDto {
+                id: author.id,
+                display_name: author.display_name,
+                slug: author.slug,
+                post_count,
+                recent_posts: posts.into_iter().map(AuthorPostDto::from).collect(),
+            });
+        }
+        Ok(items)
+    }
+}
@@ -0,0 +1,3 @@
+pub mod get;
+pub mod get_all;
+pub mod update;
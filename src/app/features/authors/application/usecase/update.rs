@@ -0,0 +1,63 @@
+use crate::app::features::authors::domain::error::AuthorError;
+use crate::app::features::authors::domain::repository::AuthorRepository;
+use crate::app::features::authors::interface::dto::{AuthorDto, UpdateAuthorRequestDto};
+use crate::utils::slug::{SlugError, resolve_slug};
+use std::sync::Arc;
+use validator::{Validate, ValidationError};
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn AuthorRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn AuthorRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Sets how the account logged in as `username` is shown and linked on
+    /// the public site.
+    pub async fn execute(
+        &self,
+        username: String,
+        mut dto: UpdateAuthorRequestDto,
+    ) -> Result<AuthorDto, AuthorError> {
+        dto.display_name = dto.display_name.trim().to_string();
+        let mut validation_errors = dto.validate().err().unwrap_or_default();
+
+        let existing = self
+            .repository
+            .get_author_by_username(username.clone())
+            .map_err(|e| AuthorError::System(e.to_string()))?
+            .ok_or_else(|| AuthorError::NotFound("Author not found".to_string()))?;
+
+        // Keep the current slug unless asked otherwise or the name moved
+        let slug = if dto.slug.is_none() && dto.display_name == existing.display_name {
+            Ok(existing.slug.clone())
+        } else {
+            resolve_slug(dto.slug.as_deref(), &dto.display_name, "author", |slug| {
+                self.repository
+                    .get_author_by_slug(slug.to_string())
+                    .map(|found| found.is_some_and(|a| a.id != existing.id))
+            })
+        };
+        let slug = match slug {
+            Ok(slug) => slug,
+            Err(SlugError::Invalid(msg)) => {
+                validation_errors.add("slug", ValidationError::new(msg));
+                String::new()
+            }
+            Err(SlugError::Lookup(e)) => return Err(AuthorError::System(e.to_string())),
+        };
+
+        if !validation_errors.is_empty() {
+            return Err(AuthorError::Validation(validation_errors));
+        }
+
+        self.repository
+            .update_author(username, dto.display_name, slug)
+            .map_err(|e| AuthorError::System(e.to_string()))?
+            .map(AuthorDto::from)
+            .ok_or_else(|| AuthorError::NotFound("Author not found".to_string()))
+    }
+}
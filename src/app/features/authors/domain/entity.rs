@@ -0,0 +1,22 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+/// Public face of a user who writes posts; never carries the username (the
+/// login id), email or password.
+#[derive(Queryable, Debug, Clone, PartialEq)]
+pub struct Author {
+    pub id: i32,
+    pub display_name: String,
+    pub slug: String,
+}
+
+/// Published post as listed under its author.
+#[derive(Queryable, Debug, Clone, PartialEq)]
+pub struct AuthorPost {
+    pub id: i32,
+    pub title: String,
+    pub slug: String,
+    pub excerpt: Option<String>,
+    pub thumbnail: Option<String>,
+    pub published_at: Option<NaiveDateTime>,
+}
@@ -0,0 +1,14 @@
+use validator::ValidationErrors;
+
+#[derive(Debug)]
+pub enum AuthorError {
+    Validation(ValidationErrors),
+    System(String),
+    NotFound(String),
+}
+
+impl From<String> for AuthorError {
+    fn from(s: String) -> Self {
+        AuthorError::System(s)
+    }
+}
@@ -0,0 +1,25 @@
+use crate::app::features::authors::domain::entity::{Author, AuthorPost};
+use chrono::NaiveDateTime;
+use diesel::QueryResult;
+
+pub trait AuthorRepository {
+    /// Users with at least one post visible at `now`, paired with how many.
+    fn get_authors(&self, now: NaiveDateTime) -> QueryResult<Vec<(Author, i64)>>;
+    fn get_author_by_username(&self, username: String) -> QueryResult<Option<Author>>;
+    fn get_author_by_slug(&self, slug: String) -> QueryResult<Option<Author>>;
+    /// Sets the public name and slug of the account logged in as `username`.
+    fn update_author(
+        &self,
+        username: String,
+        display_name: String,
+        slug: String,
+    ) -> QueryResult<Option<Author>>;
+    /// Posts of `author_id` visible at `now`, newest first.
+    fn get_author_posts(
+        &self,
+        author_id: i32,
+        now: NaiveDateTime,
+        limit: i64,
+        offset: i64,
+    ) -> QueryResult<(Vec<AuthorPost>, i64)>;
+}
@@ -0,0 +1,157 @@
+use crate::app::features::authors::domain::entity::{Author, AuthorPost};
+use crate::app::features::authors::domain::repository::AuthorRepository;
+use crate::schema::{blog, users};
+use crate::utils::db::DbPool;
+use chrono::NaiveDateTime;
+use diesel::dsl::{AssumeNotNull, count_star};
+use diesel::prelude::*;
+
+#[derive(Clone)]
+pub struct AuthorRepositoryImpl {
+    pub pool: DbPool,
+}
+
+impl AuthorRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+type AuthorColumns = (
+    AssumeNotNull<users::id>,
+    AssumeNotNull<users::display_name>,
+    AssumeNotNull<users::slug>,
+);
+
+/// Columns of `Author`; a migration trigger fills in the public name and slug
+/// of every account.
+fn author_columns() -> AuthorColumns {
+    (
+        users::id.assume_not_null(),
+        users::display_name.assume_not_null(),
+        users::slug.assume_not_null(),
+    )
+}
+
+/// Authors for the given user ids, ordered by display name.
+///
+/// Shared with the blog and feed features, which show who wrote a post.
+pub fn load_authors(conn: &mut SqliteConnection, user_ids: &[i32]) -> QueryResult<Vec<Author>> {
+    if user_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    users::table
+        .filter(users::id.eq_any(user_ids))
+        .order((users::display_name.asc(), users::slug.asc()))
+        .select(author_columns())
+        .load::<Author>(conn)
+}
+
+/// The account behind a login, for attributing new posts; never exposed by
+/// username.
+pub fn find_author_by_username(
+    conn: &mut SqliteConnection,
+    username: &str,
+) -> QueryResult<Option<Author>> {
+    users::table
+        .filter(users::username.eq(username))
+        .select(author_columns())
+        .first::<Author>(conn)
+        .optional()
+}
+
+impl AuthorRepository for AuthorRepositoryImpl {
+    fn get_authors(&self, now: NaiveDateTime) -> QueryResult<Vec<(Author, i64)>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        let counts = blog::table
+            .filter(blog::status.eq("PUBLISHED"))
+            .filter(blog::published_at.le(now))
+            .filter(blog::author_id.is_not_null())
+            .group_by(blog::author_id)
+            .select((blog::author_id.assume_not_null(), count_star()))
+            .load::<(i32, i64)>(&mut conn)?;
+        let ids = counts.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+        Ok(load_authors(&mut conn, &ids)?
+            .into_iter()
+            .map(|author| {
+                let count = counts
+                    .iter()
+                    .find(|(id, _)| *id == author.id)
+                    .map_or(0, |(_, count)| *count);
+                (author, count)
+            })
+            .collect())
+    }
+    fn get_author_by_username(&self, username: String) -> QueryResult<Option<Author>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        find_author_by_username(&mut conn, &username)
+    }
+    fn get_author_by_slug(&self, slug: String) -> QueryResult<Option<Author>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        users::table
+            .filter(users::slug.eq(slug))
+            .select(author_columns())
+            .first::<Author>(&mut conn)
+            .optional()
+    }
+    fn update_author(
+        &self,
+        username: String,
+        display_name: String,
+        slug: String,
+    ) -> QueryResult<Option<Author>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::update(users::table.filter(users::username.eq(username)))
+            .set((users::display_name.eq(display_name), users::slug.eq(slug)))
+            .returning(author_columns())
+            .get_result::<Author>(&mut conn)
+            .optional()
+    }
+    fn get_author_posts(
+        &self,
+        author_id: i32,
+        now: NaiveDateTime,
+        limit: i64,
+        offset: i64,
+    ) -> QueryResult<(Vec<AuthorPost>, i64)> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        let count = blog::table
+            .filter(blog::author_id.eq(author_id))
+            .filter(blog::status.eq("PUBLISHED"))
+            .filter(blog::published_at.le(now))
+            .count()
+            .get_result(&mut conn)?;
+        let items = blog::table
+            .filter(blog::author_id.eq(author_id))
+            .filter(blog::status.eq("PUBLISHED"))
+            .filter(blog::published_at.le(now))
+            .order((blog::published_at.desc(), blog::id.desc()))
+            .limit(limit)
+            .offset(offset)
+            .select((
+                blog::id,
+                blog::title,
+                blog::slug,
+                blog::excerpt,
+                blog::thumbnail,
+                blog::published_at,
+            ))
+            .load::<AuthorPost>(&mut conn)?;
+        Ok((items, count))
+    }
+}
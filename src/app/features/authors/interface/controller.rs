@@ -0,0 +1,100 @@
+use crate::app::features::authors::domain::error::AuthorError;
+use crate::app::features::authors::interface::dto::{
+    AuthorDto, AuthorPostDto, AuthorPostsQueryDto, AuthorResponseDto, UpdateAuthorRequestDto,
+};
+use crate::utils::di::Container;
+use crate::utils::error_response::{ErrorResponse, map_string_error, map_validation_error};
+use crate::utils::success_response::map_success_with_data;
+use crate::utils::token::Claims;
+use actix_web::{HttpResponse, Responder, get, put, web};
+
+fn author_error_response(e: AuthorError) -> HttpResponse {
+    match e {
+        AuthorError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+        AuthorError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+        AuthorError::System(msg) => HttpResponse::InternalServerError().json(map_string_error(msg)),
+    }
+}
+
+#[utoipa::path(
+    path = "/api/public/authors",
+    tag = "Authors",
+    responses(
+        (status = 200, description = "Authors with published posts, by display name", body = crate::utils::success_response::SuccessResponse<Vec<AuthorResponseDto>>),
+        (status = 500, description = "Internal server error")
+    ),
+    security(())
+)]
+#[get("/authors")]
+pub async fn get_public_authors(container: web::Data<Container>) -> impl Responder {
+    match container.get_public_authors_usecase.execute().await {
+        Ok(authors) => HttpResponse::Ok().json(map_success_with_data(
+            "Authors fetched successfully".to_string(),
+            authors,
+        )),
+        Err(e) => author_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    path = "/api/public/authors/{slug}",
+    tag = "Authors",
+    params(
+        ("slug" = String, Path, description = "Author slug"),
+        AuthorPostsQueryDto
+    ),
+    responses(
+        (status = 200, description = "The author with a page of their published posts, newest first", body = crate::utils::success_response::SuccessResponse<Vec<AuthorPostDto>>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 404, description = "Author not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    security(())
+)]
+#[get("/authors/{slug}")]
+pub async fn get_public_author(
+    container: web::Data<Container>,
+    slug: web::Path<String>,
+    query: web::Query<AuthorPostsQueryDto>,
+) -> impl Responder {
+    match container
+        .get_public_author_usecase
+        .execute(slug.into_inner(), query.into_inner())
+        .await
+    {
+        Ok(author) => HttpResponse::Ok().json(map_success_with_data(
+            "Author fetched successfully".to_string(),
+            author,
+        )),
+        Err(e) => author_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    path = "/app/authors/me",
+    tag = "Authors",
+    request_body = UpdateAuthorRequestDto,
+    responses(
+        (status = 200, description = "How the logged-in account is shown on the public site", body = crate::utils::success_response::SuccessResponse<AuthorDto>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[put("/authors/me")]
+pub async fn update_current_author(
+    container: web::Data<Container>,
+    claims: web::ReqData<Claims>,
+    payload: web::Json<UpdateAuthorRequestDto>,
+) -> impl Responder {
+    match container
+        .update_author_usecase
+        .execute(claims.into_inner().sub, payload.into_inner())
+        .await
+    {
+        Ok(author) => HttpResponse::Ok().json(map_success_with_data(
+            "Author updated successfully".to_string(),
+            author,
+        )),
+        Err(e) => author_error_response(e),
+    }
+}
@@ -0,0 +1,90 @@
+use crate::app::features::authors::domain::entity::{Author, AuthorPost};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+/// Who wrote a post.
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct AuthorDto {
+    pub id: i32,
+    pub display_name: String,
+    /// Identifies the author in `/api/public/authors/{slug}`
+    pub slug: String,
+}
+
+impl From<Author> for AuthorDto {
+    fn from(author: Author) -> Self {
+        Self {
+            id: author.id,
+            display_name: author.display_name,
+            slug: author.slug,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct AuthorPostDto {
+    pub id: i32,
+    pub title: String,
+    pub slug: String,
+    pub excerpt: Option<String>,
+    pub thumbnail: Option<String>,
+    pub published_at: Option<String>,
+}
+
+impl From<AuthorPost> for AuthorPostDto {
+    fn from(post: AuthorPost) -> Self {
+        Self {
+            id: post.id,
+            title: post.title,
+            slug: post.slug,
+            excerpt: post.excerpt,
+            thumbnail: post.thumbnail,
+            published_at: post.published_at.map(|d| d.to_string()),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct AuthorResponseDto {
+    pub id: i32,
+    pub display_name: String,
+    pub slug: String,
+    /// Number of published posts
+    pub post_count: i64,
+    /// Newest published posts, see `/api/public/authors/{slug}` for all of them
+    pub recent_posts: Vec<AuthorPostDto>,
+}
+
+#[derive(Deserialize, Serialize, Validate, ToSchema)]
+pub struct UpdateAuthorRequestDto {
+    #[validate(length(min = 1, max = 100, message = "Display name is required"))]
+    pub display_name: String,
+    /// Explicit slug; when omitted the current slug is kept unless the display name changes
+    pub slug: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AuthorPostsQueryDto {
+    #[validate(range(min = 1, message = "Must be at least 1"))]
+    pub page: Option<i64>,
+    #[validate(range(min = 1, max = 100, message = "Must be between 1 and 100"))]
+    pub per_page: Option<i64>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct MetaDto {
+    pub page: i64,
+    pub per_page: i64,
+    pub total_pages: i64,
+    pub total_items: i64,
+}
+
+/// One page of an author's published posts.
+#[derive(Deserialize, Serialize)]
+pub struct AuthorPostsResponseDto {
+    pub author: AuthorDto,
+    pub items: Vec<AuthorPostDto>,
+    pub meta: MetaDto,
+}
@@ -0,0 +1,116 @@
+use crate::app::features::blog::domain::entity::BulkBlogAction;
+use crate::app::features::blog::domain::error::BlogError;
+use crate::app::features::blog::domain::repository::BlogRepository;
+use crate::app::features::blog::interface::dto::{
+    BulkBlogRequestDto, BulkBlogResponseDto, BulkBlogResultDto,
+};
+use std::sync::Arc;
+use validator::{Validate, ValidationError};
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn BlogRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn BlogRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, dto: BulkBlogRequestDto) -> Result<BulkBlogResponseDto, BlogError> {
+        let mut validation_errors = dto.validate().err().unwrap_or_default();
+
+        let action = match dto.action.as_str() {
+            "publish" => Some(BulkBlogAction::Publish),
+            "archive" => Some(BulkBlogAction::Archive),
+            "draft" => Some(BulkBlogAction::Draft),
+            "delete" => Some(BulkBlogAction::Delete),
+            "change_category" => match dto.category_id {
+                Some(category_id) => {
+                    if self
+                        .repository
+                        .get_category_by_id(category_id)
+                        .map_err(|e| BlogError::System(e.to_string()))?
+                        .is_none()
+                    {
+                        validation_errors
+                            .add("category_id", ValidationError::new("Category not found"));
+                    }
+                    Some(BulkBlogAction::ChangeCategory(category_id))
+                }
+                None => {
+                    validation_errors.add(
+                        "category_id",
+                        ValidationError::new("Category is required for change_category"),
+                    );
+                    None
+                }
+            },
+            "add_tags" | "remove_tags" => {
+                let mut tag_ids = dto.tag_ids.clone().unwrap_or_default();
+                tag_ids.sort_unstable();
+                tag_ids.dedup();
+                if tag_ids.is_empty() {
+                    validation_errors.add(
+                        "tag_ids",
+                        ValidationError::new("At least one tag is required"),
+                    );
+                }
+                for tag_id in &tag_ids {
+                    if self
+                        .repository
+                        .get_tag_by_id(*tag_id)
+                        .map_err(|e| BlogError::System(e.to_string()))?
+                        .is_none()
+                    {
+                        validation_errors.add("tag_ids", ValidationError::new("Tag not found"));
+                        break;
+                    }
+                }
+                if dto.action == "add_tags" {
+                    Some(BulkBlogAction::AddTags(tag_ids))
+                } else {
+                    Some(BulkBlogAction::RemoveTags(tag_ids))
+                }
+            }
+            // Already reported by `validate`
+            _ => None,
+        };
+
+        let action = match action {
+            Some(action) if validation_errors.is_empty() => action,
+            _ => return Err(BlogError::Validation(validation_errors)),
+        };
+
+        // Repeated ids would only report the same blog twice
+        let mut ids = Vec::with_capacity(dto.ids.len());
+        for id in dto.ids {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+
+        let outcomes = self
+            .repository
+            .bulk_update_blogs(&ids, &action)
+            .map_err(|e| BlogError::System(e.to_string()))?;
+
+        let results: Vec<BulkBlogResultDto> = outcomes
+            .into_iter()
+            .map(|(id, outcome)| BulkBlogResultDto {
+                id,
+                success: outcome.is_ok(),
+                previous_status: outcome.as_ref().ok().cloned(),
+                error: outcome.err(),
+            })
+            .collect();
+        let succeeded = results.iter().filter(|r| r.success).count();
+
+        Ok(BulkBlogResponseDto {
+            action: dto.action,
+            succeeded,
+            failed: results.len() - succeeded,
+            results,
+        })
+    }
+}
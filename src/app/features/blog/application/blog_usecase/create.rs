@@ -1,13 +1,16 @@
-use chrono::Utc;
-
-use crate::app::features::blog::domain::entity::{BlogTags, NewBlog};
+use crate::app::features::series::interface::dto::BlogSeriesDto;
+use crate::app::features::authors::interface::dto::AuthorDto;
+use crate::app::features::media::interface::dto::ResponsiveImageDto;
+use crate::app::features::blog::domain::entity::{BlogRenderedContent, BlogTags, NewBlog};
 use crate::app::features::blog::domain::repository::BlogRepository;
 use crate::app::features::blog::interface::dto::CreateBlogRequestDto;
 use std::sync::Arc;
+use crate::app::features::blog::application::blog_usecase::schedule::resolve_published_at;
 use crate::app::features::blog::domain::error::BlogError;
 use crate::app::features::blog::interface::dto::{
-    BlogResponseDto, CategoryResponseDto, TagResponseDto,
+    BlogResponseDto, CategoryResponseDto, TagResponseDto, TocEntryDto,
 };
+use crate::utils::slug::{SlugError, resolve_slug};
 use validator::Validate;
 use validator::ValidationError;
 
@@ -22,9 +25,11 @@ impl Execute {
         Self { repository }
     }
 
+    /// `username` is the JWT subject of the caller, who becomes the author.
     pub async fn execute(
         &self,
         dto: CreateBlogRequestDto,
+        username: String,
     ) -> Result<
         BlogResponseDto,
         BlogError,
@@ -66,38 +71,39 @@ impl Execute {
             }
         }
 
-        // Create Blog
-        let slug = dto
-            .title
-            .to_lowercase()
-            .replace(" ", "-")
-            .chars()
-            .filter(|c| c.is_alphanumeric() || *c == '-')
-            .collect::<String>();
-
-        // Validate Title/Slug Uniqueness
-        if self
-            .repository
-            .get_blog_by_slug(slug.clone())
-            .map_err(|e| BlogError::System(e.to_string()))?
-            .is_some()
-        {
-            validation_errors.add("title", ValidationError::new("Title already exists"));
-        }
+        let slug = match resolve_slug(dto.slug.as_deref(), &dto.title, "post", |slug| {
+            self.repository
+                .get_blog_by_slug(slug.to_string())
+                .map(|existing| existing.is_some())
+        }) {
+            Ok(slug) => slug,
+            Err(SlugError::Invalid(msg)) => {
+                validation_errors.add("slug", ValidationError::new(msg));
+                String::new()
+            }
+            Err(SlugError::Lookup(e)) => return Err(BlogError::System(e.to_string())),
+        };
+
+        let status = dto.status;
+
+        let published_at = resolve_published_at(
+            &status,
+            dto.published_at.as_deref(),
+            None,
+            &mut validation_errors,
+        );
 
         if !validation_errors.is_empty() {
             return Err(BlogError::Validation(validation_errors));
         }
 
-        // Create Blog
-        let status = dto.status;
-
-        let published_at = if status == "PUBLISHED" {
-            Some(Utc::now().naive_utc())
-        } else {
-            None
-        };
+        let author = self
+            .repository
+            .get_author_by_username(username)
+            .map_err(|e| BlogError::System(e.to_string()))?;
 
+        // Create Blog
+        let rendered = BlogRenderedContent::from_markdown(&dto.content);
         let new_blog = NewBlog {
             title: dto.title,
             content: dto.content,
@@ -108,12 +114,19 @@ impl Execute {
             status: status.clone(),
             published_at,
             view_count: 0,
+            author_id: author.as_ref().map(|a| a.id),
+            rendered,
         };
         let created_blog = self
             .repository
             .create_blog(new_blog)
             .map_err(|e| BlogError::System(e.to_string()))?;
 
+        // Initial revision so later edits always have something to diff against
+        self.repository
+            .create_blog_revision(&created_blog)
+            .map_err(|e| BlogError::System(e.to_string()))?;
+
         // Create Blog Tags if provided
         // We already have tags fetched, we just need to link them
         for tag in &tags {
@@ -128,12 +141,11 @@ impl Execute {
 
         // Construct Response
         let category = category.unwrap(); // Safe because validation passed
-        let category_dto = CategoryResponseDto {
-            id: category.id,
-            name: category.name,
-            created_at: category.created_at.to_string(),
-            updated_at: category.updated_at.to_string(),
-        };
+        let category_path = self
+            .repository
+            .get_category_path(category.id)
+            .map_err(|e| BlogError::System(e.to_string()))?;
+        let category_dto = CategoryResponseDto::from_category(category, category_path);
 
         let tags_dto = tags
             .into_iter()
@@ -142,9 +154,22 @@ impl Execute {
                 name: t.name,
                 created_at: t.created_at.to_string(),
                 updated_at: t.updated_at.to_string(),
+                post_count: None,
             })
             .collect();
 
+        let images = ResponsiveImageDto::embedded_in(
+            [created_blog.thumbnail.as_deref().unwrap_or_default(), created_blog.content.as_str()],
+            |filenames| self.repository.get_images(filenames),
+        )
+        .map_err(|e| e.to_string())?;
+
+        let series = self
+            .repository
+            .get_blog_series(created_blog.id)
+            .map_err(|e| e.to_string())?
+            .and_then(|(series, posts)| BlogSeriesDto::for_blog(series, posts, created_blog.id, false));
+
         Ok(BlogResponseDto {
             id: created_blog.id,
             title: created_blog.title,
@@ -159,6 +184,14 @@ impl Execute {
             created_at: created_blog.created_at.to_string(),
             updated_at: created_blog.updated_at.to_string(),
             published_at: created_blog.published_at.map(|d| d.to_string()),
+            content_html: created_blog.content_html,
+            toc: TocEntryDto::from_json(created_blog.toc.as_deref()),
+            word_count: created_blog.word_count,
+            reading_time_minutes: created_blog.reading_time_minutes,
+            comment_count: 0,
+            images,
+            series,
+            author: author.map(AuthorDto::from),
         })
     }
 }
@@ -1,7 +1,10 @@
+use crate::app::features::authors::interface::dto::AuthorDto;
 use crate::app::features::blog::domain::repository::BlogRepository;
 use crate::app::features::blog::interface::dto::{
-    BlogResponseDto, CategoryResponseDto, TagResponseDto,
+    BlogResponseDto, CategoryResponseDto, TagResponseDto, TocEntryDto,
 };
+use crate::app::features::media::interface::dto::ResponsiveImageDto;
+use crate::app::features::series::interface::dto::BlogSeriesDto;
 use std::sync::Arc;
 
 #[derive(Clone)]
@@ -29,13 +32,46 @@ impl Execute {
             .map_err(|e| e.to_string())?
             .ok_or_else(|| "Category not found (Data Inconsistency)".to_string())?;
 
+        let category_path = self
+            .repository
+            .get_category_path(category.id)
+            .map_err(|e| e.to_string())?;
+
         // Fetch Tags
         let tags = self
             .repository
             .get_tags_by_blog_id(blog.id)
             .map_err(|e| e.to_string())?;
 
+        let comment_count = self
+            .repository
+            .count_approved_comments(blog.id)
+            .map_err(|e| e.to_string())?;
+
         // Assemble DTO
+        let images = ResponsiveImageDto::embedded_in(
+            [
+                blog.thumbnail.as_deref().unwrap_or_default(),
+                blog.content.as_str(),
+            ],
+            |filenames| self.repository.get_images(filenames),
+        )
+        .map_err(|e| e.to_string())?;
+
+        let series = self
+            .repository
+            .get_blog_series(blog.id)
+            .map_err(|e| e.to_string())?
+            .and_then(|(series, posts)| BlogSeriesDto::for_blog(series, posts, blog.id, false));
+
+        let author = blog
+            .author_id
+            .map(|id| self.repository.get_author(id))
+            .transpose()
+            .map_err(|e| e.to_string())?
+            .flatten()
+            .map(AuthorDto::from);
+
         Ok(BlogResponseDto {
             id: blog.id,
             slug: blog.slug,
@@ -45,12 +81,7 @@ impl Execute {
             thumbnail: blog.thumbnail,
             status: blog.status,
             view_count: blog.view_count,
-            category: CategoryResponseDto {
-                id: category.id,
-                name: category.name,
-                created_at: category.created_at.to_string(),
-                updated_at: category.updated_at.to_string(),
-            },
+            category: CategoryResponseDto::from_category(category, category_path),
             tags: tags
                 .into_iter()
                 .map(|t| TagResponseDto {
@@ -58,11 +89,20 @@ impl Execute {
                     name: t.name,
                     created_at: t.created_at.to_string(),
                     updated_at: t.updated_at.to_string(),
+                    post_count: None,
                 })
                 .collect(),
             created_at: blog.created_at.to_string(),
             updated_at: blog.updated_at.to_string(),
             published_at: blog.published_at.map(|t| t.to_string()),
+            content_html: blog.content_html,
+            toc: TocEntryDto::from_json(blog.toc.as_deref()),
+            word_count: blog.word_count,
+            reading_time_minutes: blog.reading_time_minutes,
+            comment_count,
+            images,
+            series,
+            author,
         })
     }
 }
@@ -1,9 +1,16 @@
+use crate::app::features::series::interface::dto::BlogSeriesDto;
+use crate::app::features::authors::interface::dto::AuthorDto;
+use crate::app::features::media::interface::dto::ResponsiveImageDto;
+use crate::app::features::blog::domain::entity::{BlogFilter, BlogSortField};
+use crate::app::features::blog::domain::error::BlogError;
 use crate::app::features::blog::domain::repository::BlogRepository;
 use crate::app::features::blog::interface::dto::{
-    BlogResponseDto, CategoryResponseDto, TagResponseDto,
+    BlogResponseDto, CategoryResponseDto, TagResponseDto, TocEntryDto,
 };
+use chrono::{Days, NaiveDate};
 use std::sync::Arc;
-use crate::app::features::blog::interface::dto::PaginationRequestDto;
+use validator::{Validate, ValidationError, ValidationErrors};
+use crate::app::features::blog::interface::dto::BlogListQueryDto;
 use crate::app::features::blog::interface::dto::PaginatedResponseDto;
 
 #[derive(Clone)]
@@ -18,18 +25,79 @@ impl Execute {
 
     pub async fn execute(
         &self,
-        query: PaginationRequestDto,
+        query: BlogListQueryDto,
     ) -> Result<
         PaginatedResponseDto<BlogResponseDto>,
-        String,
+        BlogError,
     > {
+        let mut validation_errors = query.validate().err().unwrap_or_default();
+
+        let tag_ids = parse_tag_ids(query.tag_ids.as_deref(), &mut validation_errors);
+        let published_from = parse_date(
+            "published_from",
+            query.published_from.as_deref(),
+            &mut validation_errors,
+        );
+        let published_to = parse_date(
+            "published_to",
+            query.published_to.as_deref(),
+            &mut validation_errors,
+        );
+
+        if let (Some(from), Some(to)) = (published_from, published_to)
+            && from > to
+        {
+            validation_errors.add(
+                "published_to",
+                ValidationError::new("published_to must not be before published_from"),
+            );
+        }
+
+        if !validation_errors.is_empty() {
+            return Err(BlogError::Validation(validation_errors));
+        }
+
+        let sort_by = match query.sort_by.as_deref() {
+            Some("published_at") => BlogSortField::PublishedAt,
+            Some("updated_at") => BlogSortField::UpdatedAt,
+            Some("view_count") => BlogSortField::ViewCount,
+            Some("title") => BlogSortField::Title,
+            _ => BlogSortField::Id,
+        };
+        let descending = match query.order.as_deref() {
+            Some(order) => order == "desc",
+            None => sort_by != BlogSortField::Id,
+        };
+
+        let category_ids = match query.category_id {
+            Some(id) if query.include_descendants.unwrap_or(false) => self
+                .repository
+                .get_category_descendant_ids(id)
+                .map_err(|e| BlogError::System(e.to_string()))?,
+            Some(id) => vec![id],
+            None => Vec::new(),
+        };
+
+        let filter = BlogFilter {
+            status: query.status,
+            category_ids,
+            tag_ids,
+            published_from: published_from.map(|d| d.and_hms_opt(0, 0, 0).unwrap()),
+            // The upper bound covers the whole day, so compare against the next midnight.
+            published_until: published_to
+                .and_then(|d| d.checked_add_days(Days::new(1)))
+                .map(|d| d.and_hms_opt(0, 0, 0).unwrap()),
+            sort_by,
+            descending,
+        };
+
         let page = query.page.unwrap_or(1);
         let per_page = query.per_page.unwrap_or(10);
         let offset = (page - 1) * per_page;
 
         let (blogs, total_items) = self
             .repository
-            .get_all_blog(per_page, offset)
+            .get_all_blog(filter, per_page, offset)
             .map_err(|e| e.to_string())?;
 
         let mut dtos = Vec::new();
@@ -41,11 +109,41 @@ impl Execute {
                 .map_err(|e| e.to_string())?
                 .ok_or_else(|| "Category not found for blog".to_string())?;
 
+            let category_path = self
+                .repository
+                .get_category_path(category.id)
+                .map_err(|e| e.to_string())?;
+
             let tags = self
                 .repository
                 .get_tags_by_blog_id(blog.id)
                 .map_err(|e| e.to_string())?;
 
+            let comment_count = self
+                .repository
+                .count_approved_comments(blog.id)
+                .map_err(|e| e.to_string())?;
+
+            let images = ResponsiveImageDto::embedded_in(
+            [blog.thumbnail.as_deref().unwrap_or_default(), blog.content.as_str()],
+            |filenames| self.repository.get_images(filenames),
+        )
+        .map_err(|e| e.to_string())?;
+
+            let series = self
+                .repository
+                .get_blog_series(blog.id)
+                .map_err(|e| e.to_string())?
+                .and_then(|(series, posts)| BlogSeriesDto::for_blog(series, posts, blog.id, false));
+
+            let author = blog
+                .author_id
+                .map(|id| self.repository.get_author(id))
+                .transpose()
+                .map_err(|e| e.to_string())?
+                .flatten()
+                .map(AuthorDto::from);
+
             dtos.push(BlogResponseDto {
                 id: blog.id,
                 slug: blog.slug,
@@ -55,12 +153,7 @@ impl Execute {
                 thumbnail: blog.thumbnail,
                 status: blog.status,
                 view_count: blog.view_count,
-                category: CategoryResponseDto {
-                    id: category.id,
-                    name: category.name,
-                    created_at: category.created_at.to_string(),
-                    updated_at: category.updated_at.to_string(),
-                },
+                category: CategoryResponseDto::from_category(category, category_path),
                 tags: tags
                     .into_iter()
                     .map(|t| TagResponseDto {
@@ -68,11 +161,20 @@ impl Execute {
                         name: t.name,
                         created_at: t.created_at.to_string(),
                         updated_at: t.updated_at.to_string(),
+                        post_count: None,
                     })
                     .collect(),
                 created_at: blog.created_at.to_string(),
                 updated_at: blog.updated_at.to_string(),
                 published_at: blog.published_at.map(|t| t.to_string()),
+                content_html: blog.content_html,
+                toc: TocEntryDto::from_json(blog.toc.as_deref()),
+                word_count: blog.word_count,
+                reading_time_minutes: blog.reading_time_minutes,
+                comment_count,
+                images,
+                series,
+                author,
             });
         }
 
@@ -91,3 +193,36 @@ impl Execute {
         )
     }
 }
+
+fn parse_tag_ids(raw: Option<&str>, errors: &mut ValidationErrors) -> Vec<i32> {
+    let Some(raw) = raw else {
+        return Vec::new();
+    };
+
+    let mut ids = Vec::new();
+    for part in raw.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        match part.parse::<i32>() {
+            Ok(id) => ids.push(id),
+            Err(_) => {
+                errors.add("tag_ids", ValidationError::new("Invalid tag id"));
+                return Vec::new();
+            }
+        }
+    }
+    ids
+}
+
+fn parse_date(
+    field: &'static str,
+    raw: Option<&str>,
+    errors: &mut ValidationErrors,
+) -> Option<NaiveDate> {
+    let raw = raw?;
+    match NaiveDate::parse_from_str(raw, "%Y-%m-%d") {
+        Ok(date) => Some(date),
+        Err(_) => {
+            errors.add(field, ValidationError::new("Invalid date format (YYYY-MM-DD)"));
+            None
+        }
+    }
+}
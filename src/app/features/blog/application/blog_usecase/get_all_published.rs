@@ -0,0 +1,128 @@
+use crate::app::features::authors::interface::dto::AuthorDto;
+use crate::app::features::blog::domain::repository::BlogRepository;
+use crate::app::features::blog::interface::dto::{
+    BlogResponseDto, CategoryResponseDto, MetaDto, PaginatedResponseDto, PaginationRequestDto,
+    TagResponseDto, TocEntryDto,
+};
+use crate::app::features::media::interface::dto::ResponsiveImageDto;
+use crate::app::features::series::interface::dto::BlogSeriesDto;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn BlogRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn BlogRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        query: PaginationRequestDto,
+    ) -> Result<PaginatedResponseDto<BlogResponseDto>, String> {
+        let page = query.page.unwrap_or(1);
+        let per_page = query.per_page.unwrap_or(10);
+        let offset = (page - 1).saturating_mul(per_page);
+
+        let (blogs, total_items) = self
+            .repository
+            .get_all_published_blog(per_page, offset)
+            .map_err(|e| e.to_string())?;
+
+        let mut dtos = Vec::new();
+
+        for blog in blogs {
+            let category = self
+                .repository
+                .get_category_by_id(blog.category_id)
+                .map_err(|e| e.to_string())?
+                .ok_or_else(|| "Category not found for blog".to_string())?;
+
+            let category_path = self
+                .repository
+                .get_category_path(category.id)
+                .map_err(|e| e.to_string())?;
+
+            let tags = self
+                .repository
+                .get_tags_by_blog_id(blog.id)
+                .map_err(|e| e.to_string())?;
+
+            let comment_count = self
+                .repository
+                .count_approved_comments(blog.id)
+                .map_err(|e| e.to_string())?;
+
+            let images = ResponsiveImageDto::embedded_in(
+                [
+                    blog.thumbnail.as_deref().unwrap_or_default(),
+                    blog.content.as_str(),
+                ],
+                |filenames| self.repository.get_images(filenames),
+            )
+            .map_err(|e| e.to_string())?;
+
+            // Readers only see the published parts
+            let series = self
+                .repository
+                .get_blog_series(blog.id)
+                .map_err(|e| e.to_string())?
+                .and_then(|(series, posts)| BlogSeriesDto::for_blog(series, posts, blog.id, true));
+
+            let author = blog
+                .author_id
+                .map(|id| self.repository.get_author(id))
+                .transpose()
+                .map_err(|e| e.to_string())?
+                .flatten()
+                .map(AuthorDto::from);
+
+            dtos.push(BlogResponseDto {
+                id: blog.id,
+                slug: blog.slug,
+                title: blog.title,
+                content: blog.content,
+                excerpt: blog.excerpt,
+                thumbnail: blog.thumbnail,
+                status: blog.status,
+                view_count: blog.view_count,
+                category: CategoryResponseDto::from_category(category, category_path),
+                tags: tags
+                    .into_iter()
+                    .map(|t| TagResponseDto {
+                        id: t.id,
+                        name: t.name,
+                        created_at: t.created_at.to_string(),
+                        updated_at: t.updated_at.to_string(),
+                        post_count: None,
+                    })
+                    .collect(),
+                created_at: blog.created_at.to_string(),
+                updated_at: blog.updated_at.to_string(),
+                published_at: blog.published_at.map(|t| t.to_string()),
+                content_html: blog.content_html,
+                toc: TocEntryDto::from_json(blog.toc.as_deref()),
+                word_count: blog.word_count,
+                reading_time_minutes: blog.reading_time_minutes,
+                comment_count,
+                images,
+                series,
+                author,
+            });
+        }
+
+        let total_pages = (total_items as f64 / per_page as f64).ceil() as i64;
+
+        Ok(PaginatedResponseDto {
+            items: dtos,
+            meta: MetaDto {
+                page,
+                per_page,
+                total_pages,
+                total_items,
+            },
+        })
+    }
+}
@@ -0,0 +1,116 @@
+use crate::app::features::authors::interface::dto::AuthorDto;
+use crate::app::features::blog::domain::error::BlogError;
+use crate::app::features::blog::domain::repository::BlogRepository;
+use crate::app::features::blog::interface::dto::{
+    BlogResponseDto, CategoryResponseDto, TagResponseDto, TocEntryDto,
+};
+use crate::app::features::media::interface::dto::ResponsiveImageDto;
+use crate::app::features::series::interface::dto::BlogSeriesDto;
+use chrono::Utc;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn BlogRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn BlogRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Fetches a published blog by its slug. Drafts, archived and scheduled
+    /// posts (or any post dated in the future) are reported as not found so
+    /// they never leak through the public API.
+    pub async fn execute(&self, slug: String) -> Result<BlogResponseDto, BlogError> {
+        let blog = self
+            .repository
+            .get_blog_by_slug(slug)
+            .map_err(|e| BlogError::System(e.to_string()))?
+            .filter(|b| {
+                b.status == "PUBLISHED"
+                    && b.published_at.is_none_or(|at| at <= Utc::now().naive_utc())
+            })
+            .ok_or_else(|| BlogError::NotFound("Blog not found".to_string()))?;
+
+        let category = self
+            .repository
+            .get_category_by_id(blog.category_id)
+            .map_err(|e| BlogError::System(e.to_string()))?
+            .ok_or_else(|| {
+                BlogError::System("Category not found (Data Inconsistency)".to_string())
+            })?;
+
+        let category_path = self
+            .repository
+            .get_category_path(category.id)
+            .map_err(|e| BlogError::System(e.to_string()))?;
+
+        let tags = self
+            .repository
+            .get_tags_by_blog_id(blog.id)
+            .map_err(|e| BlogError::System(e.to_string()))?;
+
+        let comment_count = self
+            .repository
+            .count_approved_comments(blog.id)
+            .map_err(|e| BlogError::System(e.to_string()))?;
+
+        let images = ResponsiveImageDto::embedded_in(
+            [
+                blog.thumbnail.as_deref().unwrap_or_default(),
+                blog.content.as_str(),
+            ],
+            |filenames| self.repository.get_images(filenames),
+        )
+        .map_err(|e| e.to_string())?;
+
+        // Readers only see the published parts
+        let series = self
+            .repository
+            .get_blog_series(blog.id)
+            .map_err(|e| e.to_string())?
+            .and_then(|(series, posts)| BlogSeriesDto::for_blog(series, posts, blog.id, true));
+
+        let author = blog
+            .author_id
+            .map(|id| self.repository.get_author(id))
+            .transpose()
+            .map_err(|e| e.to_string())?
+            .flatten()
+            .map(AuthorDto::from);
+
+        Ok(BlogResponseDto {
+            id: blog.id,
+            slug: blog.slug,
+            title: blog.title,
+            content: blog.content,
+            excerpt: blog.excerpt,
+            thumbnail: blog.thumbnail,
+            status: blog.status,
+            view_count: blog.view_count,
+            category: CategoryResponseDto::from_category(category, category_path),
+            tags: tags
+                .into_iter()
+                .map(|t| TagResponseDto {
+                    id: t.id,
+                    name: t.name,
+                    created_at: t.created_at.to_string(),
+                    updated_at: t.updated_at.to_string(),
+                    post_count: None,
+                })
+                .collect(),
+            created_at: blog.created_at.to_string(),
+            updated_at: blog.updated_at.to_string(),
+            published_at: blog.published_at.map(|t| t.to_string()),
+            content_html: blog.content_html,
+            toc: TocEntryDto::from_json(blog.toc.as_deref()),
+            word_count: blog.word_count,
+            reading_time_minutes: blog.reading_time_minutes,
+            comment_count,
+            images,
+            series,
+            author,
+        })
+    }
+}
@@ -3,3 +3,11 @@ pub mod get;
 pub mod update;
 pub mod delete;
 pub mod create;
+pub mod get_all_published;
+pub mod get_by_slug;
+pub mod search;
+pub mod publish_scheduled;
+pub mod schedule;
+pub mod render_missing;
+pub mod related;
+pub mod bulk;
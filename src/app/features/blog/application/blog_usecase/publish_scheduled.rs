@@ -0,0 +1,24 @@
+use crate::app::features::blog::domain::entity::Blog;
+use crate::app::features::blog::domain::error::BlogError;
+use crate::app::features::blog::domain::repository::BlogRepository;
+use chrono::Utc;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn BlogRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn BlogRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Flips every SCHEDULED blog whose `published_at` has passed to
+    /// PUBLISHED and returns the blogs that went live.
+    pub async fn execute(&self) -> Result<Vec<Blog>, BlogError> {
+        self.repository
+            .publish_due_blogs(Utc::now().naive_utc())
+            .map_err(|e| BlogError::System(e.to_string()))
+    }
+}
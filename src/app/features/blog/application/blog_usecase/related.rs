@@ -0,0 +1,76 @@
+use crate::app::features::blog::domain::entity::RelatedWeights;
+use crate::app::features::blog::domain::error::BlogError;
+use crate::app::features::blog::domain::repository::BlogRepository;
+use crate::app::features::blog::interface::dto::{RelatedBlogRequestDto, RelatedBlogResponseDto};
+use chrono::Utc;
+use std::sync::Arc;
+use validator::Validate;
+
+/// The post to find recommendations for.
+pub enum RelatedTo {
+    /// Any post, as seen from the admin panel
+    Id(i32),
+    /// A post readers can currently see
+    Slug(String),
+}
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn BlogRepository + Send + Sync>,
+    weights: RelatedWeights,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn BlogRepository + Send + Sync>, weights: RelatedWeights) -> Self {
+        Self {
+            repository,
+            weights,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        target: RelatedTo,
+        dto: RelatedBlogRequestDto,
+    ) -> Result<Vec<RelatedBlogResponseDto>, BlogError> {
+        if let Err(e) = dto.validate() {
+            return Err(BlogError::Validation(e));
+        }
+
+        let blog = match target {
+            RelatedTo::Id(id) => self
+                .repository
+                .get_blog_by_id(id)
+                .map_err(|e| BlogError::System(e.to_string()))?,
+            RelatedTo::Slug(slug) => self
+                .repository
+                .get_blog_by_slug(slug)
+                .map_err(|e| BlogError::System(e.to_string()))?
+                .filter(|b| {
+                    b.status == "PUBLISHED"
+                        && b.published_at.is_some_and(|p| p <= Utc::now().naive_utc())
+                }),
+        }
+        .ok_or_else(|| BlogError::NotFound("Blog not found".to_string()))?;
+
+        let hits = self
+            .repository
+            .get_related_blogs(blog.id, self.weights, dto.limit.unwrap_or(5))
+            .map_err(|e| BlogError::System(e.to_string()))?;
+
+        Ok(hits
+            .into_iter()
+            .map(|hit| RelatedBlogResponseDto {
+                id: hit.id,
+                title: hit.title,
+                slug: hit.slug,
+                excerpt: hit.excerpt,
+                thumbnail: hit.thumbnail,
+                published_at: hit.published_at.map(|t| t.to_string()),
+                shared_tags: hit.shared_tags,
+                same_category: hit.same_category,
+                score: hit.score,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,32 @@
+use crate::app::features::blog::domain::entity::BlogRenderedContent;
+use crate::app::features::blog::domain::error::BlogError;
+use crate::app::features::blog::domain::repository::BlogRepository;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn BlogRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn BlogRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Renders and caches `content_html` for blogs written before rendering
+    /// existed. Returns how many blogs were rendered.
+    pub async fn execute(&self) -> Result<usize, BlogError> {
+        let blogs = self
+            .repository
+            .get_unrendered_blogs()
+            .map_err(|e| BlogError::System(e.to_string()))?;
+
+        for blog in &blogs {
+            self.repository
+                .save_rendered_content(blog.id, BlogRenderedContent::from_markdown(&blog.content))
+                .map_err(|e| BlogError::System(e.to_string()))?;
+        }
+
+        Ok(blogs.len())
+    }
+}
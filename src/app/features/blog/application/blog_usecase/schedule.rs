@@ -0,0 +1,57 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use validator::{ValidationError, ValidationErrors};
+
+/// Works out the `published_at` to store for a blog given its target status.
+///
+/// - `SCHEDULED` needs a parseable `requested` time that lies in the future.
+/// - `PUBLISHED` keeps `current` (the time it went live) or falls back to now.
+/// - Every other status clears it.
+pub fn resolve_published_at(
+    status: &str,
+    requested: Option<&str>,
+    current: Option<NaiveDateTime>,
+    validation_errors: &mut ValidationErrors,
+) -> Option<NaiveDateTime> {
+    let now = Utc::now().naive_utc();
+
+    match status {
+        "SCHEDULED" => {
+            let Some(raw) = requested else {
+                validation_errors.add(
+                    "published_at",
+                    ValidationError::new("Publish time is required for scheduled blogs"),
+                );
+                return None;
+            };
+            match parse_publish_time(raw) {
+                Some(at) if at > now => Some(at),
+                Some(_) => {
+                    validation_errors.add(
+                        "published_at",
+                        ValidationError::new("Publish time must be in the future"),
+                    );
+                    None
+                }
+                None => {
+                    validation_errors.add(
+                        "published_at",
+                        ValidationError::new("Invalid publish time format"),
+                    );
+                    None
+                }
+            }
+        }
+        "PUBLISHED" => Some(current.unwrap_or(now)),
+        _ => None,
+    }
+}
+
+/// Accepts RFC 3339 (any offset, normalised to UTC) or a naive UTC timestamp.
+fn parse_publish_time(raw: &str) -> Option<NaiveDateTime> {
+    if let Ok(at) = DateTime::parse_from_rfc3339(raw) {
+        return Some(at.with_timezone(&Utc).naive_utc());
+    }
+    ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S"]
+        .iter()
+        .find_map(|fmt| NaiveDateTime::parse_from_str(raw, fmt).ok())
+}
@@ -0,0 +1,91 @@
+use crate::app::features::blog::domain::entity::{SEARCH_HIGHLIGHT_END, SEARCH_HIGHLIGHT_START};
+use crate::app::features::blog::domain::error::BlogError;
+use crate::app::features::blog::domain::repository::BlogRepository;
+use crate::app::features::blog::interface::dto::{
+    BlogSearchResultDto, MetaDto, PaginatedResponseDto, SearchBlogRequestDto,
+};
+use std::sync::Arc;
+use validator::{Validate, ValidationError};
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn BlogRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn BlogRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        dto: SearchBlogRequestDto,
+        published_only: bool,
+    ) -> Result<PaginatedResponseDto<BlogSearchResultDto>, BlogError> {
+        let mut validation_errors = dto.validate().err().unwrap_or_default();
+
+        let query = dto.q.trim().to_string();
+        if query.is_empty() && validation_errors.is_empty() {
+            validation_errors.add("q", ValidationError::new("Search query is required"));
+        }
+
+        if !validation_errors.is_empty() {
+            return Err(BlogError::Validation(validation_errors));
+        }
+
+        let page = dto.page.unwrap_or(1);
+        let per_page = dto.per_page.unwrap_or(10);
+        let offset = (page - 1).saturating_mul(per_page);
+
+        let (hits, total_items) = self
+            .repository
+            .search_blog(query, published_only, per_page, offset)
+            .map_err(|e| BlogError::System(e.to_string()))?;
+
+        let items = hits
+            .into_iter()
+            .map(|hit| BlogSearchResultDto {
+                id: hit.id,
+                title: hit.title,
+                slug: hit.slug,
+                excerpt: hit.excerpt,
+                status: hit.status,
+                published_at: hit.published_at.map(|d| d.to_string()),
+                title_highlight: render_highlight(&hit.title_highlight),
+                snippet: render_highlight(&hit.snippet),
+                rank: hit.rank,
+            })
+            .collect();
+
+        let total_pages = (total_items as f64 / per_page as f64).ceil() as i64;
+
+        Ok(PaginatedResponseDto {
+            items,
+            meta: MetaDto {
+                page,
+                per_page,
+                total_pages,
+                total_items,
+            },
+        })
+    }
+}
+
+/// Escapes the stored text and only then swaps the FTS markers for `<mark>`,
+/// so post content can never inject markup into the highlighted output.
+fn render_highlight(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            SEARCH_HIGHLIGHT_START => out.push_str("<mark>"),
+            SEARCH_HIGHLIGHT_END => out.push_str("</mark>"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
@@ -1,13 +1,16 @@
-use chrono::Utc;
-
-use crate::app::features::blog::domain::entity::{BlogTags, NewBlog};
+use crate::app::features::series::interface::dto::BlogSeriesDto;
+use crate::app::features::authors::interface::dto::AuthorDto;
+use crate::app::features::media::interface::dto::ResponsiveImageDto;
+use crate::app::features::blog::domain::entity::{BlogRenderedContent, BlogTags, UpdateBlog};
 use crate::app::features::blog::domain::repository::BlogRepository;
 use crate::app::features::blog::interface::dto::UpdateBlogRequestDto;
 use std::sync::Arc;
+use crate::app::features::blog::application::blog_usecase::schedule::resolve_published_at;
 use crate::app::features::blog::domain::error::BlogError;
 use crate::app::features::blog::interface::dto::{
-    BlogResponseDto, CategoryResponseDto, TagResponseDto,
+    BlogResponseDto, CategoryResponseDto, TagResponseDto, TocEntryDto,
 };
+use crate::utils::slug::{SlugError, resolve_slug};
 use validator::Validate;
 use validator::ValidationError;
 
@@ -77,38 +80,44 @@ impl Execute {
 
         let title = dto.title;
 
-        let slug = title
-            .to_lowercase()
-            .replace(" ", "-")
-            .chars()
-            .filter(|c| c.is_alphanumeric() || *c == '-')
-            .collect::<String>();
-
-        // Validate Title/Slug Uniqueness
-        if let Some(existing) = self
-            .repository
-            .get_blog_by_slug(slug.clone())
-            .map_err(|e| BlogError::System(e.to_string()))?
-        {
-            if existing.id != id {
-                validation_errors.add("title", ValidationError::new("Title already exists"));
+        // Only a new title or an explicit slug moves the post to a new URL
+        let slug = if dto.slug.is_none() && title == existing_blog.title {
+            existing_blog.slug.clone()
+        } else {
+            match resolve_slug(dto.slug.as_deref(), &title, "post", |slug| {
+                self.repository
+                    .get_blog_by_slug(slug.to_string())
+                    .map(|existing| existing.is_some_and(|b| b.id != id))
+            }) {
+                Ok(slug) => slug,
+                Err(SlugError::Invalid(msg)) => {
+                    validation_errors.add("slug", ValidationError::new(msg));
+                    String::new()
+                }
+                Err(SlugError::Lookup(e)) => return Err(BlogError::System(e.to_string())),
             }
-        }
+        };
+
+        let status = dto.status;
+
+        // A post that is already live keeps its original publish time.
+        let current_published_at = existing_blog
+            .published_at
+            .filter(|_| existing_blog.status == "PUBLISHED");
+        let published_at = resolve_published_at(
+            &status,
+            dto.published_at.as_deref(),
+            current_published_at,
+            &mut validation_errors,
+        );
 
         if !validation_errors.is_empty() {
             return Err(BlogError::Validation(validation_errors));
         }
 
-        let status = dto.status;
-
-        let published_at = if status == "PUBLISHED" {
-            Some(Utc::now().naive_utc())
-        } else {
-            None
-        };
-
         // Update Blog (Merge DTO with existing)
-        let new_blog = NewBlog {
+        let rendered = BlogRenderedContent::from_markdown(&dto.content);
+        let new_blog = UpdateBlog {
             title,
             content: dto.content,
             category_id: dto.category_id,
@@ -117,13 +126,18 @@ impl Execute {
             thumbnail: dto.thumbnail.or(existing_blog.thumbnail),
             status: status.clone(),
             published_at,
-            view_count: existing_blog.view_count,
+            author_id: existing_blog.author_id,
+            rendered,
         };
         let updated_blog = self
             .repository
             .update_blog(id, new_blog)
             .map_err(|e| BlogError::System(e.to_string()))?;
 
+        self.repository
+            .create_blog_revision(&updated_blog)
+            .map_err(|e| BlogError::System(e.to_string()))?;
+
         // Update Tags if provided
         if let Some(ref tag_ids) = dto.tag_ids {
             // Delete existing tags
@@ -147,13 +161,12 @@ impl Execute {
         // 1. Category
         let final_category = new_category_obj
             .ok_or_else(|| BlogError::System("Category should exist".to_string()))?; // We validated it exists above
+        let category_path = self
+            .repository
+            .get_category_path(final_category.id)
+            .map_err(|e| BlogError::System(e.to_string()))?;
 
-        let category_dto = CategoryResponseDto {
-            id: final_category.id,
-            name: final_category.name,
-            created_at: final_category.created_at.to_string(),
-            updated_at: final_category.updated_at.to_string(),
-        };
+        let category_dto = CategoryResponseDto::from_category(final_category, category_path);
 
         // 2. Tags
         // If dto.tag_ids was Some, we have new_tags_objs populated.
@@ -166,6 +179,11 @@ impl Execute {
                 .map_err(|e| BlogError::System(e.to_string()))?
         };
 
+        let comment_count = self
+            .repository
+            .count_approved_comments(id)
+            .map_err(|e| BlogError::System(e.to_string()))?;
+
         let tags_dto = final_tags
             .into_iter()
             .map(|t| TagResponseDto {
@@ -173,9 +191,30 @@ impl Execute {
                 name: t.name,
                 created_at: t.created_at.to_string(),
                 updated_at: t.updated_at.to_string(),
+                post_count: None,
             })
             .collect();
 
+        let images = ResponsiveImageDto::embedded_in(
+            [updated_blog.thumbnail.as_deref().unwrap_or_default(), updated_blog.content.as_str()],
+            |filenames| self.repository.get_images(filenames),
+        )
+        .map_err(|e| e.to_string())?;
+
+        let series = self
+            .repository
+            .get_blog_series(updated_blog.id)
+            .map_err(|e| e.to_string())?
+            .and_then(|(series, posts)| BlogSeriesDto::for_blog(series, posts, updated_blog.id, false));
+
+        let author = updated_blog
+            .author_id
+            .map(|id| self.repository.get_author(id))
+            .transpose()
+            .map_err(|e| e.to_string())?
+            .flatten()
+            .map(AuthorDto::from);
+
         Ok(BlogResponseDto {
             id: updated_blog.id,
             title: updated_blog.title,
@@ -190,6 +229,14 @@ impl Execute {
             created_at: updated_blog.created_at.to_string(),
             updated_at: updated_blog.updated_at.to_string(),
             published_at: updated_blog.published_at.map(|d| d.to_string()),
+            content_html: updated_blog.content_html,
+            toc: TocEntryDto::from_json(updated_blog.toc.as_deref()),
+            word_count: updated_blog.word_count,
+            reading_time_minutes: updated_blog.reading_time_minutes,
+            comment_count,
+            images,
+            series,
+            author,
         })
     }
 }
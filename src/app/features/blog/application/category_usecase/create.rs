@@ -1,10 +1,19 @@
-use crate::app::features::blog::domain::entity::NewCategory;
+use crate::app::features::blog::domain::entity::{MAX_CATEGORY_DEPTH, NewCategory};
 use crate::app::features::blog::domain::error::BlogError;
 use crate::app::features::blog::domain::repository::BlogRepository;
 use crate::app::features::blog::interface::dto::{CategoryResponseDto, CreateCategoryRequestDto};
+use std::borrow::Cow;
 use std::sync::Arc;
 use validator::{Validate, ValidationError, ValidationErrors};
 
+/// Error for a create or move that would nest categories past `MAX_CATEGORY_DEPTH`.
+pub fn too_deep() -> ValidationError {
+    ValidationError::new("depth").with_message(Cow::Owned(format!(
+        "Categories cannot be nested more than {} levels deep",
+        MAX_CATEGORY_DEPTH
+    )))
+}
+
 #[derive(Clone)]
 pub struct Execute {
     repository: Arc<dyn BlogRepository + Send + Sync>,
@@ -33,21 +42,41 @@ impl Execute {
             validation_errors.add("name", ValidationError::new("Category name already exists"));
         }
 
+        if let Some(parent_id) = dto.parent_id {
+            let parent_path = self
+                .repository
+                .get_category_path(parent_id)
+                .map_err(|e| BlogError::System(e.to_string()))?;
+            if parent_path.is_empty() {
+                validation_errors.add(
+                    "parent_id",
+                    ValidationError::new("Parent category not found"),
+                );
+            } else if parent_path.len() + 1 > MAX_CATEGORY_DEPTH {
+                validation_errors.add("parent_id", too_deep());
+            }
+        }
+
         if !validation_errors.is_empty() {
             return Err(BlogError::Validation(validation_errors));
         }
 
-        let new_category = NewCategory { name: dto.name };
+        let new_category = NewCategory {
+            name: dto.name,
+            parent_id: dto.parent_id,
+        };
         let created_category = self
             .repository
             .create_category(new_category)
             .map_err(|e| BlogError::System(e.to_string()))?;
+        let category_path = self
+            .repository
+            .get_category_path(created_category.id)
+            .map_err(|e| BlogError::System(e.to_string()))?;
 
-        Ok(CategoryResponseDto {
-            id: created_category.id,
-            name: created_category.name,
-            created_at: created_category.created_at.to_string(),
-            updated_at: created_category.updated_at.to_string(),
-        })
+        Ok(CategoryResponseDto::from_category(
+            created_category,
+            category_path,
+        ))
     }
 }
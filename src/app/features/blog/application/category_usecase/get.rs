@@ -19,11 +19,11 @@ impl Execute {
             .map_err(|e| e.to_string())?
             .ok_or("Category not found".to_string())?;
 
-        Ok(CategoryResponseDto {
-            id: category.id,
-            name: category.name,
-            created_at: category.created_at.to_string(),
-            updated_at: category.updated_at.to_string(),
-        })
+        let category_path = self
+            .repository
+            .get_category_path(category.id)
+            .map_err(|e| e.to_string())?;
+
+        Ok(CategoryResponseDto::from_category(category, category_path))
     }
 }
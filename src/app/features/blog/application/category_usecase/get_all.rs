@@ -30,15 +30,14 @@ impl Execute {
             .get_all_category(per_page, offset)
             .map_err(|e| e.to_string())?;
 
-        let category_dtos = categories
-            .into_iter()
-            .map(|c| CategoryResponseDto {
-                id: c.id,
-                name: c.name,
-                created_at: c.created_at.to_string(),
-                updated_at: c.updated_at.to_string(),
-            })
-            .collect();
+        let mut category_dtos = Vec::new();
+        for category in categories {
+            let category_path = self
+                .repository
+                .get_category_path(category.id)
+                .map_err(|e| e.to_string())?;
+            category_dtos.push(CategoryResponseDto::from_category(category, category_path));
+        }
 
         let total_pages = (total_items as f64 / per_page as f64).ceil() as i64;
 
@@ -2,4 +2,5 @@ pub mod create;
 pub mod delete;
 pub mod get;
 pub mod get_all;
+pub mod tree;
 pub mod update;
@@ -0,0 +1,23 @@
+use crate::app::features::blog::domain::repository::BlogRepository;
+use crate::app::features::blog::interface::dto::CategoryTreeNodeDto;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn BlogRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn BlogRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<CategoryTreeNodeDto>, String> {
+        let categories = self
+            .repository
+            .get_all_categories()
+            .map_err(|e| e.to_string())?;
+
+        Ok(CategoryTreeNodeDto::build(categories))
+    }
+}
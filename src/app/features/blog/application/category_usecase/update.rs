@@ -1,4 +1,5 @@
-use crate::app::features::blog::domain::entity::NewCategory;
+use crate::app::features::blog::application::category_usecase::create::too_deep;
+use crate::app::features::blog::domain::entity::{MAX_CATEGORY_DEPTH, NewCategory};
 use crate::app::features::blog::domain::error::BlogError;
 use crate::app::features::blog::domain::repository::BlogRepository;
 use crate::app::features::blog::interface::dto::{CategoryResponseDto, UpdateCategoryRequestDto};
@@ -44,23 +45,64 @@ impl Execute {
             }
         }
 
+        // `Some(None)` moves the category to the top level, `None` keeps its parent
+        let parent_id = dto.parent_id.unwrap_or(existing.parent_id);
+        if let Some(parent_id) = parent_id
+            && existing.parent_id != Some(parent_id)
+        {
+            let parent_path = self
+                .repository
+                .get_category_path(parent_id)
+                .map_err(|e| BlogError::System(e.to_string()))?;
+            // The descendant walk has no depth cap, unlike the breadcrumb path
+            let descendant_ids = self
+                .repository
+                .get_category_descendant_ids(id)
+                .map_err(|e| BlogError::System(e.to_string()))?;
+            if parent_path.is_empty() {
+                validation_errors.add(
+                    "parent_id",
+                    ValidationError::new("Parent category not found"),
+                );
+            } else if descendant_ids.contains(&parent_id) {
+                validation_errors.add(
+                    "parent_id",
+                    ValidationError::new(
+                        "A category cannot be moved under itself or its descendants",
+                    ),
+                );
+            } else {
+                // The whole subtree moves along, so its deepest leaf counts
+                let height = self
+                    .repository
+                    .get_category_subtree_height(id)
+                    .map_err(|e| BlogError::System(e.to_string()))?;
+                if parent_path.len() + height > MAX_CATEGORY_DEPTH {
+                    validation_errors.add("parent_id", too_deep());
+                }
+            }
+        }
+
         if !validation_errors.is_empty() {
             return Err(BlogError::Validation(validation_errors));
         }
 
         let new_category = NewCategory {
             name: dto.name.unwrap_or(existing.name),
+            parent_id,
         };
         let updated_category = self
             .repository
             .update_category(id, new_category)
             .map_err(|e| BlogError::System(e.to_string()))?;
+        let category_path = self
+            .repository
+            .get_category_path(updated_category.id)
+            .map_err(|e| BlogError::System(e.to_string()))?;
 
-        Ok(CategoryResponseDto {
-            id: updated_category.id,
-            name: updated_category.name,
-            created_at: updated_category.created_at.to_string(),
-            updated_at: updated_category.updated_at.to_string(),
-        })
+        Ok(CategoryResponseDto::from_category(
+            updated_category,
+            category_path,
+        ))
     }
 }
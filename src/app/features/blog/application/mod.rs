@@ -1,3 +1,4 @@
+pub mod blog_usecase;
 pub mod category_usecase;
+pub mod revision_usecase;
 pub mod tag_usecase;
-pub mod blog_usecase;
\ No newline at end of file
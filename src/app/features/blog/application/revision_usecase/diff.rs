@@ -0,0 +1,88 @@
+use crate::app::features::blog::domain::error::BlogError;
+use crate::app::features::blog::domain::repository::BlogRepository;
+use crate::app::features::blog::interface::dto::{
+    BlogRevisionDiffRequestDto, BlogRevisionDiffResponseDto, DiffLineDto,
+};
+use similar::{ChangeTag, TextDiff};
+use std::sync::Arc;
+use validator::Validate;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn BlogRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn BlogRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        blog_id: i32,
+        query: BlogRevisionDiffRequestDto,
+    ) -> Result<BlogRevisionDiffResponseDto, BlogError> {
+        query.validate().map_err(BlogError::Validation)?;
+
+        let from = self
+            .repository
+            .get_blog_revision(blog_id, query.from)
+            .map_err(|e| BlogError::System(e.to_string()))?
+            .ok_or_else(|| BlogError::NotFound("Revision not found".to_string()))?;
+        let to = self
+            .repository
+            .get_blog_revision(blog_id, query.to)
+            .map_err(|e| BlogError::System(e.to_string()))?
+            .ok_or_else(|| BlogError::NotFound("Revision not found".to_string()))?;
+
+        let title = diff_lines(&from.title, &to.title);
+        let excerpt = diff_lines(
+            from.excerpt.as_deref().unwrap_or_default(),
+            to.excerpt.as_deref().unwrap_or_default(),
+        );
+        let content = diff_lines(&from.content, &to.content);
+
+        let count = |tag: &str| {
+            [&title, &excerpt, &content]
+                .iter()
+                .flat_map(|lines| lines.iter())
+                .filter(|l| l.tag == tag)
+                .count()
+        };
+        let additions = count("insert");
+        let deletions = count("delete");
+
+        Ok(BlogRevisionDiffResponseDto {
+            blog_id,
+            from_revision: from.revision_number,
+            to_revision: to.revision_number,
+            title,
+            excerpt,
+            content,
+            additions,
+            deletions,
+        })
+    }
+}
+
+fn diff_lines(old: &str, new: &str) -> Vec<DiffLineDto> {
+    // Terminate both sides so a missing trailing newline does not show up as
+    // a changed last line.
+    let old = format!("{}\n", old.strip_suffix('\n').unwrap_or(old));
+    let new = format!("{}\n", new.strip_suffix('\n').unwrap_or(new));
+
+    TextDiff::from_lines(&old, &new)
+        .iter_all_changes()
+        .map(|change| DiffLineDto {
+            tag: match change.tag() {
+                ChangeTag::Equal => "equal",
+                ChangeTag::Insert => "insert",
+                ChangeTag::Delete => "delete",
+            }
+            .to_string(),
+            old_line: change.old_index().map(|i| i + 1),
+            new_line: change.new_index().map(|i| i + 1),
+            value: change.value().trim_end_matches(['\r', '\n']).to_string(),
+        })
+        .collect()
+}
@@ -0,0 +1,37 @@
+use crate::app::features::blog::domain::error::BlogError;
+use crate::app::features::blog::domain::repository::BlogRepository;
+use crate::app::features::blog::interface::dto::BlogRevisionResponseDto;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn BlogRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn BlogRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        blog_id: i32,
+        revision_number: i32,
+    ) -> Result<BlogRevisionResponseDto, BlogError> {
+        let revision = self
+            .repository
+            .get_blog_revision(blog_id, revision_number)
+            .map_err(|e| BlogError::System(e.to_string()))?
+            .ok_or_else(|| BlogError::NotFound("Revision not found".to_string()))?;
+
+        Ok(BlogRevisionResponseDto {
+            id: revision.id,
+            blog_id: revision.blog_id,
+            revision_number: revision.revision_number,
+            title: revision.title,
+            excerpt: revision.excerpt,
+            content: revision.content,
+            created_at: revision.created_at.to_string(),
+        })
+    }
+}
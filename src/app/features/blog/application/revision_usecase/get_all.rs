@@ -0,0 +1,62 @@
+use crate::app::features::blog::domain::error::BlogError;
+use crate::app::features::blog::domain::repository::BlogRepository;
+use crate::app::features::blog::interface::dto::{
+    BlogRevisionResponseDto, MetaDto, PaginatedResponseDto, PaginationRequestDto,
+};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn BlogRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn BlogRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        blog_id: i32,
+        query: PaginationRequestDto,
+    ) -> Result<PaginatedResponseDto<BlogRevisionResponseDto>, BlogError> {
+        self.repository
+            .get_blog_by_id(blog_id)
+            .map_err(|e| BlogError::System(e.to_string()))?
+            .ok_or_else(|| BlogError::NotFound("Blog not found".to_string()))?;
+
+        let page = query.page.unwrap_or(1);
+        let per_page = query.per_page.unwrap_or(10);
+        let offset = (page - 1) * per_page;
+
+        let (revisions, total_items) = self
+            .repository
+            .get_blog_revisions(blog_id, per_page, offset)
+            .map_err(|e| BlogError::System(e.to_string()))?;
+
+        let items = revisions
+            .into_iter()
+            .map(|r| BlogRevisionResponseDto {
+                id: r.id,
+                blog_id: r.blog_id,
+                revision_number: r.revision_number,
+                title: r.title,
+                excerpt: r.excerpt,
+                content: r.content,
+                created_at: r.created_at.to_string(),
+            })
+            .collect();
+
+        let total_pages = (total_items as f64 / per_page as f64).ceil() as i64;
+
+        Ok(PaginatedResponseDto {
+            items,
+            meta: MetaDto {
+                page,
+                per_page,
+                total_pages,
+                total_items,
+            },
+        })
+    }
+}
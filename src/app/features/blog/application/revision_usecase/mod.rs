@@ -0,0 +1,4 @@
+pub mod diff;
+pub mod get;
+pub mod get_all;
+pub mod restore;
@@ -0,0 +1,87 @@
+use crate::app::features::blog::domain::entity::{BlogRenderedContent, UpdateBlog};
+use crate::app::features::blog::domain::error::BlogError;
+use crate::app::features::blog::domain::repository::BlogRepository;
+use crate::app::features::blog::interface::dto::BlogRevisionResponseDto;
+use crate::utils::slug::{SlugError, resolve_slug};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn BlogRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn BlogRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Copies the text of an old revision back onto the blog and records it as
+    /// a brand new revision; existing revisions are never modified.
+    pub async fn execute(
+        &self,
+        blog_id: i32,
+        revision_number: i32,
+    ) -> Result<BlogRevisionResponseDto, BlogError> {
+        let existing_blog = self
+            .repository
+            .get_blog_by_id(blog_id)
+            .map_err(|e| BlogError::System(e.to_string()))?
+            .ok_or_else(|| BlogError::NotFound("Blog not found".to_string()))?;
+
+        let revision = self
+            .repository
+            .get_blog_revision(blog_id, revision_number)
+            .map_err(|e| BlogError::System(e.to_string()))?
+            .ok_or_else(|| BlogError::NotFound("Revision not found".to_string()))?;
+
+        // The old title's slug may have been taken by another post in the
+        // meantime, in which case the restored post gets a numbered one
+        let slug = if revision.title == existing_blog.title {
+            existing_blog.slug.clone()
+        } else {
+            resolve_slug(None, &revision.title, "post", |slug| {
+                self.repository
+                    .get_blog_by_slug(slug.to_string())
+                    .map(|existing| existing.is_some_and(|b| b.id != blog_id))
+            })
+            .map_err(|e| match e {
+                SlugError::Invalid(msg) => BlogError::System(msg.to_string()),
+                SlugError::Lookup(e) => BlogError::System(e.to_string()),
+            })?
+        };
+
+        let restored_blog = self
+            .repository
+            .update_blog(
+                blog_id,
+                UpdateBlog {
+                    rendered: BlogRenderedContent::from_markdown(&revision.content),
+                    title: revision.title,
+                    content: revision.content,
+                    category_id: existing_blog.category_id,
+                    slug,
+                    excerpt: revision.excerpt.unwrap_or_default(),
+                    thumbnail: existing_blog.thumbnail,
+                    status: existing_blog.status,
+                    published_at: existing_blog.published_at,
+                    author_id: existing_blog.author_id,
+                },
+            )
+            .map_err(|e| BlogError::System(e.to_string()))?;
+
+        let new_revision = self
+            .repository
+            .create_blog_revision(&restored_blog)
+            .map_err(|e| BlogError::System(e.to_string()))?;
+
+        Ok(BlogRevisionResponseDto {
+            id: new_revision.id,
+            blog_id: new_revision.blog_id,
+            revision_number: new_revision.revision_number,
+            title: new_revision.title,
+            excerpt: new_revision.excerpt,
+            content: new_revision.content,
+            created_at: new_revision.created_at.to_string(),
+        })
+    }
+}
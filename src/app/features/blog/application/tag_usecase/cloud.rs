@@ -0,0 +1,59 @@
+use crate::app::features::blog::domain::error::BlogError;
+use crate::app::features::blog::domain::repository::BlogRepository;
+use crate::app::features::blog::interface::dto::{TagCloudEntryDto, TagCloudQueryDto};
+use chrono::Utc;
+use std::sync::Arc;
+use validator::Validate;
+
+pub const TAG_CLOUD_DEFAULT_LIMIT: i64 = 50;
+/// Weights run from 1 up to this.
+pub const TAG_CLOUD_LEVELS: i64 = 5;
+
+/// Linear weight of `count` between the smallest and largest count.
+pub fn tag_weight(count: i64, min: i64, max: i64) -> i64 {
+    if max <= min {
+        return 1;
+    }
+    1 + ((count - min) * (TAG_CLOUD_LEVELS - 1) + (max - min) / 2) / (max - min)
+}
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn BlogRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn BlogRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Most used tags among published posts, by name.
+    pub async fn execute(
+        &self,
+        query: TagCloudQueryDto,
+    ) -> Result<Vec<TagCloudEntryDto>, BlogError> {
+        query.validate().map_err(BlogError::Validation)?;
+
+        let tags = self
+            .repository
+            .get_tag_cloud(
+                Utc::now().naive_utc(),
+                query.limit.unwrap_or(TAG_CLOUD_DEFAULT_LIMIT),
+            )
+            .map_err(|e| BlogError::System(e.to_string()))?;
+
+        let min = tags.iter().map(|(_, c)| *c).min().unwrap_or(0);
+        let max = tags.iter().map(|(_, c)| *c).max().unwrap_or(0);
+        let mut entries = tags
+            .into_iter()
+            .map(|(tag, post_count)| TagCloudEntryDto {
+                id: tag.id,
+                name: tag.name,
+                post_count,
+                weight: tag_weight(post_count, min, max),
+            })
+            .collect::<Vec<_>>();
+        entries.sort_by_key(|e| e.name.to_lowercase());
+        Ok(entries)
+    }
+}
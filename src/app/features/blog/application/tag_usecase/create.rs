@@ -45,6 +45,7 @@ impl Execute {
             name: created_tag.name,
             created_at: created_tag.created_at.to_string(),
             updated_at: created_tag.updated_at.to_string(),
+            post_count: None,
         })
     }
 }
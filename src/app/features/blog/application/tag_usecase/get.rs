@@ -24,6 +24,7 @@ impl Execute {
             name: tag.name,
             created_at: tag.created_at.to_string(),
             updated_at: tag.updated_at.to_string(),
+            post_count: None,
         })
     }
 }
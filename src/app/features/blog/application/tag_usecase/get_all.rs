@@ -1,5 +1,6 @@
 use crate::app::features::blog::domain::repository::BlogRepository;
 use crate::app::features::blog::interface::dto::TagResponseDto;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 #[derive(Clone)]
@@ -14,7 +15,7 @@ impl Execute {
 
     pub async fn execute(
         &self,
-        query: crate::app::features::blog::interface::dto::PaginationRequestDto,
+        query: crate::app::features::blog::interface::dto::TagListQueryDto,
     ) -> Result<
         crate::app::features::blog::interface::dto::PaginatedResponseDto<TagResponseDto>,
         String,
@@ -28,6 +29,18 @@ impl Execute {
             .get_all_tag(per_page, offset)
             .map_err(|e| e.to_string())?;
 
+        let counts = if query.with_counts.unwrap_or(false) {
+            Some(
+                self.repository
+                    .get_tag_post_counts(tags.iter().map(|t| t.id).collect())
+                    .map_err(|e| e.to_string())?
+                    .into_iter()
+                    .collect::<HashMap<i32, i64>>(),
+            )
+        } else {
+            None
+        };
+
         let tag_dtos = tags
             .into_iter()
             .map(|tag| TagResponseDto {
@@ -35,6 +48,9 @@ impl Execute {
                 name: tag.name,
                 created_at: tag.created_at.to_string(),
                 updated_at: tag.updated_at.to_string(),
+                post_count: counts
+                    .as_ref()
+                    .map(|c| c.get(&tag.id).copied().unwrap_or(0)),
             })
             .collect();
 
@@ -0,0 +1,79 @@
+use crate::app::features::blog::domain::error::BlogError;
+use crate::app::features::blog::domain::repository::BlogRepository;
+use crate::app::features::blog::interface::dto::{
+    MergeTagRequestDto, MergeTagResponseDto, TagResponseDto,
+};
+use std::sync::Arc;
+use validator::{Validate, ValidationError};
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn BlogRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn BlogRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Folds tag `id` into `dto.target_id`: its posts move over and it is deleted.
+    pub async fn execute(
+        &self,
+        id: i32,
+        dto: MergeTagRequestDto,
+    ) -> Result<MergeTagResponseDto, BlogError> {
+        self.repository
+            .get_tag_by_id(id)
+            .map_err(|e| BlogError::System(e.to_string()))?
+            .ok_or_else(|| BlogError::NotFound("Tag not found".to_string()))?;
+
+        let mut validation_errors = dto.validate().err().unwrap_or_default();
+        if validation_errors.is_empty() {
+            if dto.target_id == id {
+                validation_errors.add(
+                    "target_id",
+                    ValidationError::new("Cannot merge a tag into itself"),
+                );
+            } else if self
+                .repository
+                .get_tag_by_id(dto.target_id)
+                .map_err(|e| BlogError::System(e.to_string()))?
+                .is_none()
+            {
+                validation_errors.add("target_id", ValidationError::new("Tag not found"));
+            }
+        }
+        if !validation_errors.is_empty() {
+            return Err(BlogError::Validation(validation_errors));
+        }
+
+        let (moved_posts, already_tagged) = self
+            .repository
+            .merge_tag(id, dto.target_id)
+            .map_err(|e| BlogError::System(e.to_string()))?;
+
+        let tag = self
+            .repository
+            .get_tag_by_id(dto.target_id)
+            .map_err(|e| BlogError::System(e.to_string()))?
+            .ok_or_else(|| BlogError::NotFound("Tag not found".to_string()))?;
+        let post_count = self
+            .repository
+            .get_tag_post_counts(vec![tag.id])
+            .map_err(|e| BlogError::System(e.to_string()))?
+            .first()
+            .map_or(0, |(_, count)| *count);
+
+        Ok(MergeTagResponseDto {
+            tag: TagResponseDto {
+                id: tag.id,
+                name: tag.name,
+                created_at: tag.created_at.to_string(),
+                updated_at: tag.updated_at.to_string(),
+                post_count: Some(post_count),
+            },
+            moved_posts,
+            already_tagged,
+        })
+    }
+}
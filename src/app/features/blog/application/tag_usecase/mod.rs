@@ -1,5 +1,7 @@
-pub mod get_all;
+pub mod cloud;
+pub mod create;
+pub mod delete;
 pub mod get;
+pub mod get_all;
+pub mod merge;
 pub mod update;
-pub mod delete;
-pub mod create;
\ No newline at end of file
@@ -60,6 +60,7 @@ impl Execute {
             name: updated_tag.name,
             created_at: updated_tag.created_at.to_string(),
             updated_at: updated_tag.updated_at.to_string(),
+            post_count: None,
         })
     }
 }
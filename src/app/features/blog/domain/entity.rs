@@ -1,20 +1,26 @@
-use crate::schema::{blog, blog_tags, categories, tags};
+use crate::schema::{blog, blog_revisions, blog_tags, categories, tags};
+use crate::utils::markdown;
 use chrono::NaiveDateTime;
 use diesel::prelude::*;
+use diesel::sql_types::{BigInt, Bool, Double, Integer, Nullable, Text, Timestamp};
 
-#[derive(Queryable, Selectable, Identifiable, Debug, Clone, PartialEq)]
+#[derive(Queryable, QueryableByName, Selectable, Identifiable, Debug, Clone, PartialEq)]
 #[diesel(table_name =categories)]
 pub struct Category {
     pub id: i32,
     pub name: String,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub parent_id: Option<i32>,
 }
 
+/// `parent_id = None` makes the category top level, also on update.
 #[derive(Insertable, AsChangeset)]
 #[diesel(table_name =categories)]
+#[diesel(treat_none_as_null = true)]
 pub struct NewCategory {
     pub name: String,
+    pub parent_id: Option<i32>,
 }
 
 #[derive(Queryable, Selectable, Identifiable, Debug, Clone, PartialEq)]
@@ -48,9 +54,14 @@ pub struct Blog {
     pub status: String,
     pub published_at: Option<NaiveDateTime>,
     pub view_count: i32,
+    pub content_html: Option<String>,
+    pub toc: Option<String>,
+    pub word_count: i32,
+    pub reading_time_minutes: i32,
+    pub author_id: Option<i32>,
 }
 
-#[derive(Insertable, AsChangeset)]
+#[derive(Insertable)]
 #[diesel(table_name =blog)]
 pub struct NewBlog {
     pub title: String,
@@ -62,6 +73,75 @@ pub struct NewBlog {
     pub status: String,
     pub published_at: Option<NaiveDateTime>,
     pub view_count: i32,
+    pub author_id: Option<i32>,
+    #[diesel(embed)]
+    pub rendered: BlogRenderedContent,
+}
+
+/// Editable columns of a blog. `view_count` is left out on purpose: only
+/// view tracking may change it, so an edit can't write back a stale count.
+#[derive(AsChangeset)]
+#[diesel(table_name =blog)]
+pub struct UpdateBlog {
+    pub title: String,
+    pub content: String,
+    pub category_id: i32,
+    pub slug: String,
+    pub excerpt: String,
+    pub thumbnail: Option<String>,
+    pub status: String,
+    pub published_at: Option<NaiveDateTime>,
+    pub author_id: Option<i32>,
+    #[diesel(embed)]
+    pub rendered: BlogRenderedContent,
+}
+
+/// Cached output of `utils::markdown::render` for a blog's content. `toc` is
+/// the JSON encoded list of `TocEntry`.
+#[derive(Insertable, AsChangeset, Debug, Clone)]
+#[diesel(table_name =blog)]
+pub struct BlogRenderedContent {
+    pub content_html: Option<String>,
+    pub toc: Option<String>,
+    pub word_count: i32,
+    pub reading_time_minutes: i32,
+}
+
+impl BlogRenderedContent {
+    pub fn from_markdown(content: &str) -> Self {
+        let rendered = markdown::render(content);
+        Self {
+            toc: Some(rendered.toc_json()),
+            content_html: Some(rendered.html),
+            word_count: rendered.word_count,
+            reading_time_minutes: rendered.reading_time_minutes,
+        }
+    }
+}
+
+/// Snapshot of a blog's editable text, written on create, every update and
+/// every restore. `revision_number` counts up per blog starting at 1.
+#[derive(Identifiable, Queryable, Selectable, Associations, Debug, Clone, PartialEq)]
+#[diesel(table_name =blog_revisions)]
+#[diesel(belongs_to(Blog,foreign_key = blog_id))]
+pub struct BlogRevision {
+    pub id: i32,
+    pub blog_id: i32,
+    pub revision_number: i32,
+    pub title: String,
+    pub excerpt: Option<String>,
+    pub content: String,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name =blog_revisions)]
+pub struct NewBlogRevision {
+    pub blog_id: i32,
+    pub revision_number: i32,
+    pub title: String,
+    pub excerpt: Option<String>,
+    pub content: String,
 }
 
 #[derive(Identifiable, Queryable, Selectable, Associations, Insertable)]
@@ -73,3 +153,102 @@ pub struct BlogTags {
     pub blog_id: i32,
     pub tag_id: i32,
 }
+
+/// A single full-text match from `blog_fts`. `title_highlight` and `snippet`
+/// wrap matched terms in `SEARCH_HIGHLIGHT_START` / `SEARCH_HIGHLIGHT_END`.
+#[derive(QueryableByName, Debug, Clone)]
+pub struct BlogSearchHit {
+    #[diesel(sql_type = Integer)]
+    pub id: i32,
+    #[diesel(sql_type = Text)]
+    pub title: String,
+    #[diesel(sql_type = Text)]
+    pub slug: String,
+    #[diesel(sql_type = Nullable<Text>)]
+    pub excerpt: Option<String>,
+    #[diesel(sql_type = Text)]
+    pub status: String,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub published_at: Option<NaiveDateTime>,
+    #[diesel(sql_type = Text)]
+    pub title_highlight: String,
+    #[diesel(sql_type = Text)]
+    pub snippet: String,
+    #[diesel(sql_type = Double)]
+    pub rank: f64,
+}
+
+/// A published post ranked against another one by `get_related_blogs`.
+#[derive(QueryableByName, Debug, Clone)]
+pub struct RelatedBlogHit {
+    #[diesel(sql_type = Integer)]
+    pub id: i32,
+    #[diesel(sql_type = Text)]
+    pub title: String,
+    #[diesel(sql_type = Text)]
+    pub slug: String,
+    #[diesel(sql_type = Nullable<Text>)]
+    pub excerpt: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    pub thumbnail: Option<String>,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub published_at: Option<NaiveDateTime>,
+    #[diesel(sql_type = BigInt)]
+    pub shared_tags: i64,
+    #[diesel(sql_type = Bool)]
+    pub same_category: bool,
+    #[diesel(sql_type = Double)]
+    pub score: f64,
+}
+
+/// How much each signal contributes to a related-post score:
+/// `tag * shared_tags + category * same_category + recency / (1 + age_days / 30)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RelatedWeights {
+    pub tag: f64,
+    pub category: f64,
+    pub recency: f64,
+}
+
+/// Deepest allowed category nesting; a top-level category is at depth 1.
+/// Creating, moving and importing categories all stay within it.
+pub const MAX_CATEGORY_DEPTH: usize = 32;
+
+pub const SEARCH_HIGHLIGHT_START: char = '\u{E000}';
+pub const SEARCH_HIGHLIGHT_END: char = '\u{E001}';
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum BlogSortField {
+    #[default]
+    Id,
+    PublishedAt,
+    UpdatedAt,
+    ViewCount,
+    Title,
+}
+
+/// What a bulk request does to each of the selected blogs.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BulkBlogAction {
+    Publish,
+    Archive,
+    Draft,
+    Delete,
+    ChangeCategory(i32),
+    AddTags(Vec<i32>),
+    RemoveTags(Vec<i32>),
+}
+
+/// Criteria for the admin blog listing. Empty `tag_ids` means no tag filter;
+/// otherwise a blog matches when it carries any of the given tags.
+#[derive(Debug, Clone, Default)]
+pub struct BlogFilter {
+    pub status: Option<String>,
+    /// Empty means no category filter; otherwise a blog must be in one of them
+    pub category_ids: Vec<i32>,
+    pub tag_ids: Vec<i32>,
+    pub published_from: Option<NaiveDateTime>,
+    pub published_until: Option<NaiveDateTime>,
+    pub sort_by: BlogSortField,
+    pub descending: bool,
+}
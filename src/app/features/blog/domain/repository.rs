@@ -1,28 +1,102 @@
+use crate::app::features::authors::domain::entity::Author;
 use crate::app::features::blog::domain::entity::{
-    Blog, BlogTags, Category, NewBlog, NewCategory, NewTag, Tag,
+    Blog, BlogFilter, BlogRenderedContent, BlogRevision, BlogSearchHit, BlogTags, BulkBlogAction,
+    Category, NewBlog, NewCategory, NewTag, RelatedBlogHit, RelatedWeights, Tag, UpdateBlog,
 };
+use crate::app::features::media::domain::entity::{Media, MediaVariant};
+use crate::app::features::series::domain::entity::{Series, SeriesPost};
+use chrono::NaiveDateTime;
 use diesel::QueryResult;
 
 pub trait BlogRepository {
-    fn get_all_blog(&self, limit: i64, offset: i64) -> QueryResult<(Vec<Blog>, i64)>;
+    fn get_all_blog(
+        &self,
+        filter: BlogFilter,
+        limit: i64,
+        offset: i64,
+    ) -> QueryResult<(Vec<Blog>, i64)>;
+    fn get_all_published_blog(&self, limit: i64, offset: i64) -> QueryResult<(Vec<Blog>, i64)>;
     fn get_blog_by_id(&self, id: i32) -> QueryResult<Option<Blog>>;
+    fn get_unrendered_blogs(&self) -> QueryResult<Vec<Blog>>;
+    fn save_rendered_content(&self, id: i32, rendered: BlogRenderedContent) -> QueryResult<usize>;
+    fn publish_due_blogs(&self, now: NaiveDateTime) -> QueryResult<Vec<Blog>>;
     fn create_blog(&self, blog: NewBlog) -> QueryResult<Blog>;
     fn create_blog_tags(&self, blog_tags: BlogTags) -> QueryResult<BlogTags>;
-    fn update_blog(&self, id: i32, blog: NewBlog) -> QueryResult<Blog>;
+    fn update_blog(&self, id: i32, blog: UpdateBlog) -> QueryResult<Blog>;
     fn delete_blog(&self, id: i32) -> QueryResult<usize>;
+    /// Applies `action` to every id in one transaction. Each id gets its own
+    /// savepoint, so a failing id is rolled back and reported with its reason
+    /// while the others still go through. A successful id comes back with the
+    /// status it had before the action, read inside the same transaction.
+    fn bulk_update_blogs(
+        &self,
+        ids: &[i32],
+        action: &BulkBlogAction,
+    ) -> QueryResult<Vec<(i32, Result<String, String>)>>;
     fn delete_blog_tags_by_blog_id(&self, blog_id: i32) -> QueryResult<usize>;
     fn get_tags_by_blog_id(&self, blog_id: i32) -> QueryResult<Vec<Tag>>;
+    fn count_approved_comments(&self, blog_id: i32) -> QueryResult<i64>;
+    /// Library images among `filenames`, with their variants
+    #[allow(clippy::type_complexity)]
+    fn get_images(&self, filenames: Vec<String>) -> QueryResult<Vec<(Media, Vec<MediaVariant>)>>;
+    /// Series the post belongs to, with all its posts in reading order
+    #[allow(clippy::type_complexity)]
+    fn get_blog_series(&self, blog_id: i32) -> QueryResult<Option<(Series, Vec<SeriesPost>)>>;
+    fn get_author(&self, user_id: i32) -> QueryResult<Option<Author>>;
+    fn get_author_by_username(&self, username: String) -> QueryResult<Option<Author>>;
     fn get_all_tag(&self, limit: i64, offset: i64) -> QueryResult<(Vec<Tag>, i64)>;
     fn get_tag_by_id(&self, id: i32) -> QueryResult<Option<Tag>>;
     fn create_tag(&self, tag: NewTag) -> QueryResult<Tag>;
     fn update_tag(&self, id: i32, tag: NewTag) -> QueryResult<Tag>;
     fn delete_tag(&self, id: i32) -> QueryResult<usize>;
+    /// `(tag_id, posts)` for the given tags; tags without posts are left out.
+    fn get_tag_post_counts(&self, tag_ids: Vec<i32>) -> QueryResult<Vec<(i32, i64)>>;
+    /// Moves every post of `source_id` to `target_id` and deletes `source_id`,
+    /// in one transaction. Returns `(moved, already_tagged)` post counts.
+    fn merge_tag(&self, source_id: i32, target_id: i32) -> QueryResult<(i64, i64)>;
+    /// The `limit` tags with the most posts visible at `now`, with those counts.
+    fn get_tag_cloud(&self, now: NaiveDateTime, limit: i64) -> QueryResult<Vec<(Tag, i64)>>;
     fn get_all_category(&self, limit: i64, offset: i64) -> QueryResult<(Vec<Category>, i64)>;
     fn get_category_by_id(&self, id: i32) -> QueryResult<Option<Category>>;
     fn create_category(&self, category: NewCategory) -> QueryResult<Category>;
     fn update_category(&self, id: i32, category: NewCategory) -> QueryResult<Category>;
     fn delete_category(&self, id: i32) -> QueryResult<usize>;
+    /// Every category, by name; small enough to build the tree in memory.
+    fn get_all_categories(&self) -> QueryResult<Vec<Category>>;
+    /// `id` and its ancestors, top level first. Empty when `id` does not exist.
+    fn get_category_path(&self, id: i32) -> QueryResult<Vec<Category>>;
+    /// `id` and every category below it.
+    fn get_category_descendant_ids(&self, id: i32) -> QueryResult<Vec<i32>>;
+    /// Levels in the subtree rooted at `id`, counting `id` itself (1 for a
+    /// category without children). Stops counting past `MAX_CATEGORY_DEPTH`.
+    fn get_category_subtree_height(&self, id: i32) -> QueryResult<usize>;
     fn get_blog_by_slug(&self, slug: String) -> QueryResult<Option<Blog>>;
     fn get_category_by_name(&self, name: String) -> QueryResult<Option<Category>>;
     fn get_tag_by_name(&self, name: String) -> QueryResult<Option<Tag>>;
+    fn create_blog_revision(&self, blog: &Blog) -> QueryResult<BlogRevision>;
+    fn get_blog_revisions(
+        &self,
+        blog_id: i32,
+        limit: i64,
+        offset: i64,
+    ) -> QueryResult<(Vec<BlogRevision>, i64)>;
+    fn get_blog_revision(
+        &self,
+        blog_id: i32,
+        revision_number: i32,
+    ) -> QueryResult<Option<BlogRevision>>;
+    /// Published posts other than `blog_id`, best match first.
+    fn get_related_blogs(
+        &self,
+        blog_id: i32,
+        weights: RelatedWeights,
+        limit: i64,
+    ) -> QueryResult<Vec<RelatedBlogHit>>;
+    fn search_blog(
+        &self,
+        query: String,
+        published_only: bool,
+        limit: i64,
+        offset: i64,
+    ) -> QueryResult<(Vec<BlogSearchHit>, i64)>;
 }
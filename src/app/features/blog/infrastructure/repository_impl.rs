@@ -1,10 +1,182 @@
+use crate::app::features::authors::domain::entity::Author;
+use crate::app::features::authors::infrastructure::repository_impl::{
+    find_author_by_username, load_authors,
+};
 use crate::app::features::blog::domain::entity::{
-    Blog, BlogTags, Category, NewBlog, NewCategory, NewTag, Tag,
+    Blog, BlogFilter, BlogRenderedContent, BlogRevision, BlogSearchHit, BlogSortField, BlogTags,
+    BulkBlogAction, Category, MAX_CATEGORY_DEPTH, NewBlog, NewBlogRevision, NewCategory, NewTag,
+    RelatedBlogHit, RelatedWeights, SEARCH_HIGHLIGHT_END, SEARCH_HIGHLIGHT_START, Tag, UpdateBlog,
 };
 use crate::app::features::blog::domain::repository::BlogRepository;
-use crate::schema::{blog, blog_tags, categories, tags};
+use crate::app::features::media::domain::entity::{Media, MediaVariant};
+use crate::app::features::media::infrastructure::repository_impl::load_images;
+use crate::app::features::series::domain::entity::{Series, SeriesPost};
+use crate::app::features::series::infrastructure::repository_impl::load_blog_series;
+use crate::schema::{blog, blog_revisions, blog_tags, categories, comments, tags};
 use crate::utils::db::DbPool;
+use chrono::{NaiveDateTime, Utc};
+use diesel::dsl::count_star;
 use diesel::prelude::*;
+use diesel::sql_types::{BigInt, Double, Integer, Text};
+use diesel::sqlite::Sqlite;
+
+#[derive(QueryableByName)]
+struct CountRow {
+    #[diesel(sql_type = BigInt)]
+    count: i64,
+}
+
+#[derive(QueryableByName)]
+struct IdRow {
+    #[diesel(sql_type = Integer)]
+    id: i32,
+}
+
+/// `id` and its ancestors, top level first. The depth cap keeps a corrupted
+/// (cyclic) hierarchy from looping forever.
+pub fn load_category_path(conn: &mut SqliteConnection, id: i32) -> QueryResult<Vec<Category>> {
+    diesel::sql_query(format!(
+        "WITH RECURSIVE path(id, depth) AS ( \
+             SELECT id, 0 FROM categories WHERE id = ? \
+             UNION ALL \
+             SELECT c.parent_id, path.depth + 1 FROM categories c \
+             JOIN path ON c.id = path.id \
+             WHERE c.parent_id IS NOT NULL AND path.depth < {} \
+         ) \
+         SELECT categories.* FROM categories JOIN path ON categories.id = path.id \
+         ORDER BY path.depth DESC",
+        MAX_CATEGORY_DEPTH
+    ))
+    .bind::<Integer, _>(id)
+    .load::<Category>(conn)
+}
+
+/// Applies one bulk action to one blog. `Ok(false)` when the blog does not exist.
+fn apply_bulk_action(
+    conn: &mut SqliteConnection,
+    id: i32,
+    action: &BulkBlogAction,
+    now: NaiveDateTime,
+) -> QueryResult<Option<String>> {
+    let Some(current) = blog::table.find(id).first::<Blog>(conn).optional()? else {
+        return Ok(None);
+    };
+    let target = blog::table.find(id);
+    match action {
+        BulkBlogAction::Publish => {
+            // A post that is already live keeps its original publish time
+            let published_at = current
+                .published_at
+                .filter(|_| current.status == "PUBLISHED")
+                .unwrap_or(now);
+            diesel::update(target)
+                .set((
+                    blog::status.eq("PUBLISHED"),
+                    blog::published_at.eq(Some(published_at)),
+                    blog::updated_at.eq(now),
+                ))
+                .execute(conn)?;
+        }
+        BulkBlogAction::Archive | BulkBlogAction::Draft => {
+            let status = if *action == BulkBlogAction::Archive {
+                "ARCHIVED"
+            } else {
+                "DRAFT"
+            };
+            diesel::update(target)
+                .set((
+                    blog::status.eq(status),
+                    blog::published_at.eq(None::<NaiveDateTime>),
+                    blog::updated_at.eq(now),
+                ))
+                .execute(conn)?;
+        }
+        BulkBlogAction::Delete => {
+            diesel::delete(blog_tags::table.filter(blog_tags::blog_id.eq(id))).execute(conn)?;
+            diesel::delete(target).execute(conn)?;
+        }
+        BulkBlogAction::ChangeCategory(category_id) => {
+            diesel::update(target)
+                .set((blog::category_id.eq(category_id), blog::updated_at.eq(now)))
+                .execute(conn)?;
+        }
+        BulkBlogAction::AddTags(tag_ids) => {
+            let rows = tag_ids
+                .iter()
+                .map(|tag_id| BlogTags {
+                    blog_id: id,
+                    tag_id: *tag_id,
+                })
+                .collect::<Vec<_>>();
+            diesel::insert_or_ignore_into(blog_tags::table)
+                .values(rows)
+                .execute(conn)?;
+            diesel::update(target)
+                .set(blog::updated_at.eq(now))
+                .execute(conn)?;
+        }
+        BulkBlogAction::RemoveTags(tag_ids) => {
+            diesel::delete(
+                blog_tags::table
+                    .filter(blog_tags::blog_id.eq(id))
+                    .filter(blog_tags::tag_id.eq_any(tag_ids)),
+            )
+            .execute(conn)?;
+            diesel::update(target)
+                .set(blog::updated_at.eq(now))
+                .execute(conn)?;
+        }
+    }
+    Ok(Some(current.status))
+}
+
+/// Turns free text into an FTS5 expression: every whitespace separated word
+/// becomes a quoted phrase (so operators like `-`, `:` or `NEAR` are taken
+/// literally) and the last word is matched as a prefix.
+fn to_fts_query(query: &str) -> String {
+    let terms: Vec<String> = query
+        .split_whitespace()
+        .map(|t| format!("\"{}\"", t.replace('"', "\"\"")))
+        .collect();
+    match terms.split_last() {
+        Some((last, rest)) => {
+            let mut parts = rest.to_vec();
+            parts.push(format!("{}*", last));
+            parts.join(" ")
+        }
+        None => "\"\"".to_string(),
+    }
+}
+
+/// Builds the filtered (unordered, unpaginated) blog query so the same
+/// conditions can back both the count and the page query.
+fn filtered_blog_query(filter: &BlogFilter) -> blog::BoxedQuery<'static, Sqlite> {
+    let mut query = blog::table.into_boxed();
+
+    if let Some(status) = &filter.status {
+        query = query.filter(blog::status.eq(status.clone()));
+    }
+    if !filter.category_ids.is_empty() {
+        query = query.filter(blog::category_id.eq_any(filter.category_ids.clone()));
+    }
+    if !filter.tag_ids.is_empty() {
+        query = query.filter(
+            blog::id.eq_any(
+                blog_tags::table
+                    .filter(blog_tags::tag_id.eq_any(filter.tag_ids.clone()))
+                    .select(blog_tags::blog_id),
+            ),
+        );
+    }
+    if let Some(from) = filter.published_from {
+        query = query.filter(blog::published_at.ge(from));
+    }
+    if let Some(until) = filter.published_until {
+        query = query.filter(blog::published_at.lt(until));
+    }
+
+    query
+}
 
 #[derive(Clone)]
 pub struct BlogRepositoryImpl {
@@ -18,14 +190,57 @@ impl BlogRepositoryImpl {
 }
 
 impl BlogRepository for BlogRepositoryImpl {
-    fn get_all_blog(&self, limit: i64, offset: i64) -> QueryResult<(Vec<Blog>, i64)> {
+    fn get_all_blog(
+        &self,
+        filter: BlogFilter,
+        limit: i64,
+        offset: i64,
+    ) -> QueryResult<(Vec<Blog>, i64)> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+
+        let count = filtered_blog_query(&filter).count().get_result(&mut conn)?;
+
+        let query = filtered_blog_query(&filter);
+        let query = match (filter.sort_by, filter.descending) {
+            (BlogSortField::Id, false) => query.order(blog::id.asc()),
+            (BlogSortField::Id, true) => query.order(blog::id.desc()),
+            (BlogSortField::PublishedAt, false) => query.order(blog::published_at.asc()),
+            (BlogSortField::PublishedAt, true) => query.order(blog::published_at.desc()),
+            (BlogSortField::UpdatedAt, false) => query.order(blog::updated_at.asc()),
+            (BlogSortField::UpdatedAt, true) => query.order(blog::updated_at.desc()),
+            (BlogSortField::ViewCount, false) => query.order(blog::view_count.asc()),
+            (BlogSortField::ViewCount, true) => query.order(blog::view_count.desc()),
+            (BlogSortField::Title, false) => query.order(blog::title.asc()),
+            (BlogSortField::Title, true) => query.order(blog::title.desc()),
+        };
+        // Tie-break on id so pages stay stable when sort values repeat.
+        let items = query
+            .then_order_by(blog::id.asc())
+            .limit(limit)
+            .offset(offset)
+            .load::<Blog>(&mut conn)?;
+
+        Ok((items, count))
+    }
+    fn get_all_published_blog(&self, limit: i64, offset: i64) -> QueryResult<(Vec<Blog>, i64)> {
         let mut conn = self
             .pool
             .get()
             .expect("couldn't get db connection from pool");
 
-        let count = blog::table.count().get_result(&mut conn)?;
+        let now = Utc::now().naive_utc();
+        let count = blog::table
+            .filter(blog::status.eq("PUBLISHED"))
+            .filter(blog::published_at.le(now))
+            .count()
+            .get_result(&mut conn)?;
         let items = blog::table
+            .filter(blog::status.eq("PUBLISHED"))
+            .filter(blog::published_at.le(now))
+            .order(blog::published_at.desc())
             .limit(limit)
             .offset(offset)
             .load::<Blog>(&mut conn)?;
@@ -39,6 +254,37 @@ impl BlogRepository for BlogRepositoryImpl {
             .expect("couldn't get db connection from pool");
         blog::table.find(id).first::<Blog>(&mut conn).optional()
     }
+    fn get_unrendered_blogs(&self) -> QueryResult<Vec<Blog>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        blog::table
+            .filter(blog::content_html.is_null())
+            .load::<Blog>(&mut conn)
+    }
+    fn save_rendered_content(&self, id: i32, rendered: BlogRenderedContent) -> QueryResult<usize> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::update(blog::table.find(id))
+            .set(rendered)
+            .execute(&mut conn)
+    }
+    fn publish_due_blogs(&self, now: NaiveDateTime) -> QueryResult<Vec<Blog>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::update(
+            blog::table
+                .filter(blog::status.eq("SCHEDULED"))
+                .filter(blog::published_at.le(now)),
+        )
+        .set((blog::status.eq("PUBLISHED"), blog::updated_at.eq(now)))
+        .get_results(&mut conn)
+    }
     fn create_blog(&self, blog: NewBlog) -> QueryResult<Blog> {
         let mut conn = self
             .pool
@@ -48,7 +294,7 @@ impl BlogRepository for BlogRepositoryImpl {
             .values(blog)
             .get_result(&mut conn)
     }
-    fn update_blog(&self, id: i32, blog: NewBlog) -> QueryResult<Blog> {
+    fn update_blog(&self, id: i32, blog: UpdateBlog) -> QueryResult<Blog> {
         let mut conn = self
             .pool
             .get()
@@ -64,6 +310,30 @@ impl BlogRepository for BlogRepositoryImpl {
             .expect("couldn't get db connection from pool");
         diesel::delete(blog::table.find(id)).execute(&mut conn)
     }
+    fn bulk_update_blogs(
+        &self,
+        ids: &[i32],
+        action: &BulkBlogAction,
+    ) -> QueryResult<Vec<(i32, Result<String, String>)>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        let now = Utc::now().naive_utc();
+        conn.transaction(|conn| {
+            let mut results = Vec::with_capacity(ids.len());
+            for &id in ids {
+                let outcome =
+                    match conn.transaction(|conn| apply_bulk_action(conn, id, action, now)) {
+                        Ok(Some(previous_status)) => Ok(previous_status),
+                        Ok(None) => Err("Blog not found".to_string()),
+                        Err(e) => Err(e.to_string()),
+                    };
+                results.push((id, outcome));
+            }
+            Ok(results)
+        })
+    }
     fn delete_blog_tags_by_blog_id(&self, blog_id: i32) -> QueryResult<usize> {
         let mut conn = self
             .pool
@@ -82,6 +352,45 @@ impl BlogRepository for BlogRepositoryImpl {
             .select(tags::all_columns)
             .load::<Tag>(&mut conn)
     }
+    fn count_approved_comments(&self, blog_id: i32) -> QueryResult<i64> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        comments::table
+            .filter(comments::blog_id.eq(blog_id))
+            .filter(comments::status.eq("APPROVED"))
+            .count()
+            .get_result(&mut conn)
+    }
+    fn get_images(&self, filenames: Vec<String>) -> QueryResult<Vec<(Media, Vec<MediaVariant>)>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        load_images(&mut conn, &filenames)
+    }
+    fn get_blog_series(&self, blog_id: i32) -> QueryResult<Option<(Series, Vec<SeriesPost>)>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        load_blog_series(&mut conn, blog_id)
+    }
+    fn get_author(&self, user_id: i32) -> QueryResult<Option<Author>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        Ok(load_authors(&mut conn, &[user_id])?.pop())
+    }
+    fn get_author_by_username(&self, username: String) -> QueryResult<Option<Author>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        find_author_by_username(&mut conn, &username)
+    }
     fn get_all_tag(&self, limit: i64, offset: i64) -> QueryResult<(Vec<Tag>, i64)> {
         let mut conn = self
             .pool
@@ -128,6 +437,67 @@ impl BlogRepository for BlogRepositoryImpl {
             .expect("couldn't get db connection from pool");
         diesel::delete(tags::table.find(id)).execute(&mut conn)
     }
+    fn get_tag_post_counts(&self, tag_ids: Vec<i32>) -> QueryResult<Vec<(i32, i64)>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        blog_tags::table
+            .filter(blog_tags::tag_id.eq_any(tag_ids))
+            .group_by(blog_tags::tag_id)
+            .select((blog_tags::tag_id, count_star()))
+            .load::<(i32, i64)>(&mut conn)
+    }
+    fn merge_tag(&self, source_id: i32, target_id: i32) -> QueryResult<(i64, i64)> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        conn.transaction(|conn| {
+            // Posts carrying both tags would end up with a duplicate
+            // (blog_id, tag_id) key, so drop their source link first
+            let target_blog_ids = blog_tags::table
+                .filter(blog_tags::tag_id.eq(target_id))
+                .select(blog_tags::blog_id)
+                .load::<i32>(conn)?;
+            let already_tagged = diesel::delete(
+                blog_tags::table
+                    .filter(blog_tags::tag_id.eq(source_id))
+                    .filter(blog_tags::blog_id.eq_any(target_blog_ids)),
+            )
+            .execute(conn)?;
+            let moved = diesel::update(blog_tags::table.filter(blog_tags::tag_id.eq(source_id)))
+                .set(blog_tags::tag_id.eq(target_id))
+                .execute(conn)?;
+            diesel::delete(tags::table.find(source_id)).execute(conn)?;
+            diesel::update(tags::table.find(target_id))
+                .set(tags::updated_at.eq(diesel::dsl::now))
+                .execute(conn)?;
+            Ok((moved as i64, already_tagged as i64))
+        })
+    }
+    fn get_tag_cloud(&self, now: NaiveDateTime, limit: i64) -> QueryResult<Vec<(Tag, i64)>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        let counts = blog_tags::table
+            .inner_join(blog::table)
+            .filter(blog::status.eq("PUBLISHED"))
+            .filter(blog::published_at.le(now))
+            .group_by(blog_tags::tag_id)
+            .select((blog_tags::tag_id, count_star()))
+            .order((count_star().desc(), blog_tags::tag_id.asc()))
+            .limit(limit)
+            .load::<(i32, i64)>(&mut conn)?;
+        let tags = tags::table
+            .filter(tags::id.eq_any(counts.iter().map(|(id, _)| *id)))
+            .load::<Tag>(&mut conn)?;
+        Ok(counts
+            .into_iter()
+            .filter_map(|(id, count)| tags.iter().find(|t| t.id == id).map(|t| (t.clone(), count)))
+            .collect())
+    }
     fn get_all_category(&self, limit: i64, offset: i64) -> QueryResult<(Vec<Category>, i64)> {
         let mut conn = self
             .pool
@@ -177,6 +547,61 @@ impl BlogRepository for BlogRepositoryImpl {
             .expect("couldn't get db connection from pool");
         diesel::delete(categories::table.find(id)).execute(&mut conn)
     }
+    fn get_all_categories(&self) -> QueryResult<Vec<Category>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        categories::table
+            .order((categories::name.asc(), categories::id.asc()))
+            .load::<Category>(&mut conn)
+    }
+    fn get_category_path(&self, id: i32) -> QueryResult<Vec<Category>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        load_category_path(&mut conn, id)
+    }
+    fn get_category_descendant_ids(&self, id: i32) -> QueryResult<Vec<i32>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        // UNION (not UNION ALL) drops repeats, so this ends even on a cycle
+        diesel::sql_query(
+            "WITH RECURSIVE tree(id) AS ( \
+                 SELECT id FROM categories WHERE id = ? \
+                 UNION \
+                 SELECT c.id FROM categories c JOIN tree ON c.parent_id = tree.id \
+             ) \
+             SELECT id FROM tree ORDER BY id",
+        )
+        .bind::<Integer, _>(id)
+        .load::<IdRow>(&mut conn)
+        .map(|rows| rows.into_iter().map(|r| r.id).collect())
+    }
+    fn get_category_subtree_height(&self, id: i32) -> QueryResult<usize> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        // One level past the limit is enough to tell it was exceeded
+        diesel::sql_query(format!(
+            "WITH RECURSIVE tree(id, depth) AS ( \
+                 SELECT id, 1 FROM categories WHERE id = ? \
+                 UNION \
+                 SELECT c.id, tree.depth + 1 FROM categories c \
+                 JOIN tree ON c.parent_id = tree.id \
+                 WHERE tree.depth <= {} \
+             ) \
+             SELECT COALESCE(MAX(depth), 0) AS count FROM tree",
+            MAX_CATEGORY_DEPTH
+        ))
+        .bind::<Integer, _>(id)
+        .get_result::<CountRow>(&mut conn)
+        .map(|row| row.count as usize)
+    }
     fn create_blog_tags(&self, blog_tags: BlogTags) -> QueryResult<BlogTags> {
         let mut conn = self
             .pool
@@ -219,4 +644,157 @@ impl BlogRepository for BlogRepositoryImpl {
             .first::<Tag>(&mut conn)
             .optional()
     }
+
+    fn create_blog_revision(&self, blog: &Blog) -> QueryResult<BlogRevision> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        conn.transaction(|conn| {
+            let latest: Option<i32> = blog_revisions::table
+                .filter(blog_revisions::blog_id.eq(blog.id))
+                .select(diesel::dsl::max(blog_revisions::revision_number))
+                .first(conn)?;
+
+            diesel::insert_into(blog_revisions::table)
+                .values(NewBlogRevision {
+                    blog_id: blog.id,
+                    revision_number: latest.unwrap_or(0) + 1,
+                    title: blog.title.clone(),
+                    excerpt: blog.excerpt.clone(),
+                    content: blog.content.clone(),
+                })
+                .get_result(conn)
+        })
+    }
+    fn get_blog_revisions(
+        &self,
+        blog_id: i32,
+        limit: i64,
+        offset: i64,
+    ) -> QueryResult<(Vec<BlogRevision>, i64)> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+
+        let count = blog_revisions::table
+            .filter(blog_revisions::blog_id.eq(blog_id))
+            .count()
+            .get_result(&mut conn)?;
+        let items = blog_revisions::table
+            .filter(blog_revisions::blog_id.eq(blog_id))
+            .order(blog_revisions::revision_number.desc())
+            .limit(limit)
+            .offset(offset)
+            .load::<BlogRevision>(&mut conn)?;
+
+        Ok((items, count))
+    }
+    fn get_blog_revision(
+        &self,
+        blog_id: i32,
+        revision_number: i32,
+    ) -> QueryResult<Option<BlogRevision>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        blog_revisions::table
+            .filter(blog_revisions::blog_id.eq(blog_id))
+            .filter(blog_revisions::revision_number.eq(revision_number))
+            .first::<BlogRevision>(&mut conn)
+            .optional()
+    }
+    fn get_related_blogs(
+        &self,
+        blog_id: i32,
+        weights: RelatedWeights,
+        limit: i64,
+    ) -> QueryResult<Vec<RelatedBlogHit>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+
+        // One pass over published posts: shared tags come from a left join
+        // restricted to the source post's tags, so untagged or unrelated
+        // posts still rank by category and recency.
+        diesel::sql_query(
+            "SELECT b.id, b.title, b.slug, b.excerpt, b.thumbnail, b.published_at, \
+             COUNT(bt.tag_id) AS shared_tags, \
+             b.category_id = src.category_id AS same_category, \
+             ? * COUNT(bt.tag_id) \
+             + ? * (b.category_id = src.category_id) \
+             + ? / (1.0 + MAX(julianday('now') - julianday(b.published_at), 0) / 30.0) AS score \
+             FROM blog src \
+             JOIN blog b ON b.id <> src.id \
+             LEFT JOIN blog_tags bt ON bt.blog_id = b.id \
+             AND bt.tag_id IN (SELECT tag_id FROM blog_tags WHERE blog_id = src.id) \
+             WHERE src.id = ? \
+             AND b.status = 'PUBLISHED' \
+             AND b.published_at <= strftime('%Y-%m-%d %H:%M:%f', 'now') \
+             GROUP BY b.id \
+             ORDER BY score DESC, b.published_at DESC, b.id DESC \
+             LIMIT ?",
+        )
+        .bind::<Double, _>(weights.tag)
+        .bind::<Double, _>(weights.category)
+        .bind::<Double, _>(weights.recency)
+        .bind::<Integer, _>(blog_id)
+        .bind::<BigInt, _>(limit)
+        .load::<RelatedBlogHit>(&mut conn)
+    }
+    fn search_blog(
+        &self,
+        query: String,
+        published_only: bool,
+        limit: i64,
+        offset: i64,
+    ) -> QueryResult<(Vec<BlogSearchHit>, i64)> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+
+        let status_filter = if published_only {
+            " AND blog.status = 'PUBLISHED' \
+             AND blog.published_at <= strftime('%Y-%m-%d %H:%M:%f', 'now')"
+        } else {
+            ""
+        };
+        let fts_query = to_fts_query(&query);
+
+        let count = diesel::sql_query(format!(
+            "SELECT COUNT(*) AS count FROM blog_fts \
+             JOIN blog ON blog.id = blog_fts.rowid \
+             WHERE blog_fts MATCH ?{}",
+            status_filter
+        ))
+        .bind::<Text, _>(&fts_query)
+        .get_result::<CountRow>(&mut conn)?
+        .count;
+
+        // Title matches weigh more than excerpt matches, which weigh more than content.
+        let items = diesel::sql_query(format!(
+            "SELECT blog.id, blog.title, blog.slug, blog.excerpt, blog.status, blog.published_at, \
+             highlight(blog_fts, 0, '{start}', '{end}') AS title_highlight, \
+             snippet(blog_fts, -1, '{start}', '{end}', '…', 24) AS snippet, \
+             bm25(blog_fts, 10.0, 5.0, 1.0) AS rank \
+             FROM blog_fts \
+             JOIN blog ON blog.id = blog_fts.rowid \
+             WHERE blog_fts MATCH ?{filter} \
+             ORDER BY rank \
+             LIMIT ? OFFSET ?",
+            start = SEARCH_HIGHLIGHT_START,
+            end = SEARCH_HIGHLIGHT_END,
+            filter = status_filter
+        ))
+        .bind::<Text, _>(&fts_query)
+        .bind::<BigInt, _>(limit)
+        .bind::<BigInt, _>(offset)
+        .load::<BlogSearchHit>(&mut conn)?;
+
+        Ok((items, count))
+    }
 }
@@ -1,14 +1,26 @@
+use crate::app::features::blog::domain::error::BlogError;
 use crate::app::features::blog::interface::dto::{
-    BlogResponseDto, CategoryResponseDto, CreateBlogRequestDto, CreateCategoryRequestDto,
-    CreateTagRequestDto, PaginationRequestDto, TagResponseDto, UpdateBlogRequestDto,
+    BlogListQueryDto, BlogResponseDto, BlogRevisionDiffRequestDto, BlogRevisionDiffResponseDto,
+    BlogRevisionResponseDto, BlogSearchResultDto, BulkBlogRequestDto, BulkBlogResponseDto,
+    CategoryResponseDto, CategoryTreeNodeDto, CreateBlogRequestDto, CreateCategoryRequestDto,
+    CreateTagRequestDto, MergeTagRequestDto, MergeTagResponseDto, PaginationRequestDto,
+    RelatedBlogRequestDto, RelatedBlogResponseDto, SearchBlogRequestDto, TagCloudEntryDto,
+    TagCloudQueryDto, TagListQueryDto, TagResponseDto, UpdateBlogRequestDto,
     UpdateCategoryRequestDto, UpdateTagRequestDto,
 };
+use crate::app::features::webhooks::domain::entity::WebhookEvent;
+use crate::app::features::webhooks::interface::controller::emit_event;
+use crate::utils::client_ip::client_ip;
 use crate::utils::di::Container;
 use crate::utils::error_response::{ErrorResponse, map_string_error, map_validation_error};
 use crate::utils::success_response::{
     SuccessResponse, map_success_response, map_success_with_data,
 };
-use actix_web::{HttpResponse, Responder, delete, get, post, put, web};
+use crate::utils::token::Claims;
+use actix_web::http::header;
+use actix_web::{HttpRequest, HttpResponse, Responder, delete, get, post, put, web};
+use serde_json::json;
+use validator::Validate;
 
 #[utoipa::path(
     path = "/app/categories",
@@ -25,7 +37,6 @@ pub async fn create_category(
     container: web::Data<Container>,
     payload: web::Json<CreateCategoryRequestDto>,
 ) -> impl Responder {
-    use crate::app::features::blog::domain::error::BlogError;
     match container
         .create_category_usecase
         .execute(payload.into_inner())
@@ -71,6 +82,25 @@ pub async fn get_categories(
     }
 }
 
+#[utoipa::path(
+    path = "/app/categories/tree",
+    tag = "Blog",
+    responses(
+        (status = 200, description = "Categories nested under their parents", body = crate::utils::success_response::SuccessResponse<Vec<CategoryTreeNodeDto>>),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[get("/categories/tree")]
+pub async fn get_category_tree(container: web::Data<Container>) -> impl Responder {
+    match container.get_category_tree_usecase.execute().await {
+        Ok(tree) => HttpResponse::Ok().json(map_success_with_data(
+            "Category tree fetched successfully".to_string(),
+            tree,
+        )),
+        Err(e) => HttpResponse::InternalServerError().json(map_string_error(e)),
+    }
+}
+
 #[utoipa::path(
     path = "/app/categories/{id}",
     tag = "Blog",
@@ -122,7 +152,6 @@ pub async fn update_category(
     id: web::Path<i32>,
     payload: web::Json<UpdateCategoryRequestDto>,
 ) -> impl Responder {
-    use crate::app::features::blog::domain::error::BlogError;
     match container
         .update_category_usecase
         .execute(id.into_inner(), payload.into_inner())
@@ -192,7 +221,6 @@ pub async fn create_tag(
     container: web::Data<Container>,
     payload: web::Json<CreateTagRequestDto>,
 ) -> impl Responder {
-    use crate::app::features::blog::domain::error::BlogError;
     match container
         .create_tag_usecase
         .execute(payload.into_inner())
@@ -215,6 +243,9 @@ pub async fn create_tag(
 #[utoipa::path(
     path = "/app/tags",
     tag = "Blog",
+    params(
+        TagListQueryDto
+    ),
     responses(
         (status = 200, description = "List tags", body = crate::utils::success_response::SuccessResponse<Vec<TagResponseDto>>),
         (status = 500, description = "Internal server error")
@@ -223,7 +254,7 @@ pub async fn create_tag(
 #[get("/tags")]
 pub async fn get_tags(
     container: web::Data<Container>,
-    query: web::Query<PaginationRequestDto>,
+    query: web::Query<TagListQueryDto>,
 ) -> impl Responder {
     match container.get_tags_usecase.execute(query.into_inner()).await {
         Ok(tags) => HttpResponse::Ok().json(map_success_with_data(
@@ -281,7 +312,6 @@ pub async fn update_tag(
     id: web::Path<i32>,
     payload: web::Json<UpdateTagRequestDto>,
 ) -> impl Responder {
-    use crate::app::features::blog::domain::error::BlogError;
     match container
         .update_tag_usecase
         .execute(id.into_inner(), payload.into_inner())
@@ -329,6 +359,90 @@ pub async fn delete_tag(container: web::Data<Container>, id: web::Path<i32>) ->
     }
 }
 
+#[utoipa::path(
+    path = "/app/tags/{id}/merge",
+    tag = "Blog",
+    params(
+        ("id", description = "Tag to merge away")
+    ),
+    request_body = MergeTagRequestDto,
+    responses(
+        (status = 200, description = "Posts moved to the target tag and the tag deleted", body = crate::utils::success_response::SuccessResponse<MergeTagResponseDto>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 404, description = "Tag not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[post("/tags/{id}/merge")]
+pub async fn merge_tag(
+    container: web::Data<Container>,
+    id: web::Path<i32>,
+    payload: web::Json<MergeTagRequestDto>,
+) -> impl Responder {
+    match container
+        .merge_tag_usecase
+        .execute(id.into_inner(), payload.into_inner())
+        .await
+    {
+        Ok(data) => HttpResponse::Ok().json(map_success_with_data(
+            "Tag merged successfully".to_string(),
+            data,
+        )),
+        Err(e) => match e {
+            BlogError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+            BlogError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+            BlogError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    path = "/api/public/tags",
+    tag = "Public",
+    params(
+        TagCloudQueryDto
+    ),
+    responses(
+        (status = 200, description = "Most used tags among published posts, by name", body = crate::utils::success_response::SuccessResponse<Vec<TagCloudEntryDto>>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    security(())
+)]
+#[get("/tags")]
+pub async fn get_public_tag_cloud(
+    container: web::Data<Container>,
+    query: web::Query<TagCloudQueryDto>,
+) -> impl Responder {
+    match container
+        .get_tag_cloud_usecase
+        .execute(query.into_inner())
+        .await
+    {
+        Ok(data) => HttpResponse::Ok().json(map_success_with_data(
+            "Tag cloud fetched successfully".to_string(),
+            data,
+        )),
+        Err(e) => match e {
+            BlogError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+            BlogError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+            BlogError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
+
+/// Announces a post to newsletter subscribers the first time it goes live.
+/// A failed announcement must not fail the save.
+async fn notify_subscribers(container: &Container, blog_id: i32) {
+    if let Err(e) = container.notify_subscribers_usecase.execute(blog_id).await {
+        eprintln!("newsletter notification failed: {:?}", e);
+    }
+}
+
 #[utoipa::path(
     path = "/app/blogs",
     tag = "Blog",
@@ -342,19 +456,25 @@ pub async fn delete_tag(container: web::Data<Container>, id: web::Path<i32>) ->
 #[post("/blogs")]
 pub async fn create_blog(
     container: web::Data<Container>,
+    claims: web::ReqData<Claims>,
     payload: web::Json<CreateBlogRequestDto>,
 ) -> impl Responder {
-    use crate::app::features::blog::domain::error::BlogError;
-
     match container
         .create_blog_usecase
-        .execute(payload.into_inner())
+        .execute(payload.into_inner(), claims.into_inner().sub)
         .await
     {
-        Ok(data) => HttpResponse::Created().json(map_success_with_data(
-            "Blog created successfully".to_string(),
-            data,
-        )),
+        Ok(data) => {
+            emit_event(&container, WebhookEvent::BlogCreated, &data).await;
+            if data.status == "PUBLISHED" {
+                emit_event(&container, WebhookEvent::BlogPublished, &data).await;
+                notify_subscribers(&container, data.id).await;
+            }
+            HttpResponse::Created().json(map_success_with_data(
+                "Blog created successfully".to_string(),
+                data,
+            ))
+        }
         Err(e) => match e {
             BlogError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
             BlogError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
@@ -368,15 +488,19 @@ pub async fn create_blog(
 #[utoipa::path(
     path = "/app/blogs",
     tag = "Blog",
+    params(
+        BlogListQueryDto
+    ),
     responses(
         (status = 200, description = "List blogs", body = crate::utils::success_response::SuccessResponse<Vec<BlogResponseDto>>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
         (status = 500, description = "Internal server error")
     )
 )]
 #[get("/blogs")]
 pub async fn get_blogs(
     container: web::Data<Container>,
-    query: web::Query<PaginationRequestDto>,
+    query: web::Query<BlogListQueryDto>,
 ) -> impl Responder {
     match container
         .get_blogs_usecase
@@ -387,7 +511,51 @@ pub async fn get_blogs(
             "Blogs fetched successfully".to_string(),
             blogs,
         )),
-        Err(e) => HttpResponse::InternalServerError().json(map_string_error(e)),
+        Err(e) => match e {
+            BlogError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+            BlogError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+            BlogError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    path = "/app/blogs/search",
+    tag = "Blog",
+    params(
+        ("q" = String, Query, description = "Words to look for in title, excerpt and content"),
+        ("page" = Option<i64>, Query, description = "Page number"),
+        ("per_page" = Option<i64>, Query, description = "Items per page")
+    ),
+    responses(
+        (status = 200, description = "Ranked search results", body = crate::utils::success_response::SuccessResponse<crate::app::features::blog::interface::dto::PaginatedResponseDto<BlogSearchResultDto>>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[get("/blogs/search")]
+pub async fn search_blogs(
+    container: web::Data<Container>,
+    query: web::Query<SearchBlogRequestDto>,
+) -> impl Responder {
+    match container
+        .search_blogs_usecase
+        .execute(query.into_inner(), false)
+        .await
+    {
+        Ok(results) => HttpResponse::Ok().json(map_success_with_data(
+            "Blogs searched successfully".to_string(),
+            results,
+        )),
+        Err(e) => match e {
+            BlogError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+            BlogError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+            BlogError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
     }
 }
 
@@ -439,17 +607,31 @@ pub async fn update_blog(
     id: web::Path<i32>,
     payload: web::Json<UpdateBlogRequestDto>,
 ) -> impl Responder {
-    use crate::app::features::blog::domain::error::BlogError;
+    let id = id.into_inner();
+    let was_published = container
+        .get_blog_usecase
+        .execute(id)
+        .await
+        .is_ok_and(|blog| blog.status == "PUBLISHED");
 
     match container
         .update_blog_usecase
-        .execute(id.into_inner(), payload.into_inner())
+        .execute(id, payload.into_inner())
         .await
     {
-        Ok(res) => HttpResponse::Ok().json(map_success_with_data(
-            "Blog updated successfully".to_string(),
-            res,
-        )),
+        Ok(res) => {
+            emit_event(&container, WebhookEvent::BlogUpdated, &res).await;
+            if res.status == "PUBLISHED" {
+                if !was_published {
+                    emit_event(&container, WebhookEvent::BlogPublished, &res).await;
+                }
+                notify_subscribers(&container, res.id).await;
+            }
+            HttpResponse::Ok().json(map_success_with_data(
+                "Blog updated successfully".to_string(),
+                res,
+            ))
+        }
         Err(e) => match e {
             BlogError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
             BlogError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
@@ -474,10 +656,14 @@ pub async fn update_blog(
 )]
 #[delete("/blogs/{id}")]
 pub async fn delete_blog(container: web::Data<Container>, id: web::Path<i32>) -> impl Responder {
-    match container.delete_blog_usecase.execute(id.into_inner()).await {
-        Ok(_) => HttpResponse::Ok().json(map_success_response(
-            "Blog deleted successfully".to_string(),
-        )),
+    let id = id.into_inner();
+    match container.delete_blog_usecase.execute(id).await {
+        Ok(_) => {
+            emit_event(&container, WebhookEvent::BlogDeleted, json!({ "id": id })).await;
+            HttpResponse::Ok().json(map_success_response(
+                "Blog deleted successfully".to_string(),
+            ))
+        }
         Err(e) => {
             if e.contains("not found") {
                 HttpResponse::NotFound().json(map_string_error(e))
@@ -487,3 +673,439 @@ pub async fn delete_blog(container: web::Data<Container>, id: web::Path<i32>) ->
         }
     }
 }
+
+#[utoipa::path(
+    path = "/app/blogs/bulk",
+    tag = "Blog",
+    request_body = BulkBlogRequestDto,
+    responses(
+        (status = 200, description = "Action applied in one transaction; see `results` for each id", body = crate::utils::success_response::SuccessResponse<BulkBlogResponseDto>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[post("/blogs/bulk")]
+pub async fn bulk_blogs(
+    container: web::Data<Container>,
+    payload: web::Json<BulkBlogRequestDto>,
+) -> impl Responder {
+    match container
+        .bulk_blogs_usecase
+        .execute(payload.into_inner())
+        .await
+    {
+        Ok(res) => {
+            for result in res.results.iter().filter(|r| r.success) {
+                if res.action == "delete" {
+                    emit_event(
+                        &container,
+                        WebhookEvent::BlogDeleted,
+                        json!({ "id": result.id }),
+                    )
+                    .await;
+                    continue;
+                }
+                let Ok(blog) = container.get_blog_usecase.execute(result.id).await else {
+                    continue;
+                };
+                emit_event(&container, WebhookEvent::BlogUpdated, &blog).await;
+                // Only posts that weren't live yet count as newly published
+                if res.action == "publish" && result.previous_status.as_deref() != Some("PUBLISHED")
+                {
+                    emit_event(&container, WebhookEvent::BlogPublished, &blog).await;
+                }
+            }
+            if res.action == "publish" {
+                for result in res.results.iter().filter(|r| r.success) {
+                    notify_subscribers(&container, result.id).await;
+                }
+            }
+            HttpResponse::Ok().json(map_success_with_data(
+                "Bulk action applied".to_string(),
+                res,
+            ))
+        }
+        Err(e) => match e {
+            BlogError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+            BlogError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+            BlogError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    path = "/app/blogs/{id}/revisions",
+    tag = "Blog",
+    params(
+        ("id", description = "Blog ID"),
+        PaginationRequestDto
+    ),
+    responses(
+        (status = 200, description = "Revisions, newest first", body = crate::utils::success_response::SuccessResponse<Vec<BlogRevisionResponseDto>>),
+        (status = 404, description = "Blog not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[get("/blogs/{id}/revisions")]
+pub async fn get_blog_revisions(
+    container: web::Data<Container>,
+    id: web::Path<i32>,
+    query: web::Query<PaginationRequestDto>,
+) -> impl Responder {
+    match container
+        .get_blog_revisions_usecase
+        .execute(id.into_inner(), query.into_inner())
+        .await
+    {
+        Ok(revisions) => HttpResponse::Ok().json(map_success_with_data(
+            "Revisions fetched successfully".to_string(),
+            revisions,
+        )),
+        Err(e) => match e {
+            BlogError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+            BlogError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+            BlogError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    path = "/app/blogs/{id}/revisions/diff",
+    tag = "Blog",
+    params(
+        ("id", description = "Blog ID"),
+        BlogRevisionDiffRequestDto
+    ),
+    responses(
+        (status = 200, description = "Line diff between two revisions", body = crate::utils::success_response::SuccessResponse<BlogRevisionDiffResponseDto>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 404, description = "Revision not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[get("/blogs/{id}/revisions/diff")]
+pub async fn diff_blog_revisions(
+    container: web::Data<Container>,
+    id: web::Path<i32>,
+    query: web::Query<BlogRevisionDiffRequestDto>,
+) -> impl Responder {
+    match container
+        .diff_blog_revisions_usecase
+        .execute(id.into_inner(), query.into_inner())
+        .await
+    {
+        Ok(diff) => HttpResponse::Ok().json(map_success_with_data(
+            "Revision diff fetched successfully".to_string(),
+            diff,
+        )),
+        Err(e) => match e {
+            BlogError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+            BlogError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+            BlogError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    path = "/app/blogs/{id}/revisions/{revision}",
+    tag = "Blog",
+    params(
+        ("id", description = "Blog ID"),
+        ("revision", description = "Revision number")
+    ),
+    responses(
+        (status = 200, description = "Revision found", body = crate::utils::success_response::SuccessResponse<BlogRevisionResponseDto>),
+        (status = 404, description = "Revision not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[get("/blogs/{id}/revisions/{revision}")]
+pub async fn get_blog_revision(
+    container: web::Data<Container>,
+    path: web::Path<(i32, i32)>,
+) -> impl Responder {
+    let (id, revision) = path.into_inner();
+    match container
+        .get_blog_revision_usecase
+        .execute(id, revision)
+        .await
+    {
+        Ok(revision) => HttpResponse::Ok().json(map_success_with_data(
+            "Revision fetched successfully".to_string(),
+            revision,
+        )),
+        Err(e) => match e {
+            BlogError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+            BlogError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+            BlogError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    path = "/app/blogs/{id}/revisions/{revision}/restore",
+    tag = "Blog",
+    params(
+        ("id", description = "Blog ID"),
+        ("revision", description = "Revision number to restore")
+    ),
+    responses(
+        (status = 201, description = "Revision restored as a new revision", body = crate::utils::success_response::SuccessResponse<BlogRevisionResponseDto>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 404, description = "Blog or revision not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[post("/blogs/{id}/revisions/{revision}/restore")]
+pub async fn restore_blog_revision(
+    container: web::Data<Container>,
+    path: web::Path<(i32, i32)>,
+) -> impl Responder {
+    let (id, revision) = path.into_inner();
+    match container
+        .restore_blog_revision_usecase
+        .execute(id, revision)
+        .await
+    {
+        Ok(revision) => HttpResponse::Created().json(map_success_with_data(
+            "Revision restored successfully".to_string(),
+            revision,
+        )),
+        Err(e) => match e {
+            BlogError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+            BlogError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+            BlogError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    path = "/api/public/blogs",
+    tag = "Public",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number"),
+        ("per_page" = Option<i64>, Query, description = "Items per page")
+    ),
+    responses(
+        (status = 200, description = "List published blogs", body = crate::utils::success_response::SuccessResponse<crate::app::features::blog::interface::dto::PaginatedResponseDto<BlogResponseDto>>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    security(())
+)]
+#[get("/blogs")]
+pub async fn get_public_blogs(
+    container: web::Data<Container>,
+    query: web::Query<PaginationRequestDto>,
+) -> impl Responder {
+    if let Err(e) = query.validate() {
+        return HttpResponse::BadRequest().json(map_validation_error(e));
+    }
+    match container
+        .get_published_blogs_usecase
+        .execute(query.into_inner())
+        .await
+    {
+        Ok(blogs) => HttpResponse::Ok().json(map_success_with_data(
+            "Blogs fetched successfully".to_string(),
+            blogs,
+        )),
+        Err(e) => HttpResponse::InternalServerError().json(map_string_error(e)),
+    }
+}
+
+#[utoipa::path(
+    path = "/api/public/blogs/{slug}",
+    tag = "Public",
+    params(
+        ("slug", description = "Blog slug")
+    ),
+    responses(
+        (status = 200, description = "Published blog found", body = crate::utils::success_response::SuccessResponse<BlogResponseDto>),
+        (status = 301, description = "Slug was renamed; Location holds the current URL"),
+        (status = 404, description = "Blog not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    security(())
+)]
+#[get("/blogs/{slug}")]
+pub async fn get_public_blog(
+    req: HttpRequest,
+    container: web::Data<Container>,
+    slug: web::Path<String>,
+) -> impl Responder {
+    use crate::app::features::slug_history::domain::entity::SlugEntity;
+    use crate::app::features::slug_history::interface::controller::redirect_or_not_found;
+
+    let slug = slug.into_inner();
+    match container
+        .get_blog_by_slug_usecase
+        .execute(slug.clone())
+        .await
+    {
+        Ok(mut blog) => {
+            let ip = client_ip(&req, &container.config.trusted_proxies)
+                .map(|ip| ip.to_string())
+                .unwrap_or_default();
+            let user_agent = req
+                .headers()
+                .get(header::USER_AGENT)
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or_default();
+            // A failed count must not cost the reader the post
+            match container
+                .record_blog_view_usecase
+                .execute(blog.id, &ip, user_agent)
+                .await
+            {
+                // The post was loaded before this view was counted
+                Ok(true) => blog.view_count += 1,
+                Ok(false) => {}
+                Err(e) => eprintln!("view tracking failed: {:?}", e),
+            }
+            HttpResponse::Ok().json(map_success_with_data(
+                "Blog fetched successfully".to_string(),
+                blog,
+            ))
+        }
+        Err(e) => match e {
+            BlogError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+            BlogError::NotFound(msg) => {
+                redirect_or_not_found(&container, SlugEntity::Blog, slug, msg).await
+            }
+            BlogError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    path = "/api/public/blogs/search",
+    tag = "Public",
+    params(
+        ("q" = String, Query, description = "Words to look for in title, excerpt and content"),
+        ("page" = Option<i64>, Query, description = "Page number"),
+        ("per_page" = Option<i64>, Query, description = "Items per page")
+    ),
+    responses(
+        (status = 200, description = "Ranked search results over published blogs", body = crate::utils::success_response::SuccessResponse<crate::app::features::blog::interface::dto::PaginatedResponseDto<BlogSearchResultDto>>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    security(())
+)]
+#[get("/blogs/search")]
+pub async fn search_public_blogs(
+    container: web::Data<Container>,
+    query: web::Query<SearchBlogRequestDto>,
+) -> impl Responder {
+    match container
+        .search_blogs_usecase
+        .execute(query.into_inner(), true)
+        .await
+    {
+        Ok(results) => HttpResponse::Ok().json(map_success_with_data(
+            "Blogs searched successfully".to_string(),
+            results,
+        )),
+        Err(e) => match e {
+            BlogError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+            BlogError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+            BlogError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    path = "/app/blogs/{id}/related",
+    tag = "Blog",
+    params(
+        ("id", description = "Blog ID"),
+        RelatedBlogRequestDto
+    ),
+    responses(
+        (status = 200, description = "Published posts related to the blog, best match first", body = crate::utils::success_response::SuccessResponse<Vec<RelatedBlogResponseDto>>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 404, description = "Blog not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[get("/blogs/{id}/related")]
+pub async fn get_related_blogs(
+    container: web::Data<Container>,
+    id: web::Path<i32>,
+    query: web::Query<RelatedBlogRequestDto>,
+) -> impl Responder {
+    use crate::app::features::blog::application::blog_usecase::related::RelatedTo;
+    match container
+        .related_blogs_usecase
+        .execute(RelatedTo::Id(id.into_inner()), query.into_inner())
+        .await
+    {
+        Ok(blogs) => HttpResponse::Ok().json(map_success_with_data(
+            "Related blogs fetched successfully".to_string(),
+            blogs,
+        )),
+        Err(e) => match e {
+            BlogError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+            BlogError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+            BlogError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    path = "/api/public/blogs/{slug}/related",
+    tag = "Public",
+    params(
+        ("slug", description = "Blog slug"),
+        RelatedBlogRequestDto
+    ),
+    responses(
+        (status = 200, description = "Published posts related to the blog, best match first", body = crate::utils::success_response::SuccessResponse<Vec<RelatedBlogResponseDto>>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 404, description = "Blog not found or not published", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    security(())
+)]
+#[get("/blogs/{slug}/related")]
+pub async fn get_public_related_blogs(
+    container: web::Data<Container>,
+    slug: web::Path<String>,
+    query: web::Query<RelatedBlogRequestDto>,
+) -> impl Responder {
+    use crate::app::features::blog::application::blog_usecase::related::RelatedTo;
+    match container
+        .related_blogs_usecase
+        .execute(RelatedTo::Slug(slug.into_inner()), query.into_inner())
+        .await
+    {
+        Ok(blogs) => HttpResponse::Ok().json(map_success_with_data(
+            "Related blogs fetched successfully".to_string(),
+            blogs,
+        )),
+        Err(e) => match e {
+            BlogError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+            BlogError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+            BlogError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
@@ -1,17 +1,42 @@
+use crate::app::features::authors::interface::dto::AuthorDto;
+use crate::app::features::media::interface::dto::ResponsiveImageDto;
+use crate::app::features::series::interface::dto::BlogSeriesDto;
+use crate::app::features::blog::domain::entity::Category;
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use std::collections::{HashMap, HashSet};
+use utoipa::{IntoParams, ToSchema};
 use validator::{Validate, ValidationError};
 
 #[derive(Deserialize, Serialize, Validate, ToSchema)]
 pub struct CreateCategoryRequestDto {
     #[validate(length(min = 1, message = "Name is required"))]
     pub name: String,
+    /// Parent category; top level when omitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<i32>,
 }
 
 #[derive(Deserialize, Serialize, Validate, ToSchema)]
 pub struct UpdateCategoryRequestDto {
     #[validate(length(min = 1, message = "Name is required"))]
     pub name: Option<String>,
+    /// Omit to keep the current parent, `null` to move the category to the top level
+    #[serde(
+        default,
+        deserialize_with = "deserialize_present",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schema(value_type = Option<i32>)]
+    pub parent_id: Option<Option<i32>>,
+}
+
+/// Tells an explicit `null` (`Some(None)`) apart from a missing field (`None`).
+fn deserialize_present<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
 }
 
 #[derive(Deserialize, Serialize, ToSchema)]
@@ -20,6 +45,84 @@ pub struct CategoryResponseDto {
     pub name: String,
     pub created_at: String,
     pub updated_at: String,
+    pub parent_id: Option<i32>,
+    /// Path from the top level category down to this one
+    pub breadcrumbs: Vec<CategoryBreadcrumbDto>,
+}
+
+impl CategoryResponseDto {
+    /// `path` is the category's ancestors, top level first, as returned by
+    /// `get_category_path`; the category itself may be included.
+    pub fn from_category(category: Category, path: Vec<Category>) -> Self {
+        let mut breadcrumbs: Vec<CategoryBreadcrumbDto> = path
+            .into_iter()
+            .filter(|c| c.id != category.id)
+            .map(CategoryBreadcrumbDto::from)
+            .collect();
+        breadcrumbs.push(CategoryBreadcrumbDto {
+            id: category.id,
+            name: category.name.clone(),
+        });
+        Self {
+            id: category.id,
+            name: category.name,
+            created_at: category.created_at.to_string(),
+            updated_at: category.updated_at.to_string(),
+            parent_id: category.parent_id,
+            breadcrumbs,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct CategoryBreadcrumbDto {
+    pub id: i32,
+    pub name: String,
+}
+
+impl From<Category> for CategoryBreadcrumbDto {
+    fn from(category: Category) -> Self {
+        Self {
+            id: category.id,
+            name: category.name,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct CategoryTreeNodeDto {
+    pub id: i32,
+    pub name: String,
+    /// Sub categories, by name
+    #[schema(no_recursion)]
+    pub children: Vec<CategoryTreeNodeDto>,
+}
+
+impl CategoryTreeNodeDto {
+    /// Nests `categories` under their parents. A category whose parent is
+    /// missing is shown at the top level.
+    pub fn build(categories: Vec<Category>) -> Vec<Self> {
+        let ids: HashSet<i32> = categories.iter().map(|c| c.id).collect();
+        let mut children: HashMap<Option<i32>, Vec<Category>> = HashMap::new();
+        for category in categories {
+            let parent = category.parent_id.filter(|p| ids.contains(p));
+            children.entry(parent).or_default().push(category);
+        }
+        Self::nodes(None, &mut children)
+    }
+
+    fn nodes(parent: Option<i32>, children: &mut HashMap<Option<i32>, Vec<Category>>) -> Vec<Self> {
+        children
+            .remove(&parent)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|c| Self {
+                children: Self::nodes(Some(c.id), children),
+                id: c.id,
+                name: c.name,
+            })
+            .collect()
+    }
 }
 
 #[derive(Deserialize, Serialize, Validate, ToSchema)]
@@ -40,12 +143,61 @@ pub struct TagResponseDto {
     pub name: String,
     pub created_at: String,
     pub updated_at: String,
+    /// Posts carrying the tag, any status; only filled in where asked for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_count: Option<i64>,
+}
+
+#[derive(Deserialize, Serialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TagListQueryDto {
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+    /// Include `post_count` on every tag
+    pub with_counts: Option<bool>,
+}
+
+#[derive(Deserialize, Serialize, Validate, ToSchema)]
+pub struct MergeTagRequestDto {
+    /// Tag that takes over the posts; the merged tag is deleted
+    #[validate(range(min = 1, message = "Target tag ID is required"))]
+    pub target_id: i32,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct MergeTagResponseDto {
+    /// The surviving tag, with its new `post_count`
+    pub tag: TagResponseDto,
+    /// Posts that moved over from the merged tag
+    pub moved_posts: i64,
+    /// Posts that already had both tags and kept a single link
+    pub already_tagged: i64,
+}
+
+#[derive(Deserialize, Serialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TagCloudQueryDto {
+    /// Most used tags to return, default 50
+    #[validate(range(min = 1, max = 200, message = "Limit must be between 1 and 200"))]
+    pub limit: Option<i64>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct TagCloudEntryDto {
+    pub id: i32,
+    pub name: String,
+    /// Published posts carrying the tag
+    pub post_count: i64,
+    /// 1 (least used) to 5 (most used), scaled between the smallest and largest count
+    pub weight: i64,
 }
 
 #[derive(Deserialize, Serialize, Validate, ToSchema)]
 pub struct CreateBlogRequestDto {
     #[validate(length(min = 1, message = "Title is required"))]
     pub title: String,
+    /// Explicit slug; generated from the title when omitted
+    pub slug: Option<String>,
     #[validate(length(min = 1, message = "Content is required"))]
     pub content: String,
     #[validate(range(min = 1, message = "Category ID is required"))]
@@ -55,13 +207,17 @@ pub struct CreateBlogRequestDto {
     pub excerpt: String,
     pub thumbnail: Option<String>,
     #[validate(length(min = 1, message = "Status is required"),custom(function = "validate_status"))]
-    pub status: String, // "DRAFT" or "PUBLISHED"
+    pub status: String, // "DRAFT", "PUBLISHED", "ARCHIVED" or "SCHEDULED"
+    /// Required for SCHEDULED: future publish time (RFC 3339, or `YYYY-MM-DD HH:MM:SS` in UTC)
+    pub published_at: Option<String>,
 }
 
 #[derive(Deserialize, Serialize, Validate, ToSchema)]
 pub struct UpdateBlogRequestDto {
     #[validate(length(min = 1, message = "Title is required"))]
     pub title: String,
+    /// Explicit slug; when omitted the current slug is kept unless the title changes
+    pub slug: Option<String>,
     #[validate(length(min = 1, message = "Content is required"))]
     pub content: String,
     #[validate(range(min = 1, message = "Category ID is required"))]
@@ -72,11 +228,13 @@ pub struct UpdateBlogRequestDto {
     pub thumbnail: Option<String>,
     #[validate(length(min = 1, message = "Status is required"),custom(function = "validate_status"))]
     pub status: String,
+    /// Required for SCHEDULED: future publish time (RFC 3339, or `YYYY-MM-DD HH:MM:SS` in UTC)
+    pub published_at: Option<String>,
 }
 
 fn validate_status(status: &str) -> Result<(), ValidationError> {
     match status {
-        "DRAFT" | "PUBLISHED" | "ARCHIVED" => Ok(()),
+        "DRAFT" | "PUBLISHED" | "ARCHIVED" | "SCHEDULED" => Ok(()),
         _ => Err(validator::ValidationError::new("Invalid status")),
     }
 }
@@ -96,15 +254,183 @@ pub struct BlogResponseDto {
     pub created_at: String,
     pub updated_at: String,
     pub published_at: Option<String>,
+    /// Sanitized HTML rendered from `content` (CommonMark + GFM)
+    pub content_html: Option<String>,
+    /// Headings of `content_html`, in document order
+    pub toc: Vec<TocEntryDto>,
+    pub word_count: i32,
+    pub reading_time_minutes: i32,
+    /// Number of approved comments
+    pub comment_count: i64,
+    /// Library images used as thumbnail or in the content, thumbnail first
+    pub images: Vec<ResponsiveImageDto>,
+    /// Series the post is part of, with its neighbours
+    pub series: Option<BlogSeriesDto>,
+    /// User who created the post
+    pub author: Option<AuthorDto>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct TocEntryDto {
+    pub level: u8,
+    pub text: String,
+    /// Id of the heading anchor in `content_html`
+    pub anchor: String,
+}
+
+impl TocEntryDto {
+    pub fn from_json(toc: Option<&str>) -> Vec<Self> {
+        crate::utils::markdown::toc_from_json(toc)
+            .into_iter()
+            .map(|t| TocEntryDto {
+                level: t.level,
+                text: t.text,
+                anchor: t.anchor,
+            })
+            .collect()
+    }
+}
+
+#[derive(Deserialize, Serialize, Validate, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct BlogListQueryDto {
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+    /// DRAFT, PUBLISHED or ARCHIVED
+    #[validate(custom(function = "validate_status"))]
+    pub status: Option<String>,
+    pub category_id: Option<i32>,
+    /// With `category_id`, also match posts in its sub categories
+    pub include_descendants: Option<bool>,
+    /// Comma separated tag ids, e.g. `1,4`; matches blogs having any of them
+    pub tag_ids: Option<String>,
+    /// Inclusive lower bound on `published_at` (YYYY-MM-DD)
+    pub published_from: Option<String>,
+    /// Inclusive upper bound on `published_at` (YYYY-MM-DD)
+    pub published_to: Option<String>,
+    /// published_at, updated_at, view_count or title
+    #[validate(custom(function = "validate_sort_by"))]
+    pub sort_by: Option<String>,
+    /// asc or desc (default desc when `sort_by` is given)
+    #[validate(custom(function = "validate_sort_order"))]
+    pub order: Option<String>,
+}
+
+fn validate_sort_by(sort_by: &str) -> Result<(), ValidationError> {
+    match sort_by {
+        "published_at" | "updated_at" | "view_count" | "title" => Ok(()),
+        _ => Err(ValidationError::new("Invalid sort field")),
+    }
+}
+
+fn validate_sort_order(order: &str) -> Result<(), ValidationError> {
+    match order {
+        "asc" | "desc" => Ok(()),
+        _ => Err(ValidationError::new("Invalid sort order")),
+    }
+}
+
+#[derive(Deserialize, Serialize, Validate, ToSchema)]
+pub struct BulkBlogRequestDto {
+    #[validate(length(min = 1, max = 100, message = "Between 1 and 100 blog ids are required"))]
+    pub ids: Vec<i32>,
+    /// publish, archive, draft, delete, change_category, add_tags or remove_tags
+    #[validate(custom(function = "validate_bulk_action"))]
+    pub action: String,
+    /// Target category, required for `change_category`
+    pub category_id: Option<i32>,
+    /// Required for `add_tags` and `remove_tags`
+    pub tag_ids: Option<Vec<i32>>,
+}
+
+fn validate_bulk_action(action: &str) -> Result<(), ValidationError> {
+    match action {
+        "publish" | "archive" | "draft" | "delete" | "change_category" | "add_tags"
+        | "remove_tags" => Ok(()),
+        _ => Err(ValidationError::new("Invalid bulk action")),
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct BulkBlogResultDto {
+    pub id: i32,
+    pub success: bool,
+    /// Status the blog had before the action; only set on success
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_status: Option<String>,
+    /// Why this blog was skipped; its changes were rolled back
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct BulkBlogResponseDto {
+    pub action: String,
+    pub succeeded: usize,
+    pub failed: usize,
+    /// One entry per distinct id, in request order
+    pub results: Vec<BulkBlogResultDto>,
+}
+
+#[derive(Deserialize, Serialize, Validate, ToSchema)]
+pub struct SearchBlogRequestDto {
+    #[validate(length(min = 1, max = 200, message = "Search query is required"))]
+    pub q: String,
+    #[validate(range(min = 1, message = "Must be at least 1"))]
+    pub page: Option<i64>,
+    #[validate(range(min = 1, max = 100, message = "Must be between 1 and 100"))]
+    pub per_page: Option<i64>,
+}
+
+#[derive(Deserialize, Serialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct RelatedBlogRequestDto {
+    /// How many posts to return (1-20, default 5)
+    #[validate(range(min = 1, max = 20, message = "Limit must be between 1 and 20"))]
+    pub limit: Option<i64>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct RelatedBlogResponseDto {
+    pub id: i32,
+    pub title: String,
+    pub slug: String,
+    pub excerpt: Option<String>,
+    pub thumbnail: Option<String>,
+    pub published_at: Option<String>,
+    /// Tags this post has in common with the source post
+    pub shared_tags: i64,
+    pub same_category: bool,
+    /// Weighted relevance, higher is a better match
+    pub score: f64,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct BlogSearchResultDto {
+    pub id: i32,
+    pub title: String,
+    pub slug: String,
+    pub excerpt: Option<String>,
+    pub status: String,
+    pub published_at: Option<String>,
+    /// Title with matched terms wrapped in `<mark>` (HTML-escaped otherwise).
+    pub title_highlight: String,
+    /// Best matching fragment with matched terms wrapped in `<mark>`.
+    pub snippet: String,
+    /// BM25 score, lower is a better match.
+    pub rank: f64,
 }
 
-#[derive(Deserialize, Serialize, Validate)]
+#[derive(Deserialize, Serialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct PaginationRequestDto {
+    #[validate(range(min = 1, message = "Must be at least 1"))]
     pub page: Option<i64>,
+    #[validate(range(min = 1, max = 100, message = "Must be between 1 and 100"))]
     pub per_page: Option<i64>,
 }
 
-#[derive(Deserialize, Serialize)]
+#[derive(Deserialize, Serialize, ToSchema)]
 pub struct MetaDto {
     pub page: i64,
     pub per_page: i64,
@@ -112,8 +438,55 @@ pub struct MetaDto {
     pub total_items: i64,
 }
 
-#[derive(Deserialize, Serialize)]
+#[derive(Deserialize, Serialize, ToSchema)]
 pub struct PaginatedResponseDto<T> {
     pub items: Vec<T>,
     pub meta: MetaDto,
 }
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct BlogRevisionResponseDto {
+    pub id: i32,
+    pub blog_id: i32,
+    pub revision_number: i32,
+    pub title: String,
+    pub excerpt: Option<String>,
+    pub content: String,
+    pub created_at: String,
+}
+
+#[derive(Deserialize, Serialize, Validate, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct BlogRevisionDiffRequestDto {
+    /// Revision number to diff from (old side)
+    #[validate(range(min = 1, message = "From revision is required"))]
+    pub from: i32,
+    /// Revision number to diff to (new side)
+    #[validate(range(min = 1, message = "To revision is required"))]
+    pub to: i32,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct DiffLineDto {
+    /// "equal", "insert" or "delete"
+    pub tag: String,
+    /// 1-based line number in the `from` revision
+    pub old_line: Option<usize>,
+    /// 1-based line number in the `to` revision
+    pub new_line: Option<usize>,
+    pub value: String,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct BlogRevisionDiffResponseDto {
+    pub blog_id: i32,
+    pub from_revision: i32,
+    pub to_revision: i32,
+    pub title: Vec<DiffLineDto>,
+    pub excerpt: Vec<DiffLineDto>,
+    pub content: Vec<DiffLineDto>,
+    /// Inserted lines across all fields
+    pub additions: usize,
+    /// Deleted lines across all fields
+    pub deletions: usize,
+}
@@ -0,0 +1,76 @@
+use crate::app::features::comments::domain::entity::NewComment;
+use crate::app::features::comments::domain::error::CommentError;
+use crate::app::features::comments::domain::repository::CommentRepository;
+use crate::app::features::comments::interface::dto::{CommentResponseDto, CreateCommentRequestDto};
+use std::sync::Arc;
+use validator::{Validate, ValidationError};
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn CommentRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn CommentRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Submits a reader comment on a published blog. New comments always
+    /// start as PENDING and stay hidden until a moderator approves them.
+    pub async fn execute(
+        &self,
+        slug: String,
+        dto: CreateCommentRequestDto,
+    ) -> Result<CommentResponseDto, CommentError> {
+        let blog_id = self
+            .repository
+            .find_public_blog_id_by_slug(slug)
+            .map_err(|e| CommentError::System(e.to_string()))?
+            .ok_or_else(|| CommentError::NotFound("Blog not found".to_string()))?;
+
+        let mut validation_errors = dto.validate().err().unwrap_or_default();
+
+        // Replies are only allowed on visible comments of the same post
+        if let Some(parent_id) = dto.parent_id {
+            let parent_visible = self
+                .repository
+                .get_comment_by_id(parent_id)
+                .map_err(|e| CommentError::System(e.to_string()))?
+                .is_some_and(|p| p.blog_id == blog_id && p.status == "APPROVED");
+            if !parent_visible {
+                validation_errors.add(
+                    "parent_id",
+                    ValidationError::new("Parent comment not found"),
+                );
+            }
+        }
+
+        if !validation_errors.is_empty() {
+            return Err(CommentError::Validation(validation_errors));
+        }
+
+        let comment = self
+            .repository
+            .create_comment(NewComment {
+                blog_id,
+                parent_id: dto.parent_id,
+                author_name: dto.author_name.trim().to_string(),
+                author_email: dto.author_email.trim().to_string(),
+                content: dto.content.trim().to_string(),
+                status: "PENDING".to_string(),
+            })
+            .map_err(|e| CommentError::System(e.to_string()))?;
+
+        Ok(CommentResponseDto {
+            id: comment.id,
+            blog_id: comment.blog_id,
+            parent_id: comment.parent_id,
+            author_name: comment.author_name,
+            author_email: comment.author_email,
+            content: comment.content,
+            status: comment.status,
+            created_at: comment.created_at.to_string(),
+            updated_at: comment.updated_at.to_string(),
+        })
+    }
+}
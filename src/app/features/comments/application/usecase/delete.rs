@@ -0,0 +1,26 @@
+use crate::app::features::comments::domain::error::CommentError;
+use crate::app::features::comments::domain::repository::CommentRepository;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn CommentRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn CommentRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Deletes a comment together with its replies.
+    pub async fn execute(&self, id: i32) -> Result<(), CommentError> {
+        let rows_affected = self
+            .repository
+            .delete_comment(id)
+            .map_err(|e| CommentError::System(e.to_string()))?;
+        if rows_affected == 0 {
+            return Err(CommentError::NotFound("Comment not found".to_string()));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use crate::app::features::comments::domain::error::CommentError;
+use crate::app::features::comments::domain::repository::CommentRepository;
+use crate::app::features::comments::interface::dto::{
+    CommentListQueryDto, CommentResponseDto, MetaDto, PaginatedResponseDto,
+};
+use std::sync::Arc;
+use validator::Validate;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn CommentRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn CommentRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        query: CommentListQueryDto,
+    ) -> Result<PaginatedResponseDto<CommentResponseDto>, CommentError> {
+        query.validate().map_err(CommentError::Validation)?;
+
+        let page = query.page.unwrap_or(1);
+        let per_page = query.per_page.unwrap_or(10);
+        let offset = (page - 1) * per_page;
+
+        let (comments, total_items) = self
+            .repository
+            .get_all_comments(query.status, query.blog_id, per_page, offset)
+            .map_err(|e| CommentError::System(e.to_string()))?;
+
+        let items = comments
+            .into_iter()
+            .map(|c| CommentResponseDto {
+                id: c.id,
+                blog_id: c.blog_id,
+                parent_id: c.parent_id,
+                author_name: c.author_name,
+                author_email: c.author_email,
+                content: c.content,
+                status: c.status,
+                created_at: c.created_at.to_string(),
+                updated_at: c.updated_at.to_string(),
+            })
+            .collect();
+
+        let total_pages = (total_items as f64 / per_page as f64).ceil() as i64;
+
+        Ok(PaginatedResponseDto {
+            items,
+            meta: MetaDto {
+                page,
+                per_page,
+                total_pages,
+                total_items,
+            },
+        })
+    }
+}
@@ -0,0 +1,94 @@
+use crate::app::features::comments::domain::entity::Comment;
+use crate::app::features::comments::domain::error::CommentError;
+use crate::app::features::comments::domain::repository::CommentRepository;
+use crate::app::features::comments::interface::dto::{
+    MetaDto, PaginatedResponseDto, PaginationRequestDto, PublicCommentDto,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+use validator::Validate;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn CommentRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn CommentRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Approved comments of a published blog as threads. Pagination applies to
+    /// top-level comments; each carries all of its approved replies. A reply
+    /// whose parent is not approved is hidden along with its subtree.
+    pub async fn execute(
+        &self,
+        slug: String,
+        query: PaginationRequestDto,
+    ) -> Result<PaginatedResponseDto<PublicCommentDto>, CommentError> {
+        query.validate().map_err(CommentError::Validation)?;
+        let blog_id = self
+            .repository
+            .find_public_blog_id_by_slug(slug)
+            .map_err(|e| CommentError::System(e.to_string()))?
+            .ok_or_else(|| CommentError::NotFound("Blog not found".to_string()))?;
+
+        let page = query.page.unwrap_or(1);
+        let per_page = query.per_page.unwrap_or(10);
+        let offset = (page - 1).saturating_mul(per_page);
+
+        let (roots, total_items) = self
+            .repository
+            .get_approved_root_comments(blog_id, per_page, offset)
+            .map_err(|e| CommentError::System(e.to_string()))?;
+
+        let mut replies_by_parent: HashMap<i32, Vec<Comment>> = HashMap::new();
+        for reply in self
+            .repository
+            .get_approved_replies(blog_id)
+            .map_err(|e| CommentError::System(e.to_string()))?
+        {
+            if let Some(parent_id) = reply.parent_id {
+                replies_by_parent.entry(parent_id).or_default().push(reply);
+            }
+        }
+
+        let items = roots
+            .into_iter()
+            .map(|root| build_thread(root, &mut replies_by_parent))
+            .collect();
+
+        let total_pages = (total_items as f64 / per_page as f64).ceil() as i64;
+
+        Ok(PaginatedResponseDto {
+            items,
+            meta: MetaDto {
+                page,
+                per_page,
+                total_pages,
+                total_items,
+            },
+        })
+    }
+}
+
+fn build_thread(
+    comment: Comment,
+    replies_by_parent: &mut HashMap<i32, Vec<Comment>>,
+) -> PublicCommentDto {
+    let replies = replies_by_parent
+        .remove(&comment.id)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|reply| build_thread(reply, replies_by_parent))
+        .collect();
+
+    PublicCommentDto {
+        id: comment.id,
+        parent_id: comment.parent_id,
+        author_name: comment.author_name,
+        content: comment.content,
+        created_at: comment.created_at.to_string(),
+        replies,
+    }
+}
@@ -0,0 +1,5 @@
+pub mod create;
+pub mod delete;
+pub mod get_all;
+pub mod get_public;
+pub mod moderate;
@@ -0,0 +1,38 @@
+use crate::app::features::comments::domain::error::CommentError;
+use crate::app::features::comments::domain::repository::CommentRepository;
+use crate::app::features::comments::interface::dto::{
+    ModerateCommentsRequestDto, ModerateCommentsResponseDto,
+};
+use std::sync::Arc;
+use validator::Validate;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn CommentRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn CommentRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Moves every listed comment to the given status. Unknown ids are
+    /// ignored; `updated` reports how many comments actually changed.
+    pub async fn execute(
+        &self,
+        dto: ModerateCommentsRequestDto,
+    ) -> Result<ModerateCommentsResponseDto, CommentError> {
+        dto.validate().map_err(CommentError::Validation)?;
+
+        let mut ids = dto.ids;
+        ids.sort_unstable();
+        ids.dedup();
+
+        let updated = self
+            .repository
+            .update_comments_status(ids, dto.status)
+            .map_err(|e| CommentError::System(e.to_string()))?;
+
+        Ok(ModerateCommentsResponseDto { updated })
+    }
+}
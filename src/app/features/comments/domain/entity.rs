@@ -0,0 +1,28 @@
+use crate::schema::comments;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone, PartialEq)]
+#[diesel(table_name = comments)]
+pub struct Comment {
+    pub id: i32,
+    pub blog_id: i32,
+    pub parent_id: Option<i32>,
+    pub author_name: String,
+    pub author_email: String,
+    pub content: String,
+    pub status: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = comments)]
+pub struct NewComment {
+    pub blog_id: i32,
+    pub parent_id: Option<i32>,
+    pub author_name: String,
+    pub author_email: String,
+    pub content: String,
+    pub status: String,
+}
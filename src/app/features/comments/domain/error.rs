@@ -0,0 +1,14 @@
+use validator::ValidationErrors;
+
+#[derive(Debug)]
+pub enum CommentError {
+    Validation(ValidationErrors),
+    System(String),
+    NotFound(String),
+}
+
+impl From<String> for CommentError {
+    fn from(s: String) -> Self {
+        CommentError::System(s)
+    }
+}
@@ -0,0 +1,25 @@
+use crate::app::features::comments::domain::entity::{Comment, NewComment};
+use diesel::QueryResult;
+
+pub trait CommentRepository {
+    /// Id of the blog behind `slug` if readers can currently see it.
+    fn find_public_blog_id_by_slug(&self, slug: String) -> QueryResult<Option<i32>>;
+    fn get_comment_by_id(&self, id: i32) -> QueryResult<Option<Comment>>;
+    fn create_comment(&self, comment: NewComment) -> QueryResult<Comment>;
+    fn get_approved_root_comments(
+        &self,
+        blog_id: i32,
+        limit: i64,
+        offset: i64,
+    ) -> QueryResult<(Vec<Comment>, i64)>;
+    fn get_approved_replies(&self, blog_id: i32) -> QueryResult<Vec<Comment>>;
+    fn get_all_comments(
+        &self,
+        status: Option<String>,
+        blog_id: Option<i32>,
+        limit: i64,
+        offset: i64,
+    ) -> QueryResult<(Vec<Comment>, i64)>;
+    fn update_comments_status(&self, ids: Vec<i32>, status: String) -> QueryResult<usize>;
+    fn delete_comment(&self, id: i32) -> QueryResult<usize>;
+}
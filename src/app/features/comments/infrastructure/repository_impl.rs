@@ -0,0 +1,143 @@
+use crate::app::features::comments::domain::entity::{Comment, NewComment};
+use crate::app::features::comments::domain::repository::CommentRepository;
+use crate::schema::{blog, comments};
+use crate::utils::db::DbPool;
+use chrono::Utc;
+use diesel::prelude::*;
+
+#[derive(Clone)]
+pub struct CommentRepositoryImpl {
+    pub pool: DbPool,
+}
+
+impl CommentRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl CommentRepository for CommentRepositoryImpl {
+    fn find_public_blog_id_by_slug(&self, slug: String) -> QueryResult<Option<i32>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        blog::table
+            .filter(blog::slug.eq(slug))
+            .filter(blog::status.eq("PUBLISHED"))
+            .filter(blog::published_at.le(Utc::now().naive_utc()))
+            .select(blog::id)
+            .first::<i32>(&mut conn)
+            .optional()
+    }
+    fn get_comment_by_id(&self, id: i32) -> QueryResult<Option<Comment>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        comments::table
+            .find(id)
+            .first::<Comment>(&mut conn)
+            .optional()
+    }
+    fn create_comment(&self, comment: NewComment) -> QueryResult<Comment> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::insert_into(comments::table)
+            .values(comment)
+            .get_result(&mut conn)
+    }
+    fn get_approved_root_comments(
+        &self,
+        blog_id: i32,
+        limit: i64,
+        offset: i64,
+    ) -> QueryResult<(Vec<Comment>, i64)> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+
+        let count = comments::table
+            .filter(comments::blog_id.eq(blog_id))
+            .filter(comments::parent_id.is_null())
+            .filter(comments::status.eq("APPROVED"))
+            .count()
+            .get_result(&mut conn)?;
+        let items = comments::table
+            .filter(comments::blog_id.eq(blog_id))
+            .filter(comments::parent_id.is_null())
+            .filter(comments::status.eq("APPROVED"))
+            .order(comments::id.asc())
+            .limit(limit)
+            .offset(offset)
+            .load::<Comment>(&mut conn)?;
+
+        Ok((items, count))
+    }
+    fn get_approved_replies(&self, blog_id: i32) -> QueryResult<Vec<Comment>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        comments::table
+            .filter(comments::blog_id.eq(blog_id))
+            .filter(comments::parent_id.is_not_null())
+            .filter(comments::status.eq("APPROVED"))
+            .order(comments::id.asc())
+            .load::<Comment>(&mut conn)
+    }
+    fn get_all_comments(
+        &self,
+        status: Option<String>,
+        blog_id: Option<i32>,
+        limit: i64,
+        offset: i64,
+    ) -> QueryResult<(Vec<Comment>, i64)> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+
+        let filtered = || {
+            let mut query = comments::table.into_boxed();
+            if let Some(status) = &status {
+                query = query.filter(comments::status.eq(status.clone()));
+            }
+            if let Some(blog_id) = blog_id {
+                query = query.filter(comments::blog_id.eq(blog_id));
+            }
+            query
+        };
+
+        let count = filtered().count().get_result(&mut conn)?;
+        let items = filtered()
+            .order(comments::id.desc())
+            .limit(limit)
+            .offset(offset)
+            .load::<Comment>(&mut conn)?;
+
+        Ok((items, count))
+    }
+    fn update_comments_status(&self, ids: Vec<i32>, status: String) -> QueryResult<usize> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::update(comments::table.filter(comments::id.eq_any(ids)))
+            .set((
+                comments::status.eq(status),
+                comments::updated_at.eq(Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)
+    }
+    fn delete_comment(&self, id: i32) -> QueryResult<usize> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::delete(comments::table.find(id)).execute(&mut conn)
+    }
+}
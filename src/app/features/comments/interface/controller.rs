@@ -0,0 +1,191 @@
+use crate::app::features::comments::domain::error::CommentError;
+use crate::app::features::comments::interface::dto::{
+    CommentListQueryDto, CommentResponseDto, CreateCommentRequestDto, ModerateCommentsRequestDto,
+    ModerateCommentsResponseDto, PaginationRequestDto, PublicCommentDto,
+};
+use crate::utils::di::Container;
+use crate::utils::error_response::{ErrorResponse, map_string_error, map_validation_error};
+use crate::utils::success_response::{map_success_response, map_success_with_data};
+use actix_web::{HttpResponse, Responder, delete, get, post, web};
+
+#[utoipa::path(
+    path = "/app/comments",
+    tag = "Comments",
+    params(
+        CommentListQueryDto
+    ),
+    responses(
+        (status = 200, description = "Comments, newest first", body = crate::utils::success_response::SuccessResponse<Vec<CommentResponseDto>>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[get("/comments")]
+pub async fn get_comments(
+    container: web::Data<Container>,
+    query: web::Query<CommentListQueryDto>,
+) -> impl Responder {
+    match container
+        .get_comments_usecase
+        .execute(query.into_inner())
+        .await
+    {
+        Ok(comments) => HttpResponse::Ok().json(map_success_with_data(
+            "Comments fetched successfully".to_string(),
+            comments,
+        )),
+        Err(e) => match e {
+            CommentError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+            CommentError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+            CommentError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    path = "/app/comments/moderate",
+    tag = "Comments",
+    request_body = ModerateCommentsRequestDto,
+    responses(
+        (status = 200, description = "Comments moderated", body = crate::utils::success_response::SuccessResponse<ModerateCommentsResponseDto>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[post("/comments/moderate")]
+pub async fn moderate_comments(
+    container: web::Data<Container>,
+    body: web::Json<ModerateCommentsRequestDto>,
+) -> impl Responder {
+    match container
+        .moderate_comments_usecase
+        .execute(body.into_inner())
+        .await
+    {
+        Ok(result) => HttpResponse::Ok().json(map_success_with_data(
+            "Comments moderated successfully".to_string(),
+            result,
+        )),
+        Err(e) => match e {
+            CommentError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+            CommentError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+            CommentError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    path = "/app/comments/{id}",
+    tag = "Comments",
+    params(
+        ("id", description = "Comment ID")
+    ),
+    responses(
+        (status = 200, description = "Comment deleted"),
+        (status = 404, description = "Comment not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[delete("/comments/{id}")]
+pub async fn delete_comment(container: web::Data<Container>, id: web::Path<i32>) -> impl Responder {
+    match container
+        .delete_comment_usecase
+        .execute(id.into_inner())
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().json(map_success_response(
+            "Comment deleted successfully".to_string(),
+        )),
+        Err(e) => match e {
+            CommentError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+            CommentError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+            CommentError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    path = "/api/public/blogs/{slug}/comments",
+    tag = "Public",
+    params(
+        ("slug", description = "Blog slug"),
+        PaginationRequestDto
+    ),
+    responses(
+        (status = 200, description = "Approved comment threads", body = crate::utils::success_response::SuccessResponse<Vec<PublicCommentDto>>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 404, description = "Blog not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    security(())
+)]
+#[get("/blogs/{slug}/comments")]
+pub async fn get_public_comments(
+    container: web::Data<Container>,
+    slug: web::Path<String>,
+    query: web::Query<PaginationRequestDto>,
+) -> impl Responder {
+    match container
+        .get_public_comments_usecase
+        .execute(slug.into_inner(), query.into_inner())
+        .await
+    {
+        Ok(comments) => HttpResponse::Ok().json(map_success_with_data(
+            "Comments fetched successfully".to_string(),
+            comments,
+        )),
+        Err(e) => match e {
+            CommentError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+            CommentError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+            CommentError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    path = "/api/public/blogs/{slug}/comments",
+    tag = "Public",
+    params(
+        ("slug", description = "Blog slug")
+    ),
+    request_body = CreateCommentRequestDto,
+    responses(
+        (status = 201, description = "Comment submitted for moderation", body = crate::utils::success_response::SuccessResponse<CommentResponseDto>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 404, description = "Blog not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    security(())
+)]
+#[post("/blogs/{slug}/comments")]
+pub async fn create_public_comment(
+    container: web::Data<Container>,
+    slug: web::Path<String>,
+    body: web::Json<CreateCommentRequestDto>,
+) -> impl Responder {
+    match container
+        .create_comment_usecase
+        .execute(slug.into_inner(), body.into_inner())
+        .await
+    {
+        Ok(comment) => HttpResponse::Created().json(map_success_with_data(
+            "Comment submitted and awaiting moderation".to_string(),
+            comment,
+        )),
+        Err(e) => match e {
+            CommentError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+            CommentError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+            CommentError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::{Validate, ValidationError};
+
+#[derive(Deserialize, Serialize, Validate, ToSchema)]
+pub struct CreateCommentRequestDto {
+    #[validate(length(min = 1, max = 100, message = "Name is required"))]
+    pub author_name: String,
+    #[validate(email(message = "Email is invalid"))]
+    pub author_email: String,
+    #[validate(length(min = 1, max = 5000, message = "Content is required"))]
+    pub content: String,
+    /// Approved comment on the same blog to reply to
+    pub parent_id: Option<i32>,
+}
+
+#[derive(Deserialize, Serialize, Validate, ToSchema)]
+pub struct ModerateCommentsRequestDto {
+    #[validate(length(min = 1, message = "At least one comment id is required"))]
+    pub ids: Vec<i32>,
+    /// PENDING, APPROVED or SPAM
+    #[validate(custom(function = "validate_status"))]
+    pub status: String,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct ModerateCommentsResponseDto {
+    pub updated: usize,
+}
+
+fn validate_status(status: &str) -> Result<(), ValidationError> {
+    match status {
+        "PENDING" | "APPROVED" | "SPAM" => Ok(()),
+        _ => Err(ValidationError::new("Invalid status")),
+    }
+}
+
+#[derive(Deserialize, Serialize, Validate, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CommentListQueryDto {
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+    /// PENDING, APPROVED or SPAM
+    #[validate(custom(function = "validate_status"))]
+    pub status: Option<String>,
+    pub blog_id: Option<i32>,
+}
+
+#[derive(Deserialize, Serialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PaginationRequestDto {
+    #[validate(range(min = 1, message = "Must be at least 1"))]
+    pub page: Option<i64>,
+    #[validate(range(min = 1, max = 100, message = "Must be between 1 and 100"))]
+    pub per_page: Option<i64>,
+}
+
+/// Full comment as seen by moderators.
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct CommentResponseDto {
+    pub id: i32,
+    pub blog_id: i32,
+    pub parent_id: Option<i32>,
+    pub author_name: String,
+    pub author_email: String,
+    pub content: String,
+    pub status: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Approved comment as shown to readers, with its approved replies nested.
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct PublicCommentDto {
+    pub id: i32,
+    pub parent_id: Option<i32>,
+    pub author_name: String,
+    pub content: String,
+    pub created_at: String,
+    #[schema(no_recursion)]
+    pub replies: Vec<PublicCommentDto>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct MetaDto {
+    pub page: i64,
+    pub per_page: i64,
+    pub total_pages: i64,
+    pub total_items: i64,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PaginatedResponseDto<T> {
+    pub items: Vec<T>,
+    pub meta: MetaDto,
+}
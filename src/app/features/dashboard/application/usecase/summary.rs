@@ -0,0 +1,178 @@
+use crate::app::features::dashboard::domain::entity::DashboardPost;
+use crate::app::features::dashboard::domain::error::DashboardError;
+use crate::app::features::dashboard::domain::repository::DashboardRepository;
+use crate::app::features::dashboard::interface::dto::{
+    DashboardPostDto, DashboardQueryDto, DashboardResponseDto, PortofolioStatsDto, PostStatsDto,
+    ProjectStatsDto, ProjectStatusStatsDto, RecentItemDto, StaleDraftDto, StaleDraftsDto,
+    StatusCountDto,
+};
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use validator::Validate;
+
+pub const DEFAULT_STALE_DAYS: i64 = 30;
+pub const DEFAULT_LIST_LIMIT: i64 = 10;
+
+const BLOG_STATUSES: [&str; 4] = ["DRAFT", "SCHEDULED", "PUBLISHED", "ARCHIVED"];
+const PROJECT_STATUSES: [&str; 3] = ["DRAFT", "ONGOING", "COMPLETED"];
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn DashboardRepository + Send + Sync>,
+}
+
+/// Rounded to two decimals; 0 for an empty group.
+fn average(sum: i64, count: i64) -> f64 {
+    if count == 0 {
+        return 0.0;
+    }
+    (sum as f64 / count as f64 * 100.0).round() / 100.0
+}
+
+/// Known statuses in their usual order, zero-filled, then any unexpected ones.
+fn order_statuses<T: Clone>(
+    known: &[&str],
+    mut rows: Vec<(String, T)>,
+    empty: T,
+) -> Vec<(String, T)> {
+    let mut ordered = Vec::new();
+    for status in known {
+        match rows.iter().position(|(s, _)| s == status) {
+            Some(index) => ordered.push(rows.remove(index)),
+            None => ordered.push((status.to_string(), empty.clone())),
+        }
+    }
+    rows.sort_by(|a, b| a.0.cmp(&b.0));
+    ordered.extend(rows);
+    ordered
+}
+
+fn post_dto(post: DashboardPost) -> DashboardPostDto {
+    DashboardPostDto {
+        id: post.id,
+        title: post.title,
+        slug: post.slug,
+        status: post.status,
+        view_count: post.view_count,
+        updated_at: post.updated_at.to_string(),
+    }
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn DashboardRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        query: DashboardQueryDto,
+    ) -> Result<DashboardResponseDto, DashboardError> {
+        query.validate().map_err(DashboardError::Validation)?;
+        let stale_days = query.stale_days.unwrap_or(DEFAULT_STALE_DAYS);
+        let limit = query.limit.unwrap_or(DEFAULT_LIST_LIMIT);
+        let now = Utc::now().naive_utc();
+
+        let blog_counts = self
+            .repository
+            .count_blogs_by_status()
+            .map_err(|e| DashboardError::System(e.to_string()))?;
+        let by_status = order_statuses(&BLOG_STATUSES, blog_counts, 0)
+            .into_iter()
+            .map(|(status, count)| StatusCountDto { status, count })
+            .collect::<Vec<_>>();
+        let posts = PostStatsDto {
+            total: by_status.iter().map(|s| s.count).sum(),
+            by_status,
+        };
+
+        let project_totals = self
+            .repository
+            .get_project_totals()
+            .map_err(|e| DashboardError::System(e.to_string()))?
+            .into_iter()
+            .map(|t| (t.status, (t.count, t.progress_sum.unwrap_or(0))))
+            .collect();
+        let project_totals = order_statuses(&PROJECT_STATUSES, project_totals, (0, 0));
+        let total = project_totals.iter().map(|(_, (count, _))| count).sum();
+        let progress_sum = project_totals.iter().map(|(_, (_, sum))| sum).sum();
+        let projects = ProjectStatsDto {
+            total,
+            average_progress: average(progress_sum, total),
+            by_status: project_totals
+                .into_iter()
+                .map(|(status, (count, sum))| ProjectStatusStatsDto {
+                    status,
+                    count,
+                    average_progress: average(sum, count),
+                })
+                .collect(),
+        };
+
+        let mut portofolios = PortofolioStatsDto {
+            total: 0,
+            active: 0,
+            inactive: 0,
+        };
+        for (is_active, count) in self
+            .repository
+            .count_portofolios_by_activity()
+            .map_err(|e| DashboardError::System(e.to_string()))?
+        {
+            if is_active {
+                portofolios.active += count;
+            } else {
+                portofolios.inactive += count;
+            }
+            portofolios.total += count;
+        }
+
+        let top_posts = self
+            .repository
+            .get_top_viewed_posts(limit)
+            .map_err(|e| DashboardError::System(e.to_string()))?
+            .into_iter()
+            .map(post_dto)
+            .collect();
+
+        let recent_updates = self
+            .repository
+            .get_recent_items(limit)
+            .map_err(|e| DashboardError::System(e.to_string()))?
+            .into_iter()
+            .map(|item| RecentItemDto {
+                kind: item.kind.to_string(),
+                id: item.id,
+                title: item.title,
+                updated_at: item.updated_at.to_string(),
+            })
+            .collect();
+
+        let (drafts, stale_total) = self
+            .repository
+            .get_stale_drafts(now - Duration::days(stale_days), limit)
+            .map_err(|e| DashboardError::System(e.to_string()))?;
+        let stale_drafts = StaleDraftsDto {
+            stale_days,
+            total: stale_total,
+            items: drafts
+                .into_iter()
+                .map(|d| StaleDraftDto {
+                    id: d.id,
+                    days_untouched: (now - d.updated_at).num_days(),
+                    title: d.title,
+                    slug: d.slug,
+                    updated_at: d.updated_at.to_string(),
+                })
+                .collect(),
+        };
+
+        Ok(DashboardResponseDto {
+            posts,
+            projects,
+            portofolios,
+            top_posts,
+            recent_updates,
+            stale_drafts,
+        })
+    }
+}
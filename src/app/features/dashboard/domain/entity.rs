@@ -0,0 +1,29 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+/// Projects sharing a status, with the sum of their `progress` for averaging.
+#[derive(Queryable, Debug, Clone, PartialEq)]
+pub struct ProjectStatusTotals {
+    pub status: String,
+    pub count: i64,
+    pub progress_sum: Option<i64>,
+}
+
+#[derive(Queryable, Debug, Clone, PartialEq)]
+pub struct DashboardPost {
+    pub id: i32,
+    pub title: String,
+    pub slug: String,
+    pub status: String,
+    pub view_count: i32,
+    pub updated_at: NaiveDateTime,
+}
+
+/// Anything with an `updated_at`, whatever feature it belongs to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecentItem {
+    pub kind: &'static str,
+    pub id: i32,
+    pub title: String,
+    pub updated_at: NaiveDateTime,
+}
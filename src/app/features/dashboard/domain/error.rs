@@ -0,0 +1,13 @@
+use validator::ValidationErrors;
+
+#[derive(Debug)]
+pub enum DashboardError {
+    Validation(ValidationErrors),
+    System(String),
+}
+
+impl From<String> for DashboardError {
+    fn from(s: String) -> Self {
+        DashboardError::System(s)
+    }
+}
@@ -0,0 +1,23 @@
+use crate::app::features::dashboard::domain::entity::{
+    DashboardPost, ProjectStatusTotals, RecentItem,
+};
+use chrono::NaiveDateTime;
+use diesel::QueryResult;
+
+pub trait DashboardRepository {
+    fn count_blogs_by_status(&self) -> QueryResult<Vec<(String, i64)>>;
+    fn get_project_totals(&self) -> QueryResult<Vec<ProjectStatusTotals>>;
+    /// Number of portofolios per `is_active` value.
+    fn count_portofolios_by_activity(&self) -> QueryResult<Vec<(bool, i64)>>;
+    /// Posts with the highest all-time `view_count`.
+    fn get_top_viewed_posts(&self, limit: i64) -> QueryResult<Vec<DashboardPost>>;
+    /// The most recently updated posts, projects, portofolios, series,
+    /// categories, tags and stacks, newest first.
+    fn get_recent_items(&self, limit: i64) -> QueryResult<Vec<RecentItem>>;
+    /// Drafts last updated before `before`, oldest first, and how many there are.
+    fn get_stale_drafts(
+        &self,
+        before: NaiveDateTime,
+        limit: i64,
+    ) -> QueryResult<(Vec<DashboardPost>, i64)>;
+}
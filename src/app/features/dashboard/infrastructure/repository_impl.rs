@@ -0,0 +1,177 @@
+use crate::app::features::dashboard::domain::entity::{
+    DashboardPost, ProjectStatusTotals, RecentItem,
+};
+use crate::app::features::dashboard::domain::repository::DashboardRepository;
+use crate::schema::{blog, categories, portofolios, projects, series, stacks, tags};
+use crate::utils::db::DbPool;
+use chrono::NaiveDateTime;
+use diesel::dsl::{count_star, sum};
+use diesel::prelude::*;
+
+#[derive(Clone)]
+pub struct DashboardRepositoryImpl {
+    pub pool: DbPool,
+}
+
+impl DashboardRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn tag_items(kind: &'static str, rows: Vec<(i32, String, NaiveDateTime)>) -> Vec<RecentItem> {
+    rows.into_iter()
+        .map(|(id, title, updated_at)| RecentItem {
+            kind,
+            id,
+            title,
+            updated_at,
+        })
+        .collect()
+}
+
+impl DashboardRepository for DashboardRepositoryImpl {
+    fn count_blogs_by_status(&self) -> QueryResult<Vec<(String, i64)>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        blog::table
+            .group_by(blog::status)
+            .select((blog::status, count_star()))
+            .load::<(String, i64)>(&mut conn)
+    }
+    fn get_project_totals(&self) -> QueryResult<Vec<ProjectStatusTotals>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        projects::table
+            .group_by(projects::status)
+            .select((projects::status, count_star(), sum(projects::progress)))
+            .order(projects::status.asc())
+            .load::<ProjectStatusTotals>(&mut conn)
+    }
+    fn count_portofolios_by_activity(&self) -> QueryResult<Vec<(bool, i64)>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        portofolios::table
+            .group_by(portofolios::is_active)
+            .select((portofolios::is_active, count_star()))
+            .load::<(bool, i64)>(&mut conn)
+    }
+    fn get_top_viewed_posts(&self, limit: i64) -> QueryResult<Vec<DashboardPost>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        blog::table
+            .filter(blog::view_count.gt(0))
+            .order((blog::view_count.desc(), blog::id.desc()))
+            .limit(limit)
+            .select((
+                blog::id,
+                blog::title,
+                blog::slug,
+                blog::status,
+                blog::view_count,
+                blog::updated_at,
+            ))
+            .load::<DashboardPost>(&mut conn)
+    }
+    fn get_recent_items(&self, limit: i64) -> QueryResult<Vec<RecentItem>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        // The newest `limit` of each table are enough to find the newest overall
+        let mut items = tag_items(
+            "blog",
+            blog::table
+                .order(blog::updated_at.desc())
+                .limit(limit)
+                .select((blog::id, blog::title, blog::updated_at))
+                .load(&mut conn)?,
+        );
+        items.extend(tag_items(
+            "project",
+            projects::table
+                .order(projects::updated_at.desc())
+                .limit(limit)
+                .select((projects::id, projects::nama_projek, projects::updated_at))
+                .load(&mut conn)?,
+        ));
+        items.extend(tag_items(
+            "portofolio",
+            portofolios::table
+                .order(portofolios::updated_at.desc())
+                .limit(limit)
+                .select((portofolios::id, portofolios::judul, portofolios::updated_at))
+                .load(&mut conn)?,
+        ));
+        items.extend(tag_items(
+            "series",
+            series::table
+                .order(series::updated_at.desc())
+                .limit(limit)
+                .select((series::id, series::title, series::updated_at))
+                .load(&mut conn)?,
+        ));
+        items.extend(tag_items(
+            "category",
+            categories::table
+                .order(categories::updated_at.desc())
+                .limit(limit)
+                .select((categories::id, categories::name, categories::updated_at))
+                .load(&mut conn)?,
+        ));
+        items.extend(tag_items(
+            "tag",
+            tags::table
+                .order(tags::updated_at.desc())
+                .limit(limit)
+                .select((tags::id, tags::name, tags::updated_at))
+                .load(&mut conn)?,
+        ));
+        items.extend(tag_items(
+            "stack",
+            stacks::table
+                .order(stacks::updated_at.desc())
+                .limit(limit)
+                .select((stacks::id, stacks::nama_stack, stacks::updated_at))
+                .load(&mut conn)?,
+        ));
+        items.sort_by_key(|item| std::cmp::Reverse(item.updated_at));
+        items.truncate(limit as usize);
+        Ok(items)
+    }
+    fn get_stale_drafts(
+        &self,
+        before: NaiveDateTime,
+        limit: i64,
+    ) -> QueryResult<(Vec<DashboardPost>, i64)> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        let stale = blog::table
+            .filter(blog::status.eq("DRAFT"))
+            .filter(blog::updated_at.lt(before));
+        let count = stale.count().get_result(&mut conn)?;
+        let items = stale
+            .order((blog::updated_at.asc(), blog::id.asc()))
+            .limit(limit)
+            .select((
+                blog::id,
+                blog::title,
+                blog::slug,
+                blog::status,
+                blog::view_count,
+                blog::updated_at,
+            ))
+            .load::<DashboardPost>(&mut conn)?;
+        Ok((items, count))
+    }
+}
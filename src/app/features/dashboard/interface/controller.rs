@@ -0,0 +1,45 @@
+use crate::app::features::dashboard::domain::error::DashboardError;
+use crate::app::features::dashboard::interface::dto::{DashboardQueryDto, DashboardResponseDto};
+use crate::utils::di::Container;
+use crate::utils::error_response::{ErrorResponse, map_string_error, map_validation_error};
+use crate::utils::success_response::map_success_with_data;
+use actix_web::{HttpResponse, Responder, get, web};
+
+fn dashboard_error_response(e: DashboardError) -> HttpResponse {
+    match e {
+        DashboardError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+        DashboardError::System(msg) => {
+            HttpResponse::InternalServerError().json(map_string_error(msg))
+        }
+    }
+}
+
+#[utoipa::path(
+    path = "/app/dashboard",
+    tag = "Dashboard",
+    params(
+        DashboardQueryDto
+    ),
+    responses(
+        (status = 200, description = "Content totals, top posts, recent updates and stale drafts", body = crate::utils::success_response::SuccessResponse<DashboardResponseDto>),
+        (status = 400, description = "Invalid stale_days or limit", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[get("/dashboard")]
+pub async fn get_dashboard(
+    container: web::Data<Container>,
+    query: web::Query<DashboardQueryDto>,
+) -> impl Responder {
+    match container
+        .get_dashboard_usecase
+        .execute(query.into_inner())
+        .await
+    {
+        Ok(dashboard) => HttpResponse::Ok().json(map_success_with_data(
+            "Dashboard fetched successfully".to_string(),
+            dashboard,
+        )),
+        Err(e) => dashboard_error_response(e),
+    }
+}
@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Deserialize, Serialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DashboardQueryDto {
+    /// Drafts untouched for more than this many days are stale; defaults to 30
+    #[validate(range(min = 1, max = 3650, message = "Must be between 1 and 3650"))]
+    pub stale_days: Option<i64>,
+    /// Length of the top posts, recent updates and stale drafts lists; defaults to 10
+    #[validate(range(min = 1, max = 50, message = "Must be between 1 and 50"))]
+    pub limit: Option<i64>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct StatusCountDto {
+    pub status: String,
+    pub count: i64,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct PostStatsDto {
+    pub total: i64,
+    /// Every known status, including those without posts
+    pub by_status: Vec<StatusCountDto>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct ProjectStatusStatsDto {
+    pub status: String,
+    pub count: i64,
+    /// 0 when there are no projects with this status
+    pub average_progress: f64,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct ProjectStatsDto {
+    pub total: i64,
+    pub average_progress: f64,
+    pub by_status: Vec<ProjectStatusStatsDto>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct PortofolioStatsDto {
+    pub total: i64,
+    pub active: i64,
+    pub inactive: i64,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct DashboardPostDto {
+    pub id: i32,
+    pub title: String,
+    pub slug: String,
+    pub status: String,
+    pub view_count: i32,
+    pub updated_at: String,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct RecentItemDto {
+    /// blog, project, portofolio, series, category, tag or stack
+    pub kind: String,
+    pub id: i32,
+    pub title: String,
+    pub updated_at: String,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct StaleDraftDto {
+    pub id: i32,
+    pub title: String,
+    pub slug: String,
+    pub updated_at: String,
+    pub days_untouched: i64,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct StaleDraftsDto {
+    pub stale_days: i64,
+    /// All stale drafts, even those beyond `limit`
+    pub total: i64,
+    /// Oldest first
+    pub items: Vec<StaleDraftDto>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct DashboardResponseDto {
+    pub posts: PostStatsDto,
+    pub projects: ProjectStatsDto,
+    pub portofolios: PortofolioStatsDto,
+    /// Highest all-time view count first
+    pub top_posts: Vec<DashboardPostDto>,
+    /// Newest first, across every feature
+    pub recent_updates: Vec<RecentItemDto>,
+    pub stale_drafts: StaleDraftsDto,
+}
@@ -0,0 +1,107 @@
+use crate::app::features::feed::domain::entity::{Feed, FeedEntry, FeedScope};
+use crate::app::features::feed::domain::error::FeedError;
+use crate::app::features::feed::domain::repository::FeedRepository;
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// How many of the newest posts a feed carries.
+pub const FEED_ITEM_LIMIT: i64 = 20;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn FeedRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn FeedRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, scope: FeedScope) -> Result<Feed, FeedError> {
+        let author = self
+            .repository
+            .get_author()
+            .map_err(|e| FeedError::System(e.to_string()))?;
+
+        let site_title = author
+            .as_ref()
+            .map(|a| a.name.clone())
+            .unwrap_or_else(|| "Blog".to_string());
+        let (title, description) = match scope {
+            FeedScope::All => (
+                site_title.clone(),
+                format!("Latest posts from {}", site_title),
+            ),
+            FeedScope::Category(id) => {
+                let name = self
+                    .repository
+                    .get_category_name(id)
+                    .map_err(|e| FeedError::System(e.to_string()))?
+                    .ok_or_else(|| FeedError::NotFound("Category not found".to_string()))?;
+                (
+                    format!("{} - {}", site_title, name),
+                    format!("Latest posts in {} from {}", name, site_title),
+                )
+            }
+            FeedScope::Tag(id) => {
+                let name = self
+                    .repository
+                    .get_tag_name(id)
+                    .map_err(|e| FeedError::System(e.to_string()))?
+                    .ok_or_else(|| FeedError::NotFound("Tag not found".to_string()))?;
+                (
+                    format!("{} - {}", site_title, name),
+                    format!("Latest posts tagged {} from {}", name, site_title),
+                )
+            }
+        };
+
+        let posts = self
+            .repository
+            .get_published_posts(scope, Utc::now().naive_utc(), FEED_ITEM_LIMIT)
+            .map_err(|e| FeedError::System(e.to_string()))?;
+
+        let mut tags_by_blog: HashMap<i32, Vec<String>> = HashMap::new();
+        for (blog_id, name) in self
+            .repository
+            .get_tag_names(posts.iter().map(|(p, _)| p.id).collect())
+            .map_err(|e| FeedError::System(e.to_string()))?
+        {
+            tags_by_blog.entry(blog_id).or_default().push(name);
+        }
+
+        let mut author_ids = posts
+            .iter()
+            .filter_map(|(p, _)| p.author_id)
+            .collect::<Vec<_>>();
+        author_ids.sort_unstable();
+        author_ids.dedup();
+        let authors: HashMap<i32, String> = self
+            .repository
+            .get_post_authors(author_ids)
+            .map_err(|e| FeedError::System(e.to_string()))?
+            .into_iter()
+            .map(|a| (a.id, a.display_name))
+            .collect();
+
+        let entries: Vec<FeedEntry> = posts
+            .into_iter()
+            .map(|(post, category)| FeedEntry {
+                tags: tags_by_blog.remove(&post.id).unwrap_or_default(),
+                author: post.author_id.and_then(|id| authors.get(&id).cloned()),
+                post,
+                category,
+            })
+            .collect();
+        let updated_at = entries.iter().map(|e| e.post.modified_at()).max();
+
+        Ok(Feed {
+            title,
+            description,
+            author,
+            updated_at,
+            entries,
+        })
+    }
+}
@@ -0,0 +1,58 @@
+use crate::schema::blog;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+/// The part of a published blog row that ends up in a feed.
+#[derive(Queryable, Selectable, Debug, Clone)]
+#[diesel(table_name = blog)]
+pub struct FeedPost {
+    pub id: i32,
+    pub slug: String,
+    pub title: String,
+    pub excerpt: Option<String>,
+    pub content_html: Option<String>,
+    pub published_at: Option<NaiveDateTime>,
+    pub updated_at: NaiveDateTime,
+    pub author_id: Option<i32>,
+}
+
+impl FeedPost {
+    /// Latest of the publish and edit times.
+    pub fn modified_at(&self) -> NaiveDateTime {
+        self.published_at
+            .map_or(self.updated_at, |p| p.max(self.updated_at))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FeedAuthor {
+    pub name: String,
+    pub email: String,
+}
+
+/// Which posts a feed covers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FeedScope {
+    All,
+    Category(i32),
+    Tag(i32),
+}
+
+#[derive(Debug, Clone)]
+pub struct FeedEntry {
+    pub post: FeedPost,
+    pub category: String,
+    pub tags: Vec<String>,
+    /// Username of the post's author; feeds fall back to the site author
+    pub author: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Feed {
+    pub title: String,
+    pub description: String,
+    pub author: Option<FeedAuthor>,
+    /// Newest modification time across the entries, if there are any
+    pub updated_at: Option<NaiveDateTime>,
+    pub entries: Vec<FeedEntry>,
+}
@@ -0,0 +1,11 @@
+#[derive(Debug)]
+pub enum FeedError {
+    System(String),
+    NotFound(String),
+}
+
+impl From<String> for FeedError {
+    fn from(s: String) -> Self {
+        FeedError::System(s)
+    }
+}
@@ -0,0 +1,21 @@
+use crate::app::features::authors::domain::entity::Author;
+use crate::app::features::feed::domain::entity::{FeedAuthor, FeedPost, FeedScope};
+use chrono::NaiveDateTime;
+use diesel::QueryResult;
+
+pub trait FeedRepository {
+    /// Posts visible at `now`, newest first, paired with their category name.
+    fn get_published_posts(
+        &self,
+        scope: FeedScope,
+        now: NaiveDateTime,
+        limit: i64,
+    ) -> QueryResult<Vec<(FeedPost, String)>>;
+    /// `(blog_id, tag name)` pairs for the given posts.
+    fn get_tag_names(&self, blog_ids: Vec<i32>) -> QueryResult<Vec<(i32, String)>>;
+    fn get_category_name(&self, id: i32) -> QueryResult<Option<String>>;
+    fn get_tag_name(&self, id: i32) -> QueryResult<Option<String>>;
+    fn get_author(&self) -> QueryResult<Option<FeedAuthor>>;
+    /// Authors of the given posts, by user id.
+    fn get_post_authors(&self, user_ids: Vec<i32>) -> QueryResult<Vec<Author>>;
+}
@@ -0,0 +1 @@
+pub mod repository_impl;
@@ -0,0 +1,111 @@
+use crate::app::features::authors::domain::entity::Author;
+use crate::app::features::authors::infrastructure::repository_impl::load_authors;
+use crate::app::features::feed::domain::entity::{FeedAuthor, FeedPost, FeedScope};
+use crate::app::features::feed::domain::repository::FeedRepository;
+use crate::schema::{blog, blog_tags, categories, profiles, tags};
+use crate::utils::db::DbPool;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+#[derive(Clone)]
+pub struct FeedRepositoryImpl {
+    pub pool: DbPool,
+}
+
+impl FeedRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl FeedRepository for FeedRepositoryImpl {
+    fn get_published_posts(
+        &self,
+        scope: FeedScope,
+        now: NaiveDateTime,
+        limit: i64,
+    ) -> QueryResult<Vec<(FeedPost, String)>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+
+        let mut query = blog::table
+            .inner_join(categories::table)
+            .filter(blog::status.eq("PUBLISHED"))
+            .filter(blog::published_at.le(now))
+            .into_boxed();
+        match scope {
+            FeedScope::All => {}
+            FeedScope::Category(id) => query = query.filter(blog::category_id.eq(id)),
+            FeedScope::Tag(id) => {
+                query = query.filter(
+                    blog::id.eq_any(
+                        blog_tags::table
+                            .filter(blog_tags::tag_id.eq(id))
+                            .select(blog_tags::blog_id),
+                    ),
+                )
+            }
+        }
+
+        query
+            .select((FeedPost::as_select(), categories::name))
+            .order((blog::published_at.desc(), blog::id.desc()))
+            .limit(limit)
+            .load::<(FeedPost, String)>(&mut conn)
+    }
+    fn get_tag_names(&self, blog_ids: Vec<i32>) -> QueryResult<Vec<(i32, String)>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        blog_tags::table
+            .inner_join(tags::table)
+            .filter(blog_tags::blog_id.eq_any(blog_ids))
+            .select((blog_tags::blog_id, tags::name))
+            .order((blog_tags::blog_id.asc(), tags::name.asc()))
+            .load::<(i32, String)>(&mut conn)
+    }
+    fn get_category_name(&self, id: i32) -> QueryResult<Option<String>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        categories::table
+            .find(id)
+            .select(categories::name)
+            .first::<String>(&mut conn)
+            .optional()
+    }
+    fn get_tag_name(&self, id: i32) -> QueryResult<Option<String>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        tags::table
+            .find(id)
+            .select(tags::name)
+            .first::<String>(&mut conn)
+            .optional()
+    }
+    fn get_author(&self) -> QueryResult<Option<FeedAuthor>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        profiles::table
+            .order(profiles::id.asc())
+            .select((profiles::full_name, profiles::email))
+            .first::<(String, String)>(&mut conn)
+            .optional()
+            .map(|author| author.map(|(name, email)| FeedAuthor { name, email }))
+    }
+    fn get_post_authors(&self, user_ids: Vec<i32>) -> QueryResult<Vec<Author>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        load_authors(&mut conn, &user_ids)
+    }
+}
@@ -0,0 +1,297 @@
+use crate::app::features::feed::domain::entity::FeedScope;
+use crate::app::features::feed::domain::error::FeedError;
+use crate::app::features::feed::interface::dto::{
+    ATOM_CONTENT_TYPE, FeedLinks, JSON_FEED_CONTENT_TYPE, JsonFeedDto, RSS_CONTENT_TYPE, to_atom,
+    to_rss,
+};
+use crate::utils::di::Container;
+use crate::utils::error_response::{ErrorResponse, map_string_error};
+use actix_web::http::header::{HttpDate, IfModifiedSince, LastModified};
+use actix_web::{HttpMessage, HttpRequest, HttpResponse, Responder, get, web};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+enum FeedFormat {
+    Rss,
+    Atom,
+    Json,
+}
+
+async fn feed_response(
+    container: &Container,
+    req: &HttpRequest,
+    scope: FeedScope,
+    format: FeedFormat,
+) -> HttpResponse {
+    let feed = match container.get_feed_usecase.execute(scope).await {
+        Ok(feed) => feed,
+        Err(e) => {
+            return match e {
+                FeedError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+                FeedError::System(msg) => {
+                    HttpResponse::InternalServerError().json(map_string_error(msg))
+                }
+            };
+        }
+    };
+
+    // HTTP dates only carry whole seconds
+    let last_modified = feed.updated_at.map(|t| {
+        HttpDate::from(UNIX_EPOCH + Duration::from_secs(t.and_utc().timestamp().max(0) as u64))
+    });
+    if let (Some(modified), Some(IfModifiedSince(since))) =
+        (last_modified, req.get_header::<IfModifiedSince>())
+        && SystemTime::from(modified) <= SystemTime::from(since)
+    {
+        return HttpResponse::NotModified()
+            .insert_header(LastModified(modified))
+            .finish();
+    }
+
+    let connection = req.connection_info();
+    let links = FeedLinks {
+        site_url: container.config.site_url.clone(),
+        self_url: format!(
+            "{}://{}{}",
+            connection.scheme(),
+            connection.host(),
+            req.path()
+        ),
+    };
+
+    let (content_type, body) = match format {
+        FeedFormat::Rss => (RSS_CONTENT_TYPE, to_rss(&feed, &links)),
+        FeedFormat::Atom => (ATOM_CONTENT_TYPE, to_atom(&feed, &links)),
+        FeedFormat::Json => match serde_json::to_string(&JsonFeedDto::from_feed(&feed, &links)) {
+            Ok(json) => (JSON_FEED_CONTENT_TYPE, json),
+            Err(e) => {
+                return HttpResponse::InternalServerError().json(map_string_error(e.to_string()));
+            }
+        },
+    };
+
+    let mut response = HttpResponse::Ok();
+    response.content_type(content_type);
+    if let Some(modified) = last_modified {
+        response.insert_header(LastModified(modified));
+    }
+    response.body(body)
+}
+
+#[utoipa::path(
+    path = "/feed.xml",
+    tag = "Feeds",
+    responses(
+        (status = 200, description = "RSS 2.0 feed of the latest published posts", body = String, content_type = "application/rss+xml"),
+        (status = 304, description = "Not modified since If-Modified-Since"),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(())
+)]
+#[get("/feed.xml")]
+pub async fn rss_feed(container: web::Data<Container>, req: HttpRequest) -> impl Responder {
+    feed_response(&container, &req, FeedScope::All, FeedFormat::Rss).await
+}
+
+#[utoipa::path(
+    path = "/atom.xml",
+    tag = "Feeds",
+    responses(
+        (status = 200, description = "Atom feed of the latest published posts", body = String, content_type = "application/atom+xml"),
+        (status = 304, description = "Not modified since If-Modified-Since"),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(())
+)]
+#[get("/atom.xml")]
+pub async fn atom_feed(container: web::Data<Container>, req: HttpRequest) -> impl Responder {
+    feed_response(&container, &req, FeedScope::All, FeedFormat::Atom).await
+}
+
+#[utoipa::path(
+    path = "/feed.json",
+    tag = "Feeds",
+    responses(
+        (status = 200, description = "JSON Feed of the latest published posts", body = JsonFeedDto, content_type = "application/feed+json"),
+        (status = 304, description = "Not modified since If-Modified-Since"),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(())
+)]
+#[get("/feed.json")]
+pub async fn json_feed(container: web::Data<Container>, req: HttpRequest) -> impl Responder {
+    feed_response(&container, &req, FeedScope::All, FeedFormat::Json).await
+}
+
+#[utoipa::path(
+    path = "/categories/{id}/feed.xml",
+    tag = "Feeds",
+    params(
+        ("id", description = "Category ID")
+    ),
+    responses(
+        (status = 200, description = "RSS 2.0 feed of the category", body = String, content_type = "application/rss+xml"),
+        (status = 304, description = "Not modified since If-Modified-Since"),
+        (status = 404, description = "Category not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(())
+)]
+#[get("/categories/{id}/feed.xml")]
+pub async fn category_rss_feed(
+    container: web::Data<Container>,
+    req: HttpRequest,
+    id: web::Path<i32>,
+) -> impl Responder {
+    feed_response(
+        &container,
+        &req,
+        FeedScope::Category(id.into_inner()),
+        FeedFormat::Rss,
+    )
+    .await
+}
+
+#[utoipa::path(
+    path = "/categories/{id}/atom.xml",
+    tag = "Feeds",
+    params(
+        ("id", description = "Category ID")
+    ),
+    responses(
+        (status = 200, description = "Atom feed of the category", body = String, content_type = "application/atom+xml"),
+        (status = 304, description = "Not modified since If-Modified-Since"),
+        (status = 404, description = "Category not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(())
+)]
+#[get("/categories/{id}/atom.xml")]
+pub async fn category_atom_feed(
+    container: web::Data<Container>,
+    req: HttpRequest,
+    id: web::Path<i32>,
+) -> impl Responder {
+    feed_response(
+        &container,
+        &req,
+        FeedScope::Category(id.into_inner()),
+        FeedFormat::Atom,
+    )
+    .await
+}
+
+#[utoipa::path(
+    path = "/categories/{id}/feed.json",
+    tag = "Feeds",
+    params(
+        ("id", description = "Category ID")
+    ),
+    responses(
+        (status = 200, description = "JSON Feed of the category", body = JsonFeedDto, content_type = "application/feed+json"),
+        (status = 304, description = "Not modified since If-Modified-Since"),
+        (status = 404, description = "Category not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(())
+)]
+#[get("/categories/{id}/feed.json")]
+pub async fn category_json_feed(
+    container: web::Data<Container>,
+    req: HttpRequest,
+    id: web::Path<i32>,
+) -> impl Responder {
+    feed_response(
+        &container,
+        &req,
+        FeedScope::Category(id.into_inner()),
+        FeedFormat::Json,
+    )
+    .await
+}
+
+#[utoipa::path(
+    path = "/tags/{id}/feed.xml",
+    tag = "Feeds",
+    params(
+        ("id", description = "Tag ID")
+    ),
+    responses(
+        (status = 200, description = "RSS 2.0 feed of the tag", body = String, content_type = "application/rss+xml"),
+        (status = 304, description = "Not modified since If-Modified-Since"),
+        (status = 404, description = "Tag not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(())
+)]
+#[get("/tags/{id}/feed.xml")]
+pub async fn tag_rss_feed(
+    container: web::Data<Container>,
+    req: HttpRequest,
+    id: web::Path<i32>,
+) -> impl Responder {
+    feed_response(
+        &container,
+        &req,
+        FeedScope::Tag(id.into_inner()),
+        FeedFormat::Rss,
+    )
+    .await
+}
+
+#[utoipa::path(
+    path = "/tags/{id}/atom.xml",
+    tag = "Feeds",
+    params(
+        ("id", description = "Tag ID")
+    ),
+    responses(
+        (status = 200, description = "Atom feed of the tag", body = String, content_type = "application/atom+xml"),
+        (status = 304, description = "Not modified since If-Modified-Since"),
+        (status = 404, description = "Tag not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(())
+)]
+#[get("/tags/{id}/atom.xml")]
+pub async fn tag_atom_feed(
+    container: web::Data<Container>,
+    req: HttpRequest,
+    id: web::Path<i32>,
+) -> impl Responder {
+    feed_response(
+        &container,
+        &req,
+        FeedScope::Tag(id.into_inner()),
+        FeedFormat::Atom,
+    )
+    .await
+}
+
+#[utoipa::path(
+    path = "/tags/{id}/feed.json",
+    tag = "Feeds",
+    params(
+        ("id", description = "Tag ID")
+    ),
+    responses(
+        (status = 200, description = "JSON Feed of the tag", body = JsonFeedDto, content_type = "application/feed+json"),
+        (status = 304, description = "Not modified since If-Modified-Since"),
+        (status = 404, description = "Tag not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(())
+)]
+#[get("/tags/{id}/feed.json")]
+pub async fn tag_json_feed(
+    container: web::Data<Container>,
+    req: HttpRequest,
+    id: web::Path<i32>,
+) -> impl Responder {
+    feed_response(
+        &container,
+        &req,
+        FeedScope::Tag(id.into_inner()),
+        FeedFormat::Json,
+    )
+    .await
+}
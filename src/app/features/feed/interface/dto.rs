@@ -0,0 +1,284 @@
+use crate::app::features::feed::domain::entity::{Feed, FeedEntry};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+pub const RSS_CONTENT_TYPE: &str = "application/rss+xml; charset=utf-8";
+pub const ATOM_CONTENT_TYPE: &str = "application/atom+xml; charset=utf-8";
+pub const JSON_FEED_CONTENT_TYPE: &str = "application/feed+json; charset=utf-8";
+
+/// Absolute URLs a feed document points at.
+pub struct FeedLinks {
+    /// Public site root, without a trailing slash
+    pub site_url: String,
+    /// URL the feed itself was requested from
+    pub self_url: String,
+}
+
+impl FeedLinks {
+    pub fn post_url(&self, slug: &str) -> String {
+        format!("{}/blog/{}", self.site_url, slug)
+    }
+}
+
+fn utc(time: NaiveDateTime) -> DateTime<Utc> {
+    time.and_utc()
+}
+
+fn entry_published(entry: &FeedEntry) -> DateTime<Utc> {
+    utc(entry.post.published_at.unwrap_or(entry.post.updated_at))
+}
+
+fn entry_content_html(entry: &FeedEntry) -> String {
+    entry
+        .post
+        .content_html
+        .clone()
+        .or_else(|| entry.post.excerpt.clone())
+        .unwrap_or_default()
+}
+
+/// RSS 2.0 document for `feed`.
+pub fn to_rss(feed: &Feed, links: &FeedLinks) -> String {
+    use rss::extension::{atom, dublincore};
+
+    let author = feed
+        .author
+        .as_ref()
+        .map(|a| format!("{} ({})", a.email, a.name));
+
+    let items = feed
+        .entries
+        .iter()
+        .map(|entry| {
+            let url = links.post_url(&entry.post.slug);
+            let mut categories = vec![rss::Category {
+                name: entry.category.clone(),
+                domain: None,
+            }];
+            categories.extend(entry.tags.iter().map(|tag| rss::Category {
+                name: tag.clone(),
+                domain: None,
+            }));
+            rss::Item {
+                title: Some(entry.post.title.clone()),
+                link: Some(url.clone()),
+                description: entry.post.excerpt.clone(),
+                // <author> must be an email address, so named authors go in dc:creator
+                author: if entry.author.is_none() {
+                    author.clone()
+                } else {
+                    None
+                },
+                dublin_core_ext: entry.author.as_ref().map(|name| {
+                    dublincore::DublinCoreExtension {
+                        creators: vec![name.clone()],
+                        ..Default::default()
+                    }
+                }),
+                categories,
+                guid: Some(rss::Guid {
+                    value: url,
+                    permalink: true,
+                }),
+                pub_date: Some(entry_published(entry).to_rfc2822()),
+                ..Default::default()
+            }
+        })
+        .collect();
+
+    let mut channel = rss::Channel {
+        title: feed.title.clone(),
+        link: links.site_url.clone(),
+        description: feed.description.clone(),
+        managing_editor: author,
+        last_build_date: feed.updated_at.map(|t| utc(t).to_rfc2822()),
+        items,
+        atom_ext: Some(atom::AtomExtension {
+            links: vec![atom::Link {
+                href: links.self_url.clone(),
+                rel: "self".to_string(),
+                mime_type: Some("application/rss+xml".to_string()),
+                ..Default::default()
+            }],
+        }),
+        ..Default::default()
+    };
+    channel
+        .namespaces
+        .insert("atom".to_string(), atom::NAMESPACE.to_string());
+    channel
+        .namespaces
+        .insert("dc".to_string(), dublincore::NAMESPACE.to_string());
+    channel.to_string()
+}
+
+/// Atom 1.0 document for `feed`.
+pub fn to_atom(feed: &Feed, links: &FeedLinks) -> String {
+    use atom_syndication::{Category, Content, Entry, Link, Person, Text};
+
+    let authors: Vec<Person> = feed
+        .author
+        .iter()
+        .map(|a| Person {
+            name: a.name.clone(),
+            email: Some(a.email.clone()),
+            uri: Some(links.site_url.clone()),
+        })
+        .collect();
+
+    let entries = feed
+        .entries
+        .iter()
+        .map(|entry| {
+            let url = links.post_url(&entry.post.slug);
+            let mut categories = vec![Category {
+                term: entry.category.clone(),
+                ..Default::default()
+            }];
+            categories.extend(entry.tags.iter().map(|tag| Category {
+                term: tag.clone(),
+                ..Default::default()
+            }));
+            Entry {
+                title: Text::plain(entry.post.title.clone()),
+                id: url.clone(),
+                updated: utc(entry.post.modified_at()).fixed_offset(),
+                authors: match &entry.author {
+                    Some(name) => vec![Person {
+                        name: name.clone(),
+                        email: None,
+                        uri: None,
+                    }],
+                    None => authors.clone(),
+                },
+                categories,
+                links: vec![Link {
+                    href: url,
+                    rel: "alternate".to_string(),
+                    mime_type: Some("text/html".to_string()),
+                    ..Default::default()
+                }],
+                published: Some(entry_published(entry).fixed_offset()),
+                summary: entry.post.excerpt.clone().map(Text::plain),
+                content: Some(Content {
+                    value: Some(entry_content_html(entry)),
+                    content_type: Some("html".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }
+        })
+        .collect();
+
+    let atom_feed = atom_syndication::Feed {
+        title: Text::plain(feed.title.clone()),
+        id: links.self_url.clone(),
+        updated: utc(feed.updated_at.unwrap_or_else(|| Utc::now().naive_utc())).fixed_offset(),
+        authors,
+        links: vec![
+            Link {
+                href: links.self_url.clone(),
+                rel: "self".to_string(),
+                mime_type: Some("application/atom+xml".to_string()),
+                ..Default::default()
+            },
+            Link {
+                href: links.site_url.clone(),
+                rel: "alternate".to_string(),
+                mime_type: Some("text/html".to_string()),
+                ..Default::default()
+            },
+        ],
+        subtitle: Some(Text::plain(feed.description.clone())),
+        entries,
+        ..Default::default()
+    };
+    atom_feed.to_string()
+}
+
+/// JSON Feed 1.1 document, see <https://www.jsonfeed.org/version/1.1/>.
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct JsonFeedDto {
+    pub version: String,
+    pub title: String,
+    pub home_page_url: String,
+    pub feed_url: String,
+    pub description: String,
+    pub authors: Vec<JsonFeedAuthorDto>,
+    pub items: Vec<JsonFeedItemDto>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Clone)]
+pub struct JsonFeedAuthorDto {
+    pub name: String,
+    pub url: String,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct JsonFeedItemDto {
+    pub id: String,
+    pub url: String,
+    pub title: String,
+    pub summary: Option<String>,
+    pub content_html: String,
+    /// RFC 3339
+    pub date_published: String,
+    /// RFC 3339
+    pub date_modified: String,
+    /// Category followed by the post's tags
+    pub tags: Vec<String>,
+    /// The post's author; empty when the feed-level author applies
+    pub authors: Vec<JsonFeedItemAuthorDto>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct JsonFeedItemAuthorDto {
+    pub name: String,
+}
+
+impl JsonFeedDto {
+    pub fn from_feed(feed: &Feed, links: &FeedLinks) -> Self {
+        let authors: Vec<JsonFeedAuthorDto> = feed
+            .author
+            .iter()
+            .map(|a| JsonFeedAuthorDto {
+                name: a.name.clone(),
+                url: links.site_url.clone(),
+            })
+            .collect();
+
+        Self {
+            version: "https://jsonfeed.org/version/1.1".to_string(),
+            title: feed.title.clone(),
+            home_page_url: links.site_url.clone(),
+            feed_url: links.self_url.clone(),
+            description: feed.description.clone(),
+            authors,
+            items: feed
+                .entries
+                .iter()
+                .map(|entry| {
+                    let url = links.post_url(&entry.post.slug);
+                    let mut tags = vec![entry.category.clone()];
+                    tags.extend(entry.tags.iter().cloned());
+                    JsonFeedItemDto {
+                        id: url.clone(),
+                        url,
+                        title: entry.post.title.clone(),
+                        summary: entry.post.excerpt.clone(),
+                        content_html: entry_content_html(entry),
+                        date_published: entry_published(entry).to_rfc3339(),
+                        date_modified: utc(entry.post.modified_at()).to_rfc3339(),
+                        tags,
+                        authors: entry
+                            .author
+                            .iter()
+                            .map(|name| JsonFeedItemAuthorDto { name: name.clone() })
+                            .collect(),
+                    }
+                })
+                .collect(),
+        }
+    }
+}
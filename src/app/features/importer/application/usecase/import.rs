@@ -0,0 +1,365 @@
+use crate::app::features::blog::domain::entity::{BlogRenderedContent, MAX_CATEGORY_DEPTH};
+use crate::app::features::importer::domain::entity::{
+    DEFAULT_CATEGORY, ImportFile, ImportedBlog, ImportedPost, OUTCOME_CREATED, OUTCOME_FAILED,
+    OUTCOME_SKIPPED,
+};
+use crate::app::features::importer::domain::error::ImportError;
+use crate::app::features::importer::domain::parser::ImportParser;
+use crate::app::features::importer::domain::repository::ImportRepository;
+use crate::app::features::importer::interface::dto::{ImportItemDto, ImportReportDto};
+use crate::utils::slug::slugify;
+use chrono::Utc;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use validator::{ValidationError, ValidationErrors};
+
+/// Generated excerpts are cut to this many characters.
+const EXCERPT_LENGTH: usize = 200;
+
+/// Inline elements whose tags don't separate words.
+const INLINE_TAGS: [&str; 11] = [
+    "a", "abbr", "b", "code", "del", "em", "i", "mark", "span", "strong", "sup",
+];
+
+/// Plain text of rendered HTML, for excerpts.
+fn plain_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut tag: Option<String> = None;
+    for c in html.chars() {
+        match (&mut tag, c) {
+            (None, '<') => tag = Some(String::new()),
+            (Some(name), '>') => {
+                let name = name
+                    .trim_start_matches('/')
+                    .split(|c: char| c.is_whitespace() || c == '/')
+                    .next()
+                    .unwrap_or_default()
+                    .to_lowercase();
+                if !INLINE_TAGS.contains(&name.as_str()) {
+                    text.push(' ');
+                }
+                tag = None;
+            }
+            (Some(name), _) => name.push(c),
+            (None, _) => text.push(c),
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn excerpt_from(rendered: &BlogRenderedContent) -> String {
+    let text = plain_text(rendered.content_html.as_deref().unwrap_or_default());
+    if text.chars().count() <= EXCERPT_LENGTH {
+        return text;
+    }
+    let cut: String = text.chars().take(EXCERPT_LENGTH).collect();
+    format!("{}…", cut.trim_end())
+}
+
+/// Categories and tags seen during one run, keyed by lowercased name. In a
+/// dry run the ones that would be created have no id. Categories also keep
+/// their depth, a top-level one being at depth 1.
+struct Names<'a> {
+    repository: &'a (dyn ImportRepository + Send + Sync),
+    dry_run: bool,
+    category_parents: HashMap<String, String>,
+    categories: HashMap<String, (Option<i32>, usize)>,
+    tags: HashMap<String, Option<i32>>,
+    categories_created: Vec<String>,
+    categories_detached: Vec<String>,
+    tags_created: Vec<String>,
+}
+
+impl Names<'_> {
+    fn category_id(&mut self, name: &str) -> Result<Option<i32>, ImportError> {
+        Ok(self.category(name, &mut Vec::new())?.0)
+    }
+
+    /// Id and depth of `name`, creating it and its missing ancestors.
+    /// `below` holds the lowercased names of the new categories waiting to be
+    /// created under it, so the chain never nests past `MAX_CATEGORY_DEPTH`
+    /// and a parent loop in the export is cut. A category whose parent can't
+    /// be kept becomes top level and is reported in `categories_detached`.
+    fn category(
+        &mut self,
+        name: &str,
+        below: &mut Vec<String>,
+    ) -> Result<(Option<i32>, usize), ImportError> {
+        let key = name.to_lowercase();
+        if let Some(category) = self.categories.get(&key) {
+            return Ok(*category);
+        }
+        let existing = self
+            .repository
+            .find_category_id(name)
+            .map_err(|e| ImportError::System(e.to_string()))?;
+        let category = match existing {
+            Some(id) => (
+                Some(id),
+                self.repository
+                    .category_depth(id)
+                    .map_err(|e| ImportError::System(e.to_string()))?,
+            ),
+            None => {
+                let parent = match self.category_parents.get(name).cloned() {
+                    Some(parent)
+                        if below.len() + 1 < MAX_CATEGORY_DEPTH
+                            && parent.to_lowercase() != key
+                            && !below.contains(&parent.to_lowercase()) =>
+                    {
+                        below.push(key.clone());
+                        let resolved = self.category(&parent, below)?;
+                        below.pop();
+                        // Everything waiting below has to fit under the parent too
+                        Some(resolved)
+                            .filter(|(_, depth)| depth + 1 + below.len() <= MAX_CATEGORY_DEPTH)
+                    }
+                    Some(_) => None,
+                    // Top level in the export too
+                    None => Some((None, 0)),
+                };
+                let (parent_id, depth) = match parent {
+                    Some((parent_id, parent_depth)) => (parent_id, parent_depth + 1),
+                    None => {
+                        self.categories_detached.push(name.to_string());
+                        (None, 1)
+                    }
+                };
+                self.categories_created.push(name.to_string());
+                let id = if self.dry_run {
+                    None
+                } else {
+                    Some(
+                        self.repository
+                            .create_category(name, parent_id)
+                            .map_err(|e| ImportError::System(e.to_string()))?,
+                    )
+                };
+                (id, depth)
+            }
+        };
+        self.categories.insert(key, category);
+        Ok(category)
+    }
+
+    fn tag_id(&mut self, name: &str) -> Result<Option<i32>, ImportError> {
+        let key = name.to_lowercase();
+        if let Some(id) = self.tags.get(&key) {
+            return Ok(*id);
+        }
+        let existing = self
+            .repository
+            .find_tag_id(name)
+            .map_err(|e| ImportError::System(e.to_string()))?;
+        let id = match existing {
+            Some(id) => Some(id),
+            None => {
+                self.tags_created.push(name.to_string());
+                if self.dry_run {
+                    None
+                } else {
+                    Some(
+                        self.repository
+                            .create_tag(name)
+                            .map_err(|e| ImportError::System(e.to_string()))?,
+                    )
+                }
+            }
+        };
+        self.tags.insert(key, id);
+        Ok(id)
+    }
+}
+
+fn item(post: &ImportedPost, slug: Option<String>, outcome: &str) -> ImportItemDto {
+    ImportItemDto {
+        source: post.source.clone(),
+        title: Some(post.title.clone()),
+        slug,
+        outcome: outcome.to_string(),
+        blog_id: None,
+        reason: None,
+    }
+}
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn ImportRepository + Send + Sync>,
+    parser: Arc<dyn ImportParser + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(
+        repository: Arc<dyn ImportRepository + Send + Sync>,
+        parser: Arc<dyn ImportParser + Send + Sync>,
+    ) -> Self {
+        Self { repository, parser }
+    }
+
+    /// Creates the posts found in `files` with their categories and tags,
+    /// keeping the original slugs and dates. Posts whose slug is already
+    /// taken are skipped, so running the same export again changes nothing.
+    /// `username` becomes the author of every imported post. Parsing,
+    /// rendering and one write per post add up for a large export, so callers
+    /// on an async runtime should run this on a blocking thread.
+    pub fn execute(
+        &self,
+        files: Vec<ImportFile>,
+        dry_run: bool,
+        username: Option<String>,
+    ) -> Result<ImportReportDto, ImportError> {
+        if files.is_empty() {
+            let mut errors = ValidationErrors::new();
+            errors.add(
+                "files",
+                ValidationError::new("At least one file is required"),
+            );
+            return Err(ImportError::Validation(errors));
+        }
+
+        let parsed = self.parser.parse(files);
+        let author_id = match username {
+            Some(username) => self
+                .repository
+                .find_author_id(&username)
+                .map_err(|e| ImportError::System(e.to_string()))?,
+            None => None,
+        };
+
+        let mut names = Names {
+            repository: self.repository.as_ref(),
+            dry_run,
+            category_parents: parsed.category_parents,
+            categories: HashMap::new(),
+            tags: HashMap::new(),
+            categories_created: Vec::new(),
+            categories_detached: Vec::new(),
+            tags_created: Vec::new(),
+        };
+        let mut slugs = HashSet::new();
+        let mut items: Vec<ImportItemDto> = parsed
+            .failures
+            .into_iter()
+            .map(|failure| ImportItemDto {
+                source: failure.source,
+                title: None,
+                slug: None,
+                outcome: OUTCOME_FAILED.to_string(),
+                blog_id: None,
+                reason: Some(failure.reason),
+            })
+            .collect();
+
+        for post in parsed.posts {
+            if post.content.trim().is_empty() {
+                items.push(ImportItemDto {
+                    reason: Some("Post has no content".to_string()),
+                    ..item(&post, None, OUTCOME_FAILED)
+                });
+                continue;
+            }
+
+            let mut slug = post.slug.as_deref().map(slugify).unwrap_or_default();
+            if slug.is_empty() {
+                slug = slugify(&post.title);
+            }
+            if slug.is_empty() {
+                items.push(ImportItemDto {
+                    reason: Some("No usable slug in the slug or title".to_string()),
+                    ..item(&post, None, OUTCOME_FAILED)
+                });
+                continue;
+            }
+            let taken = slugs.contains(&slug)
+                || self
+                    .repository
+                    .blog_slug_exists(&slug)
+                    .map_err(|e| ImportError::System(e.to_string()))?;
+            if taken {
+                items.push(ImportItemDto {
+                    reason: Some("A post with this slug already exists".to_string()),
+                    ..item(&post, Some(slug), OUTCOME_SKIPPED)
+                });
+                continue;
+            }
+            slugs.insert(slug.clone());
+
+            let category_id =
+                names.category_id(post.category.as_deref().unwrap_or(DEFAULT_CATEGORY))?;
+            let mut tag_ids = Vec::new();
+            for tag in &post.tags {
+                tag_ids.push(names.tag_id(tag)?);
+            }
+
+            if dry_run {
+                items.push(item(&post, Some(slug), OUTCOME_CREATED));
+                continue;
+            }
+            let (Some(category_id), Some(tag_ids)) =
+                (category_id, tag_ids.into_iter().collect::<Option<Vec<_>>>())
+            else {
+                unreachable!("only a dry run leaves names without an id");
+            };
+
+            let now = Utc::now().naive_utc();
+            let created_at = post.date.unwrap_or(now);
+            // A scheduled post without a date can't go live on its own
+            let status = match (post.status.as_str(), post.date) {
+                ("SCHEDULED", None) => "DRAFT",
+                (status, _) => status,
+            };
+            let published_at = match status {
+                "PUBLISHED" | "SCHEDULED" => Some(created_at),
+                _ => None,
+            };
+            let rendered = BlogRenderedContent::from_markdown(&post.content);
+            let excerpt = post
+                .excerpt
+                .clone()
+                .unwrap_or_else(|| excerpt_from(&rendered));
+            let blog_id = self
+                .repository
+                .create_blog(
+                    ImportedBlog {
+                        title: post.title.clone(),
+                        content: post.content.clone(),
+                        category_id,
+                        slug: slug.clone(),
+                        excerpt,
+                        thumbnail: post.thumbnail.clone(),
+                        status: status.to_string(),
+                        published_at,
+                        author_id,
+                        created_at,
+                        updated_at: post.modified.unwrap_or(created_at).max(created_at),
+                        rendered,
+                    },
+                    tag_ids,
+                )
+                .map_err(|e| ImportError::System(e.to_string()))?;
+            items.push(ImportItemDto {
+                blog_id: Some(blog_id),
+                ..item(&post, Some(slug), OUTCOME_CREATED)
+            });
+        }
+
+        let count = |outcome: &str| items.iter().filter(|i| i.outcome == outcome).count();
+        Ok(ImportReportDto {
+            dry_run,
+            created: count(OUTCOME_CREATED),
+            skipped: count(OUTCOME_SKIPPED),
+            failed: count(OUTCOME_FAILED),
+            categories_created: names.categories_created,
+            categories_detached: names.categories_detached,
+            tags_created: names.tags_created,
+            items,
+        })
+    }
+}
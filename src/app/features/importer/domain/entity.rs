@@ -0,0 +1,74 @@
+use crate::app::features::blog::domain::entity::BlogRenderedContent;
+use crate::schema::blog;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use std::collections::HashMap;
+
+pub const OUTCOME_CREATED: &str = "CREATED";
+pub const OUTCOME_SKIPPED: &str = "SKIPPED";
+pub const OUTCOME_FAILED: &str = "FAILED";
+
+/// Category used for posts that don't name one, as in WordPress.
+pub const DEFAULT_CATEGORY: &str = "Uncategorized";
+
+/// One uploaded or on-disk file to import.
+pub struct ImportFile {
+    pub name: String,
+    pub content: String,
+}
+
+/// A post read from an export, before it is matched against the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedPost {
+    /// Where the post came from, e.g. `export.xml#42` or `2015-03-01-hello.md`
+    pub source: String,
+    pub title: String,
+    /// The original slug; derived from the title when the export has none
+    pub slug: Option<String>,
+    /// Markdown
+    pub content: String,
+    pub excerpt: Option<String>,
+    /// DRAFT, PUBLISHED or SCHEDULED
+    pub status: String,
+    /// Original publish date (UTC)
+    pub date: Option<NaiveDateTime>,
+    /// Original last-modified date (UTC)
+    pub modified: Option<NaiveDateTime>,
+    pub thumbnail: Option<String>,
+    pub category: Option<String>,
+    pub tags: Vec<String>,
+}
+
+/// A file or entry that could not be read.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportFailure {
+    pub source: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Default)]
+pub struct ParsedImport {
+    pub posts: Vec<ImportedPost>,
+    pub failures: Vec<ImportFailure>,
+    /// Category name to parent category name, from WXR `<wp:category>`
+    pub category_parents: HashMap<String, String>,
+}
+
+/// A `blog` row with its original timestamps.
+#[derive(Insertable)]
+#[diesel(table_name = blog)]
+pub struct ImportedBlog {
+    pub title: String,
+    pub content: String,
+    pub category_id: i32,
+    pub slug: String,
+    pub excerpt: String,
+    pub thumbnail: Option<String>,
+    pub status: String,
+    pub published_at: Option<NaiveDateTime>,
+    pub author_id: Option<i32>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    #[diesel(embed)]
+    pub rendered: BlogRenderedContent,
+}
@@ -0,0 +1,13 @@
+use validator::ValidationErrors;
+
+#[derive(Debug)]
+pub enum ImportError {
+    Validation(ValidationErrors),
+    System(String),
+}
+
+impl From<String> for ImportError {
+    fn from(s: String) -> Self {
+        ImportError::System(s)
+    }
+}
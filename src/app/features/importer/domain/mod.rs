@@ -0,0 +1,4 @@
+pub mod entity;
+pub mod error;
+pub mod parser;
+pub mod repository;
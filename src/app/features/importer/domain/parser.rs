@@ -0,0 +1,8 @@
+use crate::app::features::importer::domain::entity::{ImportFile, ParsedImport};
+
+/// Reads export files into posts.
+pub trait ImportParser {
+    /// Files that can't be read, or entries inside them that can't, end up
+    /// in `failures`; the rest of the batch is still returned.
+    fn parse(&self, files: Vec<ImportFile>) -> ParsedImport;
+}
@@ -0,0 +1,19 @@
+use crate::app::features::importer::domain::entity::ImportedBlog;
+use diesel::QueryResult;
+
+pub trait ImportRepository {
+    /// Case-insensitive lookup by name.
+    fn find_category_id(&self, name: &str) -> QueryResult<Option<i32>>;
+    fn create_category(&self, name: &str, parent_id: Option<i32>) -> QueryResult<i32>;
+    /// Levels from the top down to the category, counting itself.
+    fn category_depth(&self, id: i32) -> QueryResult<usize>;
+    /// Case-insensitive lookup by name.
+    fn find_tag_id(&self, name: &str) -> QueryResult<Option<i32>>;
+    fn create_tag(&self, name: &str) -> QueryResult<i32>;
+    fn blog_slug_exists(&self, slug: &str) -> QueryResult<bool>;
+    fn find_author_id(&self, username: &str) -> QueryResult<Option<i32>>;
+    /// Inserts the post with its tags and first revision in one transaction.
+    /// Published posts are also marked as announced so the newsletter
+    /// doesn't send them as new.
+    fn create_blog(&self, blog: ImportedBlog, tag_ids: Vec<i32>) -> QueryResult<i32>;
+}
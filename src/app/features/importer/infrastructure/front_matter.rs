@@ -0,0 +1,125 @@
+use crate::app::features::importer::domain::entity::{ImportFile, ImportedPost};
+use crate::app::features::importer::infrastructure::parser_impl::parse_date;
+use chrono::{NaiveDate, Utc};
+use serde_yaml::{Mapping, Value};
+use std::path::Path;
+
+fn get<'a>(front: &'a Mapping, keys: &[&str]) -> Option<&'a Value> {
+    keys.iter().find_map(|key| front.get(*key))
+}
+
+fn text(value: &Value) -> Option<String> {
+    let text = match value {
+        Value::String(s) => s.trim().to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::Bool(b) => b.to_string(),
+        _ => return None,
+    };
+    (!text.is_empty()).then_some(text)
+}
+
+/// A YAML list, or a space-separated string as Jekyll allows for
+/// `categories` and `tags`.
+fn list(value: &Value) -> Vec<String> {
+    match value {
+        Value::Sequence(items) => items.iter().filter_map(text).collect(),
+        Value::String(s) => s.split_whitespace().map(str::to_string).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Splits `---` delimited front-matter from the body.
+fn split(content: &str) -> Option<(&str, &str)> {
+    let rest = content.strip_prefix("---")?.trim_start_matches([' ', '\t']);
+    let rest = rest.strip_prefix('\n')?;
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        let marker = line.trim_end();
+        if marker == "---" || marker == "..." {
+            return Some((&rest[..offset], &rest[offset + line.len()..]));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Reads a Markdown post with YAML front-matter. Jekyll file names
+/// (`YYYY-MM-DD-slug.md`) supply the date and slug when the front-matter
+/// doesn't.
+pub fn parse(file: &ImportFile) -> Result<ImportedPost, String> {
+    let content = file
+        .content
+        .trim_start_matches('\u{feff}')
+        .replace("\r\n", "\n");
+    let (yaml, body) = split(&content).ok_or("Missing YAML front-matter")?;
+    let front: Mapping = match serde_yaml::from_str::<Option<Mapping>>(yaml) {
+        Ok(front) => front.unwrap_or_default(),
+        Err(e) => return Err(format!("Invalid front-matter: {}", e)),
+    };
+
+    let stem = Path::new(&file.name)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or_default();
+    let (file_date, file_slug) = match stem
+        .get(..10)
+        .map(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d"))
+    {
+        Some(Ok(date)) if stem.as_bytes().get(10) == Some(&b'-') => {
+            (date.and_hms_opt(0, 0, 0), Some(stem[11..].to_string()))
+        }
+        _ => (None, Some(stem.to_string())),
+    };
+
+    let title = get(&front, &["title"])
+        .and_then(text)
+        .ok_or("Front-matter has no title")?;
+    let date = get(&front, &["date"])
+        .and_then(text)
+        .and_then(|d| parse_date(&d))
+        .or(file_date);
+    let modified = get(&front, &["last_modified_at", "updated", "modified"])
+        .and_then(text)
+        .and_then(|d| parse_date(&d));
+
+    let draft = get(&front, &["draft"]) == Some(&Value::Bool(true))
+        || get(&front, &["published"]) == Some(&Value::Bool(false));
+    let status = match get(&front, &["status"])
+        .and_then(text)
+        .map(|s| s.to_lowercase())
+        .as_deref()
+    {
+        _ if draft => "DRAFT",
+        Some("draft") => "DRAFT",
+        Some("scheduled") => "SCHEDULED",
+        // Like Jekyll, a post dated in the future waits until then
+        _ if date.is_some_and(|d| d > Utc::now().naive_utc()) => "SCHEDULED",
+        _ => "PUBLISHED",
+    };
+
+    let category = get(&front, &["category"])
+        .and_then(text)
+        .or_else(|| get(&front, &["categories"]).map(list)?.into_iter().next());
+    let mut tags: Vec<String> = Vec::new();
+    for tag in get(&front, &["tags"]).map(list).unwrap_or_default() {
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+
+    Ok(ImportedPost {
+        source: file.name.clone(),
+        title,
+        slug: get(&front, &["slug"])
+            .and_then(text)
+            .or(file_slug.filter(|s| !s.is_empty())),
+        content: body.trim().to_string(),
+        excerpt: get(&front, &["excerpt", "description", "summary"]).and_then(text),
+        status: status.to_string(),
+        date,
+        modified,
+        thumbnail: get(&front, &["image", "thumbnail", "cover"]).and_then(text),
+        category,
+        tags,
+    })
+}
@@ -0,0 +1,4 @@
+pub mod front_matter;
+pub mod parser_impl;
+pub mod repository_impl;
+pub mod wxr;
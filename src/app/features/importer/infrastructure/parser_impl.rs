@@ -0,0 +1,66 @@
+use crate::app::features::importer::domain::entity::{ImportFailure, ImportFile, ParsedImport};
+use crate::app::features::importer::domain::parser::ImportParser;
+use crate::app::features::importer::infrastructure::{front_matter, wxr};
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+
+/// Picks the reader by file extension: `.xml` is a WordPress (WXR) export,
+/// `.md` / `.markdown` a post with YAML front-matter.
+#[derive(Clone, Default)]
+pub struct FileImportParser;
+
+impl FileImportParser {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ImportParser for FileImportParser {
+    fn parse(&self, files: Vec<ImportFile>) -> ParsedImport {
+        let mut parsed = ParsedImport::default();
+        for file in files {
+            let name = file.name.to_lowercase();
+            if name.ends_with(".xml") {
+                wxr::parse(&file, &mut parsed);
+            } else if name.ends_with(".md") || name.ends_with(".markdown") {
+                match front_matter::parse(&file) {
+                    Ok(post) => parsed.posts.push(post),
+                    Err(reason) => parsed.failures.push(ImportFailure {
+                        source: file.name,
+                        reason,
+                    }),
+                }
+            } else {
+                parsed.failures.push(ImportFailure {
+                    source: file.name,
+                    reason: "Unsupported file type, expected .xml or .md".to_string(),
+                });
+            }
+        }
+        parsed
+    }
+}
+
+/// Dates as found in WordPress and Jekyll exports, normalised to UTC. Values
+/// without an offset are taken as UTC; a bare date is midnight.
+pub fn parse_date(raw: &str) -> Option<NaiveDateTime> {
+    let raw = raw.trim();
+    if let Ok(at) = DateTime::parse_from_rfc3339(raw) {
+        return Some(at.naive_utc());
+    }
+    if let Ok(at) = DateTime::parse_from_rfc2822(raw) {
+        return Some(at.naive_utc());
+    }
+    for format in ["%Y-%m-%d %H:%M:%S %z", "%Y-%m-%d %H:%M %z"] {
+        if let Ok(at) = DateTime::parse_from_str(raw, format) {
+            return Some(at.naive_utc());
+        }
+    }
+    for format in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M"] {
+        if let Ok(at) = NaiveDateTime::parse_from_str(raw, format) {
+            return Some(at);
+        }
+    }
+    NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+}
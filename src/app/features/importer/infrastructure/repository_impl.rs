@@ -0,0 +1,140 @@
+use crate::app::features::authors::infrastructure::repository_impl::find_author_by_username;
+use crate::app::features::blog::domain::entity::NewBlogRevision;
+use crate::app::features::blog::infrastructure::repository_impl::load_category_path;
+use crate::app::features::importer::domain::entity::ImportedBlog;
+use crate::app::features::importer::domain::repository::ImportRepository;
+use crate::schema::{blog, blog_revisions, blog_tags, categories, newsletter_issues, tags};
+use crate::utils::db::DbPool;
+use diesel::prelude::*;
+use diesel::sql_types::Text;
+
+diesel::define_sql_function! {
+    fn lower(x: Text) -> Text;
+}
+
+#[derive(Clone)]
+pub struct ImportRepositoryImpl {
+    pub pool: DbPool,
+}
+
+impl ImportRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl ImportRepository for ImportRepositoryImpl {
+    fn find_category_id(&self, name: &str) -> QueryResult<Option<i32>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        categories::table
+            .filter(lower(categories::name).eq(name.to_lowercase()))
+            .order(categories::id.asc())
+            .select(categories::id)
+            .first(&mut conn)
+            .optional()
+    }
+    fn create_category(&self, name: &str, parent_id: Option<i32>) -> QueryResult<i32> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::insert_into(categories::table)
+            .values((
+                categories::name.eq(name),
+                categories::parent_id.eq(parent_id),
+            ))
+            .returning(categories::id)
+            .get_result(&mut conn)
+    }
+    fn category_depth(&self, id: i32) -> QueryResult<usize> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        load_category_path(&mut conn, id).map(|path| path.len())
+    }
+    fn find_tag_id(&self, name: &str) -> QueryResult<Option<i32>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        tags::table
+            .filter(lower(tags::name).eq(name.to_lowercase()))
+            .order(tags::id.asc())
+            .select(tags::id)
+            .first(&mut conn)
+            .optional()
+    }
+    fn create_tag(&self, name: &str) -> QueryResult<i32> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::insert_into(tags::table)
+            .values(tags::name.eq(name))
+            .returning(tags::id)
+            .get_result(&mut conn)
+    }
+    fn blog_slug_exists(&self, slug: &str) -> QueryResult<bool> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::select(diesel::dsl::exists(blog::table.filter(blog::slug.eq(slug))))
+            .get_result(&mut conn)
+    }
+    fn find_author_id(&self, username: &str) -> QueryResult<Option<i32>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        Ok(find_author_by_username(&mut conn, username)?.map(|author| author.id))
+    }
+    fn create_blog(&self, post: ImportedBlog, tag_ids: Vec<i32>) -> QueryResult<i32> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        conn.transaction(|conn| {
+            let revision = (
+                post.title.clone(),
+                post.excerpt.clone(),
+                post.content.clone(),
+            );
+            let published = post.status == "PUBLISHED";
+            let blog_id: i32 = diesel::insert_into(blog::table)
+                .values(post)
+                .returning(blog::id)
+                .get_result(conn)?;
+
+            let links: Vec<_> = tag_ids
+                .into_iter()
+                .map(|tag_id| (blog_tags::blog_id.eq(blog_id), blog_tags::tag_id.eq(tag_id)))
+                .collect();
+            diesel::insert_or_ignore_into(blog_tags::table)
+                .values(links)
+                .execute(conn)?;
+
+            let (title, excerpt, content) = revision;
+            diesel::insert_into(blog_revisions::table)
+                .values(NewBlogRevision {
+                    blog_id,
+                    revision_number: 1,
+                    title,
+                    excerpt: Some(excerpt),
+                    content,
+                })
+                .execute(conn)?;
+
+            if published {
+                diesel::insert_or_ignore_into(newsletter_issues::table)
+                    .values(newsletter_issues::blog_id.eq(blog_id))
+                    .execute(conn)?;
+            }
+            Ok(blog_id)
+        })
+    }
+}
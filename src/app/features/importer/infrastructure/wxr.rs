@@ -0,0 +1,248 @@
+use crate::app::features::importer::domain::entity::{
+    ImportFailure, ImportFile, ImportedPost, ParsedImport,
+};
+use crate::app::features::importer::infrastructure::parser_impl::parse_date;
+use roxmltree::{Document, Node};
+use std::collections::HashMap;
+
+const CONTENT_NS: &str = "http://purl.org/rss/1.0/modules/content/";
+/// Followed by the export version (`1.0/` to `1.2/`); `excerpt/` is its own namespace.
+const WP_NS_PREFIX: &str = "http://wordpress.org/export/";
+
+#[derive(Clone, Copy)]
+enum Ns {
+    Rss,
+    Content,
+    Excerpt,
+    Wp,
+}
+
+impl Ns {
+    fn matches(self, uri: Option<&str>) -> bool {
+        let wp = uri.filter(|u| u.starts_with(WP_NS_PREFIX));
+        match self {
+            Ns::Rss => uri.is_none(),
+            Ns::Content => uri == Some(CONTENT_NS),
+            Ns::Excerpt => wp.is_some_and(|u| u.ends_with("/excerpt/")),
+            Ns::Wp => wp.is_some_and(|u| !u.ends_with("/excerpt/")),
+        }
+    }
+}
+
+fn children<'a, 'input>(
+    node: Node<'a, 'input>,
+    ns: Ns,
+    name: &'static str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children().filter(move |child| {
+        child.is_element()
+            && child.tag_name().name() == name
+            && ns.matches(child.tag_name().namespace())
+    })
+}
+
+/// Text of the first matching child, CDATA included.
+fn field(node: Node, ns: Ns, name: &'static str) -> Option<String> {
+    children(node, ns, name)
+        .next()
+        .map(|child| child.children().filter_map(|c| c.text()).collect())
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+fn map_status(status: &str) -> Option<&'static str> {
+    match status {
+        "publish" => Some("PUBLISHED"),
+        "future" => Some("SCHEDULED"),
+        "draft" | "pending" | "private" => Some("DRAFT"),
+        // trash, auto-draft, inherit
+        _ => None,
+    }
+}
+
+/// WordPress stores non-ASCII slugs percent-encoded.
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = (bytes[i] == b'%')
+            .then(|| value.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match hex {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Wraps blank-line separated text in paragraphs, like WordPress does when
+/// rendering posts saved by the classic editor.
+fn autop(html: &str) -> String {
+    const BLOCK_TAGS: [&str; 10] = [
+        "<h",
+        "<ul",
+        "<ol",
+        "<pre",
+        "<blockquote",
+        "<table",
+        "<div",
+        "<figure",
+        "<hr",
+        "<!--",
+    ];
+    html.replace("\r\n", "\n")
+        .split("\n\n")
+        .map(str::trim)
+        .filter(|chunk| !chunk.is_empty())
+        .map(|chunk| {
+            if BLOCK_TAGS.iter().any(|tag| chunk.starts_with(tag)) {
+                chunk.to_string()
+            } else {
+                format!("<p>{}</p>", chunk.replace('\n', "<br>\n"))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn to_markdown(html: &str) -> String {
+    let html = if html.contains("<p") {
+        html.to_string()
+    } else {
+        autop(html)
+    };
+    html2md::parse_html(&html).trim().to_string()
+}
+
+/// Reads the posts of a WordPress export (WXR). Pages, attachments and
+/// trashed posts are left out; the featured image becomes the thumbnail.
+pub fn parse(file: &ImportFile, parsed: &mut ParsedImport) {
+    let document = match Document::parse(&file.content) {
+        Ok(document) => document,
+        Err(e) => {
+            parsed.failures.push(ImportFailure {
+                source: file.name.clone(),
+                reason: format!("Invalid XML: {}", e),
+            });
+            return;
+        }
+    };
+    let Some(channel) = children(document.root_element(), Ns::Rss, "channel").next() else {
+        parsed.failures.push(ImportFailure {
+            source: file.name.clone(),
+            reason: "Not a WordPress export: <channel> is missing".to_string(),
+        });
+        return;
+    };
+
+    // `<wp:category_parent>` refers to the parent's nicename
+    let mut category_names = HashMap::new();
+    let mut category_parent_slugs = Vec::new();
+    for category in children(channel, Ns::Wp, "category") {
+        let (Some(slug), Some(name)) = (
+            non_empty(field(category, Ns::Wp, "category_nicename")),
+            non_empty(field(category, Ns::Wp, "cat_name")),
+        ) else {
+            continue;
+        };
+        if let Some(parent) = non_empty(field(category, Ns::Wp, "category_parent")) {
+            category_parent_slugs.push((name.clone(), parent));
+        }
+        category_names.insert(slug, name);
+    }
+    for (name, parent_slug) in category_parent_slugs {
+        if let Some(parent) = category_names.get(&parent_slug) {
+            parsed.category_parents.insert(name, parent.clone());
+        }
+    }
+
+    let items: Vec<_> = children(channel, Ns::Rss, "item").collect();
+    let attachments: HashMap<String, String> = items
+        .iter()
+        .filter(|item| field(**item, Ns::Wp, "post_type").as_deref() == Some("attachment"))
+        .filter_map(|item| {
+            Some((
+                non_empty(field(*item, Ns::Wp, "post_id"))?,
+                non_empty(field(*item, Ns::Wp, "attachment_url"))?,
+            ))
+        })
+        .collect();
+
+    for item in items {
+        if field(item, Ns::Wp, "post_type").as_deref() != Some("post") {
+            continue;
+        }
+        let Some(status) = field(item, Ns::Wp, "status")
+            .as_deref()
+            .and_then(|s| map_status(s.trim()))
+        else {
+            continue;
+        };
+        let post_id = non_empty(field(item, Ns::Wp, "post_id")).unwrap_or_default();
+        let source = format!("{}#{}", file.name, post_id);
+
+        let Some(title) = non_empty(field(item, Ns::Rss, "title")) else {
+            parsed.failures.push(ImportFailure {
+                source,
+                reason: "Post has no title".to_string(),
+            });
+            continue;
+        };
+
+        let date = non_empty(field(item, Ns::Wp, "post_date_gmt"))
+            .and_then(|d| parse_date(&d))
+            .or_else(|| non_empty(field(item, Ns::Wp, "post_date")).and_then(|d| parse_date(&d)))
+            .or_else(|| non_empty(field(item, Ns::Rss, "pubDate")).and_then(|d| parse_date(&d)));
+        let modified = non_empty(field(item, Ns::Wp, "post_modified_gmt"))
+            .and_then(|d| parse_date(&d))
+            .or_else(|| {
+                non_empty(field(item, Ns::Wp, "post_modified")).and_then(|d| parse_date(&d))
+            });
+
+        let mut category = None;
+        let mut tags: Vec<String> = Vec::new();
+        for term in children(item, Ns::Rss, "category") {
+            let Some(name) = non_empty(Some(term.children().filter_map(|c| c.text()).collect()))
+            else {
+                continue;
+            };
+            match term.attribute("domain") {
+                Some("category") if category.is_none() => category = Some(name),
+                Some("post_tag") if !tags.contains(&name) => tags.push(name),
+                _ => {}
+            }
+        }
+
+        let thumbnail = children(item, Ns::Wp, "postmeta")
+            .find(|meta| field(*meta, Ns::Wp, "meta_key").as_deref() == Some("_thumbnail_id"))
+            .and_then(|meta| non_empty(field(meta, Ns::Wp, "meta_value")))
+            .and_then(|id| attachments.get(&id).cloned());
+
+        parsed.posts.push(ImportedPost {
+            source,
+            title,
+            slug: non_empty(field(item, Ns::Wp, "post_name")).map(|s| percent_decode(&s)),
+            content: to_markdown(&field(item, Ns::Content, "encoded").unwrap_or_default()),
+            excerpt: non_empty(field(item, Ns::Excerpt, "encoded")),
+            status: status.to_string(),
+            date,
+            modified,
+            thumbnail,
+            category,
+            tags,
+        });
+    }
+}
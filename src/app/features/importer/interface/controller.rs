@@ -0,0 +1,108 @@
+use crate::app::features::importer::domain::entity::ImportFile;
+use crate::app::features::importer::domain::error::ImportError;
+use crate::app::features::importer::interface::dto::{ImportForm, ImportQueryDto, ImportReportDto};
+use crate::utils::di::Container;
+use crate::utils::error_response::{ErrorResponse, map_string_error, map_validation_error};
+use crate::utils::success_response::map_success_with_data;
+use crate::utils::token::Claims;
+use actix_multipart::Multipart;
+use actix_web::{HttpResponse, Responder, post, web};
+use futures_util::TryStreamExt;
+
+fn import_error_response(e: ImportError) -> HttpResponse {
+    match e {
+        ImportError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+        ImportError::System(msg) => HttpResponse::InternalServerError().json(map_string_error(msg)),
+    }
+}
+
+/// Collects every `files` field. Returns `Ok(None)` once the upload grows
+/// past `max_bytes` in total.
+async fn read_files(
+    payload: &mut Multipart,
+    max_bytes: usize,
+) -> Result<Option<Vec<ImportFile>>, actix_multipart::MultipartError> {
+    let mut files = Vec::new();
+    let mut total = 0;
+
+    while let Some(mut field) = payload.try_next().await? {
+        if field.name() != Some("files") {
+            continue;
+        }
+        let name = field
+            .content_disposition()
+            .and_then(|cd| cd.get_filename())
+            .unwrap_or("file")
+            .to_string();
+        let mut bytes = Vec::new();
+        while let Some(chunk) = field.try_next().await? {
+            total += chunk.len();
+            if total > max_bytes {
+                return Ok(None);
+            }
+            bytes.extend_from_slice(&chunk);
+        }
+        files.push(ImportFile {
+            name,
+            content: String::from_utf8_lossy(&bytes).into_owned(),
+        });
+    }
+
+    Ok(Some(files))
+}
+
+#[utoipa::path(
+    path = "/app/import",
+    tag = "Import",
+    params(
+        ImportQueryDto
+    ),
+    request_body(content = ImportForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Per-post outcome; nothing is written when `dry_run` is set", body = crate::utils::success_response::SuccessResponse<ImportReportDto>),
+        (status = 400, description = "No files, or upload too large", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[post("/import")]
+pub async fn import_posts(
+    container: web::Data<Container>,
+    claims: web::ReqData<Claims>,
+    query: web::Query<ImportQueryDto>,
+    mut payload: Multipart,
+) -> impl Responder {
+    let max_bytes = container.config.import_max_bytes;
+    let files = match read_files(&mut payload, max_bytes).await {
+        Ok(Some(files)) => files,
+        Ok(None) => {
+            return HttpResponse::BadRequest().json(map_string_error(format!(
+                "Upload is larger than {} bytes",
+                max_bytes
+            )));
+        }
+        Err(e) => {
+            return HttpResponse::BadRequest().json(map_string_error(format!(
+                "Invalid multipart payload: {}",
+                e
+            )));
+        }
+    };
+
+    // A large export takes a while to parse and write; keep it off the async
+    // workers
+    let usecase = container.import_posts_usecase.clone();
+    let dry_run = query.dry_run.unwrap_or(false);
+    let username = claims.into_inner().sub;
+    match web::block(move || usecase.execute(files, dry_run, Some(username))).await {
+        Ok(Ok(report)) => HttpResponse::Ok().json(map_success_with_data(
+            if report.dry_run {
+                "Dry run finished, nothing was imported".to_string()
+            } else {
+                "Import finished".to_string()
+            },
+            report,
+        )),
+        Ok(Err(e)) => import_error_response(e),
+        Err(e) => HttpResponse::InternalServerError().json(map_string_error(e.to_string())),
+    }
+}
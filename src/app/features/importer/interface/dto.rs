@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+/// Multipart form of `POST /app/import`, for the API docs only; the handler
+/// reads the stream itself.
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct ImportForm {
+    /// WordPress export (`.xml`) and/or Markdown posts (`.md`), repeatable
+    #[schema(value_type = Vec<String>, format = Binary)]
+    pub files: Vec<Vec<u8>>,
+}
+
+#[derive(Deserialize, Serialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ImportQueryDto {
+    /// Report what would happen without writing anything
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct ImportItemDto {
+    /// File name, plus `#<post id>` for WordPress entries
+    pub source: String,
+    pub title: Option<String>,
+    pub slug: Option<String>,
+    /// CREATED, SKIPPED or FAILED
+    pub outcome: String,
+    /// Only for posts actually created
+    pub blog_id: Option<i32>,
+    pub reason: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct ImportReportDto {
+    pub dry_run: bool,
+    pub created: usize,
+    pub skipped: usize,
+    pub failed: usize,
+    /// Categories and tags that didn't exist yet
+    pub categories_created: Vec<String>,
+    /// New categories put at the top level instead of under their exported
+    /// parent, because keeping it would nest past the depth limit or the
+    /// parents loop
+    pub categories_detached: Vec<String>,
+    pub tags_created: Vec<String>,
+    pub items: Vec<ImportItemDto>,
+}
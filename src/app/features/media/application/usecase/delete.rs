@@ -0,0 +1,83 @@
+use crate::app::features::media::domain::entity::MediaReference;
+use crate::app::features::media::domain::error::MediaError;
+use crate::app::features::media::domain::repository::MediaRepository;
+use crate::app::features::media::domain::storage::MediaStorage;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn MediaRepository + Send + Sync>,
+    storage: Arc<dyn MediaStorage + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(
+        repository: Arc<dyn MediaRepository + Send + Sync>,
+        storage: Arc<dyn MediaStorage + Send + Sync>,
+    ) -> Self {
+        Self {
+            repository,
+            storage,
+        }
+    }
+
+    pub async fn execute(&self, id: i32) -> Result<(), MediaError> {
+        let media = self
+            .repository
+            .get_media_by_id(id)
+            .map_err(|e| MediaError::System(e.to_string()))?
+            .ok_or_else(|| MediaError::NotFound("Media not found".to_string()))?;
+
+        let variants = self
+            .repository
+            .get_variants_by_media_id(id)
+            .map_err(|e| MediaError::System(e.to_string()))?;
+
+        // Content may point at a variant instead of the original
+        let mut references = Vec::new();
+        for path in
+            std::iter::once(media.public_path()).chain(variants.iter().map(|v| v.public_path()))
+        {
+            for reference in self
+                .repository
+                .get_media_references(path)
+                .map_err(|e| MediaError::System(e.to_string()))?
+            {
+                if !references.contains(&reference) {
+                    references.push(reference);
+                }
+            }
+        }
+        if !references.is_empty() {
+            let users = references
+                .iter()
+                .map(|r| match r {
+                    MediaReference::Blog(title) => format!("blog \"{}\"", title),
+                    MediaReference::Portofolio(judul) => format!("portofolio \"{}\"", judul),
+                    MediaReference::ProfileImage => "profile image".to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(MediaError::Conflict(format!(
+                "Media is still used by {}",
+                users
+            )));
+        }
+
+        // Variant rows go with the media row through the foreign key
+        self.repository
+            .delete_media(id)
+            .map_err(|e| MediaError::System(e.to_string()))?;
+        for filename in variants
+            .iter()
+            .map(|v| &v.filename)
+            .chain(std::iter::once(&media.filename))
+        {
+            self.storage
+                .delete(filename)
+                .map_err(|e| MediaError::System(e.to_string()))?;
+        }
+
+        Ok(())
+    }
+}
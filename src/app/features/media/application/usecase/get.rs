@@ -0,0 +1,37 @@
+use crate::app::features::media::domain::error::MediaError;
+use crate::app::features::media::domain::repository::MediaRepository;
+use crate::app::features::media::interface::dto::MediaResponseDto;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn MediaRepository + Send + Sync>,
+    site_url: String,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn MediaRepository + Send + Sync>, site_url: String) -> Self {
+        Self {
+            repository,
+            site_url,
+        }
+    }
+
+    pub async fn execute(&self, id: i32) -> Result<MediaResponseDto, MediaError> {
+        let media = self
+            .repository
+            .get_media_by_id(id)
+            .map_err(|e| MediaError::System(e.to_string()))?
+            .ok_or_else(|| MediaError::NotFound("Media not found".to_string()))?;
+        let variants = self
+            .repository
+            .get_variants_by_media_id(media.id)
+            .map_err(|e| MediaError::System(e.to_string()))?;
+
+        Ok(MediaResponseDto::from_media(
+            media,
+            variants,
+            &self.site_url,
+        ))
+    }
+}
@@ -0,0 +1,55 @@
+use crate::app::features::media::domain::error::MediaError;
+use crate::app::features::media::domain::repository::MediaRepository;
+use crate::app::features::media::interface::dto::{
+    MediaListQueryDto, MediaResponseDto, MetaDto, PaginatedResponseDto,
+};
+use std::sync::Arc;
+use validator::Validate;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn MediaRepository + Send + Sync>,
+    site_url: String,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn MediaRepository + Send + Sync>, site_url: String) -> Self {
+        Self {
+            repository,
+            site_url,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        query: MediaListQueryDto,
+    ) -> Result<PaginatedResponseDto<MediaResponseDto>, MediaError> {
+        query.validate().map_err(MediaError::Validation)?;
+
+        let page = query.page.unwrap_or(1);
+        let per_page = query.per_page.unwrap_or(10);
+        let offset = (page - 1) * per_page;
+
+        let (media, total_items) = self
+            .repository
+            .get_all_media(query.mime_type, per_page, offset)
+            .map_err(|e| MediaError::System(e.to_string()))?;
+
+        let items = media
+            .into_iter()
+            .map(|(m, variants)| MediaResponseDto::from_media(m, variants, &self.site_url))
+            .collect();
+
+        let total_pages = (total_items as f64 / per_page as f64).ceil() as i64;
+
+        Ok(PaginatedResponseDto {
+            items,
+            meta: MetaDto {
+                page,
+                per_page,
+                total_pages,
+                total_items,
+            },
+        })
+    }
+}
@@ -0,0 +1,5 @@
+pub mod delete;
+pub mod get;
+pub mod get_all;
+pub mod serve;
+pub mod upload;
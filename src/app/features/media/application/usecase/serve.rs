@@ -0,0 +1,43 @@
+use crate::app::features::media::domain::error::MediaError;
+use crate::app::features::media::domain::repository::MediaRepository;
+use crate::app::features::media::domain::storage::MediaStorage;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn MediaRepository + Send + Sync>,
+    storage: Arc<dyn MediaStorage + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(
+        repository: Arc<dyn MediaRepository + Send + Sync>,
+        storage: Arc<dyn MediaStorage + Send + Sync>,
+    ) -> Self {
+        Self {
+            repository,
+            storage,
+        }
+    }
+
+    /// Only files known to the library (originals and their variants) are
+    /// served, so arbitrary paths under the media directory can never be
+    /// requested. Returns the file's mime type and location.
+    pub async fn execute(&self, filename: String) -> Result<(String, PathBuf), MediaError> {
+        if let Some(media) = self
+            .repository
+            .get_media_by_filename(filename.clone())
+            .map_err(|e| MediaError::System(e.to_string()))?
+        {
+            return Ok((media.mime_type, self.storage.path(&media.filename)));
+        }
+
+        let variant = self
+            .repository
+            .get_variant_by_filename(filename)
+            .map_err(|e| MediaError::System(e.to_string()))?
+            .ok_or_else(|| MediaError::NotFound("Media not found".to_string()))?;
+        Ok((variant.mime_type, self.storage.path(&variant.filename)))
+    }
+}
@@ -0,0 +1,225 @@
+use crate::app::features::media::domain::entity::{NewMedia, NewMediaVariant};
+use crate::app::features::media::domain::error::MediaError;
+use crate::app::features::media::domain::image::ImageProcessor;
+use crate::app::features::media::domain::repository::MediaRepository;
+use crate::app::features::media::domain::storage::MediaStorage;
+use crate::app::features::media::interface::dto::{MediaResponseDto, UploadMediaDto};
+use crate::utils::slug::slugify;
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+use std::path::Path;
+use std::sync::Arc;
+use validator::{ValidationError, ValidationErrors};
+
+pub const MAX_ALT_TEXT_LENGTH: usize = 500;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn MediaRepository + Send + Sync>,
+    storage: Arc<dyn MediaStorage + Send + Sync>,
+    processor: Arc<dyn ImageProcessor + Send + Sync>,
+    max_bytes: usize,
+    allowed_types: Vec<String>,
+    site_url: String,
+}
+
+impl Execute {
+    pub fn new(
+        repository: Arc<dyn MediaRepository + Send + Sync>,
+        storage: Arc<dyn MediaStorage + Send + Sync>,
+        processor: Arc<dyn ImageProcessor + Send + Sync>,
+        max_bytes: usize,
+        allowed_types: Vec<String>,
+        site_url: String,
+    ) -> Self {
+        Self {
+            repository,
+            storage,
+            processor,
+            max_bytes,
+            allowed_types,
+            site_url,
+        }
+    }
+
+    /// Stores the file and returns it with `true`, or returns the existing
+    /// entry with `false` when the same bytes were uploaded before.
+    ///
+    /// Images are stored without their metadata and get resized WebP variants
+    /// and a blurhash; other files are kept as uploaded. Decoding, resizing
+    /// and encoding are CPU-bound, so callers on an async runtime should run
+    /// this on a blocking thread.
+    pub fn execute(&self, dto: UploadMediaDto) -> Result<(MediaResponseDto, bool), MediaError> {
+        let mut validation_errors = ValidationErrors::new();
+
+        let alt_text = dto
+            .alt_text
+            .map(|a| a.trim().to_string())
+            .filter(|a| !a.is_empty());
+        if dto.alt_text_truncated
+            || alt_text
+                .as_ref()
+                .is_some_and(|a| a.chars().count() > MAX_ALT_TEXT_LENGTH)
+        {
+            validation_errors.add(
+                "alt_text",
+                ValidationError::new("length").with_message(Cow::Borrowed("Alt text is too long")),
+            );
+        }
+
+        let file = match dto.file {
+            Some(file) if file.bytes.is_empty() => {
+                validation_errors.add(
+                    "file",
+                    ValidationError::new("empty").with_message(Cow::Borrowed("File is empty")),
+                );
+                None
+            }
+            Some(file) if file.truncated || file.bytes.len() > self.max_bytes => {
+                validation_errors.add(
+                    "file",
+                    ValidationError::new("size").with_message(Cow::Owned(format!(
+                        "File is larger than {} bytes",
+                        self.max_bytes
+                    ))),
+                );
+                None
+            }
+            Some(file) => Some(file),
+            None => {
+                validation_errors.add(
+                    "file",
+                    ValidationError::new("required")
+                        .with_message(Cow::Borrowed("File is required")),
+                );
+                None
+            }
+        };
+
+        // The type comes from the bytes themselves, never from the client
+        let kind = file.as_ref().and_then(|f| infer::get(&f.bytes));
+        if file.is_some()
+            && !kind.is_some_and(|k| self.allowed_types.iter().any(|t| t == k.mime_type()))
+        {
+            validation_errors.add(
+                "file",
+                ValidationError::new("type").with_message(Cow::Borrowed("Unsupported file type")),
+            );
+        }
+
+        let (file, kind) = match (file, kind) {
+            (Some(file), Some(kind)) if validation_errors.is_empty() => (file, kind),
+            _ => return Err(MediaError::Validation(validation_errors)),
+        };
+
+        let checksum = format!("{:x}", Sha256::digest(&file.bytes));
+        if let Some(existing) = self
+            .repository
+            .get_media_by_checksum(checksum.clone())
+            .map_err(|e| MediaError::System(e.to_string()))?
+        {
+            let variants = self
+                .repository
+                .get_variants_by_media_id(existing.id)
+                .map_err(|e| MediaError::System(e.to_string()))?;
+            return Ok((
+                MediaResponseDto::from_media(existing, variants, &self.site_url),
+                false,
+            ));
+        }
+
+        let processed = self.processor.process(&file.bytes).map_err(|_| {
+            let mut errors = ValidationErrors::new();
+            errors.add(
+                "file",
+                ValidationError::new("image")
+                    .with_message(Cow::Borrowed("Image could not be processed")),
+            );
+            MediaError::Validation(errors)
+        })?;
+
+        let stem = Path::new(&file.original_name)
+            .file_stem()
+            .map(|s| slugify(&s.to_string_lossy()))
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| "file".to_string());
+        let base = format!("{}-{}", stem, &checksum[..12]);
+        let filename = format!("{}.{}", base, kind.extension());
+
+        // The checksum stays that of the upload so re-uploads still dedupe
+        let mut bytes = file.bytes;
+        let mut new_variants = Vec::new();
+        let mut files = Vec::new();
+        let (width, height, blurhash) = match processed {
+            Some(image) => {
+                if let Some(stripped) = image.stripped {
+                    bytes = stripped;
+                }
+                for variant in image.variants {
+                    let variant_filename =
+                        format!("{}-{}w.{}", base, variant.width, variant.extension);
+                    new_variants.push(NewMediaVariant {
+                        media_id: 0,
+                        filename: variant_filename.clone(),
+                        mime_type: variant.mime_type.to_string(),
+                        width: variant.width as i32,
+                        height: variant.height as i32,
+                        size: variant.bytes.len() as i64,
+                    });
+                    files.push((variant_filename, variant.bytes));
+                }
+                (
+                    Some(image.width as i32),
+                    Some(image.height as i32),
+                    Some(image.blurhash),
+                )
+            }
+            None => (None, None, None),
+        };
+        let size = bytes.len() as i64;
+        files.push((filename.clone(), bytes));
+
+        let mut saved = Vec::new();
+        for (name, contents) in &files {
+            if let Err(e) = self.storage.save(name, contents) {
+                self.discard(&saved);
+                return Err(MediaError::System(e.to_string()));
+            }
+            saved.push(name.clone());
+        }
+
+        let created = self.repository.create_media(
+            NewMedia {
+                filename,
+                original_name: file.original_name,
+                mime_type: kind.mime_type().to_string(),
+                size,
+                checksum,
+                alt_text,
+                width,
+                height,
+                blurhash,
+            },
+            new_variants,
+        );
+        let (media, variants) = match created {
+            Ok(created) => created,
+            Err(e) => {
+                self.discard(&saved);
+                return Err(MediaError::System(e.to_string()));
+            }
+        };
+
+        Ok((
+            MediaResponseDto::from_media(media, variants, &self.site_url),
+            true,
+        ))
+    }
+
+    /// Best-effort cleanup of files written for an upload that failed.
+    fn discard(&self, filenames: &[String]) {
+        for filename in filenames {
+            let _ = self.storage.delete(filename);
+        }
+    }
+}
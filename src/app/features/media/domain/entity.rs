@@ -0,0 +1,100 @@
+use crate::schema::{media, media_variants};
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone, PartialEq)]
+#[diesel(table_name = media)]
+pub struct Media {
+    pub id: i32,
+    pub filename: String,
+    pub original_name: String,
+    pub mime_type: String,
+    pub size: i64,
+    pub checksum: String,
+    pub alt_text: Option<String>,
+    pub created_at: NaiveDateTime,
+    /// Pixel size, only known for images the processor could decode
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    /// Compact placeholder to paint while the image loads
+    pub blurhash: Option<String>,
+}
+
+impl Media {
+    /// Path the file is served under; also what posts embed to reference it.
+    pub fn public_path(&self) -> String {
+        format!("/media/{}", self.filename)
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = media)]
+pub struct NewMedia {
+    pub filename: String,
+    pub original_name: String,
+    pub mime_type: String,
+    pub size: i64,
+    pub checksum: String,
+    pub alt_text: Option<String>,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub blurhash: Option<String>,
+}
+
+/// Resized copy of an image, generated on upload.
+#[derive(Queryable, Selectable, Identifiable, Associations, Debug, Clone, PartialEq)]
+#[diesel(belongs_to(Media))]
+#[diesel(table_name = media_variants)]
+pub struct MediaVariant {
+    pub id: i32,
+    pub media_id: i32,
+    pub filename: String,
+    pub mime_type: String,
+    pub width: i32,
+    pub height: i32,
+    pub size: i64,
+}
+
+impl MediaVariant {
+    pub fn public_path(&self) -> String {
+        format!("/media/{}", self.filename)
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = media_variants)]
+pub struct NewMediaVariant {
+    pub media_id: i32,
+    pub filename: String,
+    pub mime_type: String,
+    pub width: i32,
+    pub height: i32,
+    pub size: i64,
+}
+
+/// Where a media file is still in use.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MediaReference {
+    Blog(String),
+    Portofolio(String),
+    ProfileImage,
+}
+
+/// Names of the library files `texts` point at through `/media/...` paths,
+/// in order of first appearance.
+pub fn referenced_filenames<'a>(texts: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut filenames: Vec<String> = Vec::new();
+    for text in texts {
+        for (start, _) in text.match_indices("/media/") {
+            let rest = &text[start + "/media/".len()..];
+            let end = rest
+                .find(|c: char| c.is_whitespace() || "()[]<>\"'?#".contains(c))
+                .unwrap_or(rest.len());
+            let filename = &rest[..end];
+            if !filename.is_empty() && !filenames.iter().any(|f| f == filename) {
+                filenames.push(filename.to_string());
+            }
+        }
+    }
+    filenames
+}
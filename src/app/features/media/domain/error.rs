@@ -0,0 +1,16 @@
+use validator::ValidationErrors;
+
+#[derive(Debug)]
+pub enum MediaError {
+    Validation(ValidationErrors),
+    System(String),
+    NotFound(String),
+    /// The file is still referenced and cannot be removed.
+    Conflict(String),
+}
+
+impl From<String> for MediaError {
+    fn from(s: String) -> Self {
+        MediaError::System(s)
+    }
+}
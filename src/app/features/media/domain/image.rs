@@ -0,0 +1,24 @@
+/// Web-ready copy of an uploaded image.
+pub struct ImageVariant {
+    pub width: u32,
+    pub height: u32,
+    pub mime_type: &'static str,
+    pub extension: &'static str,
+    pub bytes: Vec<u8>,
+}
+
+pub struct ProcessedImage {
+    pub width: u32,
+    pub height: u32,
+    pub blurhash: String,
+    /// Original re-encoded without its metadata, when it carried any
+    pub stripped: Option<Vec<u8>>,
+    /// Resized copies, narrowest first
+    pub variants: Vec<ImageVariant>,
+}
+
+/// Turns uploaded images into their responsive variants.
+pub trait ImageProcessor {
+    /// `Ok(None)` for files it cannot decode, which are stored untouched.
+    fn process(&self, bytes: &[u8]) -> Result<Option<ProcessedImage>, String>;
+}
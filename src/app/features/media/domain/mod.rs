@@ -0,0 +1,5 @@
+pub mod entity;
+pub mod error;
+pub mod image;
+pub mod repository;
+pub mod storage;
@@ -0,0 +1,30 @@
+use crate::app::features::media::domain::entity::{
+    Media, MediaReference, MediaVariant, NewMedia, NewMediaVariant,
+};
+use diesel::QueryResult;
+
+pub trait MediaRepository {
+    /// Inserts the file and its variants together; the variants' `media_id`
+    /// is replaced with the id of the new row.
+    fn create_media(
+        &self,
+        media: NewMedia,
+        variants: Vec<NewMediaVariant>,
+    ) -> QueryResult<(Media, Vec<MediaVariant>)>;
+    fn get_media_by_id(&self, id: i32) -> QueryResult<Option<Media>>;
+    fn get_media_by_filename(&self, filename: String) -> QueryResult<Option<Media>>;
+    fn get_media_by_checksum(&self, checksum: String) -> QueryResult<Option<Media>>;
+    #[allow(clippy::type_complexity)]
+    fn get_all_media(
+        &self,
+        mime_type: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> QueryResult<(Vec<(Media, Vec<MediaVariant>)>, i64)>;
+    fn get_variants_by_media_id(&self, media_id: i32) -> QueryResult<Vec<MediaVariant>>;
+    fn get_variant_by_filename(&self, filename: String) -> QueryResult<Option<MediaVariant>>;
+    /// Blogs (thumbnail or content), portfolio descriptions and the profile
+    /// image pointing at `path`.
+    fn get_media_references(&self, path: String) -> QueryResult<Vec<MediaReference>>;
+    fn delete_media(&self, id: i32) -> QueryResult<usize>;
+}
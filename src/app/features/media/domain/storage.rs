@@ -0,0 +1,10 @@
+use std::io;
+use std::path::PathBuf;
+
+/// Where uploaded files live; the database only keeps their metadata.
+pub trait MediaStorage {
+    fn save(&self, filename: &str, bytes: &[u8]) -> io::Result<()>;
+    /// Removing a file that is already gone is not an error.
+    fn delete(&self, filename: &str) -> io::Result<()>;
+    fn path(&self, filename: &str) -> PathBuf;
+}
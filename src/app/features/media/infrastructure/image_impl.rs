@@ -0,0 +1,146 @@
+use crate::app::features::media::domain::image::{ImageProcessor, ImageVariant, ProcessedImage};
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::PngEncoder;
+use image::imageops::FilterType;
+use image::metadata::Orientation;
+use image::{DynamicImage, ImageDecoder, ImageEncoder, ImageFormat, ImageReader, Limits};
+use std::io::Cursor;
+
+/// Anything larger is refused before its pixels are allocated.
+const MAX_DIMENSION: u32 = 12_000;
+const JPEG_QUALITY: u8 = 90;
+/// The blurhash is computed from a thumbnail this wide; more detail is lost anyway.
+const BLURHASH_SOURCE_WIDTH: u32 = 64;
+
+/// Decodes, resizes and encodes images in-process, without external services.
+#[derive(Clone)]
+pub struct LocalImageProcessor {
+    pub widths: Vec<u32>,
+    pub webp_quality: f32,
+}
+
+impl LocalImageProcessor {
+    pub fn new(widths: Vec<u32>, webp_quality: f32) -> Self {
+        Self {
+            widths,
+            webp_quality,
+        }
+    }
+
+    fn encode_webp(&self, image: &DynamicImage) -> Result<ImageVariant, String> {
+        let rgba = image.to_rgba8();
+        let encoded = webp::Encoder::from_rgba(&rgba, rgba.width(), rgba.height())
+            .encode_simple(false, self.webp_quality)
+            .map_err(|e| format!("WebP encoding failed: {:?}", e))?;
+        Ok(ImageVariant {
+            width: rgba.width(),
+            height: rgba.height(),
+            mime_type: "image/webp",
+            extension: "webp",
+            bytes: encoded.to_vec(),
+        })
+    }
+
+    /// Same format as the upload, minus everything but the pixels.
+    fn reencode(
+        &self,
+        image: &DynamicImage,
+        format: ImageFormat,
+    ) -> Result<Option<Vec<u8>>, String> {
+        let mut bytes = Vec::new();
+        match format {
+            ImageFormat::Jpeg => {
+                let rgb = image.to_rgb8();
+                JpegEncoder::new_with_quality(&mut bytes, JPEG_QUALITY)
+                    .write_image(
+                        &rgb,
+                        rgb.width(),
+                        rgb.height(),
+                        image::ColorType::Rgb8.into(),
+                    )
+                    .map_err(|e| e.to_string())?;
+            }
+            ImageFormat::Png => {
+                let rgba = image.to_rgba8();
+                PngEncoder::new(&mut bytes)
+                    .write_image(
+                        &rgba,
+                        rgba.width(),
+                        rgba.height(),
+                        image::ColorType::Rgba8.into(),
+                    )
+                    .map_err(|e| e.to_string())?;
+            }
+            ImageFormat::WebP => bytes = self.encode_webp(image)?.bytes,
+            _ => return Ok(None),
+        }
+        Ok(Some(bytes))
+    }
+}
+
+impl ImageProcessor for LocalImageProcessor {
+    fn process(&self, bytes: &[u8]) -> Result<Option<ProcessedImage>, String> {
+        let mut reader = match ImageReader::new(Cursor::new(bytes)).with_guessed_format() {
+            Ok(reader) => reader,
+            Err(_) => return Ok(None),
+        };
+        let format = match reader.format() {
+            Some(format @ (ImageFormat::Jpeg | ImageFormat::Png | ImageFormat::WebP)) => format,
+            _ => return Ok(None),
+        };
+        let mut limits = Limits::default();
+        limits.max_image_width = Some(MAX_DIMENSION);
+        limits.max_image_height = Some(MAX_DIMENSION);
+        reader.limits(limits);
+
+        let mut decoder = reader.into_decoder().map_err(|e| e.to_string())?;
+        let exif = decoder.exif_metadata().map_err(|e| e.to_string())?;
+        let has_metadata = exif.is_some()
+            || decoder.xmp_metadata().map_err(|e| e.to_string())?.is_some()
+            || decoder
+                .iptc_metadata()
+                .map_err(|e| e.to_string())?
+                .is_some();
+        let orientation = exif
+            .as_deref()
+            .and_then(Orientation::from_exif_chunk)
+            .unwrap_or(Orientation::NoTransforms);
+
+        let mut image = DynamicImage::from_decoder(decoder).map_err(|e| e.to_string())?;
+        // Rotation lives in the EXIF block we are about to drop, so bake it in
+        image.apply_orientation(orientation);
+        let (width, height) = (image.width(), image.height());
+
+        let stripped = if has_metadata {
+            self.reencode(&image, format)?
+        } else {
+            None
+        };
+
+        let mut variants = Vec::new();
+        let mut widths = self.widths.clone();
+        widths.sort_unstable();
+        widths.dedup();
+        for target in widths.into_iter().filter(|w| *w > 0 && *w < width) {
+            let resized = image.resize(target, u32::MAX, FilterType::Lanczos3);
+            variants.push(self.encode_webp(&resized)?);
+        }
+        if format != ImageFormat::WebP {
+            variants.push(self.encode_webp(&image)?);
+        }
+
+        let thumbnail = image
+            .resize(BLURHASH_SOURCE_WIDTH, u32::MAX, FilterType::Triangle)
+            .to_rgba8();
+        let blurhash = blurhash::encode(4, 3, thumbnail.width(), thumbnail.height(), &thumbnail)
+            .map_err(|e| e.to_string())?;
+
+        Ok(Some(ProcessedImage {
+            width,
+            height,
+            blurhash,
+            stripped,
+            variants,
+        }))
+    }
+}
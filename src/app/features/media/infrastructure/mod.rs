@@ -0,0 +1,3 @@
+pub mod image_impl;
+pub mod repository_impl;
+pub mod storage_impl;
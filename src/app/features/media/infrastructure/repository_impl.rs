@@ -0,0 +1,202 @@
+use crate::app::features::media::domain::entity::{
+    Media, MediaReference, MediaVariant, NewMedia, NewMediaVariant,
+};
+use crate::app::features::media::domain::repository::MediaRepository;
+use crate::schema::{blog, media, media_variants, portofolios, profiles};
+use crate::utils::db::DbPool;
+use diesel::prelude::*;
+
+#[derive(Clone)]
+pub struct MediaRepositoryImpl {
+    pub pool: DbPool,
+}
+
+impl MediaRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Library images with their variants, in the order of `filenames`. Names
+/// that are not images in the library are skipped.
+///
+/// Shared by the features that expose responsive images for what they embed.
+pub fn load_images(
+    conn: &mut SqliteConnection,
+    filenames: &[String],
+) -> QueryResult<Vec<(Media, Vec<MediaVariant>)>> {
+    if filenames.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut images = media::table
+        .filter(media::filename.eq_any(filenames))
+        .filter(media::mime_type.like("image/%"))
+        .load::<Media>(conn)?;
+    images.sort_by_key(|m| filenames.iter().position(|f| *f == m.filename));
+    with_variants(conn, images)
+}
+
+fn with_variants(
+    conn: &mut SqliteConnection,
+    items: Vec<Media>,
+) -> QueryResult<Vec<(Media, Vec<MediaVariant>)>> {
+    let variants = MediaVariant::belonging_to(&items)
+        .order(media_variants::width.asc())
+        .load::<MediaVariant>(conn)?
+        .grouped_by(&items);
+    Ok(items.into_iter().zip(variants).collect())
+}
+
+impl MediaRepository for MediaRepositoryImpl {
+    fn create_media(
+        &self,
+        new_media: NewMedia,
+        variants: Vec<NewMediaVariant>,
+    ) -> QueryResult<(Media, Vec<MediaVariant>)> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        conn.transaction(|conn| {
+            let created: Media = diesel::insert_into(media::table)
+                .values(new_media)
+                .get_result(conn)?;
+            let variants = variants
+                .into_iter()
+                .map(|variant| {
+                    diesel::insert_into(media_variants::table)
+                        .values(NewMediaVariant {
+                            media_id: created.id,
+                            ..variant
+                        })
+                        .get_result::<MediaVariant>(conn)
+                })
+                .collect::<QueryResult<Vec<_>>>()?;
+            Ok((created, variants))
+        })
+    }
+    fn get_media_by_id(&self, id: i32) -> QueryResult<Option<Media>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        media::table.find(id).first::<Media>(&mut conn).optional()
+    }
+    fn get_media_by_filename(&self, filename: String) -> QueryResult<Option<Media>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        media::table
+            .filter(media::filename.eq(filename))
+            .first::<Media>(&mut conn)
+            .optional()
+    }
+    fn get_media_by_checksum(&self, checksum: String) -> QueryResult<Option<Media>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        media::table
+            .filter(media::checksum.eq(checksum))
+            .first::<Media>(&mut conn)
+            .optional()
+    }
+    fn get_all_media(
+        &self,
+        mime_type: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> QueryResult<(Vec<(Media, Vec<MediaVariant>)>, i64)> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+
+        // `image/` matches every image type, `image/png` just that one
+        let mime_pattern = mime_type.map(|m| format!("{}%", m));
+
+        let mut count_query = media::table.into_boxed();
+        let mut items_query = media::table.into_boxed();
+        if let Some(pattern) = mime_pattern {
+            count_query = count_query.filter(media::mime_type.like(pattern.clone()));
+            items_query = items_query.filter(media::mime_type.like(pattern));
+        }
+
+        let count = count_query.count().get_result(&mut conn)?;
+        let items = items_query
+            .order(media::id.desc())
+            .limit(limit)
+            .offset(offset)
+            .load::<Media>(&mut conn)?;
+
+        Ok((with_variants(&mut conn, items)?, count))
+    }
+    fn get_variants_by_media_id(&self, media_id: i32) -> QueryResult<Vec<MediaVariant>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        media_variants::table
+            .filter(media_variants::media_id.eq(media_id))
+            .order(media_variants::width.asc())
+            .load::<MediaVariant>(&mut conn)
+    }
+    fn get_variant_by_filename(&self, filename: String) -> QueryResult<Option<MediaVariant>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        media_variants::table
+            .filter(media_variants::filename.eq(filename))
+            .first::<MediaVariant>(&mut conn)
+            .optional()
+    }
+    fn get_media_references(&self, path: String) -> QueryResult<Vec<MediaReference>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+
+        // Posts may store the path on its own or as part of an absolute URL
+        let mut references = blog::table
+            .filter(
+                blog::thumbnail
+                    .like(format!("%{}", path))
+                    .or(blog::content.like(format!("%{}%", path))),
+            )
+            .select(blog::title)
+            .order(blog::id.asc())
+            .load::<String>(&mut conn)?
+            .into_iter()
+            .map(MediaReference::Blog)
+            .collect::<Vec<_>>();
+
+        references.extend(
+            portofolios::table
+                .filter(portofolios::deskripsi.like(format!("%{}%", path)))
+                .select(portofolios::judul)
+                .order(portofolios::id.asc())
+                .load::<String>(&mut conn)?
+                .into_iter()
+                .map(MediaReference::Portofolio),
+        );
+
+        let profile_count: i64 = profiles::table
+            .filter(profiles::profile_image.like(format!("%{}", path)))
+            .count()
+            .get_result(&mut conn)?;
+        if profile_count > 0 {
+            references.push(MediaReference::ProfileImage);
+        }
+
+        Ok(references)
+    }
+    fn delete_media(&self, id: i32) -> QueryResult<usize> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::delete(media::table.find(id)).execute(&mut conn)
+    }
+}
@@ -0,0 +1,35 @@
+use crate::app::features::media::domain::storage::MediaStorage;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Keeps uploads as plain files in one directory on the local disk.
+#[derive(Clone)]
+pub struct LocalMediaStorage {
+    pub dir: PathBuf,
+}
+
+impl LocalMediaStorage {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+impl MediaStorage for LocalMediaStorage {
+    fn save(&self, filename: &str, bytes: &[u8]) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        // Write under a temporary name so a half-written file is never served
+        let tmp = self.dir.join(format!(".{}.part", filename));
+        fs::write(&tmp, bytes)?;
+        fs::rename(&tmp, self.path(filename))
+    }
+    fn delete(&self, filename: &str) -> io::Result<()> {
+        match fs::remove_file(self.path(filename)) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    }
+    fn path(&self, filename: &str) -> PathBuf {
+        self.dir.join(filename)
+    }
+}
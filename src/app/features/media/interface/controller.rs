@@ -0,0 +1,251 @@
+use crate::app::features::media::application::usecase::upload::MAX_ALT_TEXT_LENGTH;
+use crate::app::features::media::domain::error::MediaError;
+use crate::app::features::media::interface::dto::{
+    MediaListQueryDto, MediaResponseDto, UploadMediaDto, UploadMediaForm, UploadedFileDto,
+};
+use crate::utils::di::Container;
+use crate::utils::error_response::{ErrorResponse, map_string_error, map_validation_error};
+use crate::utils::success_response::{map_success_response, map_success_with_data};
+use actix_files::NamedFile;
+use actix_multipart::Multipart;
+use actix_web::http::header::{self, HeaderValue};
+use actix_web::{HttpRequest, HttpResponse, Responder, delete, get, post, web};
+use futures_util::TryStreamExt;
+
+fn media_error_response(e: MediaError) -> HttpResponse {
+    match e {
+        MediaError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+        MediaError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+        MediaError::Conflict(msg) => HttpResponse::Conflict().json(map_string_error(msg)),
+        MediaError::System(msg) => HttpResponse::InternalServerError().json(map_string_error(msg)),
+    }
+}
+
+/// Collects the `file` and `alt_text` fields. File bytes past `max_bytes`,
+/// and alt text past what its length limit could take, are not buffered;
+/// the upload is only marked as truncated.
+async fn read_upload(
+    payload: &mut Multipart,
+    max_bytes: usize,
+) -> Result<UploadMediaDto, actix_multipart::MultipartError> {
+    let mut upload = UploadMediaDto {
+        file: None,
+        alt_text: None,
+        alt_text_truncated: false,
+    };
+
+    while let Some(mut field) = payload.try_next().await? {
+        match field.name() {
+            Some("file") => {
+                let original_name = field
+                    .content_disposition()
+                    .and_then(|cd| cd.get_filename())
+                    .unwrap_or("file")
+                    .to_string();
+                let mut bytes = Vec::new();
+                let mut truncated = false;
+                while let Some(chunk) = field.try_next().await? {
+                    if bytes.len() + chunk.len() > max_bytes {
+                        truncated = true;
+                        break;
+                    }
+                    bytes.extend_from_slice(&chunk);
+                }
+                upload.file = Some(UploadedFileDto {
+                    original_name,
+                    bytes,
+                    truncated,
+                });
+                if truncated {
+                    break;
+                }
+            }
+            Some("alt_text") => {
+                // No UTF-8 character takes more than four bytes
+                let max_alt_bytes = MAX_ALT_TEXT_LENGTH * 4;
+                let mut bytes = Vec::new();
+                while let Some(chunk) = field.try_next().await? {
+                    if bytes.len() + chunk.len() > max_alt_bytes {
+                        upload.alt_text_truncated = true;
+                        break;
+                    }
+                    bytes.extend_from_slice(&chunk);
+                }
+                upload.alt_text = Some(String::from_utf8_lossy(&bytes).into_owned());
+            }
+            _ => {}
+        }
+    }
+
+    Ok(upload)
+}
+
+#[utoipa::path(
+    path = "/app/media",
+    tag = "Media",
+    request_body(content = UploadMediaForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "Media uploaded", body = crate::utils::success_response::SuccessResponse<MediaResponseDto>),
+        (status = 200, description = "Identical file already in the library", body = crate::utils::success_response::SuccessResponse<MediaResponseDto>),
+        (status = 400, description = "Missing, empty, oversized or unsupported file", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[post("/media")]
+pub async fn upload_media(
+    container: web::Data<Container>,
+    mut payload: Multipart,
+) -> impl Responder {
+    let upload = match read_upload(&mut payload, container.config.media_max_bytes).await {
+        Ok(upload) => upload,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(map_string_error(format!(
+                "Invalid multipart payload: {}",
+                e
+            )));
+        }
+    };
+
+    // Image processing is CPU-bound; keep it off the async workers
+    let usecase = container.upload_media_usecase.clone();
+    match web::block(move || usecase.execute(upload)).await {
+        Ok(Ok((media, true))) => HttpResponse::Created().json(map_success_with_data(
+            "Media uploaded successfully".to_string(),
+            media,
+        )),
+        Ok(Ok((media, false))) => HttpResponse::Ok().json(map_success_with_data(
+            "Media already exists".to_string(),
+            media,
+        )),
+        Ok(Err(e)) => media_error_response(e),
+        Err(e) => HttpResponse::InternalServerError().json(map_string_error(e.to_string())),
+    }
+}
+
+#[utoipa::path(
+    path = "/app/media",
+    tag = "Media",
+    params(
+        MediaListQueryDto
+    ),
+    responses(
+        (status = 200, description = "Media, newest first", body = crate::utils::success_response::SuccessResponse<Vec<MediaResponseDto>>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[get("/media")]
+pub async fn get_all_media(
+    container: web::Data<Container>,
+    query: web::Query<MediaListQueryDto>,
+) -> impl Responder {
+    match container
+        .get_all_media_usecase
+        .execute(query.into_inner())
+        .await
+    {
+        Ok(media) => HttpResponse::Ok().json(map_success_with_data(
+            "Media fetched successfully".to_string(),
+            media,
+        )),
+        Err(e) => media_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    path = "/app/media/{id}",
+    tag = "Media",
+    params(
+        ("id" = i32, Path, description = "Media id")
+    ),
+    responses(
+        (status = 200, description = "Media found", body = crate::utils::success_response::SuccessResponse<MediaResponseDto>),
+        (status = 404, description = "Media not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[get("/media/{id}")]
+pub async fn get_media(container: web::Data<Container>, id: web::Path<i32>) -> impl Responder {
+    match container.get_media_usecase.execute(id.into_inner()).await {
+        Ok(media) => HttpResponse::Ok().json(map_success_with_data(
+            "Media fetched successfully".to_string(),
+            media,
+        )),
+        Err(e) => media_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    path = "/app/media/{id}",
+    tag = "Media",
+    params(
+        ("id" = i32, Path, description = "Media id")
+    ),
+    responses(
+        (status = 200, description = "Media deleted"),
+        (status = 404, description = "Media not found", body = ErrorResponse),
+        (status = 409, description = "Media is still used by a post or the profile", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[delete("/media/{id}")]
+pub async fn delete_media(container: web::Data<Container>, id: web::Path<i32>) -> impl Responder {
+    match container
+        .delete_media_usecase
+        .execute(id.into_inner())
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().json(map_success_response(
+            "Media deleted successfully".to_string(),
+        )),
+        Err(e) => media_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    path = "/media/{filename}",
+    tag = "Media",
+    params(
+        ("filename" = String, Path, description = "Stored file name of an original or one of its variants")
+    ),
+    responses(
+        (status = 200, description = "File contents"),
+        (status = 304, description = "Not modified since the cached copy"),
+        (status = 404, description = "Media not found", body = ErrorResponse)
+    ),
+    security(())
+)]
+#[get("/media/{filename}")]
+pub async fn serve_media(
+    req: HttpRequest,
+    container: web::Data<Container>,
+    filename: web::Path<String>,
+) -> impl Responder {
+    let (mime_type, path) = match container
+        .serve_media_usecase
+        .execute(filename.into_inner())
+        .await
+    {
+        Ok(found) => found,
+        Err(e) => return media_error_response(e),
+    };
+
+    let file = match NamedFile::open_async(path).await {
+        Ok(file) => file,
+        Err(_) => {
+            return HttpResponse::NotFound().json(map_string_error("Media not found".to_string()));
+        }
+    };
+    let file = match mime_type.parse() {
+        Ok(mime) => file.set_content_type(mime),
+        Err(_) => file,
+    };
+
+    // Stored names embed the checksum, so a name always maps to the same bytes
+    let mut response = file.into_response(&req);
+    response.headers_mut().insert(
+        header::CACHE_CONTROL,
+        HeaderValue::from_static("public, max-age=31536000, immutable"),
+    );
+    response
+}
@@ -0,0 +1,175 @@
+use crate::app::features::media::domain::entity::{Media, MediaVariant, referenced_filenames};
+use diesel::QueryResult;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+/// Multipart fields accepted by `POST /app/media`; only used for the API docs,
+/// the handler reads the stream itself.
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct UploadMediaForm {
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+    pub alt_text: Option<String>,
+}
+
+/// File part of an upload as read from the multipart stream.
+pub struct UploadedFileDto {
+    pub original_name: String,
+    pub bytes: Vec<u8>,
+    /// The stream was cut off after `bytes` because it ran past the size limit.
+    pub truncated: bool,
+}
+
+pub struct UploadMediaDto {
+    pub file: Option<UploadedFileDto>,
+    pub alt_text: Option<String>,
+    /// `alt_text` was cut off because it could not fit the length limit.
+    pub alt_text_truncated: bool,
+}
+
+#[derive(Deserialize, Serialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct MediaListQueryDto {
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+    /// Exact type (`image/png`) or prefix (`image/`)
+    #[validate(length(min = 1, max = 100, message = "Invalid mime type"))]
+    pub mime_type: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct MediaResponseDto {
+    pub id: i32,
+    /// Name the file is stored and served under
+    pub filename: String,
+    pub original_name: String,
+    /// Absolute URL of the file
+    pub url: String,
+    pub mime_type: String,
+    /// Size in bytes
+    pub size: i64,
+    /// SHA-256 of the file contents, hex encoded
+    pub checksum: String,
+    pub alt_text: Option<String>,
+    pub created_at: String,
+    /// Pixel size; empty for files that are not decodable images
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    /// Placeholder to paint while the image loads, see https://blurha.sh
+    pub blurhash: Option<String>,
+    /// Every size the image is available in, narrowest first
+    pub srcset: Vec<ImageSourceDto>,
+}
+
+impl MediaResponseDto {
+    pub fn from_media(media: Media, variants: Vec<MediaVariant>, site_url: &str) -> Self {
+        Self {
+            url: format!("{}{}", site_url, media.public_path()),
+            srcset: ImageSourceDto::candidates(&media, &variants, site_url),
+            width: media.width,
+            height: media.height,
+            blurhash: media.blurhash,
+            id: media.id,
+            filename: media.filename,
+            original_name: media.original_name,
+            mime_type: media.mime_type,
+            size: media.size,
+            checksum: media.checksum,
+            alt_text: media.alt_text,
+            created_at: media.created_at.to_string(),
+        }
+    }
+}
+
+/// One `srcset` candidate.
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct ImageSourceDto {
+    pub url: String,
+    pub width: i32,
+    pub height: i32,
+    pub mime_type: String,
+}
+
+impl ImageSourceDto {
+    /// The generated variants plus the original when no variant matches its
+    /// width, ordered by width. URLs are prefixed with `base`.
+    pub fn candidates(media: &Media, variants: &[MediaVariant], base: &str) -> Vec<Self> {
+        let mut sources = variants
+            .iter()
+            .map(|v| Self {
+                url: format!("{}{}", base, v.public_path()),
+                width: v.width,
+                height: v.height,
+                mime_type: v.mime_type.clone(),
+            })
+            .collect::<Vec<_>>();
+        if let (Some(width), Some(height)) = (media.width, media.height)
+            && !variants.iter().any(|v| v.width == width)
+        {
+            sources.push(Self {
+                url: format!("{}{}", base, media.public_path()),
+                width,
+                height,
+                mime_type: media.mime_type.clone(),
+            });
+        }
+        sources.sort_by_key(|s| s.width);
+        sources
+    }
+}
+
+/// Library image embedded by a post or portfolio, with the sizes a client can
+/// pick from.
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct ResponsiveImageDto {
+    /// Path of the original, as embedded
+    pub src: String,
+    pub alt_text: Option<String>,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub blurhash: Option<String>,
+    /// Narrowest first; join as `"{url} {width}w"` for a `srcset` attribute
+    pub srcset: Vec<ImageSourceDto>,
+}
+
+impl ResponsiveImageDto {
+    pub fn from_media(media: Media, variants: Vec<MediaVariant>) -> Self {
+        Self {
+            src: media.public_path(),
+            srcset: ImageSourceDto::candidates(&media, &variants, ""),
+            alt_text: media.alt_text,
+            width: media.width,
+            height: media.height,
+            blurhash: media.blurhash,
+        }
+    }
+
+    /// The library images `texts` embed, in order of first appearance.
+    /// `load` looks up the filenames through the caller's repository.
+    #[allow(clippy::type_complexity)]
+    pub fn embedded_in<'a>(
+        texts: impl IntoIterator<Item = &'a str>,
+        load: impl FnOnce(Vec<String>) -> QueryResult<Vec<(Media, Vec<MediaVariant>)>>,
+    ) -> QueryResult<Vec<Self>> {
+        Ok(load(referenced_filenames(texts))?
+            .into_iter()
+            .map(|(media, variants)| Self::from_media(media, variants))
+            .collect())
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct MetaDto {
+    pub page: i64,
+    pub per_page: i64,
+    pub total_pages: i64,
+    pub total_items: i64,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PaginatedResponseDto<T> {
+    pub items: Vec<T>,
+    pub meta: MetaDto,
+}
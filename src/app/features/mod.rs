@@ -1,6 +1,19 @@
+pub mod analytics;
 pub mod auth;
+pub mod authors;
 pub mod blog;
+pub mod comments;
+pub mod dashboard;
+pub mod feed;
 pub mod home;
+pub mod importer;
+pub mod media;
 pub mod portofolio;
+pub mod previews;
 pub mod profile;
 pub mod projects;
+pub mod series;
+pub mod sitemap;
+pub mod slug_history;
+pub mod subscribers;
+pub mod webhooks;
@@ -1,3 +1,4 @@
+use crate::app::features::media::interface::dto::ResponsiveImageDto;
 use crate::app::features::portofolio::domain::entity::NewPortofolio;
 use crate::app::features::portofolio::domain::error::PortofolioError;
 use crate::app::features::portofolio::domain::repository::PortofolioRepository;
@@ -5,6 +6,7 @@ use crate::app::features::portofolio::interface::dto::{
     CreatePortofolioRequestDto, PortofolioResponseDto,
 };
 use crate::app::features::projects::interface::dto::ProjectResponseDto;
+use crate::utils::slug::{SlugError, resolve_slug};
 use std::sync::Arc;
 
 #[derive(Clone)]
@@ -25,22 +27,18 @@ impl Execute {
 
         let mut validation_errors = ValidationErrors::new();
 
-        let slug = dto
-            .judul
-            .to_lowercase()
-            .replace(" ", "-")
-            .chars()
-            .filter(|c| c.is_alphanumeric() || *c == '-')
-            .collect::<String>();
-
-        if self
-            .repository
-            .find_by_slug(slug.clone())
-            .map_err(|e| PortofolioError::System(e.to_string()))?
-            .is_some()
-        {
-            validation_errors.add("judul", ValidationError::new("Title already exists"));
-        }
+        let slug = match resolve_slug(dto.slug.as_deref(), &dto.judul, "portofolio", |slug| {
+            self.repository
+                .find_by_slug(slug.to_string())
+                .map(|existing| existing.is_some())
+        }) {
+            Ok(slug) => slug,
+            Err(SlugError::Invalid(msg)) => {
+                validation_errors.add("slug", ValidationError::new(msg));
+                String::new()
+            }
+            Err(SlugError::Lookup(e)) => return Err(PortofolioError::System(e.to_string())),
+        };
 
         if !validation_errors.is_empty() {
             return Err(PortofolioError::Validation(validation_errors));
@@ -59,6 +57,11 @@ impl Execute {
             .create(new_portfolio)
             .map_err(|e| PortofolioError::System(e.to_string()))?;
 
+        let images = ResponsiveImageDto::embedded_in(portfolio.deskripsi.as_deref(), |filenames| {
+            self.repository.get_images(filenames)
+        })
+        .map_err(|e| PortofolioError::System(e.to_string()))?;
+
         Ok(PortofolioResponseDto {
             id: portfolio.id,
             judul: portfolio.judul,
@@ -67,6 +70,7 @@ impl Execute {
             is_active: portfolio.is_active,
             created_at: portfolio.created_at.to_string(),
             updated_at: portfolio.updated_at.to_string(),
+            images,
             project: ProjectResponseDto {
                 id: project.id,
                 nama_projek: project.nama_projek,
@@ -1,3 +1,4 @@
+use crate::app::features::media::interface::dto::ResponsiveImageDto;
 use crate::app::features::portofolio::domain::error::PortofolioError;
 use crate::app::features::portofolio::domain::repository::PortofolioRepository;
 use crate::app::features::portofolio::interface::dto::PortofolioResponseDto;
@@ -23,6 +24,11 @@ impl Execute {
             }
         })?;
 
+        let images = ResponsiveImageDto::embedded_in(result.0.deskripsi.as_deref(), |filenames| {
+            self.repository.get_images(filenames)
+        })
+        .map_err(|e| PortofolioError::System(e.to_string()))?;
+
         Ok(PortofolioResponseDto {
             id: result.0.id,
             judul: result.0.judul,
@@ -31,6 +37,7 @@ impl Execute {
             is_active: result.0.is_active,
             created_at: result.0.created_at.to_string(),
             updated_at: result.0.updated_at.to_string(),
+            images,
             project: ProjectResponseDto {
                 id: result.1.id,
                 nama_projek: result.1.nama_projek,
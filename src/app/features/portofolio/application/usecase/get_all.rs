@@ -1,3 +1,4 @@
+use crate::app::features::media::interface::dto::ResponsiveImageDto;
 use crate::app::features::portofolio::domain::error::PortofolioError;
 use crate::app::features::portofolio::domain::repository::PortofolioRepository;
 use crate::app::features::portofolio::interface::dto::{
@@ -31,9 +32,20 @@ impl Execute {
 
         let total_pages = (total_count as f64 / per_page as f64).ceil() as i64;
 
+        let images = items
+            .iter()
+            .map(|(item, _, _)| {
+                ResponsiveImageDto::embedded_in(item.deskripsi.as_deref(), |filenames| {
+                    self.repository.get_images(filenames)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| PortofolioError::System(e.to_string()))?;
+
         let response_items: Vec<PortofolioResponseDto> = items
             .into_iter()
-            .map(|(item, project, stacks)| PortofolioResponseDto {
+            .zip(images)
+            .map(|((item, project, stacks), images)| PortofolioResponseDto {
                 id: item.id,
                 judul: item.judul,
                 slug: item.slug,
@@ -41,6 +53,7 @@ impl Execute {
                 is_active: item.is_active,
                 created_at: item.created_at.to_string(),
                 updated_at: item.updated_at.to_string(),
+                images,
                 project: ProjectResponseDto {
                     id: project.id,
                     nama_projek: project.nama_projek,
@@ -0,0 +1,72 @@
+use crate::app::features::media::interface::dto::ResponsiveImageDto;
+use crate::app::features::portofolio::domain::error::PortofolioError;
+use crate::app::features::portofolio::domain::repository::PortofolioRepository;
+use crate::app::features::portofolio::interface::dto::PortofolioResponseDto;
+use crate::app::features::projects::interface::dto::ProjectResponseDto;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn PortofolioRepository>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn PortofolioRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Fetches an active portofolio by slug for the public API. Inactive
+    /// portofolios are reported as not found.
+    pub fn execute(&self, slug: String) -> Result<PortofolioResponseDto, PortofolioError> {
+        let portfolio = self
+            .repository
+            .find_by_slug(slug)
+            .map_err(|e| PortofolioError::System(e.to_string()))?
+            .filter(|p| p.is_active)
+            .ok_or_else(|| PortofolioError::NotFound("Portofolio not found".to_string()))?;
+
+        let (portfolio, project, stacks) = self
+            .repository
+            .find_by_id(portfolio.id)
+            .map_err(|e| PortofolioError::System(e.to_string()))?;
+
+        let images = ResponsiveImageDto::embedded_in(portfolio.deskripsi.as_deref(), |filenames| {
+            self.repository.get_images(filenames)
+        })
+        .map_err(|e| PortofolioError::System(e.to_string()))?;
+
+        Ok(PortofolioResponseDto {
+            id: portfolio.id,
+            judul: portfolio.judul,
+            slug: portfolio.slug,
+            deskripsi: portfolio.deskripsi,
+            is_active: portfolio.is_active,
+            created_at: portfolio.created_at.to_string(),
+            updated_at: portfolio.updated_at.to_string(),
+            images,
+            project: ProjectResponseDto {
+                id: project.id,
+                nama_projek: project.nama_projek,
+                deskripsi: project.deskripsi,
+                status: project.status,
+                progress: project.progress,
+                link_demo: project.link_demo,
+                repository: project.repository,
+                tanggal_mulai: project.tanggal_mulai.to_string(),
+                tanggal_selesai: project.tanggal_selesai.map(|d| d.to_string()),
+                stacks: stacks
+                    .into_iter()
+                    .map(
+                        |s| crate::app::features::projects::interface::dto::StackResponseDto {
+                            id: s.id,
+                            nama_stack: s.nama_stack,
+                        },
+                    )
+                    .collect(),
+                created_at: project.created_at.to_string(),
+                updated_at: project.updated_at.to_string(),
+                slug: project.slug,
+            },
+        })
+    }
+}
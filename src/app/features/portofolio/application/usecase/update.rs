@@ -1,3 +1,4 @@
+use crate::app::features::media::interface::dto::ResponsiveImageDto;
 use crate::app::features::portofolio::domain::entity::NewPortofolio;
 use crate::app::features::portofolio::domain::error::PortofolioError;
 use crate::app::features::portofolio::domain::repository::PortofolioRepository;
@@ -5,6 +6,7 @@ use crate::app::features::portofolio::interface::dto::{
     PortofolioResponseDto, UpdatePortofolioRequestDto,
 };
 use crate::app::features::projects::interface::dto::ProjectResponseDto;
+use crate::utils::slug::{SlugError, resolve_slug};
 use std::sync::Arc;
 
 #[derive(Clone)]
@@ -23,7 +25,7 @@ impl Execute {
         dto: UpdatePortofolioRequestDto,
     ) -> Result<PortofolioResponseDto, PortofolioError> {
         // Fetch existing
-        let (_existing, _, _) = self.repository.find_by_id(id).map_err(|e| {
+        let (existing, _, _) = self.repository.find_by_id(id).map_err(|e| {
             if e.to_string().to_lowercase().contains("not found") {
                 PortofolioError::NotFound("Portofolio not found".to_string())
             } else {
@@ -35,23 +37,23 @@ impl Execute {
 
         let mut validation_errors = ValidationErrors::new();
 
-        let slug = dto
-            .judul
-            .to_lowercase()
-            .replace(" ", "-")
-            .chars()
-            .filter(|c| c.is_alphanumeric() || *c == '-')
-            .collect::<String>();
-
-        if let Some(existing_portfolio) = self
-            .repository
-            .find_by_slug(slug.clone())
-            .map_err(|e| PortofolioError::System(e.to_string()))?
-        {
-            if existing_portfolio.id != id {
-                validation_errors.add("judul", ValidationError::new("Title already exists"));
+        // Only a new title or an explicit slug moves the portofolio to a new URL
+        let slug = if dto.slug.is_none() && dto.judul == existing.judul {
+            existing.slug.clone()
+        } else {
+            match resolve_slug(dto.slug.as_deref(), &dto.judul, "portofolio", |slug| {
+                self.repository
+                    .find_by_slug(slug.to_string())
+                    .map(|existing_portfolio| existing_portfolio.is_some_and(|p| p.id != id))
+            }) {
+                Ok(slug) => slug,
+                Err(SlugError::Invalid(msg)) => {
+                    validation_errors.add("slug", ValidationError::new(msg));
+                    String::new()
+                }
+                Err(SlugError::Lookup(e)) => return Err(PortofolioError::System(e.to_string())),
             }
-        }
+        };
 
         if !validation_errors.is_empty() {
             return Err(PortofolioError::Validation(validation_errors));
@@ -71,6 +73,11 @@ impl Execute {
             .update(id, new_data)
             .map_err(|e| PortofolioError::System(e.to_string()))?;
 
+        let images = ResponsiveImageDto::embedded_in(portfolio.deskripsi.as_deref(), |filenames| {
+            self.repository.get_images(filenames)
+        })
+        .map_err(|e| PortofolioError::System(e.to_string()))?;
+
         Ok(PortofolioResponseDto {
             id: portfolio.id,
             judul: portfolio.judul,
@@ -79,6 +86,7 @@ impl Execute {
             is_active: portfolio.is_active,
             created_at: portfolio.created_at.to_string(),
             updated_at: portfolio.updated_at.to_string(),
+            images,
             project: ProjectResponseDto {
                 id: project.id,
                 nama_projek: project.nama_projek,
@@ -1,5 +1,6 @@
 use super::super::super::projects::domain::entity::{Project, Stack};
 use super::entity::{NewPortofolio, Portofolio};
+use crate::app::features::media::domain::entity::{Media, MediaVariant};
 use diesel::QueryResult;
 
 pub trait PortofolioRepository: Send + Sync {
@@ -12,6 +13,12 @@ pub trait PortofolioRepository: Send + Sync {
         offset: i64,
         limit: i64,
     ) -> QueryResult<(Vec<(Portofolio, Project, Vec<Stack>)>, i64)>;
+    #[allow(clippy::type_complexity)]
+    fn find_all_active(
+        &self,
+        offset: i64,
+        limit: i64,
+    ) -> QueryResult<(Vec<(Portofolio, Project, Vec<Stack>)>, i64)>;
     fn find_by_id(&self, id: i32) -> QueryResult<(Portofolio, Project, Vec<Stack>)>;
     fn update(
         &self,
@@ -20,4 +27,7 @@ pub trait PortofolioRepository: Send + Sync {
     ) -> QueryResult<(Portofolio, Project, Vec<Stack>)>;
     fn delete(&self, id: i32) -> QueryResult<usize>;
     fn find_by_slug(&self, slug: String) -> QueryResult<Option<Portofolio>>;
+    /// Library images among `filenames`, with their variants
+    #[allow(clippy::type_complexity)]
+    fn get_images(&self, filenames: Vec<String>) -> QueryResult<Vec<(Media, Vec<MediaVariant>)>>;
 }
@@ -1,6 +1,8 @@
 use super::super::super::projects::domain::entity::{Project, Stack};
 use super::super::domain::entity::{NewPortofolio, Portofolio};
 use super::super::domain::repository::PortofolioRepository;
+use crate::app::features::media::domain::entity::{Media, MediaVariant};
+use crate::app::features::media::infrastructure::repository_impl::load_images;
 use crate::schema::{portofolios, project_stack, projects, stacks};
 use crate::utils::db::DbPool;
 use diesel::prelude::*;
@@ -77,6 +79,36 @@ impl PortofolioRepository for PortofolioRepositoryImpl {
         Ok((results, total_count))
     }
 
+    fn find_all_active(
+        &self,
+        offset: i64,
+        limit: i64,
+    ) -> QueryResult<(Vec<(Portofolio, Project, Vec<Stack>)>, i64)> {
+        let mut conn = self.pool.get().expect("Failed to get db connection");
+
+        let items: Vec<(Portofolio, Project)> = portofolios::table
+            .inner_join(projects::table)
+            .filter(portofolios::is_active.eq(true))
+            .select((Portofolio::as_select(), Project::as_select()))
+            .order(portofolios::created_at.desc())
+            .offset(offset)
+            .limit(limit)
+            .load(&mut conn)?;
+
+        let mut results = Vec::new();
+        for (portfolio, project) in items {
+            let stacks = self.get_stacks_for_project(&mut conn, project.id)?;
+            results.push((portfolio, project, stacks));
+        }
+
+        let total_count: i64 = portofolios::table
+            .filter(portofolios::is_active.eq(true))
+            .count()
+            .get_result(&mut conn)?;
+
+        Ok((results, total_count))
+    }
+
     fn find_by_id(&self, id: i32) -> QueryResult<(Portofolio, Project, Vec<Stack>)> {
         let mut conn = self.pool.get().expect("Failed to get db connection");
 
@@ -134,4 +166,10 @@ impl PortofolioRepository for PortofolioRepositoryImpl {
             .first(&mut conn)
             .optional()
     }
+
+    fn get_images(&self, filenames: Vec<String>) -> QueryResult<Vec<(Media, Vec<MediaVariant>)>> {
+        let mut conn = self.pool.get().expect("Failed to get db connection");
+
+        load_images(&mut conn, &filenames)
+    }
 }
@@ -2,10 +2,13 @@ use crate::app::features::portofolio::interface::dto::{
     CreatePortofolioRequestDto, PaginationRequestDto, PortofolioResponseDto,
     UpdatePortofolioRequestDto,
 };
+use crate::app::features::webhooks::domain::entity::WebhookEvent;
+use crate::app::features::webhooks::interface::controller::emit_event;
 use crate::utils::di::Container;
 use crate::utils::error_response::{ErrorResponse, map_string_error, map_validation_error};
 use crate::utils::success_response::{map_success_response, map_success_with_data};
 use actix_web::{HttpResponse, Responder, delete, get, post, put, web};
+use serde_json::json;
 use validator::Validate;
 
 #[utoipa::path(
@@ -30,8 +33,11 @@ pub async fn create_portfolio(
     }
 
     match data.portofolio_create_usecase.execute(payload.into_inner()) {
-        Ok(res) => HttpResponse::Created()
-            .json(map_success_with_data("Portofolio created".to_string(), res)),
+        Ok(res) => {
+            emit_event(&data, WebhookEvent::PortofolioCreated, &res).await;
+            HttpResponse::Created()
+                .json(map_success_with_data("Portofolio created".to_string(), res))
+        }
         Err(e) => match e {
             PortofolioError::Validation(e) => {
                 HttpResponse::BadRequest().json(map_validation_error(e))
@@ -135,6 +141,7 @@ pub async fn update_portfolio(
         .execute(id.into_inner(), payload.into_inner())
     {
         Ok(res) => {
+            emit_event(&data, WebhookEvent::PortofolioUpdated, &res).await;
             HttpResponse::Ok().json(map_success_with_data("Portofolio updated".to_string(), res))
         }
         Err(e) => match e {
@@ -164,8 +171,12 @@ pub async fn update_portfolio(
 #[delete("/portofolios/{id}")]
 pub async fn delete_portfolio(data: web::Data<Container>, id: web::Path<i32>) -> impl Responder {
     use crate::app::features::portofolio::domain::error::PortofolioError;
-    match data.portofolio_delete_usecase.execute(id.into_inner()) {
-        Ok(_) => HttpResponse::Ok().json(map_success_response("Portofolio deleted".to_string())),
+    let id = id.into_inner();
+    match data.portofolio_delete_usecase.execute(id) {
+        Ok(_) => {
+            emit_event(&data, WebhookEvent::PortofolioDeleted, json!({ "id": id })).await;
+            HttpResponse::Ok().json(map_success_response("Portofolio deleted".to_string()))
+        }
         Err(e) => match e {
             PortofolioError::Validation(e) => {
                 HttpResponse::BadRequest().json(map_validation_error(e))
@@ -177,3 +188,87 @@ pub async fn delete_portfolio(data: web::Data<Container>, id: web::Path<i32>) ->
         },
     }
 }
+
+#[utoipa::path(
+    path = "/api/public/portofolios",
+    tag = "Public",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number"),
+        ("per_page" = Option<i64>, Query, description = "Items per page")
+    ),
+    responses(
+        (status = 200, description = "List active portofolios", body = crate::utils::success_response::SuccessResponse<crate::app::features::portofolio::interface::dto::PaginatedResponseDto<PortofolioResponseDto>>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    security(())
+)]
+#[get("/portofolios")]
+pub async fn get_public_portfolios(
+    data: web::Data<Container>,
+    query: web::Query<PaginationRequestDto>,
+) -> impl Responder {
+    use crate::app::features::portofolio::domain::error::PortofolioError;
+    if let Err(e) = query.validate() {
+        return HttpResponse::BadRequest().json(map_validation_error(e));
+    }
+    match data
+        .portofolio_get_all_active_usecase
+        .execute(query.into_inner())
+    {
+        Ok(res) => {
+            HttpResponse::Ok().json(map_success_with_data("List portofolios".to_string(), res))
+        }
+        Err(e) => match e {
+            PortofolioError::Validation(e) => {
+                HttpResponse::BadRequest().json(map_validation_error(e))
+            }
+            PortofolioError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+            PortofolioError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    path = "/api/public/portofolios/{slug}",
+    tag = "Public",
+    params(
+        ("slug", description = "Portofolio slug")
+    ),
+    responses(
+        (status = 200, description = "Portofolio found", body = crate::utils::success_response::SuccessResponse<PortofolioResponseDto>),
+        (status = 301, description = "Slug was renamed; Location holds the current URL"),
+        (status = 404, description = "Portofolio not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    security(())
+)]
+#[get("/portofolios/{slug}")]
+pub async fn get_public_portfolio(
+    data: web::Data<Container>,
+    slug: web::Path<String>,
+) -> impl Responder {
+    use crate::app::features::portofolio::domain::error::PortofolioError;
+    use crate::app::features::slug_history::domain::entity::SlugEntity;
+    use crate::app::features::slug_history::interface::controller::redirect_or_not_found;
+
+    let slug = slug.into_inner();
+    match data.portofolio_get_by_slug_usecase.execute(slug.clone()) {
+        Ok(res) => {
+            HttpResponse::Ok().json(map_success_with_data("Portofolio found".to_string(), res))
+        }
+        Err(e) => match e {
+            PortofolioError::Validation(e) => {
+                HttpResponse::BadRequest().json(map_validation_error(e))
+            }
+            PortofolioError::NotFound(msg) => {
+                redirect_or_not_found(&data, SlugEntity::Portofolio, slug, msg).await
+            }
+            PortofolioError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
@@ -1,3 +1,4 @@
+use crate::app::features::media::interface::dto::ResponsiveImageDto;
 use crate::app::features::projects::interface::dto::ProjectResponseDto;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -9,6 +10,8 @@ pub struct CreatePortofolioRequestDto {
     pub project_id: i32,
     #[validate(length(min = 1, message = "Judul is required"))]
     pub judul: String,
+    /// Explicit slug; generated from the title when omitted
+    pub slug: Option<String>,
     #[validate(length(min = 1, message = "Deskripsi is required"))]
     pub deskripsi: String,
     pub is_active: bool,
@@ -20,6 +23,8 @@ pub struct UpdatePortofolioRequestDto {
     pub project_id: i32,
     #[validate(length(min = 1, message = "Judul is required"))]
     pub judul: String,
+    /// Explicit slug; when omitted the current slug is kept unless the title changes
+    pub slug: Option<String>,
     #[validate(length(min = 1, message = "Deskripsi is required"))]
     pub deskripsi: String,
     pub is_active: bool,
@@ -27,7 +32,9 @@ pub struct UpdatePortofolioRequestDto {
 
 #[derive(Deserialize, Serialize, Validate, ToSchema)]
 pub struct PaginationRequestDto {
+    #[validate(range(min = 1, message = "Must be at least 1"))]
     pub page: Option<i64>,
+    #[validate(range(min = 1, max = 100, message = "Must be between 1 and 100"))]
     pub per_page: Option<i64>,
 }
 
@@ -54,5 +61,7 @@ pub struct PortofolioResponseDto {
     pub is_active: bool,
     pub created_at: String,
     pub updated_at: String,
+    /// Library images embedded in `deskripsi`, in order of appearance
+    pub images: Vec<ResponsiveImageDto>,
     pub project: ProjectResponseDto,
 }
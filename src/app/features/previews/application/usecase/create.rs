@@ -0,0 +1,67 @@
+use crate::app::features::previews::domain::entity::NewPreviewToken;
+use crate::app::features::previews::domain::error::PreviewError;
+use crate::app::features::previews::domain::repository::PreviewRepository;
+use crate::app::features::previews::interface::dto::{
+    CreatePreviewTokenRequestDto, PreviewTokenResponseDto,
+};
+use crate::utils::token::create_preview_token;
+use chrono::{Duration, SubsecRound, Utc};
+use std::sync::Arc;
+use validator::Validate;
+
+pub const DEFAULT_PREVIEW_HOURS: i64 = 72;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn PreviewRepository + Send + Sync>,
+    jwt_secret: String,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn PreviewRepository + Send + Sync>, jwt_secret: String) -> Self {
+        Self {
+            repository,
+            jwt_secret,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        blog_id: i32,
+        dto: CreatePreviewTokenRequestDto,
+    ) -> Result<PreviewTokenResponseDto, PreviewError> {
+        dto.validate().map_err(PreviewError::Validation)?;
+
+        if !self
+            .repository
+            .blog_exists(blog_id)
+            .map_err(|e| PreviewError::System(e.to_string()))?
+        {
+            return Err(PreviewError::NotFound("Blog not found".to_string()));
+        }
+
+        // Whole seconds, so the stored expiry matches the token's `exp`
+        let hours = dto.expires_in_hours.unwrap_or(DEFAULT_PREVIEW_HOURS);
+        let expires_at = (Utc::now() + Duration::hours(hours)).trunc_subsecs(0);
+
+        let token = self
+            .repository
+            .create_preview_token(NewPreviewToken {
+                blog_id,
+                expires_at: expires_at.naive_utc(),
+            })
+            .map_err(|e| PreviewError::System(e.to_string()))?;
+        let signed = create_preview_token(
+            blog_id,
+            token.id,
+            expires_at.timestamp() as u64,
+            &self.jwt_secret,
+        );
+
+        Ok(PreviewTokenResponseDto::from_token(
+            token,
+            Some(signed),
+            Utc::now().naive_utc(),
+        ))
+    }
+}
@@ -0,0 +1,40 @@
+use crate::app::features::previews::domain::error::PreviewError;
+use crate::app::features::previews::domain::repository::PreviewRepository;
+use crate::app::features::previews::interface::dto::PreviewTokenResponseDto;
+use chrono::Utc;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn PreviewRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn PreviewRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        blog_id: i32,
+    ) -> Result<Vec<PreviewTokenResponseDto>, PreviewError> {
+        if !self
+            .repository
+            .blog_exists(blog_id)
+            .map_err(|e| PreviewError::System(e.to_string()))?
+        {
+            return Err(PreviewError::NotFound("Blog not found".to_string()));
+        }
+
+        let now = Utc::now().naive_utc();
+        let tokens = self
+            .repository
+            .get_preview_tokens(blog_id)
+            .map_err(|e| PreviewError::System(e.to_string()))?;
+
+        Ok(tokens
+            .into_iter()
+            .map(|t| PreviewTokenResponseDto::from_token(t, None, now))
+            .collect())
+    }
+}
@@ -0,0 +1,4 @@
+pub mod create;
+pub mod get_all;
+pub mod revoke;
+pub mod view;
@@ -0,0 +1,41 @@
+use crate::app::features::previews::domain::error::PreviewError;
+use crate::app::features::previews::domain::repository::PreviewRepository;
+use crate::app::features::previews::interface::dto::PreviewTokenResponseDto;
+use chrono::Utc;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn PreviewRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn PreviewRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Revoking twice is not an error; the first revocation time is kept.
+    pub async fn execute(
+        &self,
+        blog_id: i32,
+        id: i32,
+    ) -> Result<PreviewTokenResponseDto, PreviewError> {
+        let found = self
+            .repository
+            .get_preview_token(id)
+            .map_err(|e| PreviewError::System(e.to_string()))?;
+        if found.is_none_or(|t| t.blog_id != blog_id) {
+            return Err(PreviewError::NotFound(
+                "Preview token not found".to_string(),
+            ));
+        }
+
+        let now = Utc::now().naive_utc();
+        let revoked = self
+            .repository
+            .revoke_preview_token(id, now)
+            .map_err(|e| PreviewError::System(e.to_string()))?;
+
+        Ok(PreviewTokenResponseDto::from_token(revoked, None, now))
+    }
+}
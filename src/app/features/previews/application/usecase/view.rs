@@ -0,0 +1,56 @@
+use crate::app::features::blog::application::blog_usecase;
+use crate::app::features::blog::interface::dto::BlogResponseDto;
+use crate::app::features::previews::domain::error::PreviewError;
+use crate::app::features::previews::domain::repository::PreviewRepository;
+use crate::utils::token::verify_preview_token;
+use chrono::Utc;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn PreviewRepository + Send + Sync>,
+    get_blog: blog_usecase::get::Execute,
+    jwt_secret: String,
+}
+
+impl Execute {
+    pub fn new(
+        repository: Arc<dyn PreviewRepository + Send + Sync>,
+        get_blog: blog_usecase::get::Execute,
+        jwt_secret: String,
+    ) -> Self {
+        Self {
+            repository,
+            get_blog,
+            jwt_secret,
+        }
+    }
+
+    /// The post behind a preview link, whatever its status.
+    pub async fn execute(&self, token: String) -> Result<BlogResponseDto, PreviewError> {
+        let invalid = || PreviewError::Unauthorized("Invalid or expired preview link".to_string());
+
+        let claims = verify_preview_token(&token, &self.jwt_secret).map_err(|_| invalid())?;
+        let (Ok(blog_id), Ok(token_id)) = (claims.sub.parse::<i32>(), claims.jti.parse::<i32>())
+        else {
+            return Err(invalid());
+        };
+
+        // The signature alone cannot tell a revoked link from a live one
+        let stored = self
+            .repository
+            .get_preview_token(token_id)
+            .map_err(|e| PreviewError::System(e.to_string()))?;
+        if !stored.is_some_and(|t| t.blog_id == blog_id && t.is_active(Utc::now().naive_utc())) {
+            return Err(invalid());
+        }
+
+        self.get_blog.execute(blog_id).await.map_err(|e| {
+            if e.contains("not found") {
+                PreviewError::NotFound(e)
+            } else {
+                PreviewError::System(e)
+            }
+        })
+    }
+}
@@ -0,0 +1,27 @@
+use crate::schema::blog_preview_tokens;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+/// A minted preview link. Only its id travels in the signed token (`jti`).
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone, PartialEq)]
+#[diesel(table_name = blog_preview_tokens)]
+pub struct PreviewToken {
+    pub id: i32,
+    pub blog_id: i32,
+    pub expires_at: NaiveDateTime,
+    pub revoked_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+impl PreviewToken {
+    pub fn is_active(&self, now: NaiveDateTime) -> bool {
+        self.revoked_at.is_none() && self.expires_at > now
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = blog_preview_tokens)]
+pub struct NewPreviewToken {
+    pub blog_id: i32,
+    pub expires_at: NaiveDateTime,
+}
@@ -0,0 +1,16 @@
+use validator::ValidationErrors;
+
+#[derive(Debug)]
+pub enum PreviewError {
+    Validation(ValidationErrors),
+    System(String),
+    NotFound(String),
+    /// The preview link is malformed, expired or revoked
+    Unauthorized(String),
+}
+
+impl From<String> for PreviewError {
+    fn from(s: String) -> Self {
+        PreviewError::System(s)
+    }
+}
@@ -0,0 +1,13 @@
+use crate::app::features::previews::domain::entity::{NewPreviewToken, PreviewToken};
+use chrono::NaiveDateTime;
+use diesel::QueryResult;
+
+pub trait PreviewRepository {
+    fn blog_exists(&self, blog_id: i32) -> QueryResult<bool>;
+    fn create_preview_token(&self, token: NewPreviewToken) -> QueryResult<PreviewToken>;
+    fn get_preview_token(&self, id: i32) -> QueryResult<Option<PreviewToken>>;
+    /// Tokens minted for `blog_id`, newest first.
+    fn get_preview_tokens(&self, blog_id: i32) -> QueryResult<Vec<PreviewToken>>;
+    /// Stamps `revoked_at` unless already set; the first revocation time is kept.
+    fn revoke_preview_token(&self, id: i32, now: NaiveDateTime) -> QueryResult<PreviewToken>;
+}
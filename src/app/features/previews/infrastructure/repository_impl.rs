@@ -0,0 +1,72 @@
+use crate::app::features::previews::domain::entity::{NewPreviewToken, PreviewToken};
+use crate::app::features::previews::domain::repository::PreviewRepository;
+use crate::schema::{blog, blog_preview_tokens};
+use crate::utils::db::DbPool;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+#[derive(Clone)]
+pub struct PreviewRepositoryImpl {
+    pub pool: DbPool,
+}
+
+impl PreviewRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl PreviewRepository for PreviewRepositoryImpl {
+    fn blog_exists(&self, blog_id: i32) -> QueryResult<bool> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::select(diesel::dsl::exists(blog::table.find(blog_id))).get_result(&mut conn)
+    }
+    fn create_preview_token(&self, token: NewPreviewToken) -> QueryResult<PreviewToken> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::insert_into(blog_preview_tokens::table)
+            .values(token)
+            .get_result(&mut conn)
+    }
+    fn get_preview_token(&self, id: i32) -> QueryResult<Option<PreviewToken>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        blog_preview_tokens::table
+            .find(id)
+            .first::<PreviewToken>(&mut conn)
+            .optional()
+    }
+    fn get_preview_tokens(&self, blog_id: i32) -> QueryResult<Vec<PreviewToken>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        blog_preview_tokens::table
+            .filter(blog_preview_tokens::blog_id.eq(blog_id))
+            .order(blog_preview_tokens::id.desc())
+            .load::<PreviewToken>(&mut conn)
+    }
+    fn revoke_preview_token(&self, id: i32, now: NaiveDateTime) -> QueryResult<PreviewToken> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::update(
+            blog_preview_tokens::table
+                .find(id)
+                .filter(blog_preview_tokens::revoked_at.is_null()),
+        )
+        .set(blog_preview_tokens::revoked_at.eq(now))
+        .execute(&mut conn)?;
+        blog_preview_tokens::table
+            .find(id)
+            .first::<PreviewToken>(&mut conn)
+    }
+}
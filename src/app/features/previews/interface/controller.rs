@@ -0,0 +1,155 @@
+use crate::app::features::blog::interface::dto::BlogResponseDto;
+use crate::app::features::previews::domain::error::PreviewError;
+use crate::app::features::previews::interface::dto::{
+    CreatePreviewTokenRequestDto, PreviewTokenResponseDto,
+};
+use crate::utils::di::Container;
+use crate::utils::error_response::{ErrorResponse, map_string_error, map_validation_error};
+use crate::utils::success_response::map_success_with_data;
+use actix_web::http::header::{CacheControl, CacheDirective};
+use actix_web::{HttpResponse, Responder, delete, get, post, web};
+
+fn preview_error_response(e: PreviewError) -> HttpResponse {
+    match e {
+        PreviewError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+        PreviewError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+        PreviewError::Unauthorized(msg) => HttpResponse::Unauthorized().json(map_string_error(msg)),
+        PreviewError::System(msg) => {
+            HttpResponse::InternalServerError().json(map_string_error(msg))
+        }
+    }
+}
+
+#[utoipa::path(
+    path = "/app/blogs/{id}/previews",
+    tag = "Previews",
+    params(
+        ("id" = i32, Path, description = "Blog id")
+    ),
+    request_body = CreatePreviewTokenRequestDto,
+    responses(
+        (status = 201, description = "Preview link minted; `token` is only shown this once", body = crate::utils::success_response::SuccessResponse<PreviewTokenResponseDto>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 404, description = "Blog not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[post("/blogs/{id}/previews")]
+pub async fn create_preview_token(
+    container: web::Data<Container>,
+    id: web::Path<i32>,
+    payload: web::Json<CreatePreviewTokenRequestDto>,
+) -> impl Responder {
+    match container
+        .create_preview_token_usecase
+        .execute(id.into_inner(), payload.into_inner())
+        .await
+    {
+        Ok(token) => HttpResponse::Created().json(map_success_with_data(
+            "Preview token created successfully".to_string(),
+            token,
+        )),
+        Err(e) => preview_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    path = "/app/blogs/{id}/previews",
+    tag = "Previews",
+    params(
+        ("id" = i32, Path, description = "Blog id")
+    ),
+    responses(
+        (status = 200, description = "Preview links of the blog, newest first", body = crate::utils::success_response::SuccessResponse<Vec<PreviewTokenResponseDto>>),
+        (status = 404, description = "Blog not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[get("/blogs/{id}/previews")]
+pub async fn get_preview_tokens(
+    container: web::Data<Container>,
+    id: web::Path<i32>,
+) -> impl Responder {
+    match container
+        .get_preview_tokens_usecase
+        .execute(id.into_inner())
+        .await
+    {
+        Ok(tokens) => HttpResponse::Ok().json(map_success_with_data(
+            "Preview tokens fetched successfully".to_string(),
+            tokens,
+        )),
+        Err(e) => preview_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    path = "/app/blogs/{id}/previews/{preview_id}",
+    tag = "Previews",
+    params(
+        ("id" = i32, Path, description = "Blog id"),
+        ("preview_id" = i32, Path, description = "Preview token id")
+    ),
+    responses(
+        (status = 200, description = "Preview link revoked", body = crate::utils::success_response::SuccessResponse<PreviewTokenResponseDto>),
+        (status = 404, description = "Preview token not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[delete("/blogs/{id}/previews/{preview_id}")]
+pub async fn revoke_preview_token(
+    container: web::Data<Container>,
+    path: web::Path<(i32, i32)>,
+) -> impl Responder {
+    let (id, preview_id) = path.into_inner();
+    match container
+        .revoke_preview_token_usecase
+        .execute(id, preview_id)
+        .await
+    {
+        Ok(token) => HttpResponse::Ok().json(map_success_with_data(
+            "Preview token revoked successfully".to_string(),
+            token,
+        )),
+        Err(e) => preview_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    path = "/api/public/preview/{token}",
+    tag = "Previews",
+    params(
+        ("token" = String, Path, description = "Signed preview token")
+    ),
+    responses(
+        (status = 200, description = "The post, whatever its status", body = crate::utils::success_response::SuccessResponse<BlogResponseDto>),
+        (status = 401, description = "Invalid, expired or revoked preview link", body = ErrorResponse),
+        (status = 404, description = "Blog not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    security(())
+)]
+#[get("/preview/{token}")]
+pub async fn get_preview(
+    container: web::Data<Container>,
+    token: web::Path<String>,
+) -> impl Responder {
+    match container
+        .get_preview_usecase
+        .execute(token.into_inner())
+        .await
+    {
+        // Drafts must not end up in shared caches or search results
+        Ok(blog) => HttpResponse::Ok()
+            .insert_header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::NoStore,
+            ]))
+            .insert_header(("X-Robots-Tag", "noindex"))
+            .json(map_success_with_data(
+                "Preview fetched successfully".to_string(),
+                blog,
+            )),
+        Err(e) => preview_error_response(e),
+    }
+}
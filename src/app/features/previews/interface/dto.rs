@@ -0,0 +1,40 @@
+use crate::app::features::previews::domain::entity::PreviewToken;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Serialize, Validate, ToSchema, Default)]
+pub struct CreatePreviewTokenRequestDto {
+    /// Lifetime of the link, 72 hours when omitted
+    #[validate(range(min = 1, max = 720, message = "Must be between 1 and 720 hours"))]
+    pub expires_in_hours: Option<i64>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct PreviewTokenResponseDto {
+    pub id: i32,
+    pub blog_id: i32,
+    /// Signed token for `/api/public/preview/{token}`; only returned when minted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    pub expires_at: String,
+    pub revoked_at: Option<String>,
+    /// Neither expired nor revoked
+    pub active: bool,
+    pub created_at: String,
+}
+
+impl PreviewTokenResponseDto {
+    pub fn from_token(token: PreviewToken, signed: Option<String>, now: NaiveDateTime) -> Self {
+        Self {
+            active: token.is_active(now),
+            id: token.id,
+            blog_id: token.blog_id,
+            token: signed,
+            expires_at: token.expires_at.to_string(),
+            revoked_at: token.revoked_at.map(|t| t.to_string()),
+            created_at: token.created_at.to_string(),
+        }
+    }
+}
@@ -1,4 +1,6 @@
 use crate::app::features::profile::interface::dto::{ProfileResponseDto, UpsertProfileRequestDto};
+use crate::app::features::webhooks::domain::entity::WebhookEvent;
+use crate::app::features::webhooks::interface::controller::emit_event;
 use crate::utils::di::Container;
 use crate::utils::error_response::{ErrorResponse, map_string_error, map_validation_error};
 use crate::utils::success_response::{SuccessResponse, map_success_with_data};
@@ -54,10 +56,39 @@ pub async fn upsert_profile(
         .upsert_profile_usecase
         .execute(payload.into_inner())
     {
-        Ok(profile) => HttpResponse::Ok().json(map_success_with_data(
-            "Profile upserted successfully".to_string(),
-            profile,
-        )),
+        Ok(profile) => {
+            emit_event(&container, WebhookEvent::ProfileUpserted, &profile).await;
+            HttpResponse::Ok().json(map_success_with_data(
+                "Profile upserted successfully".to_string(),
+                profile,
+            ))
+        }
+        Err(e) => HttpResponse::InternalServerError().json(map_string_error(e)),
+    }
+}
+
+#[utoipa::path(
+    path = "/api/public/profile",
+    tag = "Public",
+    responses(
+        (status = 200, description = "Get public profile", body = crate::utils::success_response::SuccessResponse<Option<ProfileResponseDto>>),
+        (status = 500, description = "Internal server error")
+    ),
+    security(())
+)]
+#[get("/profile")]
+pub async fn get_public_profile(container: web::Data<Container>) -> impl Responder {
+    match container.get_profile_usecase.execute() {
+        Ok(profile_opt) => match profile_opt {
+            Some(profile) => HttpResponse::Ok().json(map_success_with_data(
+                "Profile fetched successfully".to_string(),
+                profile,
+            )),
+            None => HttpResponse::Ok().json(SuccessResponse {
+                message: "Profile belum dibuat".to_string(),
+                data: Some(None::<ProfileResponseDto>),
+            }),
+        },
         Err(e) => HttpResponse::InternalServerError().json(map_string_error(e)),
     }
 }
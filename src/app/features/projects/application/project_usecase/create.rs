@@ -4,6 +4,7 @@ use crate::app::features::projects::domain::repository::ProjectRepository;
 use crate::app::features::projects::interface::dto::{
     CreateProjectRequestDto, ProjectResponseDto, StackResponseDto,
 };
+use crate::utils::slug::{SlugError, resolve_slug};
 use std::sync::Arc;
 use validator::{Validate, ValidationError, ValidationErrors};
 
@@ -38,25 +39,18 @@ impl Execute {
             );
         }
 
-        let slug = dto
-            .nama_projek
-            .to_lowercase()
-            .replace(" ", "-")
-            .chars()
-            .filter(|c| c.is_alphanumeric() || *c == '-')
-            .collect::<String>();
-
-        if self
-            .repository
-            .get_project_by_slug(slug.clone())
-            .map_err(|e| ProjectError::System(e.to_string()))?
-            .is_some()
-        {
-            validation_errors.add(
-                "nama_projek",
-                ValidationError::new("Slug derived from name already exists"),
-            );
-        }
+        let slug = match resolve_slug(dto.slug.as_deref(), &dto.nama_projek, "project", |slug| {
+            self.repository
+                .get_project_by_slug(slug.to_string())
+                .map(|existing| existing.is_some())
+        }) {
+            Ok(slug) => slug,
+            Err(SlugError::Invalid(msg)) => {
+                validation_errors.add("slug", ValidationError::new(msg));
+                String::new()
+            }
+            Err(SlugError::Lookup(e)) => return Err(ProjectError::System(e.to_string())),
+        };
 
         if !validation_errors.is_empty() {
             return Err(ProjectError::Validation(validation_errors));
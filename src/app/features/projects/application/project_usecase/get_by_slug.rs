@@ -0,0 +1,55 @@
+use crate::app::features::projects::domain::error::ProjectError;
+use crate::app::features::projects::domain::repository::ProjectRepository;
+use crate::app::features::projects::interface::dto::{ProjectResponseDto, StackResponseDto};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn ProjectRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn ProjectRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Fetches a project by slug for the public API. Projects still in
+    /// `DRAFT` are reported as not found.
+    pub fn execute(&self, slug: String) -> Result<ProjectResponseDto, ProjectError> {
+        let project = self
+            .repository
+            .get_project_by_slug(slug)
+            .map_err(|e| ProjectError::System(e.to_string()))?
+            .filter(|p| p.status != "DRAFT")
+            .ok_or_else(|| ProjectError::NotFound("Project not found".to_string()))?;
+
+        let stacks = self
+            .repository
+            .get_stacks_by_project_id(project.id)
+            .map_err(|e| ProjectError::System(e.to_string()))?;
+
+        let stack_dtos = stacks
+            .into_iter()
+            .map(|s| StackResponseDto {
+                id: s.id,
+                nama_stack: s.nama_stack,
+            })
+            .collect();
+
+        Ok(ProjectResponseDto {
+            id: project.id,
+            nama_projek: project.nama_projek,
+            deskripsi: project.deskripsi,
+            status: project.status,
+            progress: project.progress,
+            link_demo: project.link_demo,
+            repository: project.repository,
+            tanggal_mulai: project.tanggal_mulai.to_string(),
+            tanggal_selesai: project.tanggal_selesai.map(|d| d.to_string()),
+            stacks: stack_dtos,
+            created_at: project.created_at.to_string(),
+            updated_at: project.updated_at.to_string(),
+            slug: project.slug,
+        })
+    }
+}
@@ -2,4 +2,6 @@ pub mod create;
 pub mod delete;
 pub mod get;
 pub mod get_all;
+pub mod get_all_public;
+pub mod get_by_slug;
 pub mod update;
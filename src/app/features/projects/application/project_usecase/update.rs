@@ -2,6 +2,7 @@ use crate::app::features::projects::domain::entity::NewProject;
 use crate::app::features::projects::domain::error::ProjectError;
 use crate::app::features::projects::domain::repository::ProjectRepository;
 use crate::app::features::projects::interface::dto::UpdateProjectRequestDto;
+use crate::utils::slug::{SlugError, resolve_slug};
 use std::sync::Arc;
 use validator::{Validate, ValidationError, ValidationErrors};
 
@@ -49,26 +50,23 @@ impl Execute {
             }
         }
 
-        let slug = dto
-            .nama_projek
-            .to_lowercase()
-            .replace(" ", "-")
-            .chars()
-            .filter(|c| c.is_alphanumeric() || *c == '-')
-            .collect::<String>();
-
-        if let Some(existing_proj) = self
-            .repository
-            .get_project_by_slug(slug.clone())
-            .map_err(|e| ProjectError::System(e.to_string()))?
-        {
-            if existing_proj.id != id {
-                validation_errors.add(
-                    "nama_projek",
-                    ValidationError::new("Slug derived from name already exists"),
-                );
+        // Only a new name or an explicit slug moves the project to a new URL
+        let slug = if dto.slug.is_none() && dto.nama_projek == existing.nama_projek {
+            existing.slug.clone()
+        } else {
+            match resolve_slug(dto.slug.as_deref(), &dto.nama_projek, "project", |slug| {
+                self.repository
+                    .get_project_by_slug(slug.to_string())
+                    .map(|existing_proj| existing_proj.is_some_and(|p| p.id != id))
+            }) {
+                Ok(slug) => slug,
+                Err(SlugError::Invalid(msg)) => {
+                    validation_errors.add("slug", ValidationError::new(msg));
+                    String::new()
+                }
+                Err(SlugError::Lookup(e)) => return Err(ProjectError::System(e.to_string())),
             }
-        }
+        };
 
         if !validation_errors.is_empty() {
             return Err(ProjectError::Validation(validation_errors));
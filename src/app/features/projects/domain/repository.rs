@@ -4,6 +4,7 @@ use diesel::QueryResult;
 pub trait ProjectRepository {
     // Project CRUD
     fn get_all_projects(&self, limit: i64, offset: i64) -> QueryResult<(Vec<Project>, i64)>;
+    fn get_all_public_projects(&self, limit: i64, offset: i64) -> QueryResult<(Vec<Project>, i64)>;
     fn get_project_by_id(&self, id: i32) -> QueryResult<Option<Project>>;
     fn create_project(&self, project: NewProject) -> QueryResult<Project>;
     fn update_project(&self, id: i32, project: NewProject) -> QueryResult<Project>;
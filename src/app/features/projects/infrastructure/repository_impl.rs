@@ -33,6 +33,24 @@ impl ProjectRepository for ProjectRepositoryImpl {
         Ok((items, total_count))
     }
 
+    fn get_all_public_projects(&self, limit: i64, offset: i64) -> QueryResult<(Vec<Project>, i64)> {
+        let mut conn = self.pool.get().unwrap();
+
+        let items = projects::table
+            .filter(projects::status.ne("DRAFT"))
+            .limit(limit)
+            .offset(offset)
+            .order(projects::created_at.desc())
+            .load::<Project>(&mut conn)?;
+
+        let total_count: i64 = projects::table
+            .filter(projects::status.ne("DRAFT"))
+            .count()
+            .get_result(&mut conn)?;
+
+        Ok((items, total_count))
+    }
+
     fn get_project_by_id(&self, id: i32) -> QueryResult<Option<Project>> {
         let mut conn = self.pool.get().unwrap();
         projects::table
@@ -2,12 +2,15 @@ use crate::app::features::projects::interface::dto::{
     CreateProjectRequestDto, CreateStackRequestDto, PaginationRequestDto, ProjectResponseDto,
     StackResponseDto, UpdateProjectRequestDto, UpdateStackRequestDto,
 };
+use crate::app::features::webhooks::domain::entity::WebhookEvent;
+use crate::app::features::webhooks::interface::controller::emit_event;
 use crate::utils::di::Container;
 use crate::utils::{
     error_response::{ErrorResponse, map_validation_error},
     success_response::SuccessResponse,
 };
 use actix_web::{HttpResponse, Responder, delete, get, post, put, web};
+use serde_json::json;
 use validator::Validate;
 
 // --- Projects ---
@@ -31,10 +34,13 @@ pub async fn create_project(
     use crate::app::features::projects::domain::error::ProjectError;
 
     match data.create_project_usecase.execute(payload.into_inner()) {
-        Ok(res) => HttpResponse::Created().json(SuccessResponse::new(
-            "Project created successfully".to_string(),
-            Some(res),
-        )),
+        Ok(res) => {
+            emit_event(&data, WebhookEvent::ProjectCreated, &res).await;
+            HttpResponse::Created().json(SuccessResponse::new(
+                "Project created successfully".to_string(),
+                Some(res),
+            ))
+        }
         Err(e) => match e {
             ProjectError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
             ProjectError::NotFound(msg) => {
@@ -135,10 +141,13 @@ pub async fn update_project(
         .update_project_usecase
         .execute(id, payload.into_inner())
     {
-        Ok(res) => HttpResponse::Ok().json(SuccessResponse::new(
-            "Project updated successfully".to_string(),
-            Some(res),
-        )),
+        Ok(res) => {
+            emit_event(&data, WebhookEvent::ProjectUpdated, &res).await;
+            HttpResponse::Ok().json(SuccessResponse::new(
+                "Project updated successfully".to_string(),
+                Some(res),
+            ))
+        }
         Err(e) => match e {
             ProjectError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
             ProjectError::NotFound(msg) => {
@@ -166,10 +175,13 @@ pub async fn update_project(
 pub async fn delete_project(data: web::Data<Container>, path: web::Path<i32>) -> impl Responder {
     let id = path.into_inner();
     match data.delete_project_usecase.execute(id) {
-        Ok(_) => HttpResponse::Ok().json(SuccessResponse::<()>::new(
-            "Project deleted successfully".to_string(),
-            None,
-        )),
+        Ok(_) => {
+            emit_event(&data, WebhookEvent::ProjectDeleted, json!({ "id": id })).await;
+            HttpResponse::Ok().json(SuccessResponse::<()>::new(
+                "Project deleted successfully".to_string(),
+                None,
+            ))
+        }
         Err(e) => {
             if e.contains("not found") {
                 HttpResponse::NotFound().json(crate::utils::error_response::map_string_error(e))
@@ -352,3 +364,79 @@ pub async fn delete_stack(data: web::Data<Container>, path: web::Path<i32>) -> i
         }
     }
 }
+
+// --- Public ---
+
+#[utoipa::path(
+    path = "/api/public/projects",
+    tag = "Public",
+    params(
+        PaginationRequestDto
+    ),
+    responses(
+        (status = 200, description = "List public projects", body = crate::utils::success_response::SuccessResponse<crate::app::features::projects::interface::dto::PaginatedResponseDto<ProjectResponseDto>>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    security(())
+)]
+#[get("/projects")]
+pub async fn get_public_projects(
+    data: web::Data<Container>,
+    query: web::Query<PaginationRequestDto>,
+) -> impl Responder {
+    if let Err(e) = query.validate() {
+        return HttpResponse::BadRequest().json(map_validation_error(e));
+    }
+    let page = query.page.unwrap_or(1);
+    let per_page = query.per_page.unwrap_or(10);
+
+    match data.get_public_projects_usecase.execute(page, per_page) {
+        Ok(res) => HttpResponse::Ok().json(SuccessResponse::new(
+            "Projects retrieved successfully".to_string(),
+            Some(res),
+        )),
+        Err(e) => HttpResponse::InternalServerError()
+            .json(crate::utils::error_response::map_string_error(e)),
+    }
+}
+
+#[utoipa::path(
+    path = "/api/public/projects/{slug}",
+    tag = "Public",
+    params(
+        ("slug", description = "Project slug")
+    ),
+    responses(
+        (status = 200, description = "Project found", body = crate::utils::success_response::SuccessResponse<ProjectResponseDto>),
+        (status = 301, description = "Slug was renamed; Location holds the current URL"),
+        (status = 404, description = "Project not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    security(())
+)]
+#[get("/projects/{slug}")]
+pub async fn get_public_project(
+    data: web::Data<Container>,
+    path: web::Path<String>,
+) -> impl Responder {
+    use crate::app::features::projects::domain::error::ProjectError;
+    use crate::app::features::slug_history::domain::entity::SlugEntity;
+    use crate::app::features::slug_history::interface::controller::redirect_or_not_found;
+
+    let slug = path.into_inner();
+    match data.get_project_by_slug_usecase.execute(slug.clone()) {
+        Ok(res) => HttpResponse::Ok().json(SuccessResponse::new(
+            "Project retrieved successfully".to_string(),
+            Some(res),
+        )),
+        Err(e) => match e {
+            ProjectError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+            ProjectError::NotFound(msg) => {
+                redirect_or_not_found(&data, SlugEntity::Project, slug, msg).await
+            }
+            ProjectError::System(msg) => HttpResponse::InternalServerError()
+                .json(crate::utils::error_response::map_string_error(msg)),
+        },
+    }
+}
@@ -16,7 +16,9 @@ pub struct UpdateStackRequestDto {
 
 #[derive(Deserialize, Serialize, Validate, ToSchema, IntoParams)]
 pub struct PaginationRequestDto {
+    #[validate(range(min = 1, message = "Must be at least 1"))]
     pub page: Option<i64>,
+    #[validate(range(min = 1, max = 100, message = "Must be between 1 and 100"))]
     pub per_page: Option<i64>,
 }
 
@@ -30,6 +32,8 @@ pub struct StackResponseDto {
 pub struct CreateProjectRequestDto {
     #[validate(length(min = 1, message = "Nama projek is required"))]
     pub nama_projek: String,
+    /// Explicit slug; generated from the name when omitted
+    pub slug: Option<String>,
     #[validate(length(min = 1, message = "Deskripsi is required"))]
     pub deskripsi: String,
     #[validate(custom(function = "validate_status"))]
@@ -48,6 +52,8 @@ pub struct CreateProjectRequestDto {
 pub struct UpdateProjectRequestDto {
     #[validate(length(min = 1, message = "Nama projek is required"))]
     pub nama_projek: String,
+    /// Explicit slug; when omitted the current slug is kept unless the name changes
+    pub slug: Option<String>,
     #[validate(length(min = 1, message = "Deskripsi is required"))]
     pub deskripsi: String,
     #[validate(custom(function = "validate_status"))]
@@ -0,0 +1,119 @@
+use crate::app::features::series::domain::entity::NewSeries;
+use crate::app::features::series::domain::error::SeriesError;
+use crate::app::features::series::domain::repository::SeriesRepository;
+use crate::app::features::series::interface::dto::{CreateSeriesRequestDto, SeriesResponseDto};
+use crate::utils::slug::{SlugError, resolve_slug};
+use std::borrow::Cow;
+use std::sync::Arc;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+/// Adds a `blog_ids` error when an id repeats, has no post, or belongs to a
+/// series other than `series_id`.
+pub(super) fn check_blog_ids(
+    repository: &Arc<dyn SeriesRepository + Send + Sync>,
+    blog_ids: &[i32],
+    series_id: Option<i32>,
+    errors: &mut ValidationErrors,
+) -> Result<(), SeriesError> {
+    let mut seen = Vec::with_capacity(blog_ids.len());
+    if let Some(duplicate) = blog_ids.iter().find(|id| {
+        let repeated = seen.contains(*id);
+        seen.push(**id);
+        repeated
+    }) {
+        errors.add(
+            "blog_ids",
+            ValidationError::new("duplicate").with_message(Cow::Owned(format!(
+                "Blog {} is listed more than once",
+                duplicate
+            ))),
+        );
+        return Ok(());
+    }
+
+    let missing = repository
+        .get_missing_blog_ids(blog_ids.to_vec())
+        .map_err(|e| SeriesError::System(e.to_string()))?;
+    if let Some(id) = missing.first() {
+        errors.add(
+            "blog_ids",
+            ValidationError::new("not_found")
+                .with_message(Cow::Owned(format!("Blog {} not found", id))),
+        );
+        return Ok(());
+    }
+
+    let taken = repository
+        .get_blog_memberships(blog_ids.to_vec(), series_id)
+        .map_err(|e| SeriesError::System(e.to_string()))?;
+    if let Some((id, series)) = taken.first() {
+        errors.add(
+            "blog_ids",
+            ValidationError::new("taken").with_message(Cow::Owned(format!(
+                "Blog {} is already part of series \"{}\"",
+                id, series.title
+            ))),
+        );
+    }
+    Ok(())
+}
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn SeriesRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn SeriesRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        dto: CreateSeriesRequestDto,
+    ) -> Result<SeriesResponseDto, SeriesError> {
+        let mut validation_errors = dto.validate().err().unwrap_or_default();
+
+        let slug = match resolve_slug(dto.slug.as_deref(), &dto.title, "series", |slug| {
+            self.repository
+                .get_series_by_slug(slug.to_string())
+                .map(|existing| existing.is_some())
+        }) {
+            Ok(slug) => slug,
+            Err(SlugError::Invalid(msg)) => {
+                validation_errors.add("slug", ValidationError::new(msg));
+                String::new()
+            }
+            Err(SlugError::Lookup(e)) => return Err(SeriesError::System(e.to_string())),
+        };
+
+        check_blog_ids(
+            &self.repository,
+            &dto.blog_ids,
+            None,
+            &mut validation_errors,
+        )?;
+
+        if !validation_errors.is_empty() {
+            return Err(SeriesError::Validation(validation_errors));
+        }
+
+        let series = self
+            .repository
+            .create_series(
+                NewSeries {
+                    title: dto.title,
+                    slug,
+                    description: dto.description.filter(|d| !d.trim().is_empty()),
+                },
+                dto.blog_ids,
+            )
+            .map_err(|e| SeriesError::System(e.to_string()))?;
+        let posts = self
+            .repository
+            .get_series_posts(series.id)
+            .map_err(|e| SeriesError::System(e.to_string()))?;
+
+        Ok(SeriesResponseDto::from_series(series, posts))
+    }
+}
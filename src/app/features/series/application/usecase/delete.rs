@@ -0,0 +1,26 @@
+use crate::app::features::series::domain::error::SeriesError;
+use crate::app::features::series::domain::repository::SeriesRepository;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn SeriesRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn SeriesRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Removes the series; its posts stay and simply lose the series block.
+    pub async fn execute(&self, id: i32) -> Result<(), SeriesError> {
+        let deleted = self
+            .repository
+            .delete_series(id)
+            .map_err(|e| SeriesError::System(e.to_string()))?;
+        if deleted == 0 {
+            return Err(SeriesError::NotFound("Series not found".to_string()));
+        }
+        Ok(())
+    }
+}
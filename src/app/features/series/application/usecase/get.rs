@@ -0,0 +1,29 @@
+use crate::app::features::series::domain::error::SeriesError;
+use crate::app::features::series::domain::repository::SeriesRepository;
+use crate::app::features::series::interface::dto::SeriesResponseDto;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn SeriesRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn SeriesRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, id: i32) -> Result<SeriesResponseDto, SeriesError> {
+        let series = self
+            .repository
+            .get_series_by_id(id)
+            .map_err(|e| SeriesError::System(e.to_string()))?
+            .ok_or_else(|| SeriesError::NotFound("Series not found".to_string()))?;
+        let posts = self
+            .repository
+            .get_series_posts(series.id)
+            .map_err(|e| SeriesError::System(e.to_string()))?;
+
+        Ok(SeriesResponseDto::from_series(series, posts))
+    }
+}
@@ -0,0 +1,52 @@
+use crate::app::features::series::domain::error::SeriesError;
+use crate::app::features::series::domain::repository::SeriesRepository;
+use crate::app::features::series::interface::dto::{
+    MetaDto, PaginatedResponseDto, SeriesListQueryDto, SeriesResponseDto,
+};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn SeriesRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn SeriesRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        query: SeriesListQueryDto,
+    ) -> Result<PaginatedResponseDto<SeriesResponseDto>, SeriesError> {
+        let page = query.page.unwrap_or(1);
+        let per_page = query.per_page.unwrap_or(10);
+        let offset = (page - 1) * per_page;
+
+        let (series, total_items) = self
+            .repository
+            .get_all_series(per_page, offset)
+            .map_err(|e| SeriesError::System(e.to_string()))?;
+
+        let mut items = Vec::with_capacity(series.len());
+        for series in series {
+            let posts = self
+                .repository
+                .get_series_posts(series.id)
+                .map_err(|e| SeriesError::System(e.to_string()))?;
+            items.push(SeriesResponseDto::from_series(series, posts));
+        }
+
+        let total_pages = (total_items as f64 / per_page as f64).ceil() as i64;
+
+        Ok(PaginatedResponseDto {
+            items,
+            meta: MetaDto {
+                page,
+                per_page,
+                total_pages,
+                total_items,
+            },
+        })
+    }
+}
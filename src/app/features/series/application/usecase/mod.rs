@@ -0,0 +1,6 @@
+pub mod create;
+pub mod delete;
+pub mod get;
+pub mod get_all;
+pub mod get_public;
+pub mod update;
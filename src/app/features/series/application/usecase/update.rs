@@ -0,0 +1,82 @@
+use super::create::check_blog_ids;
+use crate::app::features::series::domain::entity::NewSeries;
+use crate::app::features::series::domain::error::SeriesError;
+use crate::app::features::series::domain::repository::SeriesRepository;
+use crate::app::features::series::interface::dto::{SeriesResponseDto, UpdateSeriesRequestDto};
+use crate::utils::slug::{SlugError, resolve_slug};
+use std::sync::Arc;
+use validator::{Validate, ValidationError};
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn SeriesRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn SeriesRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        id: i32,
+        dto: UpdateSeriesRequestDto,
+    ) -> Result<SeriesResponseDto, SeriesError> {
+        let existing = self
+            .repository
+            .get_series_by_id(id)
+            .map_err(|e| SeriesError::System(e.to_string()))?
+            .ok_or_else(|| SeriesError::NotFound("Series not found".to_string()))?;
+
+        let mut validation_errors = dto.validate().err().unwrap_or_default();
+
+        // Keep the current slug unless asked otherwise or the title moved
+        let slug = if dto.slug.is_none() && dto.title == existing.title {
+            Ok(existing.slug.clone())
+        } else {
+            resolve_slug(dto.slug.as_deref(), &dto.title, "series", |slug| {
+                self.repository
+                    .get_series_by_slug(slug.to_string())
+                    .map(|found| found.is_some_and(|s| s.id != id))
+            })
+        };
+        let slug = match slug {
+            Ok(slug) => slug,
+            Err(SlugError::Invalid(msg)) => {
+                validation_errors.add("slug", ValidationError::new(msg));
+                String::new()
+            }
+            Err(SlugError::Lookup(e)) => return Err(SeriesError::System(e.to_string())),
+        };
+
+        check_blog_ids(
+            &self.repository,
+            &dto.blog_ids,
+            Some(id),
+            &mut validation_errors,
+        )?;
+
+        if !validation_errors.is_empty() {
+            return Err(SeriesError::Validation(validation_errors));
+        }
+
+        let series = self
+            .repository
+            .update_series(
+                id,
+                NewSeries {
+                    title: dto.title,
+                    slug,
+                    description: dto.description.filter(|d| !d.trim().is_empty()),
+                },
+                dto.blog_ids,
+            )
+            .map_err(|e| SeriesError::System(e.to_string()))?;
+        let posts = self
+            .repository
+            .get_series_posts(series.id)
+            .map_err(|e| SeriesError::System(e.to_string()))?;
+
+        Ok(SeriesResponseDto::from_series(series, posts))
+    }
+}
@@ -0,0 +1,31 @@
+use crate::schema::series;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone, PartialEq)]
+#[diesel(table_name = series)]
+pub struct Series {
+    pub id: i32,
+    pub title: String,
+    pub slug: String,
+    pub description: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = series)]
+pub struct NewSeries {
+    pub title: String,
+    pub slug: String,
+    pub description: Option<String>,
+}
+
+/// Post in a series, as listed in reading order.
+#[derive(Queryable, Debug, Clone, PartialEq)]
+pub struct SeriesPost {
+    pub blog_id: i32,
+    pub title: String,
+    pub slug: String,
+    pub status: String,
+}
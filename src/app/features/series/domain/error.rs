@@ -0,0 +1,14 @@
+use validator::ValidationErrors;
+
+#[derive(Debug)]
+pub enum SeriesError {
+    Validation(ValidationErrors),
+    System(String),
+    NotFound(String),
+}
+
+impl From<String> for SeriesError {
+    fn from(s: String) -> Self {
+        SeriesError::System(s)
+    }
+}
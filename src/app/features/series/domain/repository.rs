@@ -0,0 +1,24 @@
+use crate::app::features::series::domain::entity::{NewSeries, Series, SeriesPost};
+use diesel::QueryResult;
+
+pub trait SeriesRepository {
+    /// Inserts the series with `blog_ids` as its posts, in that order.
+    fn create_series(&self, series: NewSeries, blog_ids: Vec<i32>) -> QueryResult<Series>;
+    /// Replaces the series fields and its whole post list.
+    fn update_series(&self, id: i32, series: NewSeries, blog_ids: Vec<i32>) -> QueryResult<Series>;
+    fn get_series_by_id(&self, id: i32) -> QueryResult<Option<Series>>;
+    fn get_series_by_slug(&self, slug: String) -> QueryResult<Option<Series>>;
+    fn get_all_series(&self, limit: i64, offset: i64) -> QueryResult<(Vec<Series>, i64)>;
+    /// Posts of the series in reading order.
+    fn get_series_posts(&self, series_id: i32) -> QueryResult<Vec<SeriesPost>>;
+    /// Ids among `blog_ids` that have no post.
+    fn get_missing_blog_ids(&self, blog_ids: Vec<i32>) -> QueryResult<Vec<i32>>;
+    /// Series other than `exclude_id` that already hold one of `blog_ids`,
+    /// keyed by blog id.
+    fn get_blog_memberships(
+        &self,
+        blog_ids: Vec<i32>,
+        exclude_id: Option<i32>,
+    ) -> QueryResult<Vec<(i32, Series)>>;
+    fn delete_series(&self, id: i32) -> QueryResult<usize>;
+}
@@ -0,0 +1,188 @@
+use crate::app::features::series::domain::entity::{NewSeries, Series, SeriesPost};
+use crate::app::features::series::domain::repository::SeriesRepository;
+use crate::schema::{blog, series, series_posts};
+use crate::utils::db::DbPool;
+use diesel::prelude::*;
+
+#[derive(Clone)]
+pub struct SeriesRepositoryImpl {
+    pub pool: DbPool,
+}
+
+impl SeriesRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn load_posts(conn: &mut SqliteConnection, series_id: i32) -> QueryResult<Vec<SeriesPost>> {
+    series_posts::table
+        .inner_join(blog::table)
+        .filter(series_posts::series_id.eq(series_id))
+        .order(series_posts::position.asc())
+        .select((blog::id, blog::title, blog::slug, blog::status))
+        .load::<SeriesPost>(conn)
+}
+
+fn replace_posts(conn: &mut SqliteConnection, series_id: i32, blog_ids: &[i32]) -> QueryResult<()> {
+    diesel::delete(series_posts::table.filter(series_posts::series_id.eq(series_id)))
+        .execute(conn)?;
+    if blog_ids.is_empty() {
+        return Ok(());
+    }
+    let rows = blog_ids
+        .iter()
+        .enumerate()
+        .map(|(position, blog_id)| {
+            (
+                series_posts::series_id.eq(series_id),
+                series_posts::blog_id.eq(*blog_id),
+                series_posts::position.eq(position as i32 + 1),
+            )
+        })
+        .collect::<Vec<_>>();
+    diesel::insert_into(series_posts::table)
+        .values(rows)
+        .execute(conn)?;
+    Ok(())
+}
+
+/// The series `blog_id` belongs to, with all its posts in reading order.
+///
+/// Shared with the blog feature, which shows the series on every post.
+pub fn load_blog_series(
+    conn: &mut SqliteConnection,
+    blog_id: i32,
+) -> QueryResult<Option<(Series, Vec<SeriesPost>)>> {
+    let found = series_posts::table
+        .inner_join(series::table)
+        .filter(series_posts::blog_id.eq(blog_id))
+        .select(Series::as_select())
+        .first::<Series>(conn)
+        .optional()?;
+    match found {
+        Some(series) => {
+            let posts = load_posts(conn, series.id)?;
+            Ok(Some((series, posts)))
+        }
+        None => Ok(None),
+    }
+}
+
+impl SeriesRepository for SeriesRepositoryImpl {
+    fn create_series(&self, new_series: NewSeries, blog_ids: Vec<i32>) -> QueryResult<Series> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        conn.transaction(|conn| {
+            let created: Series = diesel::insert_into(series::table)
+                .values(new_series)
+                .get_result(conn)?;
+            replace_posts(conn, created.id, &blog_ids)?;
+            Ok(created)
+        })
+    }
+    fn update_series(
+        &self,
+        id: i32,
+        new_series: NewSeries,
+        blog_ids: Vec<i32>,
+    ) -> QueryResult<Series> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        conn.transaction(|conn| {
+            let updated: Series = diesel::update(series::table.find(id))
+                .set((
+                    series::title.eq(new_series.title),
+                    series::slug.eq(new_series.slug),
+                    series::description.eq(new_series.description),
+                    series::updated_at.eq(diesel::dsl::now),
+                ))
+                .get_result(conn)?;
+            replace_posts(conn, id, &blog_ids)?;
+            Ok(updated)
+        })
+    }
+    fn get_series_by_id(&self, id: i32) -> QueryResult<Option<Series>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        series::table.find(id).first::<Series>(&mut conn).optional()
+    }
+    fn get_series_by_slug(&self, slug: String) -> QueryResult<Option<Series>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        series::table
+            .filter(series::slug.eq(slug))
+            .first::<Series>(&mut conn)
+            .optional()
+    }
+    fn get_all_series(&self, limit: i64, offset: i64) -> QueryResult<(Vec<Series>, i64)> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        let count = series::table.count().get_result(&mut conn)?;
+        let items = series::table
+            .order(series::id.desc())
+            .limit(limit)
+            .offset(offset)
+            .load::<Series>(&mut conn)?;
+        Ok((items, count))
+    }
+    fn get_series_posts(&self, series_id: i32) -> QueryResult<Vec<SeriesPost>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        load_posts(&mut conn, series_id)
+    }
+    fn get_missing_blog_ids(&self, blog_ids: Vec<i32>) -> QueryResult<Vec<i32>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        let existing = blog::table
+            .filter(blog::id.eq_any(&blog_ids))
+            .select(blog::id)
+            .load::<i32>(&mut conn)?;
+        Ok(blog_ids
+            .into_iter()
+            .filter(|id| !existing.contains(id))
+            .collect())
+    }
+    fn get_blog_memberships(
+        &self,
+        blog_ids: Vec<i32>,
+        exclude_id: Option<i32>,
+    ) -> QueryResult<Vec<(i32, Series)>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        let mut query = series_posts::table
+            .inner_join(series::table)
+            .filter(series_posts::blog_id.eq_any(blog_ids))
+            .into_boxed();
+        if let Some(exclude_id) = exclude_id {
+            query = query.filter(series_posts::series_id.ne(exclude_id));
+        }
+        query
+            .order(series_posts::blog_id.asc())
+            .select((series_posts::blog_id, Series::as_select()))
+            .load::<(i32, Series)>(&mut conn)
+    }
+    fn delete_series(&self, id: i32) -> QueryResult<usize> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::delete(series::table.find(id)).execute(&mut conn)
+    }
+}
@@ -0,0 +1,186 @@
+use crate::app::features::series::domain::error::SeriesError;
+use crate::app::features::series::interface::dto::{
+    CreateSeriesRequestDto, SeriesListQueryDto, SeriesResponseDto, UpdateSeriesRequestDto,
+};
+use crate::utils::di::Container;
+use crate::utils::error_response::{ErrorResponse, map_string_error, map_validation_error};
+use crate::utils::success_response::{map_success_response, map_success_with_data};
+use actix_web::{HttpResponse, Responder, delete, get, post, put, web};
+
+fn series_error_response(e: SeriesError) -> HttpResponse {
+    match e {
+        SeriesError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+        SeriesError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+        SeriesError::System(msg) => HttpResponse::InternalServerError().json(map_string_error(msg)),
+    }
+}
+
+#[utoipa::path(
+    path = "/app/series",
+    tag = "Series",
+    request_body = CreateSeriesRequestDto,
+    responses(
+        (status = 201, description = "Series created", body = crate::utils::success_response::SuccessResponse<SeriesResponseDto>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[post("/series")]
+pub async fn create_series(
+    container: web::Data<Container>,
+    payload: web::Json<CreateSeriesRequestDto>,
+) -> impl Responder {
+    match container
+        .create_series_usecase
+        .execute(payload.into_inner())
+        .await
+    {
+        Ok(series) => HttpResponse::Created().json(map_success_with_data(
+            "Series created successfully".to_string(),
+            series,
+        )),
+        Err(e) => series_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    path = "/app/series",
+    tag = "Series",
+    params(
+        SeriesListQueryDto
+    ),
+    responses(
+        (status = 200, description = "Series, newest first", body = crate::utils::success_response::SuccessResponse<Vec<SeriesResponseDto>>),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[get("/series")]
+pub async fn get_all_series(
+    container: web::Data<Container>,
+    query: web::Query<SeriesListQueryDto>,
+) -> impl Responder {
+    match container
+        .get_all_series_usecase
+        .execute(query.into_inner())
+        .await
+    {
+        Ok(series) => HttpResponse::Ok().json(map_success_with_data(
+            "Series fetched successfully".to_string(),
+            series,
+        )),
+        Err(e) => series_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    path = "/app/series/{id}",
+    tag = "Series",
+    params(
+        ("id" = i32, Path, description = "Series id")
+    ),
+    responses(
+        (status = 200, description = "Series with all its posts", body = crate::utils::success_response::SuccessResponse<SeriesResponseDto>),
+        (status = 404, description = "Series not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[get("/series/{id}")]
+pub async fn get_series(container: web::Data<Container>, id: web::Path<i32>) -> impl Responder {
+    match container.get_series_usecase.execute(id.into_inner()).await {
+        Ok(series) => HttpResponse::Ok().json(map_success_with_data(
+            "Series fetched successfully".to_string(),
+            series,
+        )),
+        Err(e) => series_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    path = "/app/series/{id}",
+    tag = "Series",
+    params(
+        ("id" = i32, Path, description = "Series id")
+    ),
+    request_body = UpdateSeriesRequestDto,
+    responses(
+        (status = 200, description = "Series updated", body = crate::utils::success_response::SuccessResponse<SeriesResponseDto>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 404, description = "Series not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[put("/series/{id}")]
+pub async fn update_series(
+    container: web::Data<Container>,
+    id: web::Path<i32>,
+    payload: web::Json<UpdateSeriesRequestDto>,
+) -> impl Responder {
+    match container
+        .update_series_usecase
+        .execute(id.into_inner(), payload.into_inner())
+        .await
+    {
+        Ok(series) => HttpResponse::Ok().json(map_success_with_data(
+            "Series updated successfully".to_string(),
+            series,
+        )),
+        Err(e) => series_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    path = "/app/series/{id}",
+    tag = "Series",
+    params(
+        ("id" = i32, Path, description = "Series id")
+    ),
+    responses(
+        (status = 200, description = "Series deleted; its posts are kept"),
+        (status = 404, description = "Series not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[delete("/series/{id}")]
+pub async fn delete_series(container: web::Data<Container>, id: web::Path<i32>) -> impl Responder {
+    match container
+        .delete_series_usecase
+        .execute(id.into_inner())
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().json(map_success_response(
+            "Series deleted successfully".to_string(),
+        )),
+        Err(e) => series_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    path = "/api/public/series/{slug}",
+    tag = "Series",
+    params(
+        ("slug" = String, Path, description = "Series slug")
+    ),
+    responses(
+        (status = 200, description = "Series with its published posts", body = crate::utils::success_response::SuccessResponse<SeriesResponseDto>),
+        (status = 404, description = "Series not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    security(())
+)]
+#[get("/series/{slug}")]
+pub async fn get_public_series(
+    container: web::Data<Container>,
+    slug: web::Path<String>,
+) -> impl Responder {
+    match container
+        .get_public_series_usecase
+        .execute(slug.into_inner())
+        .await
+    {
+        Ok(series) => HttpResponse::Ok().json(map_success_with_data(
+            "Series fetched successfully".to_string(),
+            series,
+        )),
+        Err(e) => series_error_response(e),
+    }
+}
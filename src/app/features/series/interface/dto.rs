@@ -0,0 +1,154 @@
+use crate::app::features::series::domain::entity::{Series, SeriesPost};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Deserialize, Serialize, Validate, ToSchema)]
+pub struct CreateSeriesRequestDto {
+    #[validate(length(min = 1, max = 255, message = "Title is required"))]
+    pub title: String,
+    /// Explicit slug; generated from the title when omitted
+    pub slug: Option<String>,
+    pub description: Option<String>,
+    /// Posts in reading order; a post can only be part of one series
+    #[serde(default)]
+    pub blog_ids: Vec<i32>,
+}
+
+#[derive(Deserialize, Serialize, Validate, ToSchema)]
+pub struct UpdateSeriesRequestDto {
+    #[validate(length(min = 1, max = 255, message = "Title is required"))]
+    pub title: String,
+    /// Explicit slug; when omitted the current slug is kept unless the title changes
+    pub slug: Option<String>,
+    pub description: Option<String>,
+    /// Replaces the post list, in reading order
+    #[serde(default)]
+    pub blog_ids: Vec<i32>,
+}
+
+#[derive(Deserialize, Serialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SeriesListQueryDto {
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct SeriesPostDto {
+    /// 1-based position in the series
+    pub part: i32,
+    pub id: i32,
+    pub title: String,
+    pub slug: String,
+    pub status: String,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct SeriesResponseDto {
+    pub id: i32,
+    pub title: String,
+    pub slug: String,
+    pub description: Option<String>,
+    pub posts: Vec<SeriesPostDto>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl SeriesResponseDto {
+    /// Parts are numbered over `posts` as given, so pass only the posts the
+    /// reader may see.
+    pub fn from_series(series: Series, posts: Vec<SeriesPost>) -> Self {
+        Self {
+            id: series.id,
+            title: series.title,
+            slug: series.slug,
+            description: series.description,
+            posts: posts
+                .into_iter()
+                .enumerate()
+                .map(|(i, post)| SeriesPostDto {
+                    part: i as i32 + 1,
+                    id: post.blog_id,
+                    title: post.title,
+                    slug: post.slug,
+                    status: post.status,
+                })
+                .collect(),
+            created_at: series.created_at.to_string(),
+            updated_at: series.updated_at.to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct SeriesPostLinkDto {
+    pub id: i32,
+    pub title: String,
+    pub slug: String,
+}
+
+impl From<&SeriesPost> for SeriesPostLinkDto {
+    fn from(post: &SeriesPost) -> Self {
+        Self {
+            id: post.blog_id,
+            title: post.title.clone(),
+            slug: post.slug.clone(),
+        }
+    }
+}
+
+/// Where a post sits in its series.
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct BlogSeriesDto {
+    pub id: i32,
+    pub title: String,
+    pub slug: String,
+    /// 1-based position of the post
+    pub part: i32,
+    pub total_parts: i32,
+    pub previous: Option<SeriesPostLinkDto>,
+    pub next: Option<SeriesPostLinkDto>,
+}
+
+impl BlogSeriesDto {
+    /// Block for `blog_id`. With `published_only` the drafts of the series are
+    /// left out of the numbering and the links, as public readers see it.
+    pub fn for_blog(
+        series: Series,
+        posts: Vec<SeriesPost>,
+        blog_id: i32,
+        published_only: bool,
+    ) -> Option<Self> {
+        let posts = posts
+            .into_iter()
+            .filter(|p| !published_only || p.status == "PUBLISHED")
+            .collect::<Vec<_>>();
+        let index = posts.iter().position(|p| p.blog_id == blog_id)?;
+        Some(Self {
+            id: series.id,
+            title: series.title,
+            slug: series.slug,
+            part: index as i32 + 1,
+            total_parts: posts.len() as i32,
+            previous: index
+                .checked_sub(1)
+                .map(|i| SeriesPostLinkDto::from(&posts[i])),
+            next: posts.get(index + 1).map(SeriesPostLinkDto::from),
+        })
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct MetaDto {
+    pub page: i64,
+    pub per_page: i64,
+    pub total_pages: i64,
+    pub total_items: i64,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PaginatedResponseDto<T> {
+    pub items: Vec<T>,
+    pub meta: MetaDto,
+}
@@ -0,0 +1,77 @@
+use crate::app::features::sitemap::domain::entity::{Sitemap, SitemapEntry, SitemapPage};
+use crate::app::features::sitemap::domain::error::SitemapError;
+use crate::app::features::sitemap::domain::repository::SitemapRepository;
+use chrono::Utc;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn SitemapRepository + Send + Sync>,
+    max_urls: usize,
+}
+
+impl Execute {
+    /// `max_urls` is the most URLs a single sitemap file may hold before
+    /// `sitemap.xml` turns into an index of numbered pages.
+    pub fn new(repository: Arc<dyn SitemapRepository + Send + Sync>, max_urls: usize) -> Self {
+        Self {
+            repository,
+            max_urls: max_urls.max(1),
+        }
+    }
+
+    /// `page` of `None` is the top-level `sitemap.xml`; `Some(n)` is the
+    /// 1-based `sitemap-{n}.xml`.
+    pub async fn execute(&self, page: Option<usize>) -> Result<Sitemap, SitemapError> {
+        let entries = self.entries()?;
+        let pages: Vec<&[SitemapEntry]> = entries.chunks(self.max_urls).collect();
+
+        match page {
+            None if pages.len() <= 1 => Ok(Sitemap::UrlSet(entries)),
+            None => Ok(Sitemap::Index(
+                pages
+                    .iter()
+                    .enumerate()
+                    .map(|(i, chunk)| SitemapPage {
+                        page: i + 1,
+                        lastmod: chunk.iter().map(|e| e.lastmod).max(),
+                    })
+                    .collect(),
+            )),
+            Some(n) => pages
+                .get(n.wrapping_sub(1))
+                .map(|chunk| Sitemap::UrlSet(chunk.to_vec()))
+                .ok_or_else(|| SitemapError::NotFound("Sitemap page not found".to_string())),
+        }
+    }
+
+    fn entries(&self) -> Result<Vec<SitemapEntry>, SitemapError> {
+        let blogs = self
+            .repository
+            .get_published_blog_slugs(Utc::now().naive_utc())
+            .map_err(|e| SitemapError::System(e.to_string()))?;
+        let projects = self
+            .repository
+            .get_public_project_slugs()
+            .map_err(|e| SitemapError::System(e.to_string()))?;
+        let portofolios = self
+            .repository
+            .get_active_portofolio_slugs()
+            .map_err(|e| SitemapError::System(e.to_string()))?;
+
+        let sections = [
+            ("blog", blogs),
+            ("projects", projects),
+            ("portofolios", portofolios),
+        ];
+        Ok(sections
+            .into_iter()
+            .flat_map(|(prefix, rows)| {
+                rows.into_iter().map(move |(slug, lastmod)| SitemapEntry {
+                    path: format!("/{}/{}", prefix, slug),
+                    lastmod,
+                })
+            })
+            .collect())
+    }
+}
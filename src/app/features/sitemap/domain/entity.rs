@@ -0,0 +1,21 @@
+use chrono::NaiveDateTime;
+
+/// One public page, addressed relative to the site root.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SitemapEntry {
+    pub path: String,
+    pub lastmod: NaiveDateTime,
+}
+
+/// One `sitemap-{page}.xml` listed by a sitemap index.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SitemapPage {
+    pub page: usize,
+    pub lastmod: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Sitemap {
+    UrlSet(Vec<SitemapEntry>),
+    Index(Vec<SitemapPage>),
+}
@@ -0,0 +1,11 @@
+#[derive(Debug)]
+pub enum SitemapError {
+    System(String),
+    NotFound(String),
+}
+
+impl From<String> for SitemapError {
+    fn from(s: String) -> Self {
+        SitemapError::System(s)
+    }
+}
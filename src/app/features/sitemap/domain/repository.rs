@@ -0,0 +1,12 @@
+use chrono::NaiveDateTime;
+use diesel::QueryResult;
+
+/// `(slug, updated_at)` of everything readers can currently reach.
+pub trait SitemapRepository {
+    fn get_published_blog_slugs(
+        &self,
+        now: NaiveDateTime,
+    ) -> QueryResult<Vec<(String, NaiveDateTime)>>;
+    fn get_public_project_slugs(&self) -> QueryResult<Vec<(String, NaiveDateTime)>>;
+    fn get_active_portofolio_slugs(&self) -> QueryResult<Vec<(String, NaiveDateTime)>>;
+}
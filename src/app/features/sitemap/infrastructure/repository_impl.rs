@@ -0,0 +1,56 @@
+use crate::app::features::sitemap::domain::repository::SitemapRepository;
+use crate::schema::{blog, portofolios, projects};
+use crate::utils::db::DbPool;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+#[derive(Clone)]
+pub struct SitemapRepositoryImpl {
+    pub pool: DbPool,
+}
+
+impl SitemapRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl SitemapRepository for SitemapRepositoryImpl {
+    fn get_published_blog_slugs(
+        &self,
+        now: NaiveDateTime,
+    ) -> QueryResult<Vec<(String, NaiveDateTime)>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        blog::table
+            .filter(blog::status.eq("PUBLISHED"))
+            .filter(blog::published_at.le(now))
+            .select((blog::slug, blog::updated_at))
+            .order(blog::id.asc())
+            .load::<(String, NaiveDateTime)>(&mut conn)
+    }
+    fn get_public_project_slugs(&self) -> QueryResult<Vec<(String, NaiveDateTime)>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        projects::table
+            .filter(projects::status.ne("DRAFT"))
+            .select((projects::slug, projects::updated_at))
+            .order(projects::id.asc())
+            .load::<(String, NaiveDateTime)>(&mut conn)
+    }
+    fn get_active_portofolio_slugs(&self) -> QueryResult<Vec<(String, NaiveDateTime)>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        portofolios::table
+            .filter(portofolios::is_active.eq(true))
+            .select((portofolios::slug, portofolios::updated_at))
+            .order(portofolios::id.asc())
+            .load::<(String, NaiveDateTime)>(&mut conn)
+    }
+}
@@ -0,0 +1,88 @@
+use crate::app::features::sitemap::domain::error::SitemapError;
+use crate::app::features::sitemap::interface::dto::{
+    TEXT_CONTENT_TYPE, XML_CONTENT_TYPE, to_robots_txt, to_xml,
+};
+use crate::utils::di::Container;
+use crate::utils::error_response::{ErrorResponse, map_string_error};
+use actix_web::{HttpRequest, HttpResponse, Responder, get, web};
+
+fn server_url(req: &HttpRequest) -> String {
+    let connection = req.connection_info();
+    format!("{}://{}", connection.scheme(), connection.host())
+}
+
+async fn sitemap_response(
+    container: &Container,
+    req: &HttpRequest,
+    page: Option<usize>,
+) -> HttpResponse {
+    match container.get_sitemap_usecase.execute(page).await {
+        Ok(sitemap) => HttpResponse::Ok()
+            .content_type(XML_CONTENT_TYPE)
+            .body(to_xml(
+                &sitemap,
+                &container.config.site_url,
+                &server_url(req),
+            )),
+        Err(e) => match e {
+            SitemapError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+            SitemapError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    path = "/sitemap.xml",
+    tag = "SEO",
+    responses(
+        (status = 200, description = "Sitemap of all public pages, or a sitemap index once it outgrows one file", body = String, content_type = "application/xml"),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(())
+)]
+#[get("/sitemap.xml")]
+pub async fn get_sitemap(container: web::Data<Container>, req: HttpRequest) -> impl Responder {
+    sitemap_response(&container, &req, None).await
+}
+
+#[utoipa::path(
+    path = "/sitemap-{page}.xml",
+    tag = "SEO",
+    params(
+        ("page", description = "1-based page listed in the sitemap index")
+    ),
+    responses(
+        (status = 200, description = "One page of the sitemap", body = String, content_type = "application/xml"),
+        (status = 404, description = "Page not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(())
+)]
+#[get("/sitemap-{page:\\d+}.xml")]
+pub async fn get_sitemap_page(
+    container: web::Data<Container>,
+    req: HttpRequest,
+    page: web::Path<usize>,
+) -> impl Responder {
+    sitemap_response(&container, &req, Some(page.into_inner())).await
+}
+
+#[utoipa::path(
+    path = "/robots.txt",
+    tag = "SEO",
+    responses(
+        (status = 200, description = "Crawler rules pointing at the sitemap", body = String, content_type = "text/plain")
+    ),
+    security(())
+)]
+#[get("/robots.txt")]
+pub async fn get_robots_txt(container: web::Data<Container>, req: HttpRequest) -> impl Responder {
+    HttpResponse::Ok()
+        .content_type(TEXT_CONTENT_TYPE)
+        .body(to_robots_txt(
+            &container.config.robots_disallow,
+            &server_url(&req),
+        ))
+}
@@ -0,0 +1,70 @@
+use crate::app::features::sitemap::domain::entity::Sitemap;
+use chrono::{NaiveDateTime, SecondsFormat};
+
+pub const XML_CONTENT_TYPE: &str = "application/xml; charset=utf-8";
+pub const TEXT_CONTENT_TYPE: &str = "text/plain; charset=utf-8";
+
+const SITEMAP_NAMESPACE: &str = "http://www.sitemaps.org/schemas/sitemap/0.9";
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn w3c_datetime(time: NaiveDateTime) -> String {
+    time.and_utc().to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// Renders a `<urlset>` with page URLs under `site_url`, or a
+/// `<sitemapindex>` whose pages live under `server_url`.
+pub fn to_xml(sitemap: &Sitemap, site_url: &str, server_url: &str) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    match sitemap {
+        Sitemap::UrlSet(entries) => {
+            xml.push_str(&format!("<urlset xmlns=\"{}\">\n", SITEMAP_NAMESPACE));
+            for entry in entries {
+                xml.push_str(&format!(
+                    "  <url>\n    <loc>{}</loc>\n    <lastmod>{}</lastmod>\n  </url>\n",
+                    escape_xml(&format!("{}{}", site_url, entry.path)),
+                    w3c_datetime(entry.lastmod)
+                ));
+            }
+            xml.push_str("</urlset>\n");
+        }
+        Sitemap::Index(pages) => {
+            xml.push_str(&format!("<sitemapindex xmlns=\"{}\">\n", SITEMAP_NAMESPACE));
+            for page in pages {
+                xml.push_str(&format!(
+                    "  <sitemap>\n    <loc>{}</loc>\n",
+                    escape_xml(&format!("{}/sitemap-{}.xml", server_url, page.page))
+                ));
+                if let Some(lastmod) = page.lastmod {
+                    xml.push_str(&format!(
+                        "    <lastmod>{}</lastmod>\n",
+                        w3c_datetime(lastmod)
+                    ));
+                }
+                xml.push_str("  </sitemap>\n");
+            }
+            xml.push_str("</sitemapindex>\n");
+        }
+    }
+    xml
+}
+
+/// `robots.txt` for all crawlers, blocking `disallow` and pointing at the sitemap.
+pub fn to_robots_txt(disallow: &[String], server_url: &str) -> String {
+    let mut robots = String::from("User-agent: *\n");
+    if disallow.is_empty() {
+        robots.push_str("Disallow:\n");
+    }
+    for path in disallow {
+        robots.push_str(&format!("Disallow: {}\n", path));
+    }
+    robots.push_str(&format!("\nSitemap: {}/sitemap.xml\n", server_url));
+    robots
+}
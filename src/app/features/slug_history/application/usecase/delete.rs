@@ -0,0 +1,27 @@
+use crate::app::features::slug_history::domain::error::SlugHistoryError;
+use crate::app::features::slug_history::domain::repository::SlugHistoryRepository;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn SlugHistoryRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn SlugHistoryRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, id: i32) -> Result<(), SlugHistoryError> {
+        let deleted = self
+            .repository
+            .delete_slug_history(id)
+            .map_err(|e| SlugHistoryError::System(e.to_string()))?;
+        if deleted == 0 {
+            return Err(SlugHistoryError::NotFound(
+                "Slug history not found".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use crate::app::features::slug_history::domain::entity::SlugEntity;
+use crate::app::features::slug_history::domain::error::SlugHistoryError;
+use crate::app::features::slug_history::domain::repository::SlugHistoryRepository;
+use crate::app::features::slug_history::interface::dto::{
+    MetaDto, PaginatedResponseDto, SlugHistoryListQueryDto, SlugHistoryResponseDto,
+};
+use std::sync::Arc;
+use validator::Validate;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn SlugHistoryRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn SlugHistoryRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        query: SlugHistoryListQueryDto,
+    ) -> Result<PaginatedResponseDto<SlugHistoryResponseDto>, SlugHistoryError> {
+        if let Err(e) = query.validate() {
+            return Err(SlugHistoryError::Validation(e));
+        }
+
+        let page = query.page.unwrap_or(1);
+        let per_page = query.per_page.unwrap_or(10);
+        let offset = (page - 1) * per_page;
+        let entity = query.entity_type.as_deref().and_then(SlugEntity::parse);
+
+        let (rows, total_items) = self
+            .repository
+            .get_all_slug_history(entity, per_page, offset)
+            .map_err(|e| SlugHistoryError::System(e.to_string()))?;
+
+        let mut items = Vec::new();
+        for row in rows {
+            let current_slug = match SlugEntity::parse(&row.entity_type) {
+                Some(entity) => self
+                    .repository
+                    .get_current_slug(entity, row.entity_id)
+                    .map_err(|e| SlugHistoryError::System(e.to_string()))?,
+                None => None,
+            };
+            items.push(SlugHistoryResponseDto {
+                id: row.id,
+                entity_type: row.entity_type,
+                entity_id: row.entity_id,
+                slug: row.slug,
+                current_slug,
+                created_at: row.created_at.to_string(),
+            });
+        }
+
+        let total_pages = (total_items as f64 / per_page as f64).ceil() as i64;
+
+        Ok(PaginatedResponseDto {
+            items,
+            meta: MetaDto {
+                page,
+                per_page,
+                total_pages,
+                total_items,
+            },
+        })
+    }
+}
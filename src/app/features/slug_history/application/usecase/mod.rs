@@ -0,0 +1,4 @@
+pub mod delete;
+pub mod get_all;
+pub mod prune;
+pub mod resolve;
@@ -0,0 +1,42 @@
+use crate::app::features::slug_history::domain::entity::SlugEntity;
+use crate::app::features::slug_history::domain::error::SlugHistoryError;
+use crate::app::features::slug_history::domain::repository::SlugHistoryRepository;
+use crate::app::features::slug_history::interface::dto::{
+    PruneSlugHistoryRequestDto, PruneSlugHistoryResponseDto,
+};
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use validator::Validate;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn SlugHistoryRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn SlugHistoryRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Old URLs stop redirecting once their slug is pruned.
+    pub async fn execute(
+        &self,
+        dto: PruneSlugHistoryRequestDto,
+    ) -> Result<PruneSlugHistoryResponseDto, SlugHistoryError> {
+        if let Err(e) = dto.validate() {
+            return Err(SlugHistoryError::Validation(e));
+        }
+
+        let entity = dto.entity_type.as_deref().and_then(SlugEntity::parse);
+        let before = dto
+            .older_than_days
+            .map(|days| Utc::now().naive_utc() - Duration::days(days));
+
+        let deleted = self
+            .repository
+            .prune_slug_history(entity, before)
+            .map_err(|e| SlugHistoryError::System(e.to_string()))?;
+
+        Ok(PruneSlugHistoryResponseDto { deleted })
+    }
+}
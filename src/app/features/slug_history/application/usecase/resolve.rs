@@ -0,0 +1,27 @@
+use crate::app::features::slug_history::domain::entity::SlugEntity;
+use crate::app::features::slug_history::domain::error::SlugHistoryError;
+use crate::app::features::slug_history::domain::repository::SlugHistoryRepository;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn SlugHistoryRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn SlugHistoryRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Current slug for a retired one, or `None` if `slug` was never used or
+    /// its entity is not public.
+    pub async fn execute(
+        &self,
+        entity: SlugEntity,
+        slug: String,
+    ) -> Result<Option<String>, SlugHistoryError> {
+        self.repository
+            .find_current_slug(entity, slug)
+            .map_err(|e| SlugHistoryError::System(e.to_string()))
+    }
+}
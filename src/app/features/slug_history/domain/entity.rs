@@ -0,0 +1,52 @@
+use crate::schema::slug_history;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+/// A slug an entity used to have. Rows are written by database triggers
+/// whenever `blog`, `projects` or `portofolios` change their slug.
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone, PartialEq)]
+#[diesel(table_name = slug_history)]
+pub struct SlugHistory {
+    pub id: i32,
+    pub entity_type: String,
+    pub entity_id: i32,
+    pub slug: String,
+    pub created_at: NaiveDateTime,
+}
+
+/// Kinds of content whose slugs are tracked.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SlugEntity {
+    Blog,
+    Project,
+    Portofolio,
+}
+
+impl SlugEntity {
+    /// Value stored in `slug_history.entity_type`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SlugEntity::Blog => "blog",
+            SlugEntity::Project => "project",
+            SlugEntity::Portofolio => "portofolio",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "blog" => Some(SlugEntity::Blog),
+            "project" => Some(SlugEntity::Project),
+            "portofolio" => Some(SlugEntity::Portofolio),
+            _ => None,
+        }
+    }
+
+    /// Collection segment of the public API, e.g. `/api/public/blogs/{slug}`.
+    pub fn public_path(&self) -> &'static str {
+        match self {
+            SlugEntity::Blog => "blogs",
+            SlugEntity::Project => "projects",
+            SlugEntity::Portofolio => "portofolios",
+        }
+    }
+}
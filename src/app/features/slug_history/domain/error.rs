@@ -0,0 +1,14 @@
+use validator::ValidationErrors;
+
+#[derive(Debug)]
+pub enum SlugHistoryError {
+    Validation(ValidationErrors),
+    System(String),
+    NotFound(String),
+}
+
+impl From<String> for SlugHistoryError {
+    fn from(s: String) -> Self {
+        SlugHistoryError::System(s)
+    }
+}
@@ -0,0 +1,28 @@
+use crate::app::features::slug_history::domain::entity::{SlugEntity, SlugHistory};
+use chrono::NaiveDateTime;
+use diesel::QueryResult;
+
+pub trait SlugHistoryRepository {
+    /// Slug currently used by whichever entity once answered to `old_slug`,
+    /// or `None` while that entity is hidden from the public API.
+    fn find_current_slug(
+        &self,
+        entity: SlugEntity,
+        old_slug: String,
+    ) -> QueryResult<Option<String>>;
+    fn get_current_slug(&self, entity: SlugEntity, entity_id: i32) -> QueryResult<Option<String>>;
+    fn get_all_slug_history(
+        &self,
+        entity: Option<SlugEntity>,
+        limit: i64,
+        offset: i64,
+    ) -> QueryResult<(Vec<SlugHistory>, i64)>;
+    fn delete_slug_history(&self, id: i32) -> QueryResult<usize>;
+    /// Removes retired slugs, optionally only of one kind and only those
+    /// retired before `before`.
+    fn prune_slug_history(
+        &self,
+        entity: Option<SlugEntity>,
+        before: Option<NaiveDateTime>,
+    ) -> QueryResult<usize>;
+}
@@ -0,0 +1,155 @@
+use crate::app::features::slug_history::domain::entity::{SlugEntity, SlugHistory};
+use crate::app::features::slug_history::domain::repository::SlugHistoryRepository;
+use crate::schema::{blog, portofolios, projects, slug_history};
+use crate::utils::db::DbPool;
+use chrono::{NaiveDateTime, Utc};
+use diesel::prelude::*;
+
+#[derive(Clone)]
+pub struct SlugHistoryRepositoryImpl {
+    pub pool: DbPool,
+}
+
+impl SlugHistoryRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn current_slug(
+    conn: &mut SqliteConnection,
+    entity: SlugEntity,
+    entity_id: i32,
+) -> QueryResult<Option<String>> {
+    match entity {
+        SlugEntity::Blog => blog::table
+            .find(entity_id)
+            .select(blog::slug)
+            .first::<String>(conn)
+            .optional(),
+        SlugEntity::Project => projects::table
+            .find(entity_id)
+            .select(projects::slug)
+            .first::<String>(conn)
+            .optional(),
+        SlugEntity::Portofolio => portofolios::table
+            .find(entity_id)
+            .select(portofolios::slug)
+            .first::<String>(conn)
+            .optional(),
+    }
+}
+
+/// Like [`current_slug`], but only for entities the public API would show.
+fn public_slug(
+    conn: &mut SqliteConnection,
+    entity: SlugEntity,
+    entity_id: i32,
+) -> QueryResult<Option<String>> {
+    match entity {
+        SlugEntity::Blog => blog::table
+            .find(entity_id)
+            .filter(blog::status.eq("PUBLISHED"))
+            .filter(blog::published_at.le(Utc::now().naive_utc()))
+            .select(blog::slug)
+            .first::<String>(conn)
+            .optional(),
+        SlugEntity::Project => projects::table
+            .find(entity_id)
+            .filter(projects::status.ne("DRAFT"))
+            .select(projects::slug)
+            .first::<String>(conn)
+            .optional(),
+        SlugEntity::Portofolio => portofolios::table
+            .find(entity_id)
+            .filter(portofolios::is_active.eq(true))
+            .select(portofolios::slug)
+            .first::<String>(conn)
+            .optional(),
+    }
+}
+
+impl SlugHistoryRepository for SlugHistoryRepositoryImpl {
+    fn find_current_slug(
+        &self,
+        entity: SlugEntity,
+        old_slug: String,
+    ) -> QueryResult<Option<String>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+
+        let entity_id = slug_history::table
+            .filter(slug_history::entity_type.eq(entity.as_str()))
+            .filter(slug_history::slug.eq(old_slug))
+            .select(slug_history::entity_id)
+            .first::<i32>(&mut conn)
+            .optional()?;
+
+        match entity_id {
+            Some(id) => public_slug(&mut conn, entity, id),
+            None => Ok(None),
+        }
+    }
+    fn get_current_slug(&self, entity: SlugEntity, entity_id: i32) -> QueryResult<Option<String>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        current_slug(&mut conn, entity, entity_id)
+    }
+    fn get_all_slug_history(
+        &self,
+        entity: Option<SlugEntity>,
+        limit: i64,
+        offset: i64,
+    ) -> QueryResult<(Vec<SlugHistory>, i64)> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+
+        let mut count_query = slug_history::table.into_boxed();
+        let mut items_query = slug_history::table.into_boxed();
+        if let Some(entity) = entity {
+            count_query = count_query.filter(slug_history::entity_type.eq(entity.as_str()));
+            items_query = items_query.filter(slug_history::entity_type.eq(entity.as_str()));
+        }
+
+        let count = count_query.count().get_result(&mut conn)?;
+        let items = items_query
+            .order(slug_history::id.desc())
+            .limit(limit)
+            .offset(offset)
+            .load::<SlugHistory>(&mut conn)?;
+
+        Ok((items, count))
+    }
+    fn delete_slug_history(&self, id: i32) -> QueryResult<usize> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::delete(slug_history::table.find(id)).execute(&mut conn)
+    }
+    fn prune_slug_history(
+        &self,
+        entity: Option<SlugEntity>,
+        before: Option<NaiveDateTime>,
+    ) -> QueryResult<usize> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+
+        let mut query = diesel::delete(slug_history::table).into_boxed();
+        if let Some(entity) = entity {
+            query = query.filter(slug_history::entity_type.eq(entity.as_str()));
+        }
+        if let Some(before) = before {
+            query = query.filter(slug_history::created_at.lt(before));
+        }
+        query.execute(&mut conn)
+    }
+}
@@ -0,0 +1,149 @@
+use crate::app::features::slug_history::domain::entity::SlugEntity;
+use crate::app::features::slug_history::domain::error::SlugHistoryError;
+use crate::app::features::slug_history::interface::dto::{
+    PruneSlugHistoryRequestDto, PruneSlugHistoryResponseDto, SlugHistoryListQueryDto,
+    SlugHistoryResponseDto,
+};
+use crate::utils::di::Container;
+use crate::utils::error_response::{ErrorResponse, map_string_error, map_validation_error};
+use crate::utils::success_response::{map_success_response, map_success_with_data};
+use actix_web::http::header;
+use actix_web::{HttpResponse, Responder, delete, get, post, web};
+
+/// Response for a public slug lookup that found nothing: a 301 to the
+/// current URL when `slug` is a retired one, otherwise a 404 with `message`.
+pub async fn redirect_or_not_found(
+    container: &Container,
+    entity: SlugEntity,
+    slug: String,
+    message: String,
+) -> HttpResponse {
+    match container
+        .resolve_slug_redirect_usecase
+        .execute(entity, slug)
+        .await
+    {
+        Ok(Some(current)) => HttpResponse::MovedPermanently()
+            .insert_header((
+                header::LOCATION,
+                format!("/api/public/{}/{}", entity.public_path(), current),
+            ))
+            .finish(),
+        Ok(None) => HttpResponse::NotFound().json(map_string_error(message)),
+        Err(SlugHistoryError::System(msg)) => {
+            HttpResponse::InternalServerError().json(map_string_error(msg))
+        }
+        Err(_) => HttpResponse::NotFound().json(map_string_error(message)),
+    }
+}
+
+#[utoipa::path(
+    path = "/app/slug-history",
+    tag = "Slug History",
+    params(
+        SlugHistoryListQueryDto
+    ),
+    responses(
+        (status = 200, description = "Retired slugs, newest first", body = crate::utils::success_response::SuccessResponse<Vec<SlugHistoryResponseDto>>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[get("/slug-history")]
+pub async fn get_slug_history(
+    container: web::Data<Container>,
+    query: web::Query<SlugHistoryListQueryDto>,
+) -> impl Responder {
+    match container
+        .get_slug_history_usecase
+        .execute(query.into_inner())
+        .await
+    {
+        Ok(history) => HttpResponse::Ok().json(map_success_with_data(
+            "Slug history fetched successfully".to_string(),
+            history,
+        )),
+        Err(e) => match e {
+            SlugHistoryError::Validation(e) => {
+                HttpResponse::BadRequest().json(map_validation_error(e))
+            }
+            SlugHistoryError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+            SlugHistoryError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    path = "/app/slug-history/prune",
+    tag = "Slug History",
+    request_body = PruneSlugHistoryRequestDto,
+    responses(
+        (status = 200, description = "Retired slugs pruned", body = crate::utils::success_response::SuccessResponse<PruneSlugHistoryResponseDto>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[post("/slug-history/prune")]
+pub async fn prune_slug_history(
+    container: web::Data<Container>,
+    body: web::Json<PruneSlugHistoryRequestDto>,
+) -> impl Responder {
+    match container
+        .prune_slug_history_usecase
+        .execute(body.into_inner())
+        .await
+    {
+        Ok(result) => HttpResponse::Ok().json(map_success_with_data(
+            "Slug history pruned successfully".to_string(),
+            result,
+        )),
+        Err(e) => match e {
+            SlugHistoryError::Validation(e) => {
+                HttpResponse::BadRequest().json(map_validation_error(e))
+            }
+            SlugHistoryError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+            SlugHistoryError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    path = "/app/slug-history/{id}",
+    tag = "Slug History",
+    params(
+        ("id", description = "Slug history ID")
+    ),
+    responses(
+        (status = 200, description = "Retired slug deleted; it no longer redirects"),
+        (status = 404, description = "Slug history not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[delete("/slug-history/{id}")]
+pub async fn delete_slug_history(
+    container: web::Data<Container>,
+    id: web::Path<i32>,
+) -> impl Responder {
+    match container
+        .delete_slug_history_usecase
+        .execute(id.into_inner())
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().json(map_success_response(
+            "Slug history deleted successfully".to_string(),
+        )),
+        Err(e) => match e {
+            SlugHistoryError::Validation(e) => {
+                HttpResponse::BadRequest().json(map_validation_error(e))
+            }
+            SlugHistoryError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+            SlugHistoryError::System(msg) => {
+                HttpResponse::InternalServerError().json(map_string_error(msg))
+            }
+        },
+    }
+}
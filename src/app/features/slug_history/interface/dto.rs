@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::{Validate, ValidationError};
+
+fn validate_entity_type(entity_type: &str) -> Result<(), ValidationError> {
+    match entity_type {
+        "blog" | "project" | "portofolio" => Ok(()),
+        _ => Err(ValidationError::new("Invalid entity type")),
+    }
+}
+
+#[derive(Deserialize, Serialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SlugHistoryListQueryDto {
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+    /// blog, project or portofolio
+    #[validate(custom(function = "validate_entity_type"))]
+    pub entity_type: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Validate, ToSchema)]
+pub struct PruneSlugHistoryRequestDto {
+    /// Only prune slugs of this kind: blog, project or portofolio
+    #[validate(custom(function = "validate_entity_type"))]
+    pub entity_type: Option<String>,
+    /// Only prune slugs retired more than this many days ago
+    #[validate(range(min = 0, max = 36500, message = "Must be between 0 and 36500"))]
+    pub older_than_days: Option<i64>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct PruneSlugHistoryResponseDto {
+    pub deleted: usize,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct SlugHistoryResponseDto {
+    pub id: i32,
+    pub entity_type: String,
+    pub entity_id: i32,
+    /// Retired slug that still redirects
+    pub slug: String,
+    /// Slug the entity uses now
+    pub current_slug: Option<String>,
+    /// When the slug was retired
+    pub created_at: String,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct MetaDto {
+    pub page: i64,
+    pub per_page: i64,
+    pub total_pages: i64,
+    pub total_items: i64,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PaginatedResponseDto<T> {
+    pub items: Vec<T>,
+    pub meta: MetaDto,
+}
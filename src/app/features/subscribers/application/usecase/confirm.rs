@@ -0,0 +1,46 @@
+use crate::app::features::subscribers::application::usecase::links::resolve_token;
+use crate::app::features::subscribers::domain::entity::{STATUS_CONFIRMED, STATUS_PENDING};
+use crate::app::features::subscribers::domain::error::SubscriberError;
+use crate::app::features::subscribers::domain::repository::SubscriberRepository;
+use crate::app::features::subscribers::interface::dto::SubscriptionStatusDto;
+use crate::utils::token::SUBSCRIBE_AUDIENCE;
+use chrono::Utc;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn SubscriberRepository + Send + Sync>,
+    secret: String,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn SubscriberRepository + Send + Sync>, secret: String) -> Self {
+        Self { repository, secret }
+    }
+
+    pub async fn execute(&self, token: String) -> Result<SubscriptionStatusDto, SubscriberError> {
+        let subscriber = resolve_token(
+            self.repository.as_ref(),
+            &token,
+            SUBSCRIBE_AUDIENCE,
+            &self.secret,
+        )?;
+        let subscriber = match subscriber.status.as_str() {
+            STATUS_CONFIRMED => subscriber,
+            STATUS_PENDING => self
+                .repository
+                .update_subscriber_status(subscriber.id, STATUS_CONFIRMED, Utc::now().naive_utc())
+                .map_err(|e| SubscriberError::System(e.to_string()))?,
+            // An old link must not undo an unsubscribe
+            _ => {
+                return Err(SubscriberError::Unauthorized(
+                    "Invalid or expired link".to_string(),
+                ));
+            }
+        };
+        Ok(SubscriptionStatusDto {
+            email: subscriber.email,
+            status: subscriber.status,
+        })
+    }
+}
@@ -0,0 +1,46 @@
+use crate::app::features::subscribers::domain::error::SubscriberError;
+use crate::app::features::subscribers::domain::repository::SubscriberRepository;
+use crate::app::features::subscribers::interface::dto::{
+    MetaDto, PaginatedResponseDto, SubscriberListQueryDto, SubscriberResponseDto,
+};
+use std::sync::Arc;
+use validator::Validate;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn SubscriberRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn SubscriberRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        query: SubscriberListQueryDto,
+    ) -> Result<PaginatedResponseDto<SubscriberResponseDto>, SubscriberError> {
+        query.validate().map_err(SubscriberError::Validation)?;
+
+        let page = query.page.unwrap_or(1);
+        let per_page = query.per_page.unwrap_or(10);
+        let offset = (page - 1) * per_page;
+
+        let (subscribers, total_items) = self
+            .repository
+            .get_all_subscribers(query.status, per_page, offset)
+            .map_err(|e| SubscriberError::System(e.to_string()))?;
+
+        let total_pages = (total_items as f64 / per_page as f64).ceil() as i64;
+
+        Ok(PaginatedResponseDto {
+            items: subscribers.into_iter().map(Into::into).collect(),
+            meta: MetaDto {
+                page,
+                per_page,
+                total_pages,
+                total_items,
+            },
+        })
+    }
+}
@@ -0,0 +1,52 @@
+use crate::app::features::subscribers::domain::entity::Subscriber;
+use crate::app::features::subscribers::domain::error::SubscriberError;
+use crate::app::features::subscribers::domain::repository::SubscriberRepository;
+use crate::utils::token::{
+    SUBSCRIBE_AUDIENCE, UNSUBSCRIBE_AUDIENCE, create_subscriber_token, verify_subscriber_token,
+};
+use jsonwebtoken::get_current_timestamp;
+
+/// How long a confirmation link stays valid.
+pub const CONFIRMATION_HOURS: u64 = 48;
+
+pub fn confirmation_url(site_url: &str, subscriber: &Subscriber, secret: &str) -> String {
+    let token = create_subscriber_token(
+        subscriber.id,
+        &subscriber.email,
+        SUBSCRIBE_AUDIENCE,
+        Some(get_current_timestamp() + CONFIRMATION_HOURS * 3600),
+        secret,
+    );
+    format!("{}/api/public/newsletter/confirm/{}", site_url, token)
+}
+
+pub fn unsubscribe_url(site_url: &str, subscriber: &Subscriber, secret: &str) -> String {
+    let token = create_subscriber_token(
+        subscriber.id,
+        &subscriber.email,
+        UNSUBSCRIBE_AUDIENCE,
+        None,
+        secret,
+    );
+    format!("{}/api/public/newsletter/unsubscribe/{}", site_url, token)
+}
+
+/// The subscriber a confirmation or unsubscribe token was issued to. Tokens
+/// for an address that has since been removed are rejected.
+pub fn resolve_token(
+    repository: &dyn SubscriberRepository,
+    token: &str,
+    audience: &str,
+    secret: &str,
+) -> Result<Subscriber, SubscriberError> {
+    let invalid = || SubscriberError::Unauthorized("Invalid or expired link".to_string());
+    let claims = verify_subscriber_token(token, audience, secret).map_err(|_| invalid())?;
+    let id = claims.sub.parse::<i32>().map_err(|_| invalid())?;
+    match repository
+        .get_subscriber_by_id(id)
+        .map_err(|e| SubscriberError::System(e.to_string()))?
+    {
+        Some(subscriber) if subscriber.email == claims.email => Ok(subscriber),
+        _ => Err(invalid()),
+    }
+}
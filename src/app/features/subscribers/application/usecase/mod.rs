@@ -0,0 +1,8 @@
+pub mod confirm;
+pub mod get_all;
+pub mod links;
+pub mod notify;
+pub mod send;
+pub mod status;
+pub mod subscribe;
+pub mod unsubscribe;
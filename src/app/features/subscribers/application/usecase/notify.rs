@@ -0,0 +1,27 @@
+use crate::app::features::subscribers::domain::error::SubscriberError;
+use crate::app::features::subscribers::domain::repository::SubscriberRepository;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn SubscriberRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn SubscriberRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Queues an email to every confirmed subscriber about a post that just
+    /// went live and returns how many were queued; the newsletter sender
+    /// delivers them in the background. Posts are announced once: later
+    /// calls for the same post, or calls while it isn't PUBLISHED, queue
+    /// nothing.
+    pub async fn execute(&self, blog_id: i32) -> Result<usize, SubscriberError> {
+        let queued = self
+            .repository
+            .queue_newsletter_issue(blog_id)
+            .map_err(|e| SubscriberError::System(e.to_string()))?;
+        Ok(queued.unwrap_or(0))
+    }
+}
@@ -0,0 +1,164 @@
+use crate::app::features::subscribers::application::usecase::links::{
+    confirmation_url, unsubscribe_url,
+};
+use crate::app::features::subscribers::domain::entity::{
+    EMAIL_CONFIRMATION, EMAIL_FAILED, EMAIL_ISSUE, EMAIL_SENT, EMAIL_SKIPPED, NewsletterEmail,
+    NewsletterPost, STATUS_CONFIRMED, STATUS_PENDING, Subscriber,
+};
+use crate::app::features::subscribers::domain::error::SubscriberError;
+use crate::app::features::subscribers::domain::repository::SubscriberRepository;
+use crate::utils::email::Email;
+use chrono::Utc;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::sync::Arc;
+
+/// Emails sent per run; the rest wait for the next tick.
+const BATCH_SIZE: i64 = 50;
+
+pub fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// A message ready to hand to the SMTP server.
+struct Outgoing {
+    to: String,
+    subject: String,
+    body: String,
+    unsubscribe_url: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn SubscriberRepository + Send + Sync>,
+    email: Email,
+    site_url: String,
+    secret: String,
+}
+
+impl Execute {
+    pub fn new(
+        repository: Arc<dyn SubscriberRepository + Send + Sync>,
+        email: Email,
+        site_url: String,
+        secret: String,
+    ) -> Self {
+        Self {
+            repository,
+            email,
+            site_url,
+            secret,
+        }
+    }
+
+    fn confirmation(&self, subscriber: &Subscriber) -> Outgoing {
+        let link = confirmation_url(&self.site_url, subscriber, &self.secret);
+        Outgoing {
+            to: subscriber.email.clone(),
+            subject: "Confirm your subscription".to_string(),
+            body: format!(
+                "<p>Please confirm that you want to receive new posts by email.</p>\
+                 <p><a href=\"{link}\">Confirm subscription</a></p>\
+                 <p>If you did not ask for this, just ignore this email.</p>"
+            ),
+            unsubscribe_url: None,
+        }
+    }
+
+    fn issue(&self, subscriber: &Subscriber, post: &NewsletterPost) -> Outgoing {
+        let title = escape_html(&post.title);
+        let excerpt = post
+            .excerpt
+            .as_deref()
+            .map(|e| format!("<p>{}</p>", escape_html(e)))
+            .unwrap_or_default();
+        let post_url = format!("{}/blog/{}", self.site_url, post.slug);
+        let unsubscribe = unsubscribe_url(&self.site_url, subscriber, &self.secret);
+        Outgoing {
+            to: subscriber.email.clone(),
+            subject: post.title.clone(),
+            body: format!(
+                "<h1>{title}</h1>{excerpt}\
+                 <p><a href=\"{post_url}\">Read the post</a></p>\
+                 <p><small><a href=\"{unsubscribe}\">Unsubscribe</a></small></p>"
+            ),
+            unsubscribe_url: Some(unsubscribe),
+        }
+    }
+
+    /// The message for `queued`, or `None` when it no longer applies: the
+    /// subscriber confirmed, unsubscribed or the post is gone.
+    fn compose(
+        &self,
+        queued: &NewsletterEmail,
+        subscriber: &Subscriber,
+        posts: &mut HashMap<i32, Option<NewsletterPost>>,
+    ) -> Result<Option<Outgoing>, SubscriberError> {
+        match (queued.kind.as_str(), queued.blog_id) {
+            (EMAIL_CONFIRMATION, _) if subscriber.status == STATUS_PENDING => {
+                Ok(Some(self.confirmation(subscriber)))
+            }
+            (EMAIL_ISSUE, Some(blog_id)) if subscriber.status == STATUS_CONFIRMED => {
+                if let Entry::Vacant(entry) = posts.entry(blog_id) {
+                    entry.insert(
+                        self.repository
+                            .get_newsletter_post(blog_id)
+                            .map_err(|e| SubscriberError::System(e.to_string()))?,
+                    );
+                }
+                Ok(posts[&blog_id]
+                    .as_ref()
+                    .map(|post| self.issue(subscriber, post)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Sends queued emails and returns how many were attempted. A failed
+    /// address is recorded and does not hold back the others. SMTP blocks, so
+    /// callers on an async runtime should run this on a blocking thread.
+    pub fn execute(&self) -> Result<usize, SubscriberError> {
+        let queued = self
+            .repository
+            .get_pending_newsletter_emails(BATCH_SIZE)
+            .map_err(|e| SubscriberError::System(e.to_string()))?;
+        let attempted = queued.len();
+        let mut posts = HashMap::new();
+
+        for (queued, subscriber) in queued {
+            let (status, error) = match self.compose(&queued, &subscriber, &mut posts)? {
+                None => (EMAIL_SKIPPED, None),
+                Some(outgoing) => {
+                    let result = match outgoing.unsubscribe_url {
+                        Some(unsubscribe) => self.email.send_newsletter_email(
+                            &outgoing.to,
+                            outgoing.subject,
+                            outgoing.body,
+                            &unsubscribe,
+                        ),
+                        None => self.email.send_html_email(
+                            &outgoing.to,
+                            outgoing.subject,
+                            outgoing.body,
+                        ),
+                    };
+                    match result {
+                        Ok(_) => (EMAIL_SENT, None),
+                        Err(e) => {
+                            eprintln!("newsletter email {} failed: {}", queued.id, e);
+                            (EMAIL_FAILED, Some(e))
+                        }
+                    }
+                }
+            };
+            self.repository
+                .record_newsletter_email(queued.id, status, error, Utc::now().naive_utc())
+                .map_err(|e| SubscriberError::System(e.to_string()))?;
+        }
+        Ok(attempted)
+    }
+}
@@ -0,0 +1,33 @@
+use crate::app::features::subscribers::application::usecase::links::resolve_token;
+use crate::app::features::subscribers::domain::error::SubscriberError;
+use crate::app::features::subscribers::domain::repository::SubscriberRepository;
+use crate::app::features::subscribers::interface::dto::SubscriptionStatusDto;
+use crate::utils::token::UNSUBSCRIBE_AUDIENCE;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn SubscriberRepository + Send + Sync>,
+    secret: String,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn SubscriberRepository + Send + Sync>, secret: String) -> Self {
+        Self { repository, secret }
+    }
+
+    /// Where the subscription behind an unsubscribe link stands, without
+    /// changing it.
+    pub async fn execute(&self, token: String) -> Result<SubscriptionStatusDto, SubscriberError> {
+        let subscriber = resolve_token(
+            self.repository.as_ref(),
+            &token,
+            UNSUBSCRIBE_AUDIENCE,
+            &self.secret,
+        )?;
+        Ok(SubscriptionStatusDto {
+            email: subscriber.email,
+            status: subscriber.status,
+        })
+    }
+}
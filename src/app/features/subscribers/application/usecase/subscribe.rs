@@ -0,0 +1,71 @@
+use crate::app::features::subscribers::domain::entity::{
+    EMAIL_CONFIRMATION, NewNewsletterEmail, NewSubscriber, STATUS_CONFIRMED, STATUS_PENDING,
+};
+use crate::app::features::subscribers::domain::error::SubscriberError;
+use crate::app::features::subscribers::domain::repository::SubscriberRepository;
+use crate::app::features::subscribers::interface::dto::SubscribeRequestDto;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use validator::Validate;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn SubscriberRepository + Send + Sync>,
+    resend_after: Duration,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn SubscriberRepository + Send + Sync>, resend_secs: i64) -> Self {
+        Self {
+            repository,
+            resend_after: Duration::seconds(resend_secs),
+        }
+    }
+
+    /// Queues a confirmation link unless the address is already confirmed
+    /// or was sent one less than `resend_after` ago, so the endpoint can't
+    /// be used to flood an inbox. The outcome is never revealed, so the
+    /// endpoint can't be used to find out who is subscribed either.
+    pub async fn execute(&self, payload: SubscribeRequestDto) -> Result<(), SubscriberError> {
+        payload.validate().map_err(SubscriberError::Validation)?;
+        let address = payload.email.trim().to_lowercase();
+        let now = Utc::now().naive_utc();
+
+        let existing = self
+            .repository
+            .get_subscriber_by_email(address.clone())
+            .map_err(|e| SubscriberError::System(e.to_string()))?;
+        let subscriber = match existing {
+            Some(subscriber) if subscriber.status == STATUS_CONFIRMED => return Ok(()),
+            Some(subscriber) if subscriber.status == STATUS_PENDING => subscriber,
+            // Coming back after unsubscribing needs a fresh opt-in
+            Some(subscriber) => self
+                .repository
+                .update_subscriber_status(subscriber.id, STATUS_PENDING, now)
+                .map_err(|e| SubscriberError::System(e.to_string()))?,
+            None => self
+                .repository
+                .create_subscriber(NewSubscriber {
+                    email: address,
+                    status: STATUS_PENDING.to_string(),
+                })
+                .map_err(|e| SubscriberError::System(e.to_string()))?,
+        };
+
+        let last_sent = self
+            .repository
+            .get_last_confirmation_at(subscriber.id)
+            .map_err(|e| SubscriberError::System(e.to_string()))?;
+        if last_sent.is_some_and(|sent| sent > now - self.resend_after) {
+            return Ok(());
+        }
+        self.repository
+            .create_newsletter_email(NewNewsletterEmail {
+                subscriber_id: subscriber.id,
+                kind: EMAIL_CONFIRMATION.to_string(),
+                blog_id: None,
+            })
+            .map_err(|e| SubscriberError::System(e.to_string()))?;
+        Ok(())
+    }
+}
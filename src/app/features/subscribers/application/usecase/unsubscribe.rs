@@ -0,0 +1,45 @@
+use crate::app::features::subscribers::application::usecase::links::resolve_token;
+use crate::app::features::subscribers::domain::entity::STATUS_UNSUBSCRIBED;
+use crate::app::features::subscribers::domain::error::SubscriberError;
+use crate::app::features::subscribers::domain::repository::SubscriberRepository;
+use crate::app::features::subscribers::interface::dto::SubscriptionStatusDto;
+use crate::utils::token::UNSUBSCRIBE_AUDIENCE;
+use chrono::Utc;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn SubscriberRepository + Send + Sync>,
+    secret: String,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn SubscriberRepository + Send + Sync>, secret: String) -> Self {
+        Self { repository, secret }
+    }
+
+    /// Idempotent, so mail clients may follow the link more than once.
+    pub async fn execute(&self, token: String) -> Result<SubscriptionStatusDto, SubscriberError> {
+        let subscriber = resolve_token(
+            self.repository.as_ref(),
+            &token,
+            UNSUBSCRIBE_AUDIENCE,
+            &self.secret,
+        )?;
+        let subscriber = if subscriber.status == STATUS_UNSUBSCRIBED {
+            subscriber
+        } else {
+            self.repository
+                .update_subscriber_status(
+                    subscriber.id,
+                    STATUS_UNSUBSCRIBED,
+                    Utc::now().naive_utc(),
+                )
+                .map_err(|e| SubscriberError::System(e.to_string()))?
+        };
+        Ok(SubscriptionStatusDto {
+            email: subscriber.email,
+            status: subscriber.status,
+        })
+    }
+}
@@ -0,0 +1,65 @@
+use crate::schema::{newsletter_emails, subscribers};
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+pub const STATUS_PENDING: &str = "PENDING";
+pub const STATUS_CONFIRMED: &str = "CONFIRMED";
+pub const STATUS_UNSUBSCRIBED: &str = "UNSUBSCRIBED";
+
+pub const EMAIL_CONFIRMATION: &str = "CONFIRMATION";
+pub const EMAIL_ISSUE: &str = "ISSUE";
+
+pub const EMAIL_PENDING: &str = "PENDING";
+pub const EMAIL_SENT: &str = "SENT";
+pub const EMAIL_FAILED: &str = "FAILED";
+pub const EMAIL_SKIPPED: &str = "SKIPPED";
+
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone, PartialEq)]
+#[diesel(table_name = subscribers)]
+pub struct Subscriber {
+    pub id: i32,
+    pub email: String,
+    pub status: String,
+    pub confirmed_at: Option<NaiveDateTime>,
+    pub unsubscribed_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = subscribers)]
+pub struct NewSubscriber {
+    pub email: String,
+    pub status: String,
+}
+
+/// The post a newsletter email announces.
+#[derive(Queryable, Debug, Clone, PartialEq)]
+pub struct NewsletterPost {
+    pub id: i32,
+    pub title: String,
+    pub slug: String,
+    pub excerpt: Option<String>,
+}
+
+/// A queued confirmation or post announcement for one subscriber.
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone, PartialEq)]
+#[diesel(table_name = newsletter_emails)]
+pub struct NewsletterEmail {
+    pub id: i32,
+    pub subscriber_id: i32,
+    pub kind: String,
+    pub blog_id: Option<i32>,
+    pub status: String,
+    pub error: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = newsletter_emails)]
+pub struct NewNewsletterEmail {
+    pub subscriber_id: i32,
+    pub kind: String,
+    pub blog_id: Option<i32>,
+}
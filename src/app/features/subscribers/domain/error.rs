@@ -0,0 +1,15 @@
+use validator::ValidationErrors;
+
+#[derive(Debug)]
+pub enum SubscriberError {
+    Validation(ValidationErrors),
+    System(String),
+    /// Missing, expired, tampered or outdated confirmation/unsubscribe token
+    Unauthorized(String),
+}
+
+impl From<String> for SubscriberError {
+    fn from(s: String) -> Self {
+        SubscriberError::System(s)
+    }
+}
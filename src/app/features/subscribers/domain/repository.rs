@@ -0,0 +1,45 @@
+use crate::app::features::subscribers::domain::entity::{
+    NewNewsletterEmail, NewSubscriber, NewsletterEmail, NewsletterPost, Subscriber,
+};
+use chrono::NaiveDateTime;
+use diesel::QueryResult;
+
+pub trait SubscriberRepository {
+    fn get_subscriber_by_id(&self, id: i32) -> QueryResult<Option<Subscriber>>;
+    fn get_subscriber_by_email(&self, email: String) -> QueryResult<Option<Subscriber>>;
+    fn create_subscriber(&self, subscriber: NewSubscriber) -> QueryResult<Subscriber>;
+    /// Moves the subscriber to `status`, stamping `confirmed_at` or
+    /// `unsubscribed_at`; going back to PENDING clears both.
+    fn update_subscriber_status(
+        &self,
+        id: i32,
+        status: &str,
+        now: NaiveDateTime,
+    ) -> QueryResult<Subscriber>;
+    fn get_all_subscribers(
+        &self,
+        status: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> QueryResult<(Vec<Subscriber>, i64)>;
+    /// Records that `blog_id` is being announced and queues one ISSUE email
+    /// per confirmed subscriber. Returns how many were queued only the first
+    /// time, and only while the post is PUBLISHED.
+    fn queue_newsletter_issue(&self, blog_id: i32) -> QueryResult<Option<usize>>;
+    fn get_newsletter_post(&self, blog_id: i32) -> QueryResult<Option<NewsletterPost>>;
+    fn create_newsletter_email(&self, email: NewNewsletterEmail) -> QueryResult<NewsletterEmail>;
+    /// When the subscriber was last queued a confirmation email.
+    fn get_last_confirmation_at(&self, subscriber_id: i32) -> QueryResult<Option<NaiveDateTime>>;
+    /// PENDING emails with their subscriber, oldest first.
+    fn get_pending_newsletter_emails(
+        &self,
+        limit: i64,
+    ) -> QueryResult<Vec<(NewsletterEmail, Subscriber)>>;
+    fn record_newsletter_email(
+        &self,
+        id: i32,
+        status: &str,
+        error: Option<String>,
+        now: NaiveDateTime,
+    ) -> QueryResult<usize>;
+}
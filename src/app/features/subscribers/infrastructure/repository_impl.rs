@@ -0,0 +1,226 @@
+use crate::app::features::subscribers::domain::entity::{
+    EMAIL_CONFIRMATION, EMAIL_ISSUE, EMAIL_PENDING, NewNewsletterEmail, NewSubscriber,
+    NewsletterEmail, NewsletterPost, STATUS_CONFIRMED, STATUS_UNSUBSCRIBED, Subscriber,
+};
+use crate::app::features::subscribers::domain::repository::SubscriberRepository;
+use crate::schema::{blog, newsletter_emails, newsletter_issues, subscribers};
+use crate::utils::db::DbPool;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+#[derive(Clone)]
+pub struct SubscriberRepositoryImpl {
+    pub pool: DbPool,
+}
+
+impl SubscriberRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl SubscriberRepository for SubscriberRepositoryImpl {
+    fn get_subscriber_by_id(&self, id: i32) -> QueryResult<Option<Subscriber>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        subscribers::table
+            .find(id)
+            .first::<Subscriber>(&mut conn)
+            .optional()
+    }
+    fn get_subscriber_by_email(&self, email: String) -> QueryResult<Option<Subscriber>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        subscribers::table
+            .filter(subscribers::email.eq(email))
+            .first::<Subscriber>(&mut conn)
+            .optional()
+    }
+    fn create_subscriber(&self, subscriber: NewSubscriber) -> QueryResult<Subscriber> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::insert_into(subscribers::table)
+            .values(subscriber)
+            .get_result(&mut conn)
+    }
+    fn update_subscriber_status(
+        &self,
+        id: i32,
+        status: &str,
+        now: NaiveDateTime,
+    ) -> QueryResult<Subscriber> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        let target = subscribers::table.find(id);
+        match status {
+            STATUS_CONFIRMED => diesel::update(target)
+                .set((
+                    subscribers::status.eq(status),
+                    subscribers::confirmed_at.eq(Some(now)),
+                    subscribers::unsubscribed_at.eq(None::<NaiveDateTime>),
+                    subscribers::updated_at.eq(now),
+                ))
+                .get_result(&mut conn),
+            // Keeps `confirmed_at` as a record of the original opt-in
+            STATUS_UNSUBSCRIBED => diesel::update(target)
+                .set((
+                    subscribers::status.eq(status),
+                    subscribers::unsubscribed_at.eq(Some(now)),
+                    subscribers::updated_at.eq(now),
+                ))
+                .get_result(&mut conn),
+            _ => diesel::update(target)
+                .set((
+                    subscribers::status.eq(status),
+                    subscribers::confirmed_at.eq(None::<NaiveDateTime>),
+                    subscribers::unsubscribed_at.eq(None::<NaiveDateTime>),
+                    subscribers::updated_at.eq(now),
+                ))
+                .get_result(&mut conn),
+        }
+    }
+    fn get_all_subscribers(
+        &self,
+        status: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> QueryResult<(Vec<Subscriber>, i64)> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+
+        let filtered = || {
+            let mut query = subscribers::table.into_boxed();
+            if let Some(status) = &status {
+                query = query.filter(subscribers::status.eq(status.clone()));
+            }
+            query
+        };
+
+        let count = filtered().count().get_result(&mut conn)?;
+        let items = filtered()
+            .order(subscribers::id.desc())
+            .limit(limit)
+            .offset(offset)
+            .load::<Subscriber>(&mut conn)?;
+
+        Ok((items, count))
+    }
+    fn queue_newsletter_issue(&self, blog_id: i32) -> QueryResult<Option<usize>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        conn.transaction(|conn| {
+            let published = blog::table
+                .filter(blog::id.eq(blog_id))
+                .filter(blog::status.eq("PUBLISHED"))
+                .count()
+                .get_result::<i64>(conn)?;
+            if published == 0 {
+                return Ok(None);
+            }
+            let claimed = diesel::insert_or_ignore_into(newsletter_issues::table)
+                .values(newsletter_issues::blog_id.eq(blog_id))
+                .execute(conn)?;
+            if claimed == 0 {
+                return Ok(None);
+            }
+
+            let emails: Vec<NewNewsletterEmail> = subscribers::table
+                .filter(subscribers::status.eq(STATUS_CONFIRMED))
+                .order(subscribers::id.asc())
+                .select(subscribers::id)
+                .load::<i32>(conn)?
+                .into_iter()
+                .map(|subscriber_id| NewNewsletterEmail {
+                    subscriber_id,
+                    kind: EMAIL_ISSUE.to_string(),
+                    blog_id: Some(blog_id),
+                })
+                .collect();
+            let queued = diesel::insert_into(newsletter_emails::table)
+                .values(&emails)
+                .execute(conn)?;
+            diesel::update(newsletter_issues::table.find(blog_id))
+                .set(newsletter_issues::recipients.eq(queued as i32))
+                .execute(conn)?;
+            Ok(Some(queued))
+        })
+    }
+    fn get_newsletter_post(&self, blog_id: i32) -> QueryResult<Option<NewsletterPost>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        blog::table
+            .filter(blog::id.eq(blog_id))
+            .select((blog::id, blog::title, blog::slug, blog::excerpt))
+            .first::<NewsletterPost>(&mut conn)
+            .optional()
+    }
+    fn create_newsletter_email(&self, email: NewNewsletterEmail) -> QueryResult<NewsletterEmail> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::insert_into(newsletter_emails::table)
+            .values(email)
+            .get_result(&mut conn)
+    }
+    fn get_last_confirmation_at(&self, subscriber_id: i32) -> QueryResult<Option<NaiveDateTime>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        newsletter_emails::table
+            .filter(newsletter_emails::subscriber_id.eq(subscriber_id))
+            .filter(newsletter_emails::kind.eq(EMAIL_CONFIRMATION))
+            .select(diesel::dsl::max(newsletter_emails::created_at))
+            .first::<Option<NaiveDateTime>>(&mut conn)
+    }
+    fn get_pending_newsletter_emails(
+        &self,
+        limit: i64,
+    ) -> QueryResult<Vec<(NewsletterEmail, Subscriber)>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        newsletter_emails::table
+            .inner_join(subscribers::table)
+            .filter(newsletter_emails::status.eq(EMAIL_PENDING))
+            .order(newsletter_emails::id.asc())
+            .limit(limit)
+            .select((NewsletterEmail::as_select(), Subscriber::as_select()))
+            .load::<(NewsletterEmail, Subscriber)>(&mut conn)
+    }
+    fn record_newsletter_email(
+        &self,
+        id: i32,
+        status: &str,
+        error: Option<String>,
+        now: NaiveDateTime,
+    ) -> QueryResult<usize> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::update(newsletter_emails::table.find(id))
+            .set((
+                newsletter_emails::status.eq(status),
+                newsletter_emails::error.eq(error),
+                newsletter_emails::updated_at.eq(now),
+            ))
+            .execute(&mut conn)
+    }
+}
@@ -0,0 +1,222 @@
+use crate::app::features::subscribers::application::usecase::send::escape_html;
+use crate::app::features::subscribers::domain::entity::STATUS_UNSUBSCRIBED;
+use crate::app::features::subscribers::domain::error::SubscriberError;
+use crate::app::features::subscribers::interface::dto::{
+    SubscribeRequestDto, SubscriberListQueryDto, SubscriberResponseDto, SubscriptionStatusDto,
+};
+use crate::utils::di::Container;
+use crate::utils::error_response::{ErrorResponse, map_string_error, map_validation_error};
+use crate::utils::success_response::{map_success_response, map_success_with_data};
+use actix_web::http::header;
+use actix_web::{HttpRequest, HttpResponse, Responder, get, post, web};
+
+fn subscriber_error_response(e: SubscriberError) -> HttpResponse {
+    match e {
+        SubscriberError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+        SubscriberError::Unauthorized(msg) => {
+            HttpResponse::Unauthorized().json(map_string_error(msg))
+        }
+        SubscriberError::System(msg) => {
+            HttpResponse::InternalServerError().json(map_string_error(msg))
+        }
+    }
+}
+
+#[utoipa::path(
+    path = "/app/subscribers",
+    tag = "Newsletter",
+    params(
+        SubscriberListQueryDto
+    ),
+    responses(
+        (status = 200, description = "Subscribers, newest first", body = crate::utils::success_response::SuccessResponse<Vec<SubscriberResponseDto>>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[get("/subscribers")]
+pub async fn get_subscribers(
+    container: web::Data<Container>,
+    query: web::Query<SubscriberListQueryDto>,
+) -> impl Responder {
+    match container
+        .get_subscribers_usecase
+        .execute(query.into_inner())
+        .await
+    {
+        Ok(subscribers) => HttpResponse::Ok().json(map_success_with_data(
+            "Subscribers fetched successfully".to_string(),
+            subscribers,
+        )),
+        Err(e) => subscriber_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    path = "/api/public/newsletter/subscribe",
+    tag = "Newsletter",
+    request_body = SubscribeRequestDto,
+    responses(
+        (status = 202, description = "A confirmation email is queued unless the address is already confirmed or was sent one recently; the answer is the same either way"),
+        (status = 400, description = "Validation error", body = ErrorResponse)
+    ),
+    security(())
+)]
+#[post("/newsletter/subscribe")]
+pub async fn subscribe(
+    container: web::Data<Container>,
+    payload: web::Json<SubscribeRequestDto>,
+) -> impl Responder {
+    match container
+        .subscribe_usecase
+        .execute(payload.into_inner())
+        .await
+    {
+        Err(SubscriberError::Validation(e)) => {
+            HttpResponse::BadRequest().json(map_validation_error(e))
+        }
+        result => {
+            // Failures are logged, not shown, so the answer gives nothing away
+            if let Err(e) = result {
+                eprintln!("newsletter subscribe failed: {:?}", e);
+            }
+            HttpResponse::Accepted().json(map_success_response(
+                "Please check your inbox to confirm your subscription".to_string(),
+            ))
+        }
+    }
+}
+
+#[utoipa::path(
+    path = "/api/public/newsletter/confirm/{token}",
+    tag = "Newsletter",
+    params(
+        ("token" = String, Path, description = "Token from the confirmation email")
+    ),
+    responses(
+        (status = 200, description = "Subscription confirmed", body = crate::utils::success_response::SuccessResponse<SubscriptionStatusDto>),
+        (status = 401, description = "Invalid or expired link", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    security(())
+)]
+#[get("/newsletter/confirm/{token}")]
+pub async fn confirm_subscription(
+    container: web::Data<Container>,
+    token: web::Path<String>,
+) -> impl Responder {
+    match container
+        .confirm_subscription_usecase
+        .execute(token.into_inner())
+        .await
+    {
+        Ok(status) => HttpResponse::Ok().json(map_success_with_data(
+            "Subscription confirmed".to_string(),
+            status,
+        )),
+        Err(e) => subscriber_error_response(e),
+    }
+}
+
+/// Browsers following the link from the email get a page; API clients get
+/// JSON.
+fn wants_html(req: &HttpRequest) -> bool {
+    req.headers()
+        .get(header::ACCEPT)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.contains("text/html"))
+}
+
+/// The subscription status, with a button that POSTs back to the same link
+/// while the reader is still subscribed.
+fn unsubscribe_page(status: &SubscriptionStatusDto) -> HttpResponse {
+    let email = escape_html(&status.email);
+    let body = if status.status == STATUS_UNSUBSCRIBED {
+        format!("<p>{email} is unsubscribed and will not receive new posts.</p>")
+    } else {
+        format!(
+            "<p>Stop sending new posts to {email}?</p>\
+             <form method=\"post\"><button type=\"submit\">Unsubscribe</button></form>"
+        )
+    };
+    HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body(format!(
+            "<!DOCTYPE html><html><head><meta charset=\"utf-8\">\
+             <title>Unsubscribe</title></head><body>{body}</body></html>"
+        ))
+}
+
+fn subscription_status_response(
+    req: &HttpRequest,
+    message: &str,
+    status: SubscriptionStatusDto,
+) -> HttpResponse {
+    if wants_html(req) {
+        unsubscribe_page(&status)
+    } else {
+        HttpResponse::Ok().json(map_success_with_data(message.to_string(), status))
+    }
+}
+
+/// Read-only, because mail scanners and link prefetchers follow GET links;
+/// the reader unsubscribes with the button, which POSTs.
+#[utoipa::path(
+    path = "/api/public/newsletter/unsubscribe/{token}",
+    tag = "Newsletter",
+    params(
+        ("token" = String, Path, description = "Token from the newsletter email")
+    ),
+    responses(
+        (status = 200, description = "Current status, as an HTML page with an unsubscribe button when the request accepts text/html", body = crate::utils::success_response::SuccessResponse<SubscriptionStatusDto>),
+        (status = 401, description = "Invalid link", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    security(())
+)]
+#[get("/newsletter/unsubscribe/{token}")]
+pub async fn unsubscribe(
+    req: HttpRequest,
+    container: web::Data<Container>,
+    token: web::Path<String>,
+) -> impl Responder {
+    match container
+        .get_subscription_status_usecase
+        .execute(token.into_inner())
+        .await
+    {
+        Ok(status) => subscription_status_response(&req, "Subscription status fetched", status),
+        Err(e) => subscriber_error_response(e),
+    }
+}
+
+/// RFC 8058 one-click unsubscribe, sent by mail clients from the
+/// `List-Unsubscribe` header, and the button on the unsubscribe page.
+#[utoipa::path(
+    path = "/api/public/newsletter/unsubscribe/{token}",
+    tag = "Newsletter",
+    params(
+        ("token" = String, Path, description = "Token from the newsletter email")
+    ),
+    responses(
+        (status = 200, description = "Unsubscribed", body = crate::utils::success_response::SuccessResponse<SubscriptionStatusDto>),
+        (status = 401, description = "Invalid link", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    security(())
+)]
+#[post("/newsletter/unsubscribe/{token}")]
+pub async fn unsubscribe_one_click(
+    req: HttpRequest,
+    container: web::Data<Container>,
+    token: web::Path<String>,
+) -> impl Responder {
+    match container
+        .unsubscribe_usecase
+        .execute(token.into_inner())
+        .await
+    {
+        Ok(status) => subscription_status_response(&req, "Unsubscribed successfully", status),
+        Err(e) => subscriber_error_response(e),
+    }
+}
@@ -0,0 +1,77 @@
+use crate::app::features::subscribers::domain::entity::Subscriber;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::{Validate, ValidationError};
+
+#[derive(Deserialize, Serialize, Validate, ToSchema)]
+pub struct SubscribeRequestDto {
+    #[validate(
+        email(message = "Email is invalid"),
+        length(max = 254, message = "Email is too long")
+    )]
+    pub email: String,
+}
+
+fn validate_status(status: &str) -> Result<(), ValidationError> {
+    match status {
+        "PENDING" | "CONFIRMED" | "UNSUBSCRIBED" => Ok(()),
+        _ => Err(ValidationError::new("Invalid status")),
+    }
+}
+
+#[derive(Deserialize, Serialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SubscriberListQueryDto {
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+    /// PENDING, CONFIRMED or UNSUBSCRIBED
+    #[validate(custom(function = "validate_status"))]
+    pub status: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct SubscriberResponseDto {
+    pub id: i32,
+    pub email: String,
+    /// PENDING, CONFIRMED or UNSUBSCRIBED
+    pub status: String,
+    pub confirmed_at: Option<String>,
+    pub unsubscribed_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<Subscriber> for SubscriberResponseDto {
+    fn from(subscriber: Subscriber) -> Self {
+        Self {
+            id: subscriber.id,
+            email: subscriber.email,
+            status: subscriber.status,
+            confirmed_at: subscriber.confirmed_at.map(|t| t.to_string()),
+            unsubscribed_at: subscriber.unsubscribed_at.map(|t| t.to_string()),
+            created_at: subscriber.created_at.to_string(),
+            updated_at: subscriber.updated_at.to_string(),
+        }
+    }
+}
+
+/// What a reader sees after following a confirmation or unsubscribe link.
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct SubscriptionStatusDto {
+    pub email: String,
+    pub status: String,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct MetaDto {
+    pub page: i64,
+    pub per_page: i64,
+    pub total_pages: i64,
+    pub total_items: i64,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PaginatedResponseDto<T> {
+    pub items: Vec<T>,
+    pub meta: MetaDto,
+}
@@ -0,0 +1,39 @@
+use crate::app::features::webhooks::domain::entity::NewWebhook;
+use crate::app::features::webhooks::domain::error::WebhookError;
+use crate::app::features::webhooks::domain::repository::WebhookRepository;
+use crate::app::features::webhooks::interface::dto::{CreateWebhookDto, WebhookResponseDto};
+use std::sync::Arc;
+use validator::Validate;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn WebhookRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn WebhookRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        payload: CreateWebhookDto,
+    ) -> Result<WebhookResponseDto, WebhookError> {
+        payload.validate().map_err(WebhookError::Validation)?;
+        let webhook = self
+            .repository
+            .create_webhook(
+                NewWebhook {
+                    url: payload.url,
+                    secret: payload.secret,
+                    is_active: payload.is_active.unwrap_or(true),
+                },
+                payload.events.clone(),
+            )
+            .map_err(|e| WebhookError::System(e.to_string()))?;
+        let mut events = payload.events;
+        events.sort();
+        events.dedup();
+        Ok((webhook, events).into())
+    }
+}
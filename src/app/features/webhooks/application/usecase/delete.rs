@@ -0,0 +1,26 @@
+use crate::app::features::webhooks::domain::error::WebhookError;
+use crate::app::features::webhooks::domain::repository::WebhookRepository;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn WebhookRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn WebhookRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Removes the webhook together with its delivery log.
+    pub async fn execute(&self, id: i32) -> Result<(), WebhookError> {
+        let deleted = self
+            .repository
+            .delete_webhook(id)
+            .map_err(|e| WebhookError::System(e.to_string()))?;
+        if deleted == 0 {
+            return Err(WebhookError::NotFound("Webhook not found".to_string()));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use crate::app::features::webhooks::domain::entity::{
+    DELIVERY_FAILED, DELIVERY_PENDING, DELIVERY_SUCCEEDED, DeliveryAttempt, Webhook,
+    WebhookDelivery, sign_payload,
+};
+use crate::app::features::webhooks::domain::error::WebhookError;
+use crate::app::features::webhooks::domain::repository::WebhookRepository;
+use crate::app::features::webhooks::domain::sender::{WebhookRequest, WebhookSender};
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+/// Deliveries attempted per run; the rest wait for the next tick.
+const BATCH_SIZE: i64 = 50;
+/// Stored response bodies are cut to this many characters.
+const MAX_RESPONSE_BODY: usize = 1000;
+
+/// Wait before the next attempt after `attempts` failed ones: `base_secs`,
+/// then doubling each time.
+pub fn backoff(base_secs: i64, attempts: i32) -> Duration {
+    let exponent = (attempts - 1).clamp(0, 16) as u32;
+    Duration::seconds(base_secs.saturating_mul(2_i64.pow(exponent)))
+}
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn WebhookRepository + Send + Sync>,
+    sender: Arc<dyn WebhookSender + Send + Sync>,
+    max_attempts: i32,
+    backoff_secs: i64,
+}
+
+impl Execute {
+    pub fn new(
+        repository: Arc<dyn WebhookRepository + Send + Sync>,
+        sender: Arc<dyn WebhookSender + Send + Sync>,
+        max_attempts: i32,
+        backoff_secs: i64,
+    ) -> Self {
+        Self {
+            repository,
+            sender,
+            max_attempts,
+            backoff_secs,
+        }
+    }
+
+    /// Attempts every delivery that is due and returns how many were tried.
+    /// The HTTP calls block, so callers on an async runtime should run this on
+    /// a blocking thread.
+    pub fn execute(&self) -> Result<usize, WebhookError> {
+        let due = self
+            .repository
+            .get_due_deliveries(Utc::now().naive_utc(), BATCH_SIZE)
+            .map_err(|e| WebhookError::System(e.to_string()))?;
+        let attempted = due.len();
+        for (delivery, webhook) in due {
+            self.deliver_one(delivery, &webhook)?;
+        }
+        Ok(attempted)
+    }
+
+    /// Sends one delivery and records the outcome. A 2xx response succeeds;
+    /// anything else is retried with backoff until `max_attempts` is reached.
+    fn deliver_one(
+        &self,
+        delivery: WebhookDelivery,
+        webhook: &Webhook,
+    ) -> Result<WebhookDelivery, WebhookError> {
+        let signature = sign_payload(&webhook.secret, &delivery.payload);
+        let result = self.sender.send(&WebhookRequest {
+            url: &webhook.url,
+            event: &delivery.event,
+            delivery_id: delivery.id,
+            signature: &signature,
+            payload: &delivery.payload,
+        });
+
+        let attempts = delivery.attempts + 1;
+        let now = Utc::now().naive_utc();
+        let (succeeded, response_status, response_body, error) = match result {
+            Ok(response) => (
+                (200..300).contains(&response.status),
+                Some(response.status as i32),
+                Some(response.body.chars().take(MAX_RESPONSE_BODY).collect()),
+                None,
+            ),
+            Err(e) => (false, None, None, Some(e)),
+        };
+        let (status, next_attempt_at) = if succeeded {
+            (DELIVERY_SUCCEEDED, None)
+        } else if attempts >= self.max_attempts {
+            (DELIVERY_FAILED, None)
+        } else {
+            (
+                DELIVERY_PENDING,
+                Some(now + backoff(self.backoff_secs, attempts)),
+            )
+        };
+
+        self.repository
+            .record_attempt(
+                delivery.id,
+                DeliveryAttempt {
+                    status: status.to_string(),
+                    attempts,
+                    next_attempt_at,
+                    response_status,
+                    response_body,
+                    error,
+                    updated_at: now,
+                },
+            )
+            .map_err(|e| WebhookError::System(e.to_string()))
+    }
+}
@@ -0,0 +1,56 @@
+use crate::app::features::webhooks::domain::entity::{
+    DELIVERY_PENDING, NewWebhookDelivery, WebhookEvent,
+};
+use crate::app::features::webhooks::domain::error::WebhookError;
+use crate::app::features::webhooks::domain::repository::WebhookRepository;
+use chrono::Utc;
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn WebhookRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn WebhookRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Queues one delivery per active webhook subscribed to `event` and
+    /// returns how many were queued. Sending happens in the background.
+    pub async fn execute(
+        &self,
+        event: WebhookEvent,
+        data: serde_json::Value,
+    ) -> Result<usize, WebhookError> {
+        let webhooks = self
+            .repository
+            .get_subscribed_webhooks(event.as_str())
+            .map_err(|e| WebhookError::System(e.to_string()))?;
+        if webhooks.is_empty() {
+            return Ok(0);
+        }
+
+        let now = Utc::now();
+        let payload = json!({
+            "event": event.as_str(),
+            "occurred_at": now.to_rfc3339(),
+            "data": data,
+        })
+        .to_string();
+        let deliveries = webhooks
+            .into_iter()
+            .map(|webhook| NewWebhookDelivery {
+                webhook_id: webhook.id,
+                event: event.as_str().to_string(),
+                payload: payload.clone(),
+                status: DELIVERY_PENDING.to_string(),
+                next_attempt_at: Some(now.naive_utc()),
+            })
+            .collect();
+        self.repository
+            .create_deliveries(deliveries)
+            .map_err(|e| WebhookError::System(e.to_string()))
+    }
+}
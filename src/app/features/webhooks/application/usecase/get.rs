@@ -0,0 +1,23 @@
+use crate::app::features::webhooks::domain::error::WebhookError;
+use crate::app::features::webhooks::domain::repository::WebhookRepository;
+use crate::app::features::webhooks::interface::dto::WebhookResponseDto;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn WebhookRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn WebhookRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, id: i32) -> Result<WebhookResponseDto, WebhookError> {
+        self.repository
+            .get_webhook_by_id(id)
+            .map_err(|e| WebhookError::System(e.to_string()))?
+            .map(Into::into)
+            .ok_or_else(|| WebhookError::NotFound("Webhook not found".to_string()))
+    }
+}
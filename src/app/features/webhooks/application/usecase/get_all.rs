@@ -0,0 +1,23 @@
+use crate::app::features::webhooks::domain::error::WebhookError;
+use crate::app::features::webhooks::domain::repository::WebhookRepository;
+use crate::app::features::webhooks::interface::dto::WebhookResponseDto;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn WebhookRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn WebhookRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<WebhookResponseDto>, WebhookError> {
+        let webhooks = self
+            .repository
+            .get_all_webhooks()
+            .map_err(|e| WebhookError::System(e.to_string()))?;
+        Ok(webhooks.into_iter().map(Into::into).collect())
+    }
+}
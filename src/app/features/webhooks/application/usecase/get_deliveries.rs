@@ -0,0 +1,55 @@
+use crate::app::features::webhooks::domain::error::WebhookError;
+use crate::app::features::webhooks::domain::repository::WebhookRepository;
+use crate::app::features::webhooks::interface::dto::{
+    DeliveryListQueryDto, MetaDto, PaginatedResponseDto, WebhookDeliveryDto,
+};
+use std::sync::Arc;
+use validator::Validate;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn WebhookRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn WebhookRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        webhook_id: i32,
+        query: DeliveryListQueryDto,
+    ) -> Result<PaginatedResponseDto<WebhookDeliveryDto>, WebhookError> {
+        query.validate().map_err(WebhookError::Validation)?;
+        if self
+            .repository
+            .get_webhook_by_id(webhook_id)
+            .map_err(|e| WebhookError::System(e.to_string()))?
+            .is_none()
+        {
+            return Err(WebhookError::NotFound("Webhook not found".to_string()));
+        }
+
+        let page = query.page.unwrap_or(1);
+        let per_page = query.per_page.unwrap_or(10);
+        let offset = (page - 1) * per_page;
+
+        let (deliveries, total_items) = self
+            .repository
+            .get_deliveries(webhook_id, query.status, per_page, offset)
+            .map_err(|e| WebhookError::System(e.to_string()))?;
+
+        let total_pages = (total_items as f64 / per_page as f64).ceil() as i64;
+
+        Ok(PaginatedResponseDto {
+            items: deliveries.into_iter().map(Into::into).collect(),
+            meta: MetaDto {
+                page,
+                per_page,
+                total_pages,
+                total_items,
+            },
+        })
+    }
+}
@@ -0,0 +1,9 @@
+pub mod create;
+pub mod delete;
+pub mod deliver;
+pub mod enqueue;
+pub mod get;
+pub mod get_all;
+pub mod get_deliveries;
+pub mod redeliver;
+pub mod update;
@@ -0,0 +1,40 @@
+use crate::app::features::webhooks::domain::entity::{DELIVERY_PENDING, NewWebhookDelivery};
+use crate::app::features::webhooks::domain::error::WebhookError;
+use crate::app::features::webhooks::domain::repository::WebhookRepository;
+use crate::app::features::webhooks::interface::dto::WebhookDeliveryDto;
+use chrono::Utc;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn WebhookRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn WebhookRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Queues the same payload again as a new delivery, due right away; the
+    /// original entry is left as it was. The background deliverer sends it
+    /// and retries it like any other delivery.
+    pub async fn execute(&self, delivery_id: i32) -> Result<WebhookDeliveryDto, WebhookError> {
+        let (original, webhook) = self
+            .repository
+            .get_delivery(delivery_id)
+            .map_err(|e| WebhookError::System(e.to_string()))?
+            .ok_or_else(|| WebhookError::NotFound("Delivery not found".to_string()))?;
+
+        let delivery = self
+            .repository
+            .create_delivery(NewWebhookDelivery {
+                webhook_id: webhook.id,
+                event: original.event,
+                payload: original.payload,
+                status: DELIVERY_PENDING.to_string(),
+                next_attempt_at: Some(Utc::now().naive_utc()),
+            })
+            .map_err(|e| WebhookError::System(e.to_string()))?;
+        Ok(delivery.into())
+    }
+}
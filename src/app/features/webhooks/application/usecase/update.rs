@@ -0,0 +1,46 @@
+use crate::app::features::webhooks::domain::error::WebhookError;
+use crate::app::features::webhooks::domain::repository::WebhookRepository;
+use crate::app::features::webhooks::interface::dto::{UpdateWebhookDto, WebhookResponseDto};
+use std::sync::Arc;
+use validator::Validate;
+
+#[derive(Clone)]
+pub struct Execute {
+    repository: Arc<dyn WebhookRepository + Send + Sync>,
+}
+
+impl Execute {
+    pub fn new(repository: Arc<dyn WebhookRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        id: i32,
+        payload: UpdateWebhookDto,
+    ) -> Result<WebhookResponseDto, WebhookError> {
+        payload.validate().map_err(WebhookError::Validation)?;
+        if self
+            .repository
+            .get_webhook_by_id(id)
+            .map_err(|e| WebhookError::System(e.to_string()))?
+            .is_none()
+        {
+            return Err(WebhookError::NotFound("Webhook not found".to_string()));
+        }
+        let webhook = self
+            .repository
+            .update_webhook(
+                id,
+                payload.url,
+                payload.secret,
+                payload.is_active,
+                payload.events.clone(),
+            )
+            .map_err(|e| WebhookError::System(e.to_string()))?;
+        let mut events = payload.events;
+        events.sort();
+        events.dedup();
+        Ok((webhook, events).into())
+    }
+}
@@ -0,0 +1,129 @@
+use crate::schema::{webhook_deliveries, webhooks};
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+pub const DELIVERY_PENDING: &str = "PENDING";
+pub const DELIVERY_SUCCEEDED: &str = "SUCCEEDED";
+pub const DELIVERY_FAILED: &str = "FAILED";
+
+/// Content lifecycle events a webhook can subscribe to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookEvent {
+    BlogCreated,
+    BlogUpdated,
+    BlogPublished,
+    BlogDeleted,
+    ProjectCreated,
+    ProjectUpdated,
+    ProjectDeleted,
+    PortofolioCreated,
+    PortofolioUpdated,
+    PortofolioDeleted,
+    ProfileUpserted,
+}
+
+impl WebhookEvent {
+    pub const ALL: [WebhookEvent; 11] = [
+        WebhookEvent::BlogCreated,
+        WebhookEvent::BlogUpdated,
+        WebhookEvent::BlogPublished,
+        WebhookEvent::BlogDeleted,
+        WebhookEvent::ProjectCreated,
+        WebhookEvent::ProjectUpdated,
+        WebhookEvent::ProjectDeleted,
+        WebhookEvent::PortofolioCreated,
+        WebhookEvent::PortofolioUpdated,
+        WebhookEvent::PortofolioDeleted,
+        WebhookEvent::ProfileUpserted,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEvent::BlogCreated => "blog.created",
+            WebhookEvent::BlogUpdated => "blog.updated",
+            WebhookEvent::BlogPublished => "blog.published",
+            WebhookEvent::BlogDeleted => "blog.deleted",
+            WebhookEvent::ProjectCreated => "project.created",
+            WebhookEvent::ProjectUpdated => "project.updated",
+            WebhookEvent::ProjectDeleted => "project.deleted",
+            WebhookEvent::PortofolioCreated => "portofolio.created",
+            WebhookEvent::PortofolioUpdated => "portofolio.updated",
+            WebhookEvent::PortofolioDeleted => "portofolio.deleted",
+            WebhookEvent::ProfileUpserted => "profile.upserted",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|e| e.as_str() == value)
+    }
+}
+
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone, PartialEq)]
+#[diesel(table_name = webhooks)]
+pub struct Webhook {
+    pub id: i32,
+    pub url: String,
+    pub secret: String,
+    pub is_active: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = webhooks)]
+pub struct NewWebhook {
+    pub url: String,
+    pub secret: String,
+    pub is_active: bool,
+}
+
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone, PartialEq)]
+#[diesel(table_name = webhook_deliveries)]
+pub struct WebhookDelivery {
+    pub id: i32,
+    pub webhook_id: i32,
+    pub event: String,
+    pub payload: String,
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: Option<NaiveDateTime>,
+    pub response_status: Option<i32>,
+    pub response_body: Option<String>,
+    pub error: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = webhook_deliveries)]
+pub struct NewWebhookDelivery {
+    pub webhook_id: i32,
+    pub event: String,
+    pub payload: String,
+    pub status: String,
+    pub next_attempt_at: Option<NaiveDateTime>,
+}
+
+/// The outcome of one attempt, written back to the delivery log.
+#[derive(AsChangeset)]
+#[diesel(table_name = webhook_deliveries, treat_none_as_null = true)]
+pub struct DeliveryAttempt {
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: Option<NaiveDateTime>,
+    pub response_status: Option<i32>,
+    pub response_body: Option<String>,
+    pub error: Option<String>,
+    pub updated_at: NaiveDateTime,
+}
+
+/// Value of the `X-Webhook-Signature` header: `sha256=` and the hex
+/// HMAC-SHA256 of the raw request body, keyed with the webhook's secret.
+pub fn sign_payload(secret: &str, payload: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(payload.as_bytes());
+    format!("sha256={:x}", mac.finalize().into_bytes())
+}
@@ -0,0 +1,14 @@
+use validator::ValidationErrors;
+
+#[derive(Debug)]
+pub enum WebhookError {
+    Validation(ValidationErrors),
+    System(String),
+    NotFound(String),
+}
+
+impl From<String> for WebhookError {
+    fn from(s: String) -> Self {
+        WebhookError::System(s)
+    }
+}
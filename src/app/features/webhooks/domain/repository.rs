@@ -0,0 +1,42 @@
+use crate::app::features::webhooks::domain::entity::{
+    DeliveryAttempt, NewWebhook, NewWebhookDelivery, Webhook, WebhookDelivery,
+};
+use chrono::NaiveDateTime;
+use diesel::QueryResult;
+
+pub trait WebhookRepository {
+    fn create_webhook(&self, webhook: NewWebhook, events: Vec<String>) -> QueryResult<Webhook>;
+    fn get_all_webhooks(&self) -> QueryResult<Vec<(Webhook, Vec<String>)>>;
+    fn get_webhook_by_id(&self, id: i32) -> QueryResult<Option<(Webhook, Vec<String>)>>;
+    /// Replaces the webhook's fields and its event subscriptions; `secret`
+    /// is only changed when given.
+    fn update_webhook(
+        &self,
+        id: i32,
+        url: String,
+        secret: Option<String>,
+        is_active: bool,
+        events: Vec<String>,
+    ) -> QueryResult<Webhook>;
+    fn delete_webhook(&self, id: i32) -> QueryResult<usize>;
+    /// Active webhooks subscribed to `event`.
+    fn get_subscribed_webhooks(&self, event: &str) -> QueryResult<Vec<Webhook>>;
+    fn create_deliveries(&self, deliveries: Vec<NewWebhookDelivery>) -> QueryResult<usize>;
+    fn create_delivery(&self, delivery: NewWebhookDelivery) -> QueryResult<WebhookDelivery>;
+    /// PENDING deliveries of active webhooks whose next attempt is due,
+    /// oldest first.
+    fn get_due_deliveries(
+        &self,
+        now: NaiveDateTime,
+        limit: i64,
+    ) -> QueryResult<Vec<(WebhookDelivery, Webhook)>>;
+    fn get_delivery(&self, id: i32) -> QueryResult<Option<(WebhookDelivery, Webhook)>>;
+    fn record_attempt(&self, id: i32, attempt: DeliveryAttempt) -> QueryResult<WebhookDelivery>;
+    fn get_deliveries(
+        &self,
+        webhook_id: i32,
+        status: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> QueryResult<(Vec<WebhookDelivery>, i64)>;
+}
@@ -0,0 +1,20 @@
+/// One signed delivery, ready to be POSTed.
+pub struct WebhookRequest<'a> {
+    pub url: &'a str,
+    pub event: &'a str,
+    pub delivery_id: i32,
+    pub signature: &'a str,
+    pub payload: &'a str,
+}
+
+pub struct WebhookResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends webhook deliveries to their endpoints.
+pub trait WebhookSender {
+    /// `Err` when no response was received at all (refused, timed out, ...);
+    /// non-2xx responses are `Ok` and judged by the caller.
+    fn send(&self, request: &WebhookRequest) -> Result<WebhookResponse, String>;
+}
@@ -0,0 +1,2 @@
+pub mod repository_impl;
+pub mod sender_impl;
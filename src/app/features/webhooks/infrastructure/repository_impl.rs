@@ -0,0 +1,243 @@
+use crate::app::features::webhooks::domain::entity::{
+    DELIVERY_PENDING, DeliveryAttempt, NewWebhook, NewWebhookDelivery, Webhook, WebhookDelivery,
+};
+use crate::app::features::webhooks::domain::repository::WebhookRepository;
+use crate::schema::{webhook_deliveries, webhook_events, webhooks};
+use crate::utils::db::DbPool;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use diesel::sqlite::SqliteConnection;
+
+#[derive(Clone)]
+pub struct WebhookRepositoryImpl {
+    pub pool: DbPool,
+}
+
+impl WebhookRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn replace_events(conn: &mut SqliteConnection, id: i32, events: Vec<String>) -> QueryResult<()> {
+    diesel::delete(webhook_events::table.filter(webhook_events::webhook_id.eq(id)))
+        .execute(conn)?;
+    let rows: Vec<_> = events
+        .into_iter()
+        .map(|event| {
+            (
+                webhook_events::webhook_id.eq(id),
+                webhook_events::event.eq(event),
+            )
+        })
+        .collect();
+    diesel::insert_or_ignore_into(webhook_events::table)
+        .values(rows)
+        .execute(conn)?;
+    Ok(())
+}
+
+fn load_events(conn: &mut SqliteConnection, ids: Vec<i32>) -> QueryResult<Vec<(i32, String)>> {
+    webhook_events::table
+        .filter(webhook_events::webhook_id.eq_any(ids))
+        .order(webhook_events::event.asc())
+        .select((webhook_events::webhook_id, webhook_events::event))
+        .load(conn)
+}
+
+impl WebhookRepository for WebhookRepositoryImpl {
+    fn create_webhook(&self, webhook: NewWebhook, events: Vec<String>) -> QueryResult<Webhook> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        conn.transaction(|conn| {
+            let created: Webhook = diesel::insert_into(webhooks::table)
+                .values(webhook)
+                .get_result(conn)?;
+            replace_events(conn, created.id, events)?;
+            Ok(created)
+        })
+    }
+    fn get_all_webhooks(&self) -> QueryResult<Vec<(Webhook, Vec<String>)>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        let items = webhooks::table
+            .order(webhooks::id.asc())
+            .load::<Webhook>(&mut conn)?;
+        let events = load_events(&mut conn, items.iter().map(|w| w.id).collect())?;
+        Ok(items
+            .into_iter()
+            .map(|webhook| {
+                let subscribed = events
+                    .iter()
+                    .filter(|(id, _)| *id == webhook.id)
+                    .map(|(_, event)| event.clone())
+                    .collect();
+                (webhook, subscribed)
+            })
+            .collect())
+    }
+    fn get_webhook_by_id(&self, id: i32) -> QueryResult<Option<(Webhook, Vec<String>)>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        let Some(webhook) = webhooks::table
+            .find(id)
+            .first::<Webhook>(&mut conn)
+            .optional()?
+        else {
+            return Ok(None);
+        };
+        let events = load_events(&mut conn, vec![id])?
+            .into_iter()
+            .map(|(_, event)| event)
+            .collect();
+        Ok(Some((webhook, events)))
+    }
+    fn update_webhook(
+        &self,
+        id: i32,
+        url: String,
+        secret: Option<String>,
+        is_active: bool,
+        events: Vec<String>,
+    ) -> QueryResult<Webhook> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        conn.transaction(|conn| {
+            let now = chrono::Utc::now().naive_utc();
+            if let Some(secret) = secret {
+                diesel::update(webhooks::table.find(id))
+                    .set(webhooks::secret.eq(secret))
+                    .execute(conn)?;
+            }
+            let updated: Webhook = diesel::update(webhooks::table.find(id))
+                .set((
+                    webhooks::url.eq(url),
+                    webhooks::is_active.eq(is_active),
+                    webhooks::updated_at.eq(now),
+                ))
+                .get_result(conn)?;
+            replace_events(conn, id, events)?;
+            Ok(updated)
+        })
+    }
+    fn delete_webhook(&self, id: i32) -> QueryResult<usize> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::delete(webhooks::table.find(id)).execute(&mut conn)
+    }
+    fn get_subscribed_webhooks(&self, event: &str) -> QueryResult<Vec<Webhook>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        webhooks::table
+            .inner_join(webhook_events::table)
+            .filter(webhook_events::event.eq(event))
+            .filter(webhooks::is_active.eq(true))
+            .order(webhooks::id.asc())
+            .select(Webhook::as_select())
+            .load(&mut conn)
+    }
+    fn create_deliveries(&self, deliveries: Vec<NewWebhookDelivery>) -> QueryResult<usize> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::insert_into(webhook_deliveries::table)
+            .values(deliveries)
+            .execute(&mut conn)
+    }
+    fn create_delivery(&self, delivery: NewWebhookDelivery) -> QueryResult<WebhookDelivery> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::insert_into(webhook_deliveries::table)
+            .values(delivery)
+            .get_result(&mut conn)
+    }
+    fn get_due_deliveries(
+        &self,
+        now: NaiveDateTime,
+        limit: i64,
+    ) -> QueryResult<Vec<(WebhookDelivery, Webhook)>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        webhook_deliveries::table
+            .inner_join(webhooks::table)
+            .filter(webhook_deliveries::status.eq(DELIVERY_PENDING))
+            .filter(webhook_deliveries::next_attempt_at.le(now))
+            .filter(webhooks::is_active.eq(true))
+            .order((
+                webhook_deliveries::next_attempt_at.asc(),
+                webhook_deliveries::id.asc(),
+            ))
+            .limit(limit)
+            .select((WebhookDelivery::as_select(), Webhook::as_select()))
+            .load(&mut conn)
+    }
+    fn get_delivery(&self, id: i32) -> QueryResult<Option<(WebhookDelivery, Webhook)>> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        webhook_deliveries::table
+            .inner_join(webhooks::table)
+            .filter(webhook_deliveries::id.eq(id))
+            .select((WebhookDelivery::as_select(), Webhook::as_select()))
+            .first(&mut conn)
+            .optional()
+    }
+    fn record_attempt(&self, id: i32, attempt: DeliveryAttempt) -> QueryResult<WebhookDelivery> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+        diesel::update(webhook_deliveries::table.find(id))
+            .set(attempt)
+            .get_result(&mut conn)
+    }
+    fn get_deliveries(
+        &self,
+        webhook_id: i32,
+        status: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> QueryResult<(Vec<WebhookDelivery>, i64)> {
+        let mut conn = self
+            .pool
+            .get()
+            .expect("couldn't get db connection from pool");
+
+        let filtered = || {
+            let mut query = webhook_deliveries::table
+                .filter(webhook_deliveries::webhook_id.eq(webhook_id))
+                .into_boxed();
+            if let Some(status) = &status {
+                query = query.filter(webhook_deliveries::status.eq(status.clone()));
+            }
+            query
+        };
+
+        let count = filtered().count().get_result(&mut conn)?;
+        let items = filtered()
+            .order(webhook_deliveries::id.desc())
+            .limit(limit)
+            .offset(offset)
+            .load::<WebhookDelivery>(&mut conn)?;
+
+        Ok((items, count))
+    }
+}
@@ -0,0 +1,44 @@
+use crate::app::features::webhooks::domain::sender::{
+    WebhookRequest, WebhookResponse, WebhookSender,
+};
+use std::time::Duration;
+use ureq::Agent;
+
+/// Delivers webhooks over plain HTTP(S) with a blocking client.
+#[derive(Clone)]
+pub struct HttpWebhookSender {
+    agent: Agent,
+}
+
+impl HttpWebhookSender {
+    pub fn new(timeout_secs: u64) -> Self {
+        let agent = Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(timeout_secs.max(1))))
+            // Error statuses are recorded, not treated as transport failures
+            .http_status_as_error(false)
+            .build()
+            .into();
+        Self { agent }
+    }
+}
+
+impl WebhookSender for HttpWebhookSender {
+    fn send(&self, request: &WebhookRequest) -> Result<WebhookResponse, String> {
+        let mut response = self
+            .agent
+            .post(request.url)
+            .header("User-Agent", "myblog-webhooks")
+            .header("X-Webhook-Event", request.event)
+            .header("X-Webhook-Delivery", request.delivery_id.to_string())
+            .header("X-Webhook-Signature", request.signature)
+            .content_type("application/json")
+            .send(request.payload)
+            .map_err(|e| e.to_string())?;
+        // The body is only kept for the delivery log; an unreadable one is not a failure
+        let body = response.body_mut().read_to_string().unwrap_or_default();
+        Ok(WebhookResponse {
+            status: response.status().as_u16(),
+            body,
+        })
+    }
+}
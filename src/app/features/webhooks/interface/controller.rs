@@ -0,0 +1,228 @@
+use crate::app::features::webhooks::domain::entity::WebhookEvent;
+use crate::app::features::webhooks::domain::error::WebhookError;
+use crate::app::features::webhooks::interface::dto::{
+    CreateWebhookDto, DeliveryListQueryDto, UpdateWebhookDto, WebhookDeliveryDto,
+    WebhookResponseDto,
+};
+use crate::utils::di::Container;
+use crate::utils::error_response::{ErrorResponse, map_string_error, map_validation_error};
+use crate::utils::success_response::{map_success_response, map_success_with_data};
+use actix_web::{HttpResponse, Responder, delete, get, post, put, web};
+use serde::Serialize;
+
+fn webhook_error_response(e: WebhookError) -> HttpResponse {
+    match e {
+        WebhookError::Validation(e) => HttpResponse::BadRequest().json(map_validation_error(e)),
+        WebhookError::NotFound(msg) => HttpResponse::NotFound().json(map_string_error(msg)),
+        WebhookError::System(msg) => {
+            HttpResponse::InternalServerError().json(map_string_error(msg))
+        }
+    }
+}
+
+/// Queues `event` for every subscribed webhook. Called by the content
+/// controllers after a successful change; a failure here must not fail it.
+pub async fn emit_event(container: &Container, event: WebhookEvent, data: impl Serialize) {
+    let data = match serde_json::to_value(data) {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("webhook {} payload failed: {:?}", event.as_str(), e);
+            return;
+        }
+    };
+    if let Err(e) = container.enqueue_webhook_usecase.execute(event, data).await {
+        eprintln!("webhook {} enqueue failed: {:?}", event.as_str(), e);
+    }
+}
+
+#[utoipa::path(
+    path = "/app/webhooks",
+    tag = "Webhooks",
+    request_body = CreateWebhookDto,
+    responses(
+        (status = 201, description = "Webhook created", body = crate::utils::success_response::SuccessResponse<WebhookResponseDto>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[post("/webhooks")]
+pub async fn create_webhook(
+    container: web::Data<Container>,
+    payload: web::Json<CreateWebhookDto>,
+) -> impl Responder {
+    match container
+        .create_webhook_usecase
+        .execute(payload.into_inner())
+        .await
+    {
+        Ok(webhook) => HttpResponse::Created().json(map_success_with_data(
+            "Webhook created successfully".to_string(),
+            webhook,
+        )),
+        Err(e) => webhook_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    path = "/app/webhooks",
+    tag = "Webhooks",
+    responses(
+        (status = 200, description = "All webhooks", body = crate::utils::success_response::SuccessResponse<Vec<WebhookResponseDto>>),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[get("/webhooks")]
+pub async fn get_webhooks(container: web::Data<Container>) -> impl Responder {
+    match container.get_webhooks_usecase.execute().await {
+        Ok(webhooks) => HttpResponse::Ok().json(map_success_with_data(
+            "Webhooks fetched successfully".to_string(),
+            webhooks,
+        )),
+        Err(e) => webhook_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    path = "/app/webhooks/{id}",
+    tag = "Webhooks",
+    params(
+        ("id", description = "Webhook ID")
+    ),
+    responses(
+        (status = 200, description = "Webhook found", body = crate::utils::success_response::SuccessResponse<WebhookResponseDto>),
+        (status = 404, description = "Webhook not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[get("/webhooks/{id}")]
+pub async fn get_webhook(container: web::Data<Container>, id: web::Path<i32>) -> impl Responder {
+    match container.get_webhook_usecase.execute(id.into_inner()).await {
+        Ok(webhook) => HttpResponse::Ok().json(map_success_with_data(
+            "Webhook fetched successfully".to_string(),
+            webhook,
+        )),
+        Err(e) => webhook_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    path = "/app/webhooks/{id}",
+    tag = "Webhooks",
+    params(
+        ("id", description = "Webhook ID")
+    ),
+    request_body = UpdateWebhookDto,
+    responses(
+        (status = 200, description = "Webhook updated", body = crate::utils::success_response::SuccessResponse<WebhookResponseDto>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 404, description = "Webhook not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[put("/webhooks/{id}")]
+pub async fn update_webhook(
+    container: web::Data<Container>,
+    id: web::Path<i32>,
+    payload: web::Json<UpdateWebhookDto>,
+) -> impl Responder {
+    match container
+        .update_webhook_usecase
+        .execute(id.into_inner(), payload.into_inner())
+        .await
+    {
+        Ok(webhook) => HttpResponse::Ok().json(map_success_with_data(
+            "Webhook updated successfully".to_string(),
+            webhook,
+        )),
+        Err(e) => webhook_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    path = "/app/webhooks/{id}",
+    tag = "Webhooks",
+    params(
+        ("id", description = "Webhook ID")
+    ),
+    responses(
+        (status = 200, description = "Webhook and its delivery log deleted", body = crate::utils::success_response::SuccessResponse<crate::utils::success_response::Empty>),
+        (status = 404, description = "Webhook not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[delete("/webhooks/{id}")]
+pub async fn delete_webhook(container: web::Data<Container>, id: web::Path<i32>) -> impl Responder {
+    match container
+        .delete_webhook_usecase
+        .execute(id.into_inner())
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().json(map_success_response(
+            "Webhook deleted successfully".to_string(),
+        )),
+        Err(e) => webhook_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    path = "/app/webhooks/{id}/deliveries",
+    tag = "Webhooks",
+    params(
+        ("id", description = "Webhook ID"),
+        DeliveryListQueryDto
+    ),
+    responses(
+        (status = 200, description = "Delivery log, newest first", body = crate::utils::success_response::SuccessResponse<Vec<WebhookDeliveryDto>>),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 404, description = "Webhook not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[get("/webhooks/{id}/deliveries")]
+pub async fn get_webhook_deliveries(
+    container: web::Data<Container>,
+    id: web::Path<i32>,
+    query: web::Query<DeliveryListQueryDto>,
+) -> impl Responder {
+    match container
+        .get_webhook_deliveries_usecase
+        .execute(id.into_inner(), query.into_inner())
+        .await
+    {
+        Ok(deliveries) => HttpResponse::Ok().json(map_success_with_data(
+            "Deliveries fetched successfully".to_string(),
+            deliveries,
+        )),
+        Err(e) => webhook_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    path = "/app/webhooks/deliveries/{id}/redeliver",
+    tag = "Webhooks",
+    params(
+        ("id", description = "Delivery ID")
+    ),
+    responses(
+        (status = 201, description = "Payload queued again as a new delivery, due right away; the background deliverer sends it", body = crate::utils::success_response::SuccessResponse<WebhookDeliveryDto>),
+        (status = 404, description = "Delivery not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+#[post("/webhooks/deliveries/{id}/redeliver")]
+pub async fn redeliver_webhook(
+    container: web::Data<Container>,
+    id: web::Path<i32>,
+) -> impl Responder {
+    match container
+        .redeliver_webhook_usecase
+        .execute(id.into_inner())
+        .await
+    {
+        Ok(delivery) => HttpResponse::Created().json(map_success_with_data(
+            "Delivery queued again".to_string(),
+            delivery,
+        )),
+        Err(e) => webhook_error_response(e),
+    }
+}
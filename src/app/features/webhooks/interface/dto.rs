@@ -0,0 +1,150 @@
+use crate::app::features::webhooks::domain::entity::{Webhook, WebhookDelivery, WebhookEvent};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::{Validate, ValidationError};
+
+fn validate_http_url(url: &str) -> Result<(), ValidationError> {
+    if url.starts_with("http://") || url.starts_with("https://") {
+        Ok(())
+    } else {
+        Err(ValidationError::new("URL must use http or https"))
+    }
+}
+
+fn validate_events(events: &[String]) -> Result<(), ValidationError> {
+    if events.iter().all(|e| WebhookEvent::parse(e).is_some()) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("Unknown event"))
+    }
+}
+
+fn validate_delivery_status(status: &str) -> Result<(), ValidationError> {
+    match status {
+        "PENDING" | "SUCCEEDED" | "FAILED" => Ok(()),
+        _ => Err(ValidationError::new("Invalid status")),
+    }
+}
+
+#[derive(Deserialize, Serialize, Validate, ToSchema)]
+pub struct CreateWebhookDto {
+    #[validate(
+        url(message = "URL is invalid"),
+        length(max = 2048, message = "URL is too long"),
+        custom(function = "validate_http_url")
+    )]
+    pub url: String,
+    /// Key for the `X-Webhook-Signature` HMAC; never returned by the API
+    #[validate(length(min = 16, max = 200, message = "Secret must be 16 to 200 characters"))]
+    pub secret: String,
+    /// e.g. `blog.published`; see the docs for the full list
+    #[validate(
+        length(min = 1, message = "At least one event is required"),
+        custom(function = "validate_events")
+    )]
+    pub events: Vec<String>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Deserialize, Serialize, Validate, ToSchema)]
+pub struct UpdateWebhookDto {
+    #[validate(
+        url(message = "URL is invalid"),
+        length(max = 2048, message = "URL is too long"),
+        custom(function = "validate_http_url")
+    )]
+    pub url: String,
+    /// Leave out to keep the current secret
+    #[validate(length(min = 16, max = 200, message = "Secret must be 16 to 200 characters"))]
+    pub secret: Option<String>,
+    #[validate(
+        length(min = 1, message = "At least one event is required"),
+        custom(function = "validate_events")
+    )]
+    pub events: Vec<String>,
+    pub is_active: bool,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct WebhookResponseDto {
+    pub id: i32,
+    pub url: String,
+    pub events: Vec<String>,
+    pub is_active: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<(Webhook, Vec<String>)> for WebhookResponseDto {
+    fn from((webhook, events): (Webhook, Vec<String>)) -> Self {
+        Self {
+            id: webhook.id,
+            url: webhook.url,
+            events,
+            is_active: webhook.is_active,
+            created_at: webhook.created_at.to_string(),
+            updated_at: webhook.updated_at.to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DeliveryListQueryDto {
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+    /// PENDING, SUCCEEDED or FAILED
+    #[validate(custom(function = "validate_delivery_status"))]
+    pub status: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct WebhookDeliveryDto {
+    pub id: i32,
+    pub webhook_id: i32,
+    pub event: String,
+    /// The exact JSON body that was signed and sent
+    pub payload: String,
+    /// PENDING, SUCCEEDED or FAILED
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: Option<String>,
+    pub response_status: Option<i32>,
+    pub response_body: Option<String>,
+    pub error: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryDto {
+    fn from(delivery: WebhookDelivery) -> Self {
+        Self {
+            id: delivery.id,
+            webhook_id: delivery.webhook_id,
+            event: delivery.event,
+            payload: delivery.payload,
+            status: delivery.status,
+            attempts: delivery.attempts,
+            next_attempt_at: delivery.next_attempt_at.map(|t| t.to_string()),
+            response_status: delivery.response_status,
+            response_body: delivery.response_body,
+            error: delivery.error,
+            created_at: delivery.created_at.to_string(),
+            updated_at: delivery.updated_at.to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct MetaDto {
+    pub page: i64,
+    pub per_page: i64,
+    pub total_pages: i64,
+    pub total_items: i64,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PaginatedResponseDto<T> {
+    pub items: Vec<T>,
+    pub meta: MetaDto,
+}
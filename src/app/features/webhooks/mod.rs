@@ -0,0 +1,4 @@
+pub mod domain;
+pub mod infrastructure;
+pub mod application;
+pub mod interface;
\ No newline at end of file
@@ -1,9 +1,11 @@
 use dotenv::dotenv;
 use std::env;
+use std::net::IpAddr;
 
 #[derive(Clone)]
 pub struct Config {
     pub url: String,
+    pub site_url: String,
     pub database_url: String,
     pub jwt_secret: String,
     pub default_username: String,
@@ -14,13 +16,38 @@ pub struct Config {
     pub smtp_host: String,
     pub smtp_port: i32,
     pub smtp_from: String,
+    pub publish_interval_secs: u64,
+    pub sitemap_max_urls: usize,
+    pub robots_disallow: Vec<String>,
+    pub related_tag_weight: f64,
+    pub related_category_weight: f64,
+    pub related_recency_weight: f64,
+    pub media_dir: String,
+    pub media_max_bytes: usize,
+    pub media_allowed_types: Vec<String>,
+    pub media_variant_widths: Vec<u32>,
+    pub media_webp_quality: f32,
+    pub view_window_minutes: i64,
+    pub trusted_proxies: Vec<IpAddr>,
+    pub newsletter_interval_secs: u64,
+    pub newsletter_resend_secs: i64,
+    pub webhook_interval_secs: u64,
+    pub webhook_max_attempts: i32,
+    pub webhook_backoff_secs: i64,
+    pub webhook_timeout_secs: u64,
+    pub import_max_bytes: usize,
 }
 
 impl Config {
     pub fn new() -> Config {
         dotenv().ok();
+        let url = env::var("URL").unwrap();
         Config {
-            url: env::var("URL").unwrap(),
+            site_url: env::var("SITE_URL")
+                .unwrap_or_else(|_| format!("http://{}", url))
+                .trim_end_matches('/')
+                .to_string(),
+            url,
             database_url: env::var("DATABASE_URL").unwrap(),
             jwt_secret: env::var("JWT_SECRET").expect("JWT_SECRET must be set"),
             default_username: env::var("DEFAULT_USERNAME").expect("DEFAULT_USERNAME must be set"),
@@ -31,6 +58,97 @@ impl Config {
             smtp_host: env::var("SMTP_HOST").expect("SMTP_HOST must be set"),
             smtp_port: env::var("SMTP_PORT").expect("SMTP_PORT must be set").parse().unwrap(),
             smtp_from: env::var("SMTP_FROM").expect("SMTP_FROM must be set"),
+            publish_interval_secs: env::var("PUBLISH_INTERVAL_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(60),
+            // The sitemap protocol allows at most 50,000 URLs per file
+            sitemap_max_urls: env::var("SITEMAP_MAX_URLS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(50_000)
+                .min(50_000),
+            robots_disallow: env::var("ROBOTS_DISALLOW")
+                .unwrap_or_else(|_| "/app/".to_string())
+                .split(',')
+                .map(|p| p.trim().to_string())
+                .filter(|p| !p.is_empty())
+                .collect(),
+            related_tag_weight: env::var("RELATED_TAG_WEIGHT")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(3.0),
+            related_category_weight: env::var("RELATED_CATEGORY_WEIGHT")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(2.0),
+            related_recency_weight: env::var("RELATED_RECENCY_WEIGHT")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(1.0),
+            media_dir: env::var("MEDIA_DIR").unwrap_or_else(|_| "media".to_string()),
+            media_max_bytes: env::var("MEDIA_MAX_BYTES")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(5 * 1024 * 1024),
+            media_allowed_types: env::var("MEDIA_ALLOWED_TYPES")
+                .unwrap_or_else(|_| "image/jpeg,image/png,image/gif,image/webp".to_string())
+                .split(',')
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty())
+                .collect(),
+            media_variant_widths: env::var("MEDIA_VARIANT_WIDTHS")
+                .unwrap_or_else(|_| "320,640,1280".to_string())
+                .split(',')
+                .filter_map(|w| w.trim().parse().ok())
+                .collect(),
+            media_webp_quality: env::var("MEDIA_WEBP_QUALITY")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(80.0),
+            // A visitor reading the same post again within this window is not counted twice
+            view_window_minutes: env::var("VIEW_WINDOW_MINUTES")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(30),
+            // Only these peers may say who the client is through X-Forwarded-For
+            trusted_proxies: env::var("TRUSTED_PROXIES")
+                .unwrap_or_default()
+                .split(',')
+                .filter_map(|ip| ip.trim().parse().ok())
+                .collect(),
+            newsletter_interval_secs: env::var("NEWSLETTER_INTERVAL_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(5),
+            // A pending address is sent at most one confirmation email per window
+            newsletter_resend_secs: env::var("NEWSLETTER_RESEND_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(900),
+            webhook_interval_secs: env::var("WEBHOOK_INTERVAL_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(5),
+            webhook_max_attempts: env::var("WEBHOOK_MAX_ATTEMPTS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(6)
+                .max(1),
+            // Wait before the first retry; doubled after every further failure
+            webhook_backoff_secs: env::var("WEBHOOK_BACKOFF_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(30),
+            webhook_timeout_secs: env::var("WEBHOOK_TIMEOUT_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(10),
+            // Total size of one import upload
+            import_max_bytes: env::var("IMPORT_MAX_BYTES")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(20 * 1024 * 1024),
         }
     }
 }
@@ -16,9 +16,29 @@ use utoipa_swagger_ui::SwaggerUi;
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     let state = State::new();
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("import") {
+        return app::drivers::cli::import(&state.container, &args[1..]);
+    }
+
     let url = state.container.config.url.clone();
     println!("server running on {}", url);
 
+    match state
+        .container
+        .render_missing_blogs_usecase
+        .execute()
+        .await
+    {
+        Ok(0) => {}
+        Ok(count) => println!("rendered markdown for {} blogs", count),
+        Err(e) => eprintln!("markdown backfill failed: {:?}", e),
+    }
+    app::drivers::scheduler::spawn_scheduled_publisher(state.container.clone());
+    app::drivers::scheduler::spawn_newsletter_sender(state.container.clone());
+    app::drivers::scheduler::spawn_webhook_deliverer(state.container.clone());
+
     HttpServer::new(move || {
         App::new()
             .app_data(web::Data::new(state.container.clone()))
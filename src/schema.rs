@@ -14,6 +14,33 @@ diesel::table! {
         status -> Text,
         published_at -> Nullable<Timestamp>,
         view_count -> Integer,
+        content_html -> Nullable<Text>,
+        toc -> Nullable<Text>,
+        word_count -> Integer,
+        reading_time_minutes -> Integer,
+        author_id -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    blog_preview_tokens (id) {
+        id -> Integer,
+        blog_id -> Integer,
+        expires_at -> Timestamp,
+        revoked_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    blog_revisions (id) {
+        id -> Integer,
+        blog_id -> Integer,
+        revision_number -> Integer,
+        title -> Text,
+        excerpt -> Nullable<Text>,
+        content -> Text,
+        created_at -> Timestamp,
     }
 }
 
@@ -24,12 +51,43 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    blog_view_fingerprints (blog_id, fingerprint) {
+        blog_id -> Integer,
+        fingerprint -> Text,
+        counted_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    blog_views_daily (blog_id, day) {
+        blog_id -> Integer,
+        day -> Date,
+        views -> Integer,
+    }
+}
+
 diesel::table! {
     categories (id) {
         id -> Integer,
         name -> Text,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        parent_id -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    comments (id) {
+        id -> Integer,
+        blog_id -> Integer,
+        parent_id -> Nullable<Integer>,
+        author_name -> Text,
+        author_email -> Text,
+        content -> Text,
+        status -> Text,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
     }
 }
 
@@ -40,6 +98,55 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    media (id) {
+        id -> Integer,
+        filename -> Text,
+        original_name -> Text,
+        mime_type -> Text,
+        size -> BigInt,
+        checksum -> Text,
+        alt_text -> Nullable<Text>,
+        created_at -> Timestamp,
+        width -> Nullable<Integer>,
+        height -> Nullable<Integer>,
+        blurhash -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    media_variants (id) {
+        id -> Integer,
+        media_id -> Integer,
+        filename -> Text,
+        mime_type -> Text,
+        width -> Integer,
+        height -> Integer,
+        size -> BigInt,
+    }
+}
+
+diesel::table! {
+    newsletter_emails (id) {
+        id -> Integer,
+        subscriber_id -> Integer,
+        kind -> Text,
+        blog_id -> Nullable<Integer>,
+        status -> Text,
+        error -> Nullable<Text>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    newsletter_issues (blog_id) {
+        blog_id -> Integer,
+        recipients -> Integer,
+        sent_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     portofolios (id) {
         id -> Integer,
@@ -123,6 +230,35 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    series (id) {
+        id -> Integer,
+        title -> Text,
+        slug -> Text,
+        description -> Nullable<Text>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    series_posts (series_id, blog_id) {
+        series_id -> Integer,
+        blog_id -> Integer,
+        position -> Integer,
+    }
+}
+
+diesel::table! {
+    slug_history (id) {
+        id -> Integer,
+        entity_type -> Text,
+        entity_id -> Integer,
+        slug -> Text,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     stacks (id) {
         id -> Integer,
@@ -132,6 +268,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    subscribers (id) {
+        id -> Integer,
+        email -> Text,
+        status -> Text,
+        confirmed_at -> Nullable<Timestamp>,
+        unsubscribed_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     tags (id) {
         id -> Integer,
@@ -147,24 +295,83 @@ diesel::table! {
         username -> Text,
         email -> Text,
         password -> Text,
+        display_name -> Nullable<Text>,
+        slug -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    webhook_deliveries (id) {
+        id -> Integer,
+        webhook_id -> Integer,
+        event -> Text,
+        payload -> Text,
+        status -> Text,
+        attempts -> Integer,
+        next_attempt_at -> Nullable<Timestamp>,
+        response_status -> Nullable<Integer>,
+        response_body -> Nullable<Text>,
+        error -> Nullable<Text>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    webhook_events (webhook_id, event) {
+        webhook_id -> Integer,
+        event -> Text,
+    }
+}
+
+diesel::table! {
+    webhooks (id) {
+        id -> Integer,
+        url -> Text,
+        secret -> Text,
+        is_active -> Bool,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
     }
 }
 
 diesel::joinable!(blog -> categories (category_id));
+diesel::joinable!(blog_preview_tokens -> blog (blog_id));
+diesel::joinable!(blog_revisions -> blog (blog_id));
 diesel::joinable!(blog_tags -> blog (blog_id));
 diesel::joinable!(blog_tags -> tags (tag_id));
+diesel::joinable!(blog_view_fingerprints -> blog (blog_id));
+diesel::joinable!(blog_views_daily -> blog (blog_id));
+diesel::joinable!(comments -> blog (blog_id));
+diesel::joinable!(media_variants -> media (media_id));
+diesel::joinable!(newsletter_emails -> blog (blog_id));
+diesel::joinable!(newsletter_emails -> subscribers (subscriber_id));
+diesel::joinable!(newsletter_issues -> blog (blog_id));
 diesel::joinable!(portofolios -> projects (project_id));
 diesel::joinable!(profile_languages -> profiles (profile_id));
 diesel::joinable!(profile_specializations -> profiles (profile_id));
 diesel::joinable!(profile_tech_focus -> profiles (profile_id));
 diesel::joinable!(project_stack -> projects (project_id));
 diesel::joinable!(project_stack -> stacks (stack_id));
+diesel::joinable!(series_posts -> blog (blog_id));
+diesel::joinable!(series_posts -> series (series_id));
+diesel::joinable!(webhook_deliveries -> webhooks (webhook_id));
+diesel::joinable!(webhook_events -> webhooks (webhook_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     blog,
+    blog_preview_tokens,
+    blog_revisions,
     blog_tags,
+    blog_view_fingerprints,
+    blog_views_daily,
     categories,
+    comments,
     counts,
+    media,
+    media_variants,
+    newsletter_emails,
+    newsletter_issues,
     portofolios,
     profile_languages,
     profile_specializations,
@@ -172,7 +379,14 @@ diesel::allow_tables_to_appear_in_same_query!(
     profiles,
     project_stack,
     projects,
+    series,
+    series_posts,
+    slug_history,
     stacks,
+    subscribers,
     tags,
     users,
+    webhook_deliveries,
+    webhook_events,
+    webhooks,
 );
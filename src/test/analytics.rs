@@ -0,0 +1,238 @@
+use crate::app::features::analytics::domain::repository::AnalyticsRepository;
+use crate::app::features::analytics::infrastructure::repository_impl::AnalyticsRepositoryImpl;
+use crate::app::features::analytics::interface::dto::{BlogViewsResponseDto, TopPostsResponseDto};
+use crate::app::features::blog::interface::dto::{
+    BlogResponseDto, CategoryResponseDto, CreateBlogRequestDto, CreateCategoryRequestDto,
+    UpdateBlogRequestDto,
+};
+use crate::init_test_app;
+use crate::test::helpers::{login_admin, seed_user};
+use crate::utils::client_ip::client_ip;
+use crate::utils::db::establish_connection;
+use crate::utils::di::Container;
+use crate::utils::success_response::SuccessResponse;
+use actix_web::http::StatusCode;
+use actix_web::test;
+use chrono::{Duration, Utc};
+use serial_test::serial;
+
+#[actix_web::test]
+#[serial]
+async fn test_unique_views_and_analytics() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let stamp = Utc::now().timestamp_micros();
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateCategoryRequestDto {
+            name: format!("Views Cat {}", stamp),
+            parent_id: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let category_id = resp.data.unwrap().id;
+
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateBlogRequestDto {
+            title: format!("Counted Post {}", stamp),
+            slug: None,
+            content: "Content".to_string(),
+            category_id,
+            tag_ids: None,
+            excerpt: "Excerpt".to_string(),
+            thumbnail: None,
+            status: "PUBLISHED".to_string(),
+            published_at: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let blog = resp.data.unwrap();
+
+    // Refreshing does not count twice, nor does a made-up X-Forwarded-For; another visitor does.
+    // The public response already includes the view it just counted.
+    for (peer, forwarded, agent, expected) in [
+        ("203.0.113.7:4000", "10.0.0.1", "Firefox", 1),
+        ("203.0.113.7:4001", "10.0.0.2", "Firefox", 1),
+        ("203.0.113.7:4002", "10.0.0.3", "Safari", 2),
+        ("198.51.100.1:4000", "10.0.0.1", "Firefox", 3),
+    ] {
+        let req = test::TestRequest::get()
+            .uri(&format!("/api/public/blogs/{}", blog.slug))
+            .peer_addr(peer.parse().unwrap())
+            .insert_header(("X-Forwarded-For", forwarded))
+            .insert_header(("User-Agent", agent))
+            .to_request();
+        let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(resp.data.unwrap().view_count, expected);
+    }
+
+    // Admin reads are not views
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/blogs/{}", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().view_count, 3);
+
+    // Editing the post leaves the counter alone
+    let req = test::TestRequest::put()
+        .uri(&format!("/app/blogs/{}", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&UpdateBlogRequestDto {
+            title: blog.title.clone(),
+            slug: None,
+            content: "Edited content".to_string(),
+            category_id,
+            tag_ids: None,
+            excerpt: "Excerpt".to_string(),
+            thumbnail: None,
+            status: "PUBLISHED".to_string(),
+            published_at: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().view_count, 3);
+
+    // Once the window has passed the same visitor counts again
+    let repository =
+        AnalyticsRepositoryImpl::new(establish_connection(&container.config.database_url));
+    let later =
+        Utc::now().naive_utc() + Duration::minutes(container.config.view_window_minutes + 1);
+    let fingerprint = "later-visitor".to_string();
+    let window_start = later - Duration::minutes(container.config.view_window_minutes);
+    assert!(
+        repository
+            .record_view(blog.id, fingerprint.clone(), later, window_start)
+            .unwrap()
+    );
+    assert!(
+        !repository
+            .record_view(blog.id, fingerprint.clone(), later, window_start)
+            .unwrap()
+    );
+    let much_later = later + Duration::minutes(container.config.view_window_minutes + 1);
+    assert!(
+        repository
+            .record_view(
+                blog.id,
+                fingerprint,
+                much_later,
+                much_later - Duration::minutes(container.config.view_window_minutes),
+            )
+            .unwrap()
+    );
+
+    let today = Utc::now().date_naive();
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/analytics/blogs/{}/views", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<BlogViewsResponseDto> =
+        test::call_and_read_body_json(&app, req).await;
+    let views = resp.data.unwrap();
+    assert_eq!(views.days.len(), 30);
+    assert_eq!(views.to, today.to_string());
+    assert_eq!(views.days.last().unwrap().date, today.to_string());
+    let later_today = [later, much_later]
+        .iter()
+        .filter(|t| t.date() == today)
+        .count() as i64;
+    assert_eq!(views.days.last().unwrap().views, 3 + later_today);
+    assert_eq!(views.days[0].views, 0);
+
+    // The later views may land on tomorrow, so widen the range to cover them
+    let tomorrow = today.succ_opt().unwrap();
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/app/analytics/blogs/{}/views?from={}&to={}",
+            blog.id, today, tomorrow
+        ))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<BlogViewsResponseDto> =
+        test::call_and_read_body_json(&app, req).await;
+    let views = resp.data.unwrap();
+    assert_eq!(views.days.len(), 2);
+    assert_eq!(views.total_views, 5);
+
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/app/analytics/top-posts?from={}&to={}&limit=100",
+            today, tomorrow
+        ))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<TopPostsResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let top = resp.data.unwrap();
+    let entry = top.items.iter().find(|p| p.id == blog.id).unwrap();
+    assert_eq!(entry.views, 5);
+    assert!(top.items.windows(2).all(|w| w[0].views >= w[1].views));
+
+    for uri in [
+        format!(
+            "/app/analytics/blogs/{}/views?from={}&to={}",
+            blog.id, tomorrow, today
+        ),
+        format!("/app/analytics/blogs/{}/views?from=yesterday", blog.id),
+        "/app/analytics/top-posts?from=2020-01-01&to=2024-01-01".to_string(),
+        "/app/analytics/top-posts?limit=0".to_string(),
+    ] {
+        let req = test::TestRequest::get()
+            .uri(&uri)
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+        assert_eq!(
+            test::call_service(&app, req).await.status(),
+            StatusCode::BAD_REQUEST,
+            "{}",
+            uri
+        );
+    }
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/analytics/blogs/{}/views", i32::MAX))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::NOT_FOUND
+    );
+}
+
+#[actix_web::test]
+async fn test_client_ip_behind_trusted_proxy() {
+    let proxy = "192.0.2.1".parse().unwrap();
+    let request = |forwarded: &str| {
+        test::TestRequest::get()
+            .peer_addr("192.0.2.1:4000".parse().unwrap())
+            .insert_header(("X-Forwarded-For", forwarded.to_string()))
+            .to_http_request()
+    };
+
+    // Without trusted proxies the header is ignored
+    assert_eq!(client_ip(&request("203.0.113.7"), &[]), Some(proxy));
+    // The right-most address not added by a trusted proxy is the client
+    assert_eq!(
+        client_ip(&request("10.0.0.1, 203.0.113.7"), &[proxy]),
+        Some("203.0.113.7".parse().unwrap())
+    );
+    assert_eq!(
+        client_ip(&request("203.0.113.7, 192.0.2.1"), &[proxy]),
+        Some("203.0.113.7".parse().unwrap())
+    );
+    // Garbage stops the walk at the last address that could be checked
+    assert_eq!(
+        client_ip(&request("203.0.113.7, unknown"), &[proxy]),
+        Some(proxy)
+    );
+    assert_eq!(
+        client_ip(&test::TestRequest::get().to_http_request(), &[proxy]),
+        None
+    );
+}
@@ -0,0 +1,300 @@
+use crate::app::features::auth::domain::repository::UserRepository;
+use crate::app::features::auth::infrastructure::repository_impl::UserRepositoryImpl;
+use crate::app::features::auth::interface::dto::{LoginRequestDto, LoginResponseDto};
+use crate::app::features::authors::interface::dto::{
+    AuthorDto, AuthorPostsResponseDto, AuthorResponseDto, UpdateAuthorRequestDto,
+};
+use crate::app::features::blog::interface::dto::{
+    BlogResponseDto, CategoryResponseDto, CreateCategoryRequestDto, UpdateBlogRequestDto,
+};
+use crate::app::features::feed::interface::dto::JsonFeedDto;
+use crate::init_test_app;
+use crate::test::helpers::{blog_request, create_blog, login_admin, seed_user};
+use crate::utils::db::establish_connection;
+use crate::utils::di::Container;
+use crate::utils::error_response::ErrorResponse;
+use crate::utils::success_response::SuccessResponse;
+use actix_web::http::StatusCode;
+use actix_web::test;
+use chrono::Utc;
+use serial_test::serial;
+
+/// A second user who has set a public name apart from their login.
+struct Writer {
+    username: String,
+    token: String,
+    profile: AuthorDto,
+}
+
+async fn create_writer(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    container: &Container,
+    stamp: i64,
+) -> Writer {
+    let username = format!("writer{}", stamp);
+    UserRepositoryImpl::new(establish_connection(&container.config.database_url))
+        .create(
+            username.clone(),
+            format!("{}@example.com", username),
+            "secret".to_string(),
+        )
+        .unwrap();
+    let req = test::TestRequest::post()
+        .uri("/login")
+        .set_json(&LoginRequestDto {
+            username: username.clone(),
+            password: "secret".to_string(),
+        })
+        .to_request();
+    let resp: SuccessResponse<LoginResponseDto> = test::call_and_read_body_json(app, req).await;
+    let token = resp.data.unwrap().token;
+
+    let req = test::TestRequest::put()
+        .uri("/app/authors/me")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&UpdateAuthorRequestDto {
+            display_name: format!("Writer {}", stamp),
+            slug: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<AuthorDto> = test::call_and_read_body_json(app, req).await;
+    Writer {
+        username,
+        token,
+        profile: resp.data.unwrap(),
+    }
+}
+
+async fn create_category(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    stamp: i64,
+) -> i32 {
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateCategoryRequestDto {
+            name: format!("Author Cat {}", stamp),
+            parent_id: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap().id
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_update_author_profile() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let admin_token = login_admin(&app, &container).await;
+
+    // The public name and slug are chosen apart from the login username
+    let stamp = Utc::now().timestamp_micros();
+    let writer = create_writer(&app, &container, stamp).await;
+    assert_eq!(writer.profile.display_name, format!("Writer {}", stamp));
+    assert_eq!(writer.profile.slug, format!("writer-{}", stamp));
+
+    // Another author's slug and a blank name are refused
+    for (display_name, slug, field) in [
+        (
+            "Admin".to_string(),
+            Some(writer.profile.slug.clone()),
+            "slug",
+        ),
+        ("  ".to_string(), None, "display_name"),
+    ] {
+        let req = test::TestRequest::put()
+            .uri("/app/authors/me")
+            .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+            .set_json(&UpdateAuthorRequestDto { display_name, slug })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body: ErrorResponse = test::read_body_json(resp).await;
+        assert!(body.errors.unwrap().contains_key(field));
+    }
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_posts_are_attributed_to_their_author() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let admin_token = login_admin(&app, &container).await;
+    let stamp = Utc::now().timestamp_micros();
+    let writer = create_writer(&app, &container, stamp).await;
+    let category_id = create_category(&app, &admin_token, stamp).await;
+
+    let first = create_blog(
+        &app,
+        &writer.token,
+        blog_request(category_id, "Writer One".to_string(), "PUBLISHED"),
+    )
+    .await;
+    let author = first.author.clone().unwrap();
+    assert_eq!(author, writer.profile);
+    let admin_post = create_blog(
+        &app,
+        &admin_token,
+        blog_request(category_id, "Admin Post".to_string(), "PUBLISHED"),
+    )
+    .await;
+    assert_ne!(admin_post.author.unwrap().id, author.id);
+
+    // Editing someone else's post keeps its author
+    let req = test::TestRequest::put()
+        .uri(&format!("/app/blogs/{}", first.id))
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .set_json(&UpdateBlogRequestDto {
+            title: "Writer One".to_string(),
+            slug: None,
+            content: "Edited".to_string(),
+            category_id,
+            tag_ids: None,
+            excerpt: "Excerpt".to_string(),
+            thumbnail: None,
+            status: "PUBLISHED".to_string(),
+            published_at: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().author.unwrap(), author);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/blogs/{}", first.slug))
+        .to_request();
+    let body = String::from_utf8(test::call_and_read_body(&app, req).await.to_vec()).unwrap();
+    let resp: SuccessResponse<BlogResponseDto> = serde_json::from_str(&body).unwrap();
+    assert_eq!(resp.data.unwrap().author.unwrap().id, author.id);
+    assert!(!body.contains(&writer.username));
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_feeds_name_the_author_per_post() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let admin_token = login_admin(&app, &container).await;
+    let stamp = Utc::now().timestamp_micros();
+    let writer = create_writer(&app, &container, stamp).await;
+    let display_name = writer.profile.display_name;
+    let category_id = create_category(&app, &admin_token, stamp).await;
+    let post = create_blog(
+        &app,
+        &writer.token,
+        blog_request(category_id, "Writer One".to_string(), "PUBLISHED"),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/categories/{}/feed.json", category_id))
+        .to_request();
+    let feed: JsonFeedDto = test::call_and_read_body_json(&app, req).await;
+    let item = feed.items.iter().find(|i| i.title == post.title).unwrap();
+    assert_eq!(item.authors[0].name, display_name);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/categories/{}/feed.xml", category_id))
+        .to_request();
+    let body = String::from_utf8(test::call_and_read_body(&app, req).await.to_vec()).unwrap();
+    assert!(body.contains(&format!("<dc:creator>{}</dc:creator>", display_name)));
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/categories/{}/atom.xml", category_id))
+        .to_request();
+    let body = String::from_utf8(test::call_and_read_body(&app, req).await.to_vec()).unwrap();
+    assert!(body.contains(&format!("<name>{}</name>", display_name)));
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_public_author_pages_only_count_published_posts() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let admin_token = login_admin(&app, &container).await;
+    let stamp = Utc::now().timestamp_micros();
+    let writer = create_writer(&app, &container, stamp).await;
+    let author = writer.profile;
+    let category_id = create_category(&app, &admin_token, stamp).await;
+    let first = create_blog(
+        &app,
+        &writer.token,
+        blog_request(category_id, "Writer One".to_string(), "PUBLISHED"),
+    )
+    .await;
+    let draft = create_blog(
+        &app,
+        &writer.token,
+        blog_request(category_id, "Writer Draft".to_string(), "DRAFT"),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri("/api/public/authors")
+        .to_request();
+    let resp: SuccessResponse<Vec<AuthorResponseDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    let authors = resp.data.unwrap();
+    let listed = authors.iter().find(|a| a.id == author.id).unwrap();
+    assert_eq!(listed.display_name, author.display_name);
+    assert_eq!(listed.post_count, 1);
+    assert_eq!(listed.recent_posts[0].id, first.id);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/authors/{}", author.slug))
+        .to_request();
+    let resp: SuccessResponse<AuthorPostsResponseDto> =
+        test::call_and_read_body_json(&app, req).await;
+    let page = resp.data.unwrap();
+    assert_eq!(page.author, author);
+    assert_eq!(page.meta.total_items, 1);
+    assert_eq!(
+        page.items.iter().map(|p| p.id).collect::<Vec<_>>(),
+        vec![first.id]
+    );
+    assert!(page.items.iter().all(|p| p.id != draft.id));
+
+    // The login username is not an address for the author
+    for slug in [writer.username.as_str(), "nobody-here"] {
+        let req = test::TestRequest::get()
+            .uri(&format!("/api/public/authors/{}", slug))
+            .to_request();
+        assert_eq!(
+            test::call_service(&app, req).await.status(),
+            StatusCode::NOT_FOUND
+        );
+    }
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_author_posts_pagination_validation() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+
+    for query in ["per_page=-1", "per_page=101", "page=0"] {
+        let req = test::TestRequest::get()
+            .uri(&format!("/api/public/authors/author-1?{}", query))
+            .to_request();
+        assert_eq!(
+            test::call_service(&app, req).await.status(),
+            StatusCode::BAD_REQUEST,
+            "{}",
+            query
+        );
+    }
+}
@@ -1,12 +1,14 @@
 use crate::app::features::blog::interface::dto::{
-    BlogResponseDto, CreateBlogRequestDto, CreateCategoryRequestDto, CreateTagRequestDto,
-    UpdateBlogRequestDto,
+    BlogResponseDto, BulkBlogRequestDto, BulkBlogResponseDto, CategoryResponseDto,
+    CreateBlogRequestDto, CreateCategoryRequestDto, CreateTagRequestDto, PaginatedResponseDto,
+    TagResponseDto, UpdateBlogRequestDto,
 };
 use crate::init_test_app;
 use crate::test::helpers::{login_admin, seed_user};
 use crate::utils::di::Container;
 use crate::utils::error_response::ErrorResponse;
 use crate::utils::success_response::SuccessResponse;
+use actix_web::http::StatusCode;
 use actix_web::test;
 use chrono::Utc;
 use serial_test::serial;
@@ -22,6 +24,7 @@ async fn test_create_blog() {
     // Create Category
     let category_dto = CreateCategoryRequestDto {
         name: format!("Blog Category {}", Utc::now().timestamp_micros()),
+        parent_id: None,
     };
     let req = test::TestRequest::post()
         .uri("/app/categories")
@@ -49,12 +52,14 @@ async fn test_create_blog() {
     // Create Blog
     let create_dto = CreateBlogRequestDto {
         title: format!("Test Blog {}", Utc::now().timestamp_micros()),
+        slug: None,
         content: "Content".to_string(),
         category_id: 1,
         tag_ids: Some(vec![1]),
         excerpt: "Excerpt".to_string(),
         thumbnail: None,
         status: "DRAFT".to_string(),
+        published_at: None,
     };
 
     let req = test::TestRequest::post()
@@ -78,6 +83,7 @@ async fn test_get_blogs() {
     // Category
     let category_dto = CreateCategoryRequestDto {
         name: format!("Get Blog Category {}", Utc::now().timestamp_micros()),
+        parent_id: None,
     };
     let req = test::TestRequest::post()
         .uri("/app/categories")
@@ -106,12 +112,14 @@ async fn test_get_blogs() {
     let title = format!("Test Blog {}", Utc::now().timestamp_micros());
     let create_dto = CreateBlogRequestDto {
         title: title.clone(),
+        slug: None,
         content: "Content".to_string(),
         category_id: cat_id,
         tag_ids: None,
         excerpt: "Excerpt".to_string(),
         thumbnail: None,
         status: "DRAFT".to_string(),
+        published_at: None,
     };
     let req = test::TestRequest::post()
         .uri("/app/blogs")
@@ -142,6 +150,7 @@ async fn test_get_blog_by_id() {
     // Setup Data (Category)
     let category_dto = CreateCategoryRequestDto {
         name: format!("ID Cat {}", Utc::now().timestamp_micros()),
+        parent_id: None,
     };
     let req = test::TestRequest::post()
         .uri("/app/categories")
@@ -156,12 +165,14 @@ async fn test_get_blog_by_id() {
     let unique_title = format!("Test ID Blog {}", Utc::now().timestamp_micros());
     let create_dto = CreateBlogRequestDto {
         title: unique_title.clone(),
+        slug: None,
         content: "Content".to_string(),
         category_id: cat_id,
         tag_ids: None,
         excerpt: "Excerpt".to_string(),
         thumbnail: None,
         status: "DRAFT".to_string(),
+        published_at: None,
     };
     let req = test::TestRequest::post()
         .uri("/app/blogs")
@@ -191,6 +202,7 @@ async fn test_update_blog() {
     // Setup Data (Category)
     let category_dto = CreateCategoryRequestDto {
         name: format!("Upd Cat {}", Utc::now().timestamp_micros()),
+        parent_id: None,
     };
     let req = test::TestRequest::post()
         .uri("/app/categories")
@@ -205,12 +217,14 @@ async fn test_update_blog() {
     let unique_title = format!("Test Update Blog {}", Utc::now().timestamp_micros());
     let create_dto = CreateBlogRequestDto {
         title: unique_title.clone(),
+        slug: None,
         content: "Content".to_string(),
         category_id: cat_id,
         tag_ids: None,
         excerpt: "Excerpt".to_string(),
         thumbnail: None,
         status: "DRAFT".to_string(),
+        published_at: None,
     };
     let req = test::TestRequest::post()
         .uri("/app/blogs")
@@ -224,12 +238,14 @@ async fn test_update_blog() {
     let update_title = format!("Updated Title {}", Utc::now().timestamp_micros());
     let update_dto = UpdateBlogRequestDto {
         title: update_title.clone(),
+        slug: None,
         content: "Updated Content".to_string(),
         category_id: cat_id,
         tag_ids: None,
         excerpt: "Updated Excerpt".to_string(),
         thumbnail: None,
         status: "DRAFT".to_string(),
+        published_at: None,
     };
     let req = test::TestRequest::put()
         .uri(&format!("/app/blogs/{}", blog.id))
@@ -262,6 +278,7 @@ async fn test_delete_blog() {
     // Setup Data (Category)
     let category_dto = CreateCategoryRequestDto {
         name: format!("Del Cat {}", Utc::now().timestamp_micros()),
+        parent_id: None,
     };
     let req = test::TestRequest::post()
         .uri("/app/categories")
@@ -276,12 +293,14 @@ async fn test_delete_blog() {
     let unique_title = format!("Test Delete Blog {}", Utc::now().timestamp_micros());
     let create_dto = CreateBlogRequestDto {
         title: unique_title.clone(),
+        slug: None,
         content: "Content".to_string(),
         category_id: cat_id,
         tag_ids: None,
         excerpt: "Excerpt".to_string(),
         thumbnail: None,
         status: "DRAFT".to_string(),
+        published_at: None,
     };
     let req = test::TestRequest::post()
         .uri("/app/blogs")
@@ -311,6 +330,7 @@ async fn test_partial_update_blog() {
     // Setup Data (Category)
     let category_dto = CreateCategoryRequestDto {
         name: format!("Part Upd Cat {}", Utc::now().timestamp_micros()),
+        parent_id: None,
     };
     let req = test::TestRequest::post()
         .uri("/app/categories")
@@ -325,12 +345,14 @@ async fn test_partial_update_blog() {
     let unique_title = format!("Test Partial Update {}", Utc::now().timestamp_micros());
     let create_dto = CreateBlogRequestDto {
         title: unique_title.clone(),
+        slug: None,
         content: "Original Content".to_string(),
         category_id: cat_id,
         tag_ids: None,
         excerpt: "Excerpt".to_string(),
         thumbnail: None,
         status: "DRAFT".to_string(),
+        published_at: None,
     };
     let req = test::TestRequest::post()
         .uri("/app/blogs")
@@ -344,12 +366,14 @@ async fn test_partial_update_blog() {
     let update_title = format!("Partially Updated Title {}", Utc::now().timestamp_micros());
     let update_dto = UpdateBlogRequestDto {
         title: update_title.clone(),
+        slug: None,
         content: "Original Content".to_string(),
         category_id: cat_id,
         tag_ids: None,
         excerpt: "Excerpt".to_string(),
         thumbnail: None,
         status: "DRAFT".to_string(),
+        published_at: None,
     };
     let req = test::TestRequest::put()
         .uri(&format!("/app/blogs/{}", blog.id))
@@ -382,12 +406,14 @@ async fn test_update_blog_empty_title_validation() {
     // Create Blog first to ensure ID exists
     let create_dto = CreateBlogRequestDto {
         title: format!("Valid Blog {}", Utc::now().timestamp_micros()),
+        slug: None,
         content: "Content".to_string(),
         category_id: 1,
         tag_ids: None,
         excerpt: "Excerpt".to_string(),
         thumbnail: None,
         status: "DRAFT".to_string(),
+        published_at: None,
     };
     let req = test::TestRequest::post()
         .uri("/app/blogs")
@@ -400,12 +426,14 @@ async fn test_update_blog_empty_title_validation() {
     // Attempt Update with empty title
     let update_dto = UpdateBlogRequestDto {
         title: "".to_string(), // Empty string
+        slug: None,
         content: "Content".to_string(),
         category_id: 1,
         tag_ids: None,
         excerpt: "Excerpt".to_string(),
         thumbnail: None,
         status: "DRAFT".to_string(),
+        published_at: None,
     };
     let req = test::TestRequest::put()
         .uri(&format!("/app/blogs/{}", blog_id))
@@ -427,6 +455,7 @@ async fn test_create_duplicate_category() {
     let category_name = format!("Unique Cat {}", Utc::now().timestamp_micros());
     let category_dto = CreateCategoryRequestDto {
         name: category_name.clone(),
+        parent_id: None,
     };
 
     // First Create (Success)
@@ -495,3 +524,380 @@ async fn test_create_duplicate_tag() {
         "Tag name already exists"
     );
 }
+
+#[actix_web::test]
+#[serial]
+async fn test_get_blogs_filter_and_sort() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let stamp = Utc::now().timestamp_micros();
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateCategoryRequestDto {
+            name: format!("Filter Category {}", stamp),
+            parent_id: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let category_id = resp.data.unwrap().id;
+
+    let req = test::TestRequest::post()
+        .uri("/app/tags")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateTagRequestDto {
+            name: format!("Filter Tag {}", stamp),
+        })
+        .to_request();
+    let resp: SuccessResponse<TagResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let tag_id = resp.data.unwrap().id;
+
+    let mut ids = Vec::new();
+    for (title, status, tagged) in [
+        ("B filter", "PUBLISHED", true),
+        ("A filter", "PUBLISHED", true),
+        ("C filter", "DRAFT", true),
+        ("D filter", "PUBLISHED", false),
+    ] {
+        let create_dto = CreateBlogRequestDto {
+            title: format!("{} {}", title, stamp),
+            slug: None,
+            content: "Content".to_string(),
+            category_id,
+            tag_ids: if tagged { Some(vec![tag_id]) } else { None },
+            excerpt: "Excerpt".to_string(),
+            thumbnail: None,
+            status: status.to_string(),
+            published_at: None,
+        };
+        let req = test::TestRequest::post()
+            .uri("/app/blogs")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(&create_dto)
+            .to_request();
+        let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+        ids.push(resp.data.unwrap().id);
+    }
+
+    // Status + category + tag, sorted by title ascending
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/app/blogs?status=PUBLISHED&category_id={}&tag_ids={}&sort_by=title&order=asc",
+            category_id, tag_id
+        ))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<PaginatedResponseDto<BlogResponseDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    let data = resp.data.unwrap();
+    assert_eq!(data.meta.total_items, 2);
+    let got: Vec<i32> = data.items.iter().map(|b| b.id).collect();
+    assert_eq!(got, vec![ids[1], ids[0]]);
+
+    // Date range covering today keeps published posts, one ending yesterday drops them
+    let today = Utc::now().date_naive();
+    let yesterday = today.pred_opt().unwrap();
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/app/blogs?category_id={}&published_from={}&published_to={}&sort_by=title&order=desc",
+            category_id, today, today
+        ))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<PaginatedResponseDto<BlogResponseDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    let got: Vec<i32> = resp.data.unwrap().items.iter().map(|b| b.id).collect();
+    assert_eq!(got, vec![ids[3], ids[0], ids[1]]);
+
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/app/blogs?category_id={}&published_to={}",
+            category_id, yesterday
+        ))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<PaginatedResponseDto<BlogResponseDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().meta.total_items, 0);
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_get_blogs_invalid_filter_validation() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let req = test::TestRequest::get()
+        .uri("/app/blogs?sort_by=password&tag_ids=1,x&published_from=yesterday")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+
+    let body: ErrorResponse = test::read_body_json(resp).await;
+    let errors = body.errors.unwrap();
+    assert!(errors.contains_key("sort_by"));
+    assert!(errors.contains_key("tag_ids"));
+    assert!(errors.contains_key("published_from"));
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_blog_content_rendered_to_sanitized_html() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateCategoryRequestDto {
+            name: format!("Markdown Category {}", Utc::now().timestamp_micros()),
+            parent_id: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let category_id = resp.data.unwrap().id;
+
+    let content = "# Intro\n\nHello **world** ~~old~~\n\n## Setup Steps\n\n\
+                   | a | b |\n|---|---|\n| 1 | 2 |\n\n- [x] done\n\n\
+                   <script>alert(1)</script>\n\n[click](javascript:alert(1))\n\n## Intro\n";
+    let create_dto = CreateBlogRequestDto {
+        title: format!("Markdown Blog {}", Utc::now().timestamp_micros()),
+        slug: None,
+        content: content.to_string(),
+        category_id,
+        tag_ids: None,
+        excerpt: "Excerpt".to_string(),
+        thumbnail: None,
+        status: "DRAFT".to_string(),
+        published_at: None,
+    };
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&create_dto)
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let blog = resp.data.unwrap();
+
+    // Raw content is untouched, rendering is cached alongside it
+    assert_eq!(blog.content, content);
+    let html = blog.content_html.unwrap();
+    assert!(html.contains("<strong>world</strong>"));
+    assert!(html.contains("<del>old</del>"));
+    assert!(html.contains("<table>"));
+    assert!(html.contains("id=\"setup-steps\""));
+    assert!(!html.contains("<script"));
+    assert!(!html.contains("javascript:"));
+
+    let anchors: Vec<&str> = blog.toc.iter().map(|t| t.anchor.as_str()).collect();
+    assert_eq!(anchors, vec!["intro", "setup-steps", "intro-1"]);
+    assert_eq!(blog.toc[1].level, 2);
+    assert_eq!(blog.toc[1].text, "Setup Steps");
+    assert!(blog.word_count > 0);
+    assert_eq!(blog.reading_time_minutes, 1);
+
+    // Updating the content re-renders it
+    let long_content = "word ".repeat(450);
+    let update_dto = UpdateBlogRequestDto {
+        title: blog.title.clone(),
+        slug: None,
+        content: long_content,
+        category_id,
+        tag_ids: None,
+        excerpt: "Excerpt".to_string(),
+        thumbnail: None,
+        status: "DRAFT".to_string(),
+        published_at: None,
+    };
+    let req = test::TestRequest::put()
+        .uri(&format!("/app/blogs/{}", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&update_dto)
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let updated = resp.data.unwrap();
+    assert_eq!(updated.word_count, 450);
+    assert_eq!(updated.reading_time_minutes, 3);
+    assert!(updated.toc.is_empty());
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_bulk_blog_actions() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let stamp = Utc::now().timestamp_micros();
+    let mut category_ids = Vec::new();
+    for name in ["Bulk From", "Bulk To"] {
+        let req = test::TestRequest::post()
+            .uri("/app/categories")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(&CreateCategoryRequestDto {
+                name: format!("{} {}", name, stamp),
+                parent_id: None,
+            })
+            .to_request();
+        let resp: SuccessResponse<CategoryResponseDto> =
+            test::call_and_read_body_json(&app, req).await;
+        category_ids.push(resp.data.unwrap().id);
+    }
+
+    let req = test::TestRequest::post()
+        .uri("/app/tags")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateTagRequestDto {
+            name: format!("Bulk Tag {}", stamp),
+        })
+        .to_request();
+    let resp: SuccessResponse<TagResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let tag_id = resp.data.unwrap().id;
+
+    let mut ids = Vec::new();
+    for n in 1..=3 {
+        let req = test::TestRequest::post()
+            .uri("/app/blogs")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(&CreateBlogRequestDto {
+                title: format!("Bulk {} {}", n, stamp),
+                slug: None,
+                content: "Content".to_string(),
+                category_id: category_ids[0],
+                tag_ids: None,
+                excerpt: "Excerpt".to_string(),
+                thumbnail: None,
+                status: "DRAFT".to_string(),
+                published_at: None,
+            })
+            .to_request();
+        let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+        ids.push(resp.data.unwrap().id);
+    }
+
+    let bulk =
+        |action: &str, ids: Vec<i32>, category_id: Option<i32>, tag_ids: Option<Vec<i32>>| {
+            test::TestRequest::post()
+                .uri("/app/blogs/bulk")
+                .insert_header(("Authorization", format!("Bearer {}", token)))
+                .set_json(&BulkBlogRequestDto {
+                    ids,
+                    action: action.to_string(),
+                    category_id,
+                    tag_ids,
+                })
+                .to_request()
+        };
+    let get = |id: i32| {
+        test::TestRequest::get()
+            .uri(&format!("/app/blogs/{}", id))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request()
+    };
+
+    // A missing id is reported without holding back the others
+    let req = bulk(
+        "publish",
+        vec![ids[0], ids[1], i32::MAX, ids[0]],
+        None,
+        None,
+    );
+    let resp: SuccessResponse<BulkBlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let data = resp.data.unwrap();
+    assert_eq!((data.succeeded, data.failed), (2, 1));
+    assert_eq!(data.results.len(), 3);
+    assert_eq!(data.results[2].id, i32::MAX);
+    assert!(!data.results[2].success);
+    assert_eq!(data.results[2].error.as_deref(), Some("Blog not found"));
+    assert_eq!(data.results[0].previous_status.as_deref(), Some("DRAFT"));
+    assert_eq!(data.results[2].previous_status, None);
+    let resp: SuccessResponse<BlogResponseDto> =
+        test::call_and_read_body_json(&app, get(ids[0])).await;
+    let blog = resp.data.unwrap();
+    assert_eq!(blog.status, "PUBLISHED");
+    assert!(blog.published_at.is_some());
+
+    // Publishing again reports the post as already live
+    let req = bulk("publish", vec![ids[0]], None, None);
+    let resp: SuccessResponse<BulkBlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(
+        resp.data.unwrap().results[0].previous_status.as_deref(),
+        Some("PUBLISHED")
+    );
+
+    let req = bulk(
+        "change_category",
+        vec![ids[0], ids[2]],
+        Some(category_ids[1]),
+        None,
+    );
+    let resp: SuccessResponse<BulkBlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().succeeded, 2);
+    let resp: SuccessResponse<BlogResponseDto> =
+        test::call_and_read_body_json(&app, get(ids[2])).await;
+    assert_eq!(resp.data.unwrap().category.id, category_ids[1]);
+
+    // Adding a tag twice is harmless
+    for _ in 0..2 {
+        let req = bulk("add_tags", vec![ids[0], ids[1]], None, Some(vec![tag_id]));
+        let resp: SuccessResponse<BulkBlogResponseDto> =
+            test::call_and_read_body_json(&app, req).await;
+        assert_eq!(resp.data.unwrap().failed, 0);
+    }
+    let resp: SuccessResponse<BlogResponseDto> =
+        test::call_and_read_body_json(&app, get(ids[1])).await;
+    assert_eq!(
+        resp.data
+            .unwrap()
+            .tags
+            .iter()
+            .map(|t| t.id)
+            .collect::<Vec<_>>(),
+        vec![tag_id]
+    );
+
+    let req = bulk("remove_tags", vec![ids[1]], None, Some(vec![tag_id]));
+    test::call_service(&app, req).await;
+    let resp: SuccessResponse<BlogResponseDto> =
+        test::call_and_read_body_json(&app, get(ids[1])).await;
+    assert!(resp.data.unwrap().tags.is_empty());
+
+    let req = bulk("archive", vec![ids[1]], None, None);
+    test::call_service(&app, req).await;
+    let resp: SuccessResponse<BlogResponseDto> =
+        test::call_and_read_body_json(&app, get(ids[1])).await;
+    let blog = resp.data.unwrap();
+    assert_eq!(blog.status, "ARCHIVED");
+    assert!(blog.published_at.is_none());
+
+    let req = bulk("delete", vec![ids[2]], None, None);
+    let resp: SuccessResponse<BulkBlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().succeeded, 1);
+    assert_eq!(
+        test::call_service(&app, get(ids[2])).await.status(),
+        StatusCode::NOT_FOUND
+    );
+
+    let req = bulk("change_category", vec![ids[0]], None, None);
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body: ErrorResponse = test::read_body_json(resp).await;
+    assert!(body.errors.unwrap().contains_key("category_id"));
+
+    let req = bulk("explode", Vec::new(), None, None);
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body: ErrorResponse = test::read_body_json(resp).await;
+    let errors = body.errors.unwrap();
+    assert!(errors.contains_key("action"));
+    assert!(errors.contains_key("ids"));
+}
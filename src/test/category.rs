@@ -1,10 +1,14 @@
+use crate::app::features::blog::domain::entity::MAX_CATEGORY_DEPTH;
 use crate::app::features::blog::interface::dto::{
-    CategoryResponseDto, CreateCategoryRequestDto, UpdateCategoryRequestDto,
+    BlogResponseDto, CategoryResponseDto, CategoryTreeNodeDto, CreateBlogRequestDto,
+    CreateCategoryRequestDto, PaginatedResponseDto, UpdateCategoryRequestDto,
 };
 use crate::init_test_app;
 use crate::test::helpers::{login_admin, seed_user};
 use crate::utils::di::Container;
+use crate::utils::error_response::ErrorResponse;
 use crate::utils::success_response::SuccessResponse;
+use actix_web::http::StatusCode;
 use actix_web::test;
 use chrono::Utc;
 use serial_test::serial;
@@ -19,6 +23,7 @@ async fn test_create_category() {
 
     let create_dto = CreateCategoryRequestDto {
         name: format!("Test Create Category {}", Utc::now().timestamp_micros()),
+        parent_id: None,
     };
 
     let req = test::TestRequest::post()
@@ -41,6 +46,7 @@ async fn test_get_categories() {
     // Create a category to ensure at least one exists
     let create_dto = CreateCategoryRequestDto {
         name: format!("Test Get Category {}", Utc::now().timestamp_micros()),
+        parent_id: None,
     };
     let req = test::TestRequest::post()
         .uri("/app/categories")
@@ -71,6 +77,7 @@ async fn test_get_category_by_id() {
     let unique_name = format!("Test GetID Category {}", Utc::now().timestamp_micros());
     let create_dto = CreateCategoryRequestDto {
         name: unique_name.clone(),
+        parent_id: None,
     };
     let req = test::TestRequest::post()
         .uri("/app/categories")
@@ -117,6 +124,7 @@ async fn test_update_category() {
     let unique_name = format!("Test Update Category {}", Utc::now().timestamp_micros());
     let create_dto = CreateCategoryRequestDto {
         name: unique_name.clone(),
+        parent_id: None,
     };
     let req = test::TestRequest::post()
         .uri("/app/categories")
@@ -146,6 +154,7 @@ async fn test_update_category() {
     let update_name = format!("Test Updated Name {}", Utc::now().timestamp_micros());
     let update_dto = UpdateCategoryRequestDto {
         name: Some(update_name.clone()),
+        parent_id: None,
     };
     let req = test::TestRequest::put()
         .uri(&format!("/app/categories/{}", category.id))
@@ -168,6 +177,7 @@ async fn test_delete_category() {
     let unique_name = format!("Test Delete Category {}", Utc::now().timestamp_micros());
     let create_dto = CreateCategoryRequestDto {
         name: unique_name.clone(),
+        parent_id: None,
     };
     let req = test::TestRequest::post()
         .uri("/app/categories")
@@ -201,3 +211,249 @@ async fn test_delete_category() {
     let resp = test::call_service(&app, req).await;
     assert!(resp.status().is_success());
 }
+
+async fn create_category(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    name: String,
+    parent_id: Option<i32>,
+) -> CategoryResponseDto {
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateCategoryRequestDto { name, parent_id })
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap()
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_category_hierarchy() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let stamp = Utc::now().timestamp_micros();
+    let root = create_category(&app, &token, format!("Tech {}", stamp), None).await;
+    let child = create_category(&app, &token, format!("Rust {}", stamp), Some(root.id)).await;
+    let grandchild =
+        create_category(&app, &token, format!("Async {}", stamp), Some(child.id)).await;
+    assert_eq!(grandchild.parent_id, Some(child.id));
+    assert_eq!(
+        grandchild
+            .breadcrumbs
+            .iter()
+            .map(|c| c.id)
+            .collect::<Vec<_>>(),
+        vec![root.id, child.id, grandchild.id]
+    );
+
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateCategoryRequestDto {
+            name: format!("Orphan {}", stamp),
+            parent_id: Some(i32::MAX),
+        })
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    // Moving a category under its own descendant would create a cycle
+    for parent_id in [root.id, grandchild.id] {
+        let req = test::TestRequest::put()
+            .uri(&format!("/app/categories/{}", root.id))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(&UpdateCategoryRequestDto {
+                name: None,
+                parent_id: Some(Some(parent_id)),
+            })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body: ErrorResponse = test::read_body_json(resp).await;
+        assert!(body.errors.unwrap().contains_key("parent_id"));
+    }
+
+    // Renaming keeps the parent when `parent_id` is left out
+    let req = test::TestRequest::put()
+        .uri(&format!("/app/categories/{}", child.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&UpdateCategoryRequestDto {
+            name: Some(format!("Rustlang {}", stamp)),
+            parent_id: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().parent_id, Some(root.id));
+
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateBlogRequestDto {
+            title: format!("Tokio Deep Dive {}", stamp),
+            slug: None,
+            content: "Content".to_string(),
+            category_id: grandchild.id,
+            tag_ids: None,
+            excerpt: "Excerpt".to_string(),
+            thumbnail: None,
+            status: "DRAFT".to_string(),
+            published_at: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let blog = resp.data.unwrap();
+    assert_eq!(
+        blog.category
+            .breadcrumbs
+            .iter()
+            .map(|c| c.name.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            root.name.clone(),
+            format!("Rustlang {}", stamp),
+            grandchild.name.clone()
+        ]
+    );
+
+    let req = test::TestRequest::get()
+        .uri("/app/categories/tree")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<Vec<CategoryTreeNodeDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    let tree = resp.data.unwrap();
+    let node = tree.iter().find(|n| n.id == root.id).unwrap();
+    assert_eq!(node.children.len(), 1);
+    assert_eq!(node.children[0].id, child.id);
+    assert_eq!(node.children[0].children[0].id, grandchild.id);
+    assert!(tree.iter().all(|n| n.id != child.id));
+
+    let list_ids = |include: bool| {
+        test::TestRequest::get()
+            .uri(&format!(
+                "/app/blogs?per_page=100&category_id={}&include_descendants={}",
+                root.id, include
+            ))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request()
+    };
+    let resp: SuccessResponse<PaginatedResponseDto<BlogResponseDto>> =
+        test::call_and_read_body_json(&app, list_ids(true)).await;
+    let ids = resp
+        .data
+        .unwrap()
+        .items
+        .iter()
+        .map(|b| b.id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec![blog.id]);
+    let resp: SuccessResponse<PaginatedResponseDto<BlogResponseDto>> =
+        test::call_and_read_body_json(&app, list_ids(false)).await;
+    assert!(resp.data.unwrap().items.is_empty());
+
+    // Back to the top level
+    let req = test::TestRequest::put()
+        .uri(&format!("/app/categories/{}", grandchild.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&UpdateCategoryRequestDto {
+            name: None,
+            parent_id: Some(None),
+        })
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let moved = resp.data.unwrap();
+    assert_eq!(moved.parent_id, None);
+    assert_eq!(moved.breadcrumbs.len(), 1);
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_category_depth_limit() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let stamp = Utc::now().timestamp_micros();
+    // `chain[i]` sits at depth `i + 1`
+    let mut chain = vec![
+        create_category(&app, &token, format!("Depth {}", stamp), None)
+            .await
+            .id,
+    ];
+    while chain.len() < MAX_CATEGORY_DEPTH {
+        let parent_id = *chain.last().unwrap();
+        let level = chain.len();
+        chain.push(
+            create_category(
+                &app,
+                &token,
+                format!("Level {} {}", level, stamp),
+                Some(parent_id),
+            )
+            .await
+            .id,
+        );
+    }
+    let deepest = *chain.last().unwrap();
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/categories/{}", deepest))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().breadcrumbs.len(), MAX_CATEGORY_DEPTH);
+
+    // One level more is refused
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateCategoryRequestDto {
+            name: format!("Too Deep {}", stamp),
+            parent_id: Some(deepest),
+        })
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body: ErrorResponse = test::read_body_json(resp).await;
+    assert!(body.errors.unwrap()["parent_id"].contains("levels deep"));
+
+    // Cycles are still caught at the deepest level
+    let req = test::TestRequest::put()
+        .uri(&format!("/app/categories/{}", chain[0]))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&UpdateCategoryRequestDto {
+            name: None,
+            parent_id: Some(Some(deepest)),
+        })
+        .to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::BAD_REQUEST
+    );
+
+    // A moved category brings its children along, so they count too
+    let branch = create_category(&app, &token, format!("Branch {}", stamp), None).await;
+    create_category(&app, &token, format!("Twig {}", stamp), Some(branch.id)).await;
+    let move_branch = |parent_id: i32| {
+        test::TestRequest::put()
+            .uri(&format!("/app/categories/{}", branch.id))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(&UpdateCategoryRequestDto {
+                name: None,
+                parent_id: Some(Some(parent_id)),
+            })
+            .to_request()
+    };
+    let resp = test::call_service(&app, move_branch(chain[MAX_CATEGORY_DEPTH - 2])).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let resp = test::call_service(&app, move_branch(chain[MAX_CATEGORY_DEPTH - 3])).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+}
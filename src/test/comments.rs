@@ -0,0 +1,265 @@
+use crate::app::features::blog::interface::dto::{
+    BlogResponseDto, CategoryResponseDto, CreateBlogRequestDto, CreateCategoryRequestDto,
+};
+use crate::app::features::comments::interface::dto::{
+    CommentResponseDto, CreateCommentRequestDto, ModerateCommentsRequestDto,
+    ModerateCommentsResponseDto, PaginatedResponseDto, PublicCommentDto,
+};
+use crate::init_test_app;
+use crate::test::helpers::{login_admin, seed_user};
+use crate::utils::di::Container;
+use crate::utils::success_response::SuccessResponse;
+use actix_web::test;
+use chrono::Utc;
+use serial_test::serial;
+
+async fn create_blog(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    status: &str,
+) -> BlogResponseDto {
+    let category_dto = CreateCategoryRequestDto {
+        name: format!("Comment Cat {}", Utc::now().timestamp_micros()),
+        parent_id: None,
+    };
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&category_dto)
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(app, req).await;
+    let cat_id = resp.data.unwrap().id;
+
+    let create_dto = CreateBlogRequestDto {
+        title: format!("Comment Blog {}", Utc::now().timestamp_micros()),
+        slug: None,
+        content: "Content".to_string(),
+        category_id: cat_id,
+        tag_ids: None,
+        excerpt: "Excerpt".to_string(),
+        thumbnail: None,
+        status: status.to_string(),
+        published_at: None,
+    };
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&create_dto)
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap()
+}
+
+async fn submit_comment(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    slug: &str,
+    content: &str,
+    parent_id: Option<i32>,
+) -> CommentResponseDto {
+    let dto = CreateCommentRequestDto {
+        author_name: "Reader".to_string(),
+        author_email: "reader@example.com".to_string(),
+        content: content.to_string(),
+        parent_id,
+    };
+    let req = test::TestRequest::post()
+        .uri(&format!("/api/public/blogs/{}/comments", slug))
+        .set_json(&dto)
+        .to_request();
+    let resp = test::call_service(app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::CREATED);
+    let body: SuccessResponse<CommentResponseDto> = test::read_body_json(resp).await;
+    body.data.unwrap()
+}
+
+async fn moderate(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    ids: Vec<i32>,
+    status: &str,
+) -> usize {
+    let dto = ModerateCommentsRequestDto {
+        ids,
+        status: status.to_string(),
+    };
+    let req = test::TestRequest::post()
+        .uri("/app/comments/moderate")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&dto)
+        .to_request();
+    let resp: SuccessResponse<ModerateCommentsResponseDto> =
+        test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap().updated
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_comment_moderation_and_threading() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let blog = create_blog(&app, &token, "PUBLISHED").await;
+
+    let root = submit_comment(&app, &blog.slug, "First!", None).await;
+    assert_eq!(root.status, "PENDING");
+    let spam = submit_comment(&app, &blog.slug, "Buy now", None).await;
+
+    // Pending comments are not visible to readers
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/blogs/{}/comments", blog.slug))
+        .to_request();
+    let resp: SuccessResponse<PaginatedResponseDto<PublicCommentDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    assert!(resp.data.unwrap().items.is_empty());
+
+    // Replying to an unapproved comment is rejected
+    let dto = CreateCommentRequestDto {
+        author_name: "Reader".to_string(),
+        author_email: "reader@example.com".to_string(),
+        content: "Reply".to_string(),
+        parent_id: Some(root.id),
+    };
+    let req = test::TestRequest::post()
+        .uri(&format!("/api/public/blogs/{}/comments", blog.slug))
+        .set_json(&dto)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+
+    // The moderation queue lists pending comments for the blog
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/comments?status=PENDING&blog_id={}", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<PaginatedResponseDto<CommentResponseDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().meta.total_items, 2);
+
+    assert_eq!(
+        moderate(&app, &token, vec![root.id, root.id], "APPROVED").await,
+        1
+    );
+    assert_eq!(moderate(&app, &token, vec![spam.id], "SPAM").await, 1);
+
+    let reply = submit_comment(&app, &blog.slug, "Welcome", Some(root.id)).await;
+    moderate(&app, &token, vec![reply.id], "APPROVED").await;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/blogs/{}/comments", blog.slug))
+        .to_request();
+    let resp: SuccessResponse<PaginatedResponseDto<PublicCommentDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    let page = resp.data.unwrap();
+    assert_eq!(page.meta.total_items, 1);
+    assert_eq!(page.items[0].id, root.id);
+    assert_eq!(page.items[0].replies.len(), 1);
+    assert_eq!(page.items[0].replies[0].content, "Welcome");
+
+    // Blog responses carry the approved comment count
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/blogs/{}", blog.slug))
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().comment_count, 2);
+
+    // Deleting the root removes its replies too
+    let req = test::TestRequest::delete()
+        .uri(&format!("/app/comments/{}", root.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert!(resp.status().is_success());
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/blogs/{}", blog.slug))
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().comment_count, 0);
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_comment_submission_validation() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let draft = create_blog(&app, &token, "DRAFT").await;
+    let dto = CreateCommentRequestDto {
+        author_name: "Reader".to_string(),
+        author_email: "reader@example.com".to_string(),
+        content: "Hello".to_string(),
+        parent_id: None,
+    };
+    let req = test::TestRequest::post()
+        .uri(&format!("/api/public/blogs/{}/comments", draft.slug))
+        .set_json(&dto)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+
+    let published = create_blog(&app, &token, "PUBLISHED").await;
+    let dto = CreateCommentRequestDto {
+        author_name: "Reader".to_string(),
+        author_email: "not-an-email".to_string(),
+        content: "Hello".to_string(),
+        parent_id: None,
+    };
+    let req = test::TestRequest::post()
+        .uri(&format!("/api/public/blogs/{}/comments", published.slug))
+        .set_json(&dto)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_public_comments_pagination_validation() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let published = create_blog(&app, &token, "PUBLISHED").await;
+    for query in ["per_page=-1", "per_page=101", "page=0"] {
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/api/public/blogs/{}/comments?{}",
+                published.slug, query
+            ))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(
+            resp.status(),
+            actix_web::http::StatusCode::BAD_REQUEST,
+            "{}",
+            query
+        );
+    }
+
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/api/public/blogs/{}/comments?page={}",
+            published.slug,
+            i64::MAX
+        ))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert!(resp.status().is_success());
+}
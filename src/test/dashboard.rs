@@ -0,0 +1,233 @@
+use crate::app::features::blog::interface::dto::{
+    BlogResponseDto, CategoryResponseDto, CreateBlogRequestDto, CreateCategoryRequestDto,
+    CreateTagRequestDto,
+};
+use crate::app::features::dashboard::interface::dto::DashboardResponseDto;
+use crate::app::features::projects::interface::dto::CreateProjectRequestDto;
+use crate::init_test_app;
+use crate::schema::blog;
+use crate::test::helpers::{login_admin, seed_user};
+use crate::utils::db::establish_connection;
+use crate::utils::di::Container;
+use crate::utils::success_response::SuccessResponse;
+use actix_web::http::StatusCode;
+use actix_web::test;
+use chrono::{Duration, Utc};
+use diesel::prelude::*;
+use serial_test::serial;
+
+async fn get_dashboard(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    query: &str,
+) -> DashboardResponseDto {
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/dashboard{}", query))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<DashboardResponseDto> = test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap()
+}
+
+async fn create_draft(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    stamp: i64,
+) -> BlogResponseDto {
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateCategoryRequestDto {
+            name: format!("Dashboard Cat {}", stamp),
+            parent_id: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(app, req).await;
+    let category_id = resp.data.unwrap().id;
+
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateBlogRequestDto {
+            title: format!("Forgotten Draft {}", stamp),
+            slug: None,
+            content: "Content".to_string(),
+            category_id,
+            tag_ids: None,
+            excerpt: "Excerpt".to_string(),
+            thumbnail: None,
+            status: "DRAFT".to_string(),
+            published_at: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap()
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_dashboard_counts() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+    let before = get_dashboard(&app, &token, "").await;
+
+    let stamp = Utc::now().timestamp_micros();
+    create_draft(&app, &token, stamp).await;
+    let req = test::TestRequest::post()
+        .uri("/app/projects")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateProjectRequestDto {
+            nama_projek: format!("Dashboard Project {}", stamp),
+            slug: None,
+            deskripsi: "Desc".to_string(),
+            status: "ONGOING".to_string(),
+            progress: 40,
+            link_demo: None,
+            repository: None,
+            tanggal_mulai: "2023-01-01".to_string(),
+            tanggal_selesai: None,
+            stack_ids: None,
+        })
+        .to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::CREATED
+    );
+
+    let after = get_dashboard(&app, &token, "").await;
+    let count = |d: &DashboardResponseDto, status: &str| {
+        d.posts
+            .by_status
+            .iter()
+            .find(|s| s.status == status)
+            .unwrap()
+            .count
+    };
+    assert_eq!(count(&after, "DRAFT"), count(&before, "DRAFT") + 1);
+    assert_eq!(count(&after, "ARCHIVED"), count(&before, "ARCHIVED"));
+    assert_eq!(after.posts.total, before.posts.total + 1);
+
+    assert_eq!(after.projects.total, before.projects.total + 1);
+    let ongoing = after
+        .projects
+        .by_status
+        .iter()
+        .find(|s| s.status == "ONGOING")
+        .unwrap();
+    assert!(ongoing.count >= 1);
+    assert!((0.0..=100.0).contains(&ongoing.average_progress));
+    assert_eq!(
+        after.portofolios.total,
+        after.portofolios.active + after.portofolios.inactive
+    );
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_dashboard_top_posts_and_recent_updates() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+    let stamp = Utc::now().timestamp_micros();
+    let draft = create_draft(&app, &token, stamp).await;
+
+    // The most read post by far
+    let mut conn = establish_connection(&container.config.database_url)
+        .get()
+        .unwrap();
+    diesel::update(blog::table.find(draft.id))
+        .set(blog::view_count.eq(i32::MAX))
+        .execute(&mut conn)
+        .unwrap();
+
+    let tag_name = format!("Dashboard Tag {}", stamp);
+    let req = test::TestRequest::post()
+        .uri("/app/tags")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateTagRequestDto {
+            name: tag_name.clone(),
+        })
+        .to_request();
+    test::call_service(&app, req).await;
+
+    let dashboard = get_dashboard(&app, &token, "?limit=50").await;
+    assert_eq!(dashboard.top_posts[0].id, draft.id);
+    assert!(
+        dashboard
+            .recent_updates
+            .iter()
+            .any(|i| i.kind == "tag" && i.title == tag_name)
+    );
+    assert!(
+        dashboard
+            .recent_updates
+            .windows(2)
+            .all(|w| w[0].updated_at >= w[1].updated_at)
+    );
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_dashboard_stale_drafts() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+    let draft = create_draft(&app, &token, Utc::now().timestamp_micros()).await;
+
+    // Untouched for 40 days
+    let mut conn = establish_connection(&container.config.database_url)
+        .get()
+        .unwrap();
+    diesel::update(blog::table.find(draft.id))
+        .set(blog::updated_at.eq(Utc::now().naive_utc() - Duration::days(40)))
+        .execute(&mut conn)
+        .unwrap();
+
+    let dashboard = get_dashboard(&app, &token, "?limit=50").await;
+    let stale = dashboard
+        .stale_drafts
+        .items
+        .iter()
+        .find(|d| d.id == draft.id)
+        .unwrap();
+    assert_eq!(stale.days_untouched, 40);
+    assert_eq!(dashboard.stale_drafts.stale_days, 30);
+    assert!(dashboard.stale_drafts.total >= 1);
+
+    let strict = get_dashboard(&app, &token, "?stale_days=60&limit=50").await;
+    assert!(strict.stale_drafts.items.iter().all(|d| d.id != draft.id));
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_dashboard_rejects_bad_requests() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let req = test::TestRequest::get()
+        .uri("/app/dashboard?stale_days=0")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::BAD_REQUEST
+    );
+
+    let req = test::TestRequest::get().uri("/app/dashboard").to_request();
+    let err = test::try_call_service(&app, req).await.unwrap_err();
+    assert_eq!(err.error_response().status(), StatusCode::UNAUTHORIZED);
+}
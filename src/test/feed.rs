@@ -0,0 +1,167 @@
+use crate::app::features::blog::interface::dto::{
+    BlogResponseDto, CategoryResponseDto, CreateBlogRequestDto, CreateCategoryRequestDto,
+    CreateTagRequestDto, TagResponseDto,
+};
+use crate::app::features::feed::interface::dto::JsonFeedDto;
+use crate::init_test_app;
+use crate::test::helpers::{login_admin, seed_user};
+use crate::utils::di::Container;
+use crate::utils::success_response::SuccessResponse;
+use actix_web::http::{StatusCode, header};
+use actix_web::test;
+use chrono::Utc;
+use serial_test::serial;
+
+async fn create_blog(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    category_id: i32,
+    tag_id: i32,
+    status: &str,
+) -> BlogResponseDto {
+    let create_dto = CreateBlogRequestDto {
+        title: format!("Feed Blog {}", Utc::now().timestamp_micros()),
+        slug: None,
+        content: "# Hello\n\nFeed body".to_string(),
+        category_id,
+        tag_ids: Some(vec![tag_id]),
+        excerpt: "Feed excerpt".to_string(),
+        thumbnail: None,
+        status: status.to_string(),
+        published_at: None,
+    };
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&create_dto)
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap()
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_feeds_list_published_posts() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateCategoryRequestDto {
+            name: format!("Feed Cat {}", Utc::now().timestamp_micros()),
+            parent_id: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let category = resp.data.unwrap();
+
+    let req = test::TestRequest::post()
+        .uri("/app/tags")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateTagRequestDto {
+            name: format!("Feed Tag {}", Utc::now().timestamp_micros()),
+        })
+        .to_request();
+    let resp: SuccessResponse<TagResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let tag = resp.data.unwrap();
+
+    let published = create_blog(&app, &token, category.id, tag.id, "PUBLISHED").await;
+    let draft = create_blog(&app, &token, category.id, tag.id, "DRAFT").await;
+
+    // RSS
+    let req = test::TestRequest::get()
+        .uri(&format!("/categories/{}/feed.xml", category.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        resp.headers().get(header::CONTENT_TYPE).unwrap(),
+        "application/rss+xml; charset=utf-8"
+    );
+    let last_modified = resp
+        .headers()
+        .get(header::LAST_MODIFIED)
+        .expect("Last-Modified header")
+        .clone();
+    let body = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+    assert!(body.contains("<rss"));
+    assert!(body.contains(&published.title));
+    assert!(body.contains(&format!("/blog/{}</link>", published.slug)));
+    assert!(body.contains(&format!("<category>{}</category>", tag.name)));
+    assert!(!body.contains(&draft.title));
+
+    // Conditional request
+    let req = test::TestRequest::get()
+        .uri(&format!("/categories/{}/feed.xml", category.id))
+        .insert_header((header::IF_MODIFIED_SINCE, last_modified))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::NOT_MODIFIED);
+
+    // Atom
+    let req = test::TestRequest::get()
+        .uri(&format!("/tags/{}/atom.xml", tag.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        resp.headers().get(header::CONTENT_TYPE).unwrap(),
+        "application/atom+xml; charset=utf-8"
+    );
+    let body = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+    assert!(body.contains("<feed"));
+    assert!(body.contains(&published.title));
+    assert!(!body.contains(&draft.title));
+
+    // JSON Feed
+    let req = test::TestRequest::get()
+        .uri(&format!("/tags/{}/feed.json", tag.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(
+        resp.headers().get(header::CONTENT_TYPE).unwrap(),
+        "application/feed+json; charset=utf-8"
+    );
+    let feed: JsonFeedDto = test::read_body_json(resp).await;
+    assert_eq!(feed.version, "https://jsonfeed.org/version/1.1");
+    assert_eq!(feed.items.len(), 1);
+    assert_eq!(feed.items[0].title, published.title);
+    assert_eq!(
+        feed.items[0].tags,
+        vec![category.name.clone(), tag.name.clone()]
+    );
+    assert!(feed.items[0].content_html.contains("<h1"));
+
+    // Site-wide feed
+    let req = test::TestRequest::get().uri("/feed.json").to_request();
+    let feed: JsonFeedDto = test::call_and_read_body_json(&app, req).await;
+    assert!(feed.items.iter().any(|i| i.title == published.title));
+    assert!(feed.items.iter().all(|i| i.title != draft.title));
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_feed_unknown_category_or_tag() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+
+    let req = test::TestRequest::get()
+        .uri("/categories/999999/feed.xml")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    let req = test::TestRequest::get()
+        .uri("/tags/999999/feed.json")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
@@ -1,11 +1,16 @@
 use crate::app::features::auth::domain::repository::UserRepository;
 use crate::app::features::auth::infrastructure::repository_impl::UserRepositoryImpl;
 use crate::app::features::auth::interface::dto::{LoginRequestDto, LoginResponseDto};
+use crate::app::features::blog::interface::dto::{BlogResponseDto, CreateBlogRequestDto};
 use crate::utils::db::establish_connection;
 use crate::utils::di::Container;
 use crate::utils::success_response::SuccessResponse;
 use actix_http;
 use actix_web::test;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
 
 #[macro_export]
 macro_rules! init_test_app {
@@ -54,3 +59,253 @@ pub async fn login_admin(
     let resp: SuccessResponse<LoginResponseDto> = test::call_and_read_body_json(app, req).await;
     resp.data.expect("Login failed during test setup").token
 }
+
+/// A post with placeholder content and excerpt and no tags; adjust the rest
+/// with struct update syntax.
+pub fn blog_request(category_id: i32, title: String, status: &str) -> CreateBlogRequestDto {
+    CreateBlogRequestDto {
+        title,
+        slug: None,
+        content: "Content".to_string(),
+        category_id,
+        tag_ids: None,
+        excerpt: "Excerpt".to_string(),
+        thumbnail: None,
+        status: status.to_string(),
+        published_at: None,
+    }
+}
+
+pub async fn create_blog(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    dto: CreateBlogRequestDto,
+) -> BlogResponseDto {
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&dto)
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(app, req).await;
+    resp.data.expect("Blog creation failed during test setup")
+}
+
+/// A message accepted by [`SmtpStandIn`].
+#[derive(Debug, Clone)]
+pub struct CapturedEmail {
+    pub recipients: Vec<String>,
+    /// Headers and body exactly as sent after DATA
+    pub data: String,
+}
+
+impl CapturedEmail {
+    /// The body with any quoted-printable transfer encoding undone.
+    pub fn body(&self) -> String {
+        let (headers, body) = self.data.split_once("\r\n\r\n").unwrap_or(("", &self.data));
+        if !headers.contains("Content-Transfer-Encoding: quoted-printable") {
+            return body.to_string();
+        }
+        let body = body.replace("=\r\n", "");
+        let bytes = body.as_bytes();
+        let mut decoded = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+        while i < bytes.len() {
+            let hex = (bytes[i] == b'=')
+                .then(|| body.get(i + 1..i + 3))
+                .flatten()
+                .and_then(|h| u8::from_str_radix(h, 16).ok());
+            match hex {
+                Some(byte) => {
+                    decoded.push(byte);
+                    i += 3;
+                }
+                None => {
+                    decoded.push(bytes[i]);
+                    i += 1;
+                }
+            }
+        }
+        String::from_utf8_lossy(&decoded).into_owned()
+    }
+}
+
+/// Minimal SMTP server on a random local port that accepts every message
+/// and keeps it, so emails can be asserted on without a real mail server.
+pub struct SmtpStandIn {
+    pub port: u16,
+    messages: Arc<Mutex<Vec<CapturedEmail>>>,
+}
+
+impl SmtpStandIn {
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let messages = Arc::new(Mutex::new(Vec::new()));
+        let store = messages.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let store = store.clone();
+                thread::spawn(move || serve_smtp(stream, store));
+            }
+        });
+        Self { port, messages }
+    }
+
+    /// Messages sent to `address` so far.
+    pub fn messages_to(&self, address: &str) -> Vec<CapturedEmail> {
+        self.messages
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|m| m.recipients.iter().any(|r| r == address))
+            .cloned()
+            .collect()
+    }
+}
+
+fn serve_smtp(stream: TcpStream, store: Arc<Mutex<Vec<CapturedEmail>>>) {
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut writer = stream;
+    let _ = writer.write_all(b"220 localhost ESMTP\r\n");
+    let mut recipients = Vec::new();
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).unwrap_or(0) == 0 {
+            return;
+        }
+        let command = line.trim_end().to_ascii_uppercase();
+        let reply: &[u8] = if command.starts_with("RCPT TO:") {
+            let address = line.trim_end()[8..].trim().trim_matches(['<', '>']);
+            recipients.push(address.to_string());
+            b"250 OK\r\n"
+        } else if command == "DATA" {
+            let _ = writer.write_all(b"354 End data with <CR><LF>.<CR><LF>\r\n");
+            let mut data = String::new();
+            loop {
+                line.clear();
+                if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                    return;
+                }
+                if line == ".\r\n" {
+                    break;
+                }
+                // Undo dot-stuffing
+                data.push_str(line.strip_prefix('.').unwrap_or(&line));
+            }
+            store.lock().unwrap().push(CapturedEmail {
+                recipients: std::mem::take(&mut recipients),
+                data,
+            });
+            b"250 OK\r\n"
+        } else if command == "QUIT" {
+            let _ = writer.write_all(b"221 Bye\r\n");
+            return;
+        } else {
+            // EHLO, MAIL FROM, RSET, NOOP
+            b"250 OK\r\n"
+        };
+        let _ = writer.write_all(reply);
+    }
+}
+
+/// A request accepted by [`HttpReceiver`].
+#[derive(Debug, Clone)]
+pub struct CapturedRequest {
+    pub method: String,
+    pub path: String,
+    /// Header names are lowercased
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl CapturedRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n == &name.to_ascii_lowercase())
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Minimal HTTP server on a random local port that records every request
+/// and answers with the queued statuses in order, then 200 once they run out.
+pub struct HttpReceiver {
+    pub port: u16,
+    requests: Arc<Mutex<Vec<CapturedRequest>>>,
+}
+
+impl HttpReceiver {
+    pub fn start(statuses: Vec<u16>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let store = requests.clone();
+        let statuses = Arc::new(Mutex::new(statuses.into_iter()));
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let store = store.clone();
+                let status = statuses.lock().unwrap().next().unwrap_or(200);
+                thread::spawn(move || serve_http(stream, store, status));
+            }
+        });
+        Self { port, requests }
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("http://127.0.0.1:{}{}", self.port, path)
+    }
+
+    pub fn requests(&self) -> Vec<CapturedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn serve_http(stream: TcpStream, store: Arc<Mutex<Vec<CapturedRequest>>>, status: u16) {
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut writer = stream;
+    let mut line = String::new();
+    if reader.read_line(&mut line).unwrap_or(0) == 0 {
+        return;
+    }
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+
+    let mut headers = Vec::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+            break;
+        }
+        if let Some((name, value)) = line.trim_end().split_once(':') {
+            headers.push((name.trim().to_ascii_lowercase(), value.trim().to_string()));
+        }
+    }
+    let length = headers
+        .iter()
+        .find(|(n, _)| n == "content-length")
+        .and_then(|(_, v)| v.parse().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    let _ = std::io::Read::read_exact(&mut reader, &mut body);
+
+    store.lock().unwrap().push(CapturedRequest {
+        method,
+        path,
+        headers,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    });
+    let reply = format!("status {}", status);
+    let _ = write!(
+        writer,
+        "HTTP/1.1 {} Test\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reply.len(),
+        reply
+    );
+}
@@ -0,0 +1,386 @@
+use crate::app::drivers::cli;
+use crate::app::features::blog::domain::entity::MAX_CATEGORY_DEPTH;
+use crate::app::features::blog::interface::dto::{BlogResponseDto, CategoryResponseDto};
+use crate::app::features::importer::interface::dto::ImportReportDto;
+use crate::init_test_app;
+use crate::test::helpers::{login_admin, seed_user};
+use crate::utils::di::Container;
+use crate::utils::success_response::SuccessResponse;
+use actix_web::http::{StatusCode, header};
+use actix_web::test;
+use chrono::Utc;
+use serial_test::serial;
+
+const BOUNDARY: &str = "import-test-boundary";
+
+/// WordPress export with a published post in a nested category, a draft,
+/// a thumbnail attachment and a page, which isn't imported.
+fn wxr(stamp: i64) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0"
+    xmlns:excerpt="http://wordpress.org/export/1.2/excerpt/"
+    xmlns:content="http://purl.org/rss/1.0/modules/content/"
+    xmlns:dc="http://purl.org/dc/elements/1.1/"
+    xmlns:wp="http://wordpress.org/export/1.2/">
+<channel>
+    <title>Old blog</title>
+    <wp:wxr_version>1.2</wp:wxr_version>
+    <wp:category>
+        <wp:term_id>1</wp:term_id>
+        <wp:category_nicename>wp-parent-{stamp}</wp:category_nicename>
+        <wp:category_parent></wp:category_parent>
+        <wp:cat_name><![CDATA[WP Parent {stamp}]]></wp:cat_name>
+    </wp:category>
+    <wp:category>
+        <wp:term_id>2</wp:term_id>
+        <wp:category_nicename>wp-child-{stamp}</wp:category_nicename>
+        <wp:category_parent>wp-parent-{stamp}</wp:category_parent>
+        <wp:cat_name><![CDATA[WP Child {stamp}]]></wp:cat_name>
+    </wp:category>
+    <item>
+        <title>Hello from WordPress</title>
+        <pubDate>Sun, 01 Mar 2015 10:00:00 +0000</pubDate>
+        <content:encoded><![CDATA[First paragraph with <strong>bold</strong> text.
+
+<h2>A heading</h2>
+Second paragraph.]]></content:encoded>
+        <excerpt:encoded><![CDATA[Short summary]]></excerpt:encoded>
+        <wp:post_id>10</wp:post_id>
+        <wp:post_date><![CDATA[2015-03-01 12:00:00]]></wp:post_date>
+        <wp:post_date_gmt><![CDATA[2015-03-01 10:00:00]]></wp:post_date_gmt>
+        <wp:post_modified_gmt><![CDATA[2015-04-02 08:30:00]]></wp:post_modified_gmt>
+        <wp:post_name><![CDATA[hello-wordpress-{stamp}]]></wp:post_name>
+        <wp:status><![CDATA[publish]]></wp:status>
+        <wp:post_type><![CDATA[post]]></wp:post_type>
+        <category domain="category" nicename="wp-child-{stamp}"><![CDATA[WP Child {stamp}]]></category>
+        <category domain="post_tag" nicename="wp-tag-{stamp}"><![CDATA[wp-tag-{stamp}]]></category>
+        <wp:postmeta>
+            <wp:meta_key><![CDATA[_thumbnail_id]]></wp:meta_key>
+            <wp:meta_value><![CDATA[30]]></wp:meta_value>
+        </wp:postmeta>
+    </item>
+    <item>
+        <title>Unfinished thoughts</title>
+        <content:encoded><![CDATA[<p>Not done yet.</p>]]></content:encoded>
+        <wp:post_id>11</wp:post_id>
+        <wp:post_date><![CDATA[2016-05-05 09:00:00]]></wp:post_date>
+        <wp:post_date_gmt><![CDATA[0000-00-00 00:00:00]]></wp:post_date_gmt>
+        <wp:post_name><![CDATA[unfinished-{stamp}]]></wp:post_name>
+        <wp:status><![CDATA[draft]]></wp:status>
+        <wp:post_type><![CDATA[post]]></wp:post_type>
+    </item>
+    <item>
+        <title>About</title>
+        <content:encoded><![CDATA[<p>About me.</p>]]></content:encoded>
+        <wp:post_id>20</wp:post_id>
+        <wp:post_name><![CDATA[about-{stamp}]]></wp:post_name>
+        <wp:status><![CDATA[publish]]></wp:status>
+        <wp:post_type><![CDATA[page]]></wp:post_type>
+    </item>
+    <item>
+        <title>cover.png</title>
+        <wp:post_id>30</wp:post_id>
+        <wp:post_type><![CDATA[attachment]]></wp:post_type>
+        <wp:attachment_url><![CDATA[https://old.example.com/uploads/cover.png]]></wp:attachment_url>
+    </item>
+</channel>
+</rss>
+"#
+    )
+}
+
+/// Jekyll post; the date and slug come from the file name.
+fn jekyll_post(stamp: i64) -> String {
+    format!(
+        "---\ntitle: \"Notes from Jekyll\"\ncategories: [jekyll-{stamp}]\ntags: md-tag-{stamp} other-{stamp}\n---\n\n# Notes\n\nSome *markdown*.\n"
+    )
+}
+
+fn multipart_body(files: &[(&str, &str)]) -> Vec<u8> {
+    let mut body = Vec::new();
+    for (name, content) in files {
+        body.extend_from_slice(
+            format!(
+                "--{}\r\nContent-Disposition: form-data; name=\"files\"; filename=\"{}\"\r\nContent-Type: application/octet-stream\r\n\r\n{}\r\n",
+                BOUNDARY, name, content
+            )
+            .as_bytes(),
+        );
+    }
+    body.extend_from_slice(format!("--{}--\r\n", BOUNDARY).as_bytes());
+    body
+}
+
+async fn import(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    files: &[(&str, &str)],
+    dry_run: bool,
+) -> ImportReportDto {
+    let req = test::TestRequest::post()
+        .uri(&format!("/app/import?dry_run={}", dry_run))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .insert_header((
+            header::CONTENT_TYPE,
+            format!("multipart/form-data; boundary={}", BOUNDARY),
+        ))
+        .set_payload(multipart_body(files))
+        .to_request();
+    let resp = test::call_service(app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: SuccessResponse<ImportReportDto> = test::read_body_json(resp).await;
+    body.data.unwrap()
+}
+
+async fn get_blog(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    id: i32,
+) -> BlogResponseDto {
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/blogs/{}", id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap()
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_import_wxr_and_markdown() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(container);
+    let token = login_admin(&app, &container).await;
+    let stamp = Utc::now().timestamp_micros();
+
+    let wxr = wxr(stamp);
+    let jekyll = jekyll_post(stamp);
+    let jekyll_name = format!("2014-07-09-jekyll-notes-{}.md", stamp);
+    let files = [
+        ("export.xml", wxr.as_str()),
+        (jekyll_name.as_str(), jekyll.as_str()),
+        ("broken.md", "---\ntags: [a\n---\nno title\n"),
+        ("notes.txt", "plain text"),
+    ];
+
+    // Dry run reports everything and writes nothing
+    let report = import(&app, &token, &files, true).await;
+    assert!(report.dry_run);
+    assert_eq!(report.created, 3);
+    assert_eq!(report.failed, 2);
+    assert!(report.items.iter().all(|item| item.blog_id.is_none()));
+    assert!(
+        report
+            .categories_created
+            .contains(&format!("WP Child {}", stamp))
+    );
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/blogs/hello-wordpress-{}", stamp))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    // Real run keeps slugs, dates, status and taxonomy
+    let report = import(&app, &token, &files, false).await;
+    assert!(!report.dry_run);
+    assert_eq!((report.created, report.skipped, report.failed), (3, 0, 2));
+    assert!(report.tags_created.contains(&format!("wp-tag-{}", stamp)));
+    let ids: Vec<i32> = report.items.iter().filter_map(|i| i.blog_id).collect();
+    assert_eq!(ids.len(), 3);
+
+    let hello = get_blog(&app, &token, ids[0]).await;
+    assert_eq!(hello.slug, format!("hello-wordpress-{}", stamp));
+    assert_eq!(hello.status, "PUBLISHED");
+    assert_eq!(hello.created_at, "2015-03-01 10:00:00");
+    assert_eq!(hello.updated_at, "2015-04-02 08:30:00");
+    assert_eq!(hello.published_at.as_deref(), Some("2015-03-01 10:00:00"));
+    assert_eq!(hello.excerpt.as_deref(), Some("Short summary"));
+    assert_eq!(
+        hello.thumbnail.as_deref(),
+        Some("https://old.example.com/uploads/cover.png")
+    );
+    assert!(hello.content.contains("**bold**"));
+    assert!(hello.content_html.unwrap().contains("<h2"));
+    assert_eq!(hello.category.name, format!("WP Child {}", stamp));
+    assert_eq!(hello.tags[0].name, format!("wp-tag-{}", stamp));
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/categories/{}", hello.category.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let child: SuccessResponse<CategoryResponseDto> =
+        test::call_and_read_body_json(&app, req).await;
+    let breadcrumbs = child.data.unwrap().breadcrumbs;
+    assert_eq!(breadcrumbs[0].name, format!("WP Parent {}", stamp));
+
+    let draft = get_blog(&app, &token, ids[1]).await;
+    assert_eq!(draft.slug, format!("unfinished-{}", stamp));
+    assert_eq!(draft.status, "DRAFT");
+    assert_eq!(draft.created_at, "2016-05-05 09:00:00");
+    assert!(draft.published_at.is_none());
+    assert_eq!(draft.category.name, "Uncategorized");
+
+    let notes = get_blog(&app, &token, ids[2]).await;
+    assert_eq!(notes.slug, format!("jekyll-notes-{}", stamp));
+    assert_eq!(notes.status, "PUBLISHED");
+    assert_eq!(notes.created_at, "2014-07-09 00:00:00");
+    assert_eq!(notes.category.name, format!("jekyll-{}", stamp));
+    assert_eq!(notes.tags.len(), 2);
+    assert_eq!(notes.excerpt.as_deref(), Some("Notes Some markdown."));
+
+    // Re-running the same import changes nothing
+    let report = import(&app, &token, &files, false).await;
+    assert_eq!((report.created, report.skipped, report.failed), (0, 3, 2));
+    assert!(report.categories_created.is_empty());
+    assert!(report.tags_created.is_empty());
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_import_detaches_categories_past_the_depth_limit() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(container);
+    let token = login_admin(&app, &container).await;
+    let stamp = Utc::now().timestamp_micros();
+
+    // A parent chain longer than the limit, and two categories that are each
+    // other's parent
+    let deepest = MAX_CATEGORY_DEPTH + 2;
+    let mut categories = vec![(format!("depth-0-{}", stamp), String::new())];
+    for level in 1..=deepest {
+        categories.push((
+            format!("depth-{}-{}", level, stamp),
+            format!("depth-{}-{}", level - 1, stamp),
+        ));
+    }
+    categories.push((format!("loop-a-{}", stamp), format!("loop-b-{}", stamp)));
+    categories.push((format!("loop-b-{}", stamp), format!("loop-a-{}", stamp)));
+    let mut wxr = String::from(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:content="http://purl.org/rss/1.0/modules/content/" xmlns:wp="http://wordpress.org/export/1.2/">
+<channel>
+"#,
+    );
+    for (slug, parent) in &categories {
+        wxr.push_str(&format!(
+            "<wp:category><wp:category_nicename>{slug}</wp:category_nicename><wp:category_parent>{parent}</wp:category_parent><wp:cat_name>{slug}</wp:cat_name></wp:category>\n"
+        ));
+    }
+    for (post, category) in [
+        ("deep", format!("depth-{}-{}", deepest, stamp)),
+        ("loop", format!("loop-a-{}", stamp)),
+    ] {
+        wxr.push_str(&format!(
+            "<item><title>{post}</title><content:encoded>Body</content:encoded><wp:post_name>{post}-{stamp}</wp:post_name><wp:status>publish</wp:status><wp:post_type>post</wp:post_type><category domain=\"category\" nicename=\"{category}\">{category}</category></item>\n"
+        ));
+    }
+    wxr.push_str("</channel>\n</rss>\n");
+
+    let report = import(&app, &token, &[("export.xml", wxr.as_str())], false).await;
+    assert_eq!(report.created, 2);
+    // The chain keeps as many levels as fit; the top ones are dropped
+    assert_eq!(
+        report.categories_detached,
+        vec![
+            format!("depth-{}-{}", deepest + 1 - MAX_CATEGORY_DEPTH, stamp),
+            format!("loop-b-{}", stamp),
+        ]
+    );
+
+    let ids: Vec<i32> = report.items.iter().filter_map(|i| i.blog_id).collect();
+    for ((id, depth), top) in ids
+        .into_iter()
+        .zip([MAX_CATEGORY_DEPTH, 2])
+        .zip(&report.categories_detached)
+    {
+        let breadcrumbs = get_blog(&app, &token, id).await.category.breadcrumbs;
+        assert_eq!(breadcrumbs.len(), depth);
+        assert_eq!(&breadcrumbs[0].name, top);
+    }
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_import_requires_files_and_auth() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(container);
+    let token = login_admin(&app, &container).await;
+
+    let req = test::TestRequest::post()
+        .uri("/app/import")
+        .insert_header((
+            header::CONTENT_TYPE,
+            format!("multipart/form-data; boundary={}", BOUNDARY),
+        ))
+        .set_payload(multipart_body(&[("a.md", "---\ntitle: x\n---\n")]))
+        .to_request();
+    let err = test::try_call_service(&app, req).await.unwrap_err();
+    assert_eq!(err.error_response().status(), StatusCode::UNAUTHORIZED);
+
+    let req = test::TestRequest::post()
+        .uri("/app/import")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .insert_header((
+            header::CONTENT_TYPE,
+            format!("multipart/form-data; boundary={}", BOUNDARY),
+        ))
+        .set_payload(multipart_body(&[]))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_import_from_directory() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(container);
+    let token = login_admin(&app, &container).await;
+    let stamp = Utc::now().timestamp_micros();
+
+    let dir = std::env::temp_dir().join(format!("myblog-import-{}", stamp));
+    std::fs::create_dir_all(dir.join("_posts")).unwrap();
+    std::fs::write(
+        dir.join("_posts")
+            .join(format!("2013-01-02-cli-post-{}.md", stamp)),
+        format!("---\ntitle: From the CLI\ntags: [cli-{stamp}]\n---\nBody.\n"),
+    )
+    .unwrap();
+    std::fs::write(dir.join("README"), "not a post").unwrap();
+
+    let files = cli::read_import_files(std::slice::from_ref(&dir)).unwrap();
+    assert_eq!(files.len(), 1);
+
+    cli::import(&container, &[dir.display().to_string()]).unwrap();
+    assert!(cli::import(&container, &["--author".to_string()]).is_err());
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/blogs/cli-post-{}", stamp))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let report = import(
+        &app,
+        &token,
+        &[(files[0].name.as_str(), files[0].content.as_str())],
+        true,
+    )
+    .await;
+    assert_eq!((report.created, report.skipped), (0, 1));
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,560 @@
+use crate::app::features::blog::interface::dto::{
+    BlogResponseDto, CategoryResponseDto, CreateBlogRequestDto, CreateCategoryRequestDto,
+};
+use crate::app::features::media::application::usecase as media_usecase;
+use crate::app::features::media::domain::image::ImageProcessor;
+use crate::app::features::media::domain::repository::MediaRepository;
+use crate::app::features::media::domain::storage::MediaStorage;
+use crate::app::features::media::infrastructure::image_impl::LocalImageProcessor;
+use crate::app::features::media::infrastructure::repository_impl::MediaRepositoryImpl;
+use crate::app::features::media::infrastructure::storage_impl::LocalMediaStorage;
+use crate::app::features::media::interface::dto::{MediaResponseDto, PaginatedResponseDto};
+use crate::app::features::portofolio::interface::dto::{
+    CreatePortofolioRequestDto, PortofolioResponseDto,
+};
+use crate::app::features::projects::interface::dto::{CreateProjectRequestDto, ProjectResponseDto};
+use crate::init_test_app;
+use crate::test::helpers::{login_admin, seed_user};
+use crate::utils::db::establish_connection;
+use crate::utils::di::Container;
+use crate::utils::error_response::ErrorResponse;
+use crate::utils::success_response::SuccessResponse;
+use actix_web::http::{StatusCode, header};
+use actix_web::test;
+use chrono::Utc;
+use serial_test::serial;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+const BOUNDARY: &str = "media-test-boundary";
+
+/// 1x1 transparent PNG
+const PNG: [u8; 70] = [
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0x1f, 0x15, 0xc4,
+    0x89, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9c, 0x63, 0xf8, 0xcf, 0xc0, 0xf0,
+    0x1f, 0x00, 0x05, 0x00, 0x01, 0xff, 0x89, 0x99, 0x3d, 0x1d, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45,
+    0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+];
+
+/// Container whose media use cases store files in a fresh temp directory,
+/// accept at most `max_bytes` of PNG, JPEG or GIF and make 8 and 16 pixel
+/// wide variants.
+fn media_container(max_bytes: usize) -> (Container, PathBuf) {
+    let mut container = Container::new();
+    let dir = std::env::temp_dir().join(format!("myblog-media-{}", Utc::now().timestamp_micros()));
+    let pool = establish_connection(&container.config.database_url);
+    let repository: Arc<dyn MediaRepository + Send + Sync> =
+        Arc::new(MediaRepositoryImpl::new(pool));
+    let storage: Arc<dyn MediaStorage + Send + Sync> =
+        Arc::new(LocalMediaStorage::new(dir.clone()));
+    let processor: Arc<dyn ImageProcessor + Send + Sync> =
+        Arc::new(LocalImageProcessor::new(vec![8, 16], 80.0));
+    container.upload_media_usecase = media_usecase::upload::Execute::new(
+        repository.clone(),
+        storage.clone(),
+        processor,
+        max_bytes,
+        vec![
+            "image/png".to_string(),
+            "image/jpeg".to_string(),
+            "image/gif".to_string(),
+        ],
+        container.config.site_url.clone(),
+    );
+    container.delete_media_usecase =
+        media_usecase::delete::Execute::new(repository.clone(), storage.clone());
+    container.serve_media_usecase = media_usecase::serve::Execute::new(repository, storage);
+    (container, dir)
+}
+
+/// EXIF block saying the camera was turned 90 degrees clockwise.
+const EXIF_ROTATE_90: [u8; 36] = [
+    0xff, 0xe1, 0x00, 0x22, b'E', b'x', b'i', b'f', 0x00, 0x00, b'M', b'M', 0x00, 0x2a, 0x00, 0x00,
+    0x00, 0x08, 0x00, 0x01, 0x01, 0x12, 0x00, 0x03, 0x00, 0x00, 0x00, 0x01, 0x00, 0x06, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00,
+];
+
+/// 40x20 gradient JPEG carrying `EXIF_ROTATE_90`.
+fn rotated_jpeg() -> Vec<u8> {
+    let seed = (Utc::now().timestamp_micros() % 200) as u8;
+    let image = image::RgbImage::from_fn(40, 20, |x, y| {
+        image::Rgb([(x * 6) as u8, (y * 12) as u8, seed])
+    });
+    let mut encoded = Vec::new();
+    image::codecs::jpeg::JpegEncoder::new_with_quality(&mut encoded, 90)
+        .encode_image(&image)
+        .unwrap();
+    // The APP1 segment goes right after the SOI marker
+    let mut bytes = encoded[..2].to_vec();
+    bytes.extend_from_slice(&EXIF_ROTATE_90);
+    bytes.extend_from_slice(&encoded[2..]);
+    bytes
+}
+
+/// PNG that decodes like `PNG` but has its own checksum.
+fn unique_png() -> Vec<u8> {
+    let mut bytes = PNG.to_vec();
+    bytes.extend_from_slice(Utc::now().timestamp_micros().to_string().as_bytes());
+    bytes
+}
+
+fn multipart_body(file: Option<(&str, &[u8])>, alt_text: Option<&str>) -> Vec<u8> {
+    let mut body = Vec::new();
+    if let Some((name, bytes)) = file {
+        body.extend_from_slice(
+            format!(
+                "--{}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"{}\"\r\nContent-Type: application/octet-stream\r\n\r\n",
+                BOUNDARY, name
+            )
+            .as_bytes(),
+        );
+        body.extend_from_slice(bytes);
+        body.extend_from_slice(b"\r\n");
+    }
+    if let Some(alt_text) = alt_text {
+        body.extend_from_slice(
+            format!(
+                "--{}\r\nContent-Disposition: form-data; name=\"alt_text\"\r\n\r\n{}\r\n",
+                BOUNDARY, alt_text
+            )
+            .as_bytes(),
+        );
+    }
+    body.extend_from_slice(format!("--{}--\r\n", BOUNDARY).as_bytes());
+    body
+}
+
+async fn upload(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    file: Option<(&str, &[u8])>,
+    alt_text: Option<&str>,
+) -> actix_web::dev::ServiceResponse {
+    let req = test::TestRequest::post()
+        .uri("/app/media")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .insert_header((
+            header::CONTENT_TYPE,
+            format!("multipart/form-data; boundary={}", BOUNDARY),
+        ))
+        .set_payload(multipart_body(file, alt_text))
+        .to_request();
+    test::call_service(app, req).await
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_media_upload_serve_and_validation() {
+    let (container, dir) = media_container(1024);
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let bytes = unique_png();
+    let resp = upload(
+        &app,
+        &token,
+        Some(("My Photo.PNG", &bytes)),
+        Some("A pixel"),
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let body: SuccessResponse<MediaResponseDto> = test::read_body_json(resp).await;
+    let media = body.data.unwrap();
+    assert_eq!(media.mime_type, "image/png");
+    assert_eq!(media.size, bytes.len() as i64);
+    assert_eq!(media.checksum.len(), 64);
+    assert_eq!(media.alt_text.as_deref(), Some("A pixel"));
+    assert_eq!(media.original_name, "My Photo.PNG");
+    assert!(media.filename.starts_with("my-photo-"));
+    assert!(media.filename.ends_with(".png"));
+    assert_eq!(
+        media.url,
+        format!("http://localhost:8080/media/{}", media.filename)
+    );
+    assert!(dir.join(&media.filename).exists());
+
+    // Served publicly with the sniffed type
+    let req = test::TestRequest::get()
+        .uri(&format!("/media/{}", media.filename))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        resp.headers().get(header::CONTENT_TYPE).unwrap(),
+        "image/png"
+    );
+    assert!(
+        resp.headers()
+            .get(header::CACHE_CONTROL)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .contains("immutable")
+    );
+    assert_eq!(test::read_body(resp).await.to_vec(), bytes);
+
+    // Same bytes again come back as the existing entry
+    let resp = upload(&app, &token, Some(("copy.png", &bytes)), None).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: SuccessResponse<MediaResponseDto> = test::read_body_json(resp).await;
+    assert_eq!(body.data.unwrap().id, media.id);
+
+    // A PNG name does not make a text file an image
+    let resp = upload(&app, &token, Some(("fake.png", b"hello world")), None).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body: ErrorResponse = test::read_body_json(resp).await;
+    assert_eq!(
+        body.errors.unwrap().get("file").unwrap(),
+        "Unsupported file type"
+    );
+
+    let mut too_large = unique_png();
+    too_large.resize(2048, 0);
+    let resp = upload(&app, &token, Some(("big.png", &too_large)), None).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body: ErrorResponse = test::read_body_json(resp).await;
+    assert_eq!(
+        body.errors.unwrap().get("file").unwrap(),
+        "File is larger than 1024 bytes"
+    );
+
+    // Alt text far past the limit is cut off while reading and still rejected
+    let resp = upload(
+        &app,
+        &token,
+        Some(("long-alt.png", &unique_png())),
+        Some(&"a".repeat(100_000)),
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body: ErrorResponse = test::read_body_json(resp).await;
+    assert_eq!(
+        body.errors.unwrap().get("alt_text").unwrap(),
+        "Alt text is too long"
+    );
+
+    let resp = upload(&app, &token, None, Some("No file")).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body: ErrorResponse = test::read_body_json(resp).await;
+    assert_eq!(
+        body.errors.unwrap().get("file").unwrap(),
+        "File is required"
+    );
+
+    // Listing
+    let req = test::TestRequest::get()
+        .uri("/app/media?mime_type=image/&per_page=100")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<PaginatedResponseDto<MediaResponseDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    let page = resp.data.unwrap();
+    assert_eq!(page.items[0].id, media.id);
+    assert!(page.items.iter().all(|m| m.mime_type.starts_with("image/")));
+
+    let req = test::TestRequest::get()
+        .uri("/app/media?mime_type=application/pdf")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<PaginatedResponseDto<MediaResponseDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().meta.total_items, 0);
+
+    // Unknown names are never looked up on disk
+    let req = test::TestRequest::get()
+        .uri("/media/..%2Fmyblog.db")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_media_delete_refused_while_referenced() {
+    let (container, dir) = media_container(1024);
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let bytes = unique_png();
+    let resp = upload(&app, &token, Some(("cover.png", &bytes)), None).await;
+    let body: SuccessResponse<MediaResponseDto> = test::read_body_json(resp).await;
+    let media = body.data.unwrap();
+
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateCategoryRequestDto {
+            name: format!("Media Cat {}", Utc::now().timestamp_micros()),
+            parent_id: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let category_id = resp.data.unwrap().id;
+
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateBlogRequestDto {
+            title: format!("Media Blog {}", Utc::now().timestamp_micros()),
+            slug: None,
+            content: format!("Look: ![pixel](/media/{})", media.filename),
+            category_id,
+            tag_ids: None,
+            excerpt: "Excerpt".to_string(),
+            thumbnail: None,
+            status: "DRAFT".to_string(),
+            published_at: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let blog = resp.data.unwrap();
+
+    let req = test::TestRequest::delete()
+        .uri(&format!("/app/media/{}", media.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+    let body: ErrorResponse = test::read_body_json(resp).await;
+    assert!(body.message.contains(&blog.title));
+    assert!(dir.join(&media.filename).exists());
+
+    let req = test::TestRequest::delete()
+        .uri(&format!("/app/blogs/{}", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert!(resp.status().is_success());
+
+    let req = test::TestRequest::delete()
+        .uri(&format!("/app/media/{}", media.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert!(!dir.join(&media.filename).exists());
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/media/{}", media.filename))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/media/{}", media.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_media_image_variants_and_srcset() {
+    let (container, dir) = media_container(64 * 1024);
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let bytes = rotated_jpeg();
+    let resp = upload(&app, &token, Some(("Holiday.jpg", &bytes)), Some("Beach")).await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let body: SuccessResponse<MediaResponseDto> = test::read_body_json(resp).await;
+    let media = body.data.unwrap();
+
+    // The EXIF rotation is applied, then dropped along with the rest
+    assert_eq!((media.width, media.height), (Some(20), Some(40)));
+    assert!(media.blurhash.as_deref().is_some_and(|h| h.len() >= 6));
+    let stored = std::fs::read(dir.join(&media.filename)).unwrap();
+    assert!(!stored.windows(4).any(|w| w == b"Exif"));
+    assert_eq!(media.size, stored.len() as i64);
+
+    // 8 and 16 wide WebP copies plus a full-size one
+    let widths = media.srcset.iter().map(|s| s.width).collect::<Vec<_>>();
+    assert_eq!(widths, vec![8, 16, 20]);
+    assert_eq!(media.srcset[0].height, 40 * 8 / 20);
+    assert!(media.srcset.iter().all(|s| s.mime_type == "image/webp"));
+    assert!(
+        media.srcset[1]
+            .url
+            .starts_with("http://localhost:8080/media/holiday-")
+    );
+    assert!(media.srcset[1].url.ends_with("-16w.webp"));
+
+    let variant_name = media.srcset[0].url.rsplit('/').next().unwrap().to_string();
+    let req = test::TestRequest::get()
+        .uri(&format!("/media/{}", variant_name))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        resp.headers().get(header::CONTENT_TYPE).unwrap(),
+        "image/webp"
+    );
+    let served = test::read_body(resp).await;
+    assert_eq!(&served[..4], b"RIFF");
+    assert_eq!(&served[8..12], b"WEBP");
+
+    // Posts list the images they use as thumbnail or in their content
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateCategoryRequestDto {
+            name: format!("Srcset Cat {}", Utc::now().timestamp_micros()),
+            parent_id: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let category_id = resp.data.unwrap().id;
+
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateBlogRequestDto {
+            title: format!("Srcset Blog {}", Utc::now().timestamp_micros()),
+            slug: None,
+            content: "![missing](/media/not-in-library.png)".to_string(),
+            category_id,
+            tag_ids: None,
+            excerpt: "Excerpt".to_string(),
+            thumbnail: Some(format!("http://localhost:8080/media/{}", media.filename)),
+            status: "DRAFT".to_string(),
+            published_at: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let blog = resp.data.unwrap();
+    assert_eq!(blog.images.len(), 1);
+    let image = &blog.images[0];
+    assert_eq!(image.src, format!("/media/{}", media.filename));
+    assert_eq!(image.alt_text.as_deref(), Some("Beach"));
+    assert_eq!(image.blurhash, media.blurhash);
+    assert_eq!(image.srcset.len(), 3);
+    assert_eq!(image.srcset[0].url, format!("/media/{}", variant_name));
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/blogs/{}", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().images, blog.images);
+
+    // Portfolios list the images embedded in their description
+    let stamp = Utc::now().timestamp_micros();
+    let req = test::TestRequest::post()
+        .uri("/app/projects")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateProjectRequestDto {
+            nama_projek: format!("Srcset Project {}", stamp),
+            slug: None,
+            deskripsi: "Desc".to_string(),
+            status: "ONGOING".to_string(),
+            progress: 0,
+            link_demo: None,
+            repository: None,
+            tanggal_mulai: "2023-01-01".to_string(),
+            tanggal_selesai: None,
+            stack_ids: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<ProjectResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let project = resp.data.unwrap();
+
+    let req = test::TestRequest::post()
+        .uri("/app/portofolios")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreatePortofolioRequestDto {
+            project_id: project.id,
+            judul: format!("Srcset Portofolio {}", stamp),
+            slug: None,
+            deskripsi: format!("<img src=\"/media/{}\" alt=\"shot\">", media.filename),
+            is_active: true,
+        })
+        .to_request();
+    let resp: SuccessResponse<PortofolioResponseDto> =
+        test::call_and_read_body_json(&app, req).await;
+    let portofolio = resp.data.unwrap();
+    assert_eq!(portofolio.images, blog.images);
+
+    // Still embedded by the portfolio
+    let req = test::TestRequest::delete()
+        .uri(&format!("/app/blogs/{}", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    assert!(test::call_service(&app, req).await.status().is_success());
+    let req = test::TestRequest::delete()
+        .uri(&format!("/app/media/{}", media.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+    let body: ErrorResponse = test::read_body_json(resp).await;
+    assert!(body.message.contains(&portofolio.judul));
+
+    let req = test::TestRequest::delete()
+        .uri(&format!("/app/portofolios/{}", portofolio.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    assert!(test::call_service(&app, req).await.status().is_success());
+
+    // Embedding only a variant keeps the media too
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateBlogRequestDto {
+            title: format!("Variant Blog {}", Utc::now().timestamp_micros()),
+            slug: None,
+            content: format!("![small](/media/{})", variant_name),
+            category_id,
+            tag_ids: None,
+            excerpt: "Excerpt".to_string(),
+            thumbnail: None,
+            status: "DRAFT".to_string(),
+            published_at: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let variant_blog = resp.data.unwrap();
+    let req = test::TestRequest::delete()
+        .uri(&format!("/app/media/{}", media.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+    let body: ErrorResponse = test::read_body_json(resp).await;
+    assert!(body.message.contains(&variant_blog.title));
+    assert!(dir.join(&variant_name).exists());
+
+    // Deleting the media removes the variant files too
+    let req = test::TestRequest::delete()
+        .uri(&format!("/app/blogs/{}", variant_blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    assert!(test::call_service(&app, req).await.status().is_success());
+    let req = test::TestRequest::delete()
+        .uri(&format!("/app/media/{}", media.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert!(!dir.join(&variant_name).exists());
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/media/{}", variant_name))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    let _ = std::fs::remove_dir_all(dir);
+}
@@ -1,8 +1,26 @@
+pub mod analytics;
 pub mod auth;
+pub mod authors;
 pub mod blog;
 pub mod category;
+pub mod comments;
+pub mod dashboard;
+pub mod feed;
 pub mod helpers;
+pub mod importer;
+pub mod media;
+pub mod newsletter;
 pub mod portofolio;
+pub mod previews;
 pub mod profile;
 pub mod projects;
+pub mod public;
+pub mod related;
+pub mod revision;
+pub mod search;
+pub mod series;
+pub mod sitemap;
+pub mod slug;
+pub mod slug_history;
 pub mod tag;
+pub mod webhooks;
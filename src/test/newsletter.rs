@@ -0,0 +1,354 @@
+use crate::app::features::blog::interface::dto::{
+    BlogResponseDto, CategoryResponseDto, CreateBlogRequestDto, CreateCategoryRequestDto,
+    UpdateBlogRequestDto,
+};
+use crate::app::features::subscribers::interface::dto::{
+    PaginatedResponseDto, SubscribeRequestDto, SubscriberResponseDto, SubscriptionStatusDto,
+};
+use crate::config::Config;
+use crate::init_test_app;
+use crate::test::helpers::{SmtpStandIn, login_admin, seed_user};
+use crate::utils::di::Container;
+use crate::utils::success_response::SuccessResponse;
+use actix_web::http::{StatusCode, header};
+use actix_web::test;
+use chrono::Utc;
+use serial_test::serial;
+
+/// The path of the first link in `data` that contains `marker`.
+fn link_path(data: &str, marker: &str) -> String {
+    let start = data.find(marker).unwrap();
+    let end = data[start..].find('"').unwrap();
+    data[start..start + end].to_string()
+}
+
+/// Runs the newsletter sender until the queue is empty.
+fn send_queued(container: &Container) {
+    while container.send_newsletter_emails_usecase.execute().unwrap() > 0 {}
+}
+
+async fn create_post(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    category_id: i32,
+    title: &str,
+    status: &str,
+) -> BlogResponseDto {
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateBlogRequestDto {
+            title: title.to_string(),
+            slug: None,
+            content: "Content".to_string(),
+            category_id,
+            tag_ids: None,
+            excerpt: "Fresh <b>news</b>".to_string(),
+            thumbnail: None,
+            status: status.to_string(),
+            published_at: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap()
+}
+
+/// A container whose mail goes to a fresh SMTP stand-in.
+fn newsletter_container() -> (SmtpStandIn, Container) {
+    let smtp = SmtpStandIn::start();
+    let mut config = Config::new();
+    config.smtp_host = "127.0.0.1".to_string();
+    config.smtp_port = smtp.port as i32;
+    let container = Container::with_config(config);
+    seed_user(&container);
+    (smtp, container)
+}
+
+async fn create_category(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    stamp: i64,
+) -> i32 {
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateCategoryRequestDto {
+            name: format!("Newsletter Cat {}", stamp),
+            parent_id: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap().id
+}
+
+async fn subscribe(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    email: &str,
+) -> StatusCode {
+    let req = test::TestRequest::post()
+        .uri("/api/public/newsletter/subscribe")
+        .set_json(&SubscribeRequestDto {
+            email: email.to_string(),
+        })
+        .to_request();
+    test::call_service(app, req).await.status()
+}
+
+/// Subscribes `address` and follows its confirmation link; returns that link.
+async fn confirmed_subscriber(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    container: &Container,
+    smtp: &SmtpStandIn,
+    address: &str,
+) -> String {
+    assert_eq!(subscribe(app, address).await, StatusCode::ACCEPTED);
+    send_queued(container);
+    let sent = smtp.messages_to(address);
+    let confirm_path = link_path(&sent[0].body(), "/api/public/newsletter/confirm/");
+    let req = test::TestRequest::get().uri(&confirm_path).to_request();
+    let resp: SuccessResponse<SubscriptionStatusDto> =
+        test::call_and_read_body_json(app, req).await;
+    assert_eq!(resp.data.unwrap().status, "CONFIRMED");
+    confirm_path
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_newsletter_subscribe_sends_one_confirmation() {
+    let (smtp, container) = newsletter_container();
+    let app = init_test_app!(&container);
+
+    let address = format!("reader-{}@example.com", Utc::now().timestamp_micros());
+    assert_eq!(
+        subscribe(&app, &address.to_uppercase()).await,
+        StatusCode::ACCEPTED
+    );
+    // Queued, not sent while the request waits
+    assert!(smtp.messages_to(&address).is_empty());
+    send_queued(&container);
+    let sent = smtp.messages_to(&address);
+    assert_eq!(sent.len(), 1);
+    assert!(sent[0].data.contains("Subject: Confirm your subscription"));
+    let confirm_path = link_path(&sent[0].body(), "/api/public/newsletter/confirm/");
+
+    // Asking again so soon doesn't send another email, and looks the same
+    assert_eq!(subscribe(&app, &address).await, StatusCode::ACCEPTED);
+    send_queued(&container);
+    assert_eq!(smtp.messages_to(&address).len(), 1);
+
+    for _ in 0..2 {
+        let req = test::TestRequest::get().uri(&confirm_path).to_request();
+        let resp: SuccessResponse<SubscriptionStatusDto> =
+            test::call_and_read_body_json(&app, req).await;
+        let status = resp.data.unwrap();
+        assert_eq!(status.email, address);
+        assert_eq!(status.status, "CONFIRMED");
+    }
+
+    // Already confirmed: same answer, no second email
+    assert_eq!(subscribe(&app, &address).await, StatusCode::ACCEPTED);
+    send_queued(&container);
+    assert_eq!(smtp.messages_to(&address).len(), 1);
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_newsletter_rejects_an_invalid_email() {
+    let (_smtp, container) = newsletter_container();
+    let app = init_test_app!(&container);
+
+    assert_eq!(
+        subscribe(&app, "not-an-email").await,
+        StatusCode::BAD_REQUEST
+    );
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_newsletter_sends_published_posts_to_confirmed_subscribers_once() {
+    let (smtp, container) = newsletter_container();
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+    let stamp = Utc::now().timestamp_micros();
+    let category_id = create_category(&app, &token, stamp).await;
+    let address = format!("reader-{}@example.com", stamp);
+
+    // Unconfirmed addresses get nothing
+    assert_eq!(subscribe(&app, &address).await, StatusCode::ACCEPTED);
+    send_queued(&container);
+    let sent = smtp.messages_to(&address);
+    let confirm_path = link_path(&sent[0].body(), "/api/public/newsletter/confirm/");
+    create_post(
+        &app,
+        &token,
+        category_id,
+        &format!("Early {}", stamp),
+        "PUBLISHED",
+    )
+    .await;
+    send_queued(&container);
+    assert_eq!(smtp.messages_to(&address).len(), 1);
+
+    let req = test::TestRequest::get().uri(&confirm_path).to_request();
+    let resp: SuccessResponse<SubscriptionStatusDto> =
+        test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().status, "CONFIRMED");
+
+    let title = format!("Newsletter Post {}", stamp);
+    let post = create_post(&app, &token, category_id, &title, "PUBLISHED").await;
+    send_queued(&container);
+    let sent = smtp.messages_to(&address);
+    assert_eq!(sent.len(), 2);
+    let headers = &sent[1].data;
+    assert!(headers.contains(&format!("Subject: {}", title)));
+    assert!(headers.contains("List-Unsubscribe: <"));
+    assert!(headers.contains("List-Unsubscribe-Post: List-Unsubscribe=One-Click"));
+    let body = sent[1].body();
+    assert!(body.contains(&format!("/blog/{}", post.slug)));
+    assert!(body.contains("Fresh &lt;b&gt;news&lt;/b&gt;"));
+
+    // Editing a live post does not announce it again
+    let req = test::TestRequest::put()
+        .uri(&format!("/app/blogs/{}", post.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&UpdateBlogRequestDto {
+            title: title.clone(),
+            slug: None,
+            content: "Edited".to_string(),
+            category_id,
+            tag_ids: None,
+            excerpt: "Excerpt".to_string(),
+            thumbnail: None,
+            status: "PUBLISHED".to_string(),
+            published_at: None,
+        })
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+    send_queued(&container);
+    assert_eq!(smtp.messages_to(&address).len(), 2);
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_newsletter_unsubscribe_link() {
+    let (smtp, container) = newsletter_container();
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+    let stamp = Utc::now().timestamp_micros();
+    let category_id = create_category(&app, &token, stamp).await;
+    let address = format!("reader-{}@example.com", stamp);
+    let confirm_path = confirmed_subscriber(&app, &container, &smtp, &address).await;
+
+    create_post(
+        &app,
+        &token,
+        category_id,
+        &format!("Newsletter Post {}", stamp),
+        "PUBLISHED",
+    )
+    .await;
+    send_queued(&container);
+    let sent = smtp.messages_to(&address);
+    assert_eq!(sent.len(), 2);
+    let unsubscribe_path = link_path(&sent[1].body(), "/api/public/newsletter/unsubscribe/");
+
+    // Opening the link changes nothing; the page's button POSTs
+    for _ in 0..2 {
+        let req = test::TestRequest::get()
+            .uri(&unsubscribe_path)
+            .insert_header((header::ACCEPT, "text/html,*/*;q=0.8"))
+            .to_request();
+        let page = String::from_utf8(test::call_and_read_body(&app, req).await.to_vec()).unwrap();
+        assert!(page.contains(&address));
+        assert!(page.contains("<form method=\"post\">"));
+    }
+    let req = test::TestRequest::get().uri(&unsubscribe_path).to_request();
+    let resp: SuccessResponse<SubscriptionStatusDto> =
+        test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().status, "CONFIRMED");
+
+    // One-click unsubscribe, then the plain link again
+    let req = test::TestRequest::post()
+        .uri(&unsubscribe_path)
+        .to_request();
+    let resp: SuccessResponse<SubscriptionStatusDto> =
+        test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().status, "UNSUBSCRIBED");
+    let req = test::TestRequest::get().uri(&unsubscribe_path).to_request();
+    let resp: SuccessResponse<SubscriptionStatusDto> =
+        test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().status, "UNSUBSCRIBED");
+
+    create_post(
+        &app,
+        &token,
+        category_id,
+        &format!("Late {}", stamp),
+        "PUBLISHED",
+    )
+    .await;
+    send_queued(&container);
+    assert_eq!(smtp.messages_to(&address).len(), 2);
+
+    // An old confirmation link can't undo the unsubscribe, and tokens are not interchangeable
+    let req = test::TestRequest::get().uri(&confirm_path).to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::UNAUTHORIZED
+    );
+    let swapped = unsubscribe_path.replace("/unsubscribe/", "/confirm/");
+    let req = test::TestRequest::get().uri(&swapped).to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::UNAUTHORIZED
+    );
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/newsletter/unsubscribe/{}", token))
+        .to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::UNAUTHORIZED
+    );
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_newsletter_subscribers_listing() {
+    let (smtp, container) = newsletter_container();
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+    let address = format!("reader-{}@example.com", Utc::now().timestamp_micros());
+    confirmed_subscriber(&app, &container, &smtp, &address).await;
+
+    let req = test::TestRequest::get()
+        .uri("/app/subscribers?status=CONFIRMED&per_page=100")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<PaginatedResponseDto<SubscriberResponseDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    let listed = resp.data.unwrap().items;
+    let subscriber = listed.iter().find(|s| s.email == address).unwrap();
+    assert!(subscriber.confirmed_at.is_some());
+
+    let req = test::TestRequest::get()
+        .uri("/app/subscribers")
+        .to_request();
+    let err = test::try_call_service(&app, req).await.unwrap_err();
+    assert_eq!(err.error_response().status(), StatusCode::UNAUTHORIZED);
+}
@@ -0,0 +1,237 @@
+use crate::app::features::blog::interface::dto::{
+    BlogResponseDto, CategoryResponseDto, CreateBlogRequestDto, CreateCategoryRequestDto,
+};
+use crate::app::features::previews::interface::dto::{
+    CreatePreviewTokenRequestDto, PreviewTokenResponseDto,
+};
+use crate::init_test_app;
+use crate::test::helpers::{create_blog, login_admin, seed_user};
+use crate::utils::di::Container;
+use crate::utils::success_response::SuccessResponse;
+use actix_web::http::StatusCode;
+use actix_web::test;
+use chrono::Utc;
+use serial_test::serial;
+
+async fn create_draft(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+) -> BlogResponseDto {
+    let stamp = Utc::now().timestamp_micros();
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateCategoryRequestDto {
+            name: format!("Preview Cat {}", stamp),
+            parent_id: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(app, req).await;
+    let category_id = resp.data.unwrap().id;
+
+    create_blog(
+        app,
+        token,
+        CreateBlogRequestDto {
+            title: format!("Secret Draft {}", stamp),
+            slug: None,
+            content: "Not live yet".to_string(),
+            category_id,
+            tag_ids: None,
+            excerpt: "Excerpt".to_string(),
+            thumbnail: None,
+            status: "DRAFT".to_string(),
+            published_at: None,
+        },
+    )
+    .await
+}
+
+async fn create_preview(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    blog_id: i32,
+) -> PreviewTokenResponseDto {
+    let req = test::TestRequest::post()
+        .uri(&format!("/app/blogs/{}/previews", blog_id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreatePreviewTokenRequestDto {
+            expires_in_hours: Some(2),
+        })
+        .to_request();
+    let resp = test::call_service(app, req).await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let body: SuccessResponse<PreviewTokenResponseDto> = test::read_body_json(resp).await;
+    body.data.unwrap()
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_preview_link_shows_the_draft() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+    let blog = create_draft(&app, &token).await;
+    let preview = create_preview(&app, &token, blog.id).await;
+    assert!(preview.active);
+    let signed = preview.token.unwrap();
+
+    // Readers cannot see the draft, the preview link can
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/blogs/{}", blog.slug))
+        .to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::NOT_FOUND
+    );
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/preview/{}", signed))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert!(
+        resp.headers()
+            .get("Cache-Control")
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .contains("no-store")
+    );
+    let body: SuccessResponse<BlogResponseDto> = test::read_body_json(resp).await;
+    let previewed = body.data.unwrap();
+    assert_eq!(previewed.id, blog.id);
+    assert_eq!(previewed.status, "DRAFT");
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_preview_and_login_tokens_are_not_interchangeable() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+    let blog = create_draft(&app, &token).await;
+    let signed = create_preview(&app, &token, blog.id).await.token.unwrap();
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/blogs/{}", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", signed)))
+        .to_request();
+    let err = test::try_call_service(&app, req).await.unwrap_err();
+    assert_eq!(err.error_response().status(), StatusCode::UNAUTHORIZED);
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/preview/{}", token))
+        .to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::UNAUTHORIZED
+    );
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/preview/{}x", signed))
+        .to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::UNAUTHORIZED
+    );
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_preview_listing_hides_the_token() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+    let blog = create_draft(&app, &token).await;
+    let preview = create_preview(&app, &token, blog.id).await;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/blogs/{}/previews", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<Vec<PreviewTokenResponseDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    let listed = resp.data.unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].id, preview.id);
+    assert!(listed[0].token.is_none());
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_revoking_a_preview_link() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+    let blog = create_draft(&app, &token).await;
+    let preview = create_preview(&app, &token, blog.id).await;
+    let signed = preview.token.unwrap();
+
+    // Revoking is scoped to the blog and kills the link
+    let req = test::TestRequest::delete()
+        .uri(&format!("/app/blogs/{}/previews/{}", i32::MAX, preview.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::NOT_FOUND
+    );
+    let req = test::TestRequest::delete()
+        .uri(&format!("/app/blogs/{}/previews/{}", blog.id, preview.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<PreviewTokenResponseDto> =
+        test::call_and_read_body_json(&app, req).await;
+    let revoked = resp.data.unwrap();
+    assert!(!revoked.active);
+    assert!(revoked.revoked_at.is_some());
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/preview/{}", signed))
+        .to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::UNAUTHORIZED
+    );
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_create_preview_validation() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+    let blog = create_draft(&app, &token).await;
+
+    let req = test::TestRequest::post()
+        .uri(&format!("/app/blogs/{}/previews", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreatePreviewTokenRequestDto {
+            expires_in_hours: Some(0),
+        })
+        .to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::BAD_REQUEST
+    );
+    let req = test::TestRequest::post()
+        .uri(&format!("/app/blogs/{}/previews", i32::MAX))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(CreatePreviewTokenRequestDto::default())
+        .to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::NOT_FOUND
+    );
+}
@@ -92,6 +92,7 @@ async fn test_create_project() {
     let project_name = format!("Project Create {}", Utc::now().timestamp_micros());
     let create_dto = CreateProjectRequestDto {
         nama_projek: project_name.clone(),
+        slug: None,
         deskripsi: "Desc".to_string(),
         status: "DRAFT".to_string(),
         progress: 0,
@@ -126,6 +127,7 @@ async fn test_get_projects() {
     // Seed project
     let create_dto = CreateProjectRequestDto {
         nama_projek: format!("Project List {}", Utc::now().timestamp_micros()),
+        slug: None,
         deskripsi: "Desc".to_string(),
         status: "ONGOING".to_string(),
         progress: 10,
@@ -165,6 +167,7 @@ async fn test_get_project_by_id() {
     let project_name = format!("Project GetID {}", Utc::now().timestamp_micros());
     let create_dto = CreateProjectRequestDto {
         nama_projek: project_name.clone(),
+        slug: None,
         deskripsi: "Desc".to_string(),
         status: "ONGOING".to_string(),
         progress: 0,
@@ -202,6 +205,7 @@ async fn test_update_project() {
 
     let create_dto = CreateProjectRequestDto {
         nama_projek: format!("Project Update {}", Utc::now().timestamp_micros()),
+        slug: None,
         deskripsi: "Desc".to_string(),
         status: "DRAFT".to_string(),
         progress: 0,
@@ -222,6 +226,7 @@ async fn test_update_project() {
     let updated_name = format!("Updated Name {}", Utc::now().timestamp_micros());
     let update_dto = UpdateProjectRequestDto {
         nama_projek: updated_name.clone(),
+        slug: None,
         deskripsi: "Desc".to_string(),
         status: "COMPLETED".to_string(),
         progress: Some(100),
@@ -255,6 +260,7 @@ async fn test_delete_project() {
 
     let create_dto = CreateProjectRequestDto {
         nama_projek: format!("Project Delete {}", Utc::now().timestamp_micros()),
+        slug: None,
         deskripsi: "Desc".to_string(),
         status: "DRAFT".to_string(),
         progress: 0,
@@ -325,6 +331,7 @@ async fn test_project_stack_flow() {
     let project_name = format!("Flow Project {}", Utc::now().timestamp_micros());
     let create_dto = CreateProjectRequestDto {
         nama_projek: project_name.clone(),
+        slug: None,
         deskripsi: "Flow Desc".to_string(),
         status: "ONGOING".to_string(),
         progress: 50,
@@ -350,6 +357,7 @@ async fn test_project_stack_flow() {
     // Remove stack2, keep stack1
     let update_dto = UpdateProjectRequestDto {
         nama_projek: project_name.clone(), // Keep original name
+        slug: None,
         deskripsi: "Flow Desc".to_string(),
         status: "COMPLETED".to_string(),
         progress: Some(100),
@@ -488,6 +496,7 @@ async fn test_create_duplicate_project() {
     let project_name = format!("Unique Proj {}", Utc::now().timestamp_micros());
     let create_dto = CreateProjectRequestDto {
         nama_projek: project_name.clone(),
+        slug: None,
         deskripsi: "Desc".to_string(),
         status: "DRAFT".to_string(),
         progress: 0,
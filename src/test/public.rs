@@ -0,0 +1,354 @@
+use crate::app::features::blog::interface::dto::{
+    BlogResponseDto, CategoryResponseDto, CreateBlogRequestDto, CreateCategoryRequestDto,
+    PaginatedResponseDto,
+};
+use crate::app::features::portofolio::interface::dto::{
+    CreatePortofolioRequestDto, PortofolioResponseDto,
+};
+use crate::app::features::projects::interface::dto::{CreateProjectRequestDto, ProjectResponseDto};
+use crate::init_test_app;
+use crate::test::helpers::{login_admin, seed_user};
+use crate::utils::di::Container;
+use crate::utils::success_response::SuccessResponse;
+use actix_web::test;
+use chrono::Utc;
+use serial_test::serial;
+
+async fn create_blog(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    status: &str,
+) -> BlogResponseDto {
+    let category_dto = CreateCategoryRequestDto {
+        name: format!("Public Cat {}", Utc::now().timestamp_micros()),
+        parent_id: None,
+    };
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&category_dto)
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(app, req).await;
+    let cat_id = resp.data.unwrap().id;
+
+    let create_dto = CreateBlogRequestDto {
+        title: format!("Public Blog {}", Utc::now().timestamp_micros()),
+        slug: None,
+        content: "Content".to_string(),
+        category_id: cat_id,
+        tag_ids: None,
+        excerpt: "Excerpt".to_string(),
+        thumbnail: None,
+        status: status.to_string(),
+        published_at: None,
+    };
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&create_dto)
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap()
+}
+
+async fn create_project(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    status: &str,
+) -> ProjectResponseDto {
+    let create_project_dto = CreateProjectRequestDto {
+        nama_projek: format!("Public Project {}", Utc::now().timestamp_micros()),
+        slug: None,
+        deskripsi: "Desc".to_string(),
+        status: status.to_string(),
+        progress: 0,
+        link_demo: None,
+        repository: None,
+        tanggal_mulai: "2023-01-01".to_string(),
+        tanggal_selesai: None,
+        stack_ids: None,
+    };
+    let req = test::TestRequest::post()
+        .uri("/app/projects")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&create_project_dto)
+        .to_request();
+    let resp: SuccessResponse<ProjectResponseDto> = test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap()
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_get_public_blog_published() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let blog = create_blog(&app, &token, "PUBLISHED").await;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/blogs/{}", blog.slug))
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let data = resp.data.unwrap();
+    assert_eq!(data.id, blog.id);
+    assert_eq!(data.status, "PUBLISHED");
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_get_public_blog_draft_not_found() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let blog = create_blog(&app, &token, "DRAFT").await;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/blogs/{}", blog.slug))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_get_public_blogs_only_published() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let published = create_blog(&app, &token, "PUBLISHED").await;
+    let draft = create_blog(&app, &token, "DRAFT").await;
+
+    let req = test::TestRequest::get()
+        .uri("/api/public/blogs?per_page=100")
+        .to_request();
+    let resp: SuccessResponse<PaginatedResponseDto<BlogResponseDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    let items = resp.data.unwrap().items;
+
+    assert!(items.iter().all(|b| b.status == "PUBLISHED"));
+    assert!(items.iter().any(|b| b.id == published.id));
+    assert!(!items.iter().any(|b| b.id == draft.id));
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_get_public_project_draft_not_found() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let draft = create_project(&app, &token, "DRAFT").await;
+    let ongoing = create_project(&app, &token, "ONGOING").await;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/projects/{}", draft.slug))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/projects/{}", ongoing.slug))
+        .to_request();
+    let resp: SuccessResponse<ProjectResponseDto> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().id, ongoing.id);
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_get_public_portofolio_inactive_not_found() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let project = create_project(&app, &token, "ONGOING").await;
+
+    let create_portofolio_dto = CreatePortofolioRequestDto {
+        project_id: project.id,
+        judul: format!(
+            "Public Inactive Portofolio {}",
+            Utc::now().timestamp_micros()
+        ),
+        slug: None,
+        deskripsi: "Portofolio Desc".to_string(),
+        is_active: false,
+    };
+    let req = test::TestRequest::post()
+        .uri("/app/portofolios")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&create_portofolio_dto)
+        .to_request();
+    let resp: SuccessResponse<PortofolioResponseDto> =
+        test::call_and_read_body_json(&app, req).await;
+    let portofolio = resp.data.unwrap();
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/portofolios/{}", portofolio.slug))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_public_lists_reject_out_of_range_pagination() {
+    let container = Container::new();
+    let app = init_test_app!(&container);
+
+    for list in [
+        "/api/public/blogs",
+        "/api/public/projects",
+        "/api/public/portofolios",
+    ] {
+        for query in [
+            "per_page=-1",
+            "per_page=0",
+            "per_page=101",
+            "page=0",
+            "page=-5",
+        ] {
+            let req = test::TestRequest::get()
+                .uri(&format!("{}?{}", list, query))
+                .to_request();
+            let resp = test::call_service(&app, req).await;
+            assert_eq!(
+                resp.status(),
+                actix_web::http::StatusCode::BAD_REQUEST,
+                "{}?{}",
+                list,
+                query
+            );
+        }
+
+        // A page far past the end is just empty
+        let req = test::TestRequest::get()
+            .uri(&format!("{}?page={}&per_page=100", list, i64::MAX))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::OK, "{}", list);
+    }
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_get_public_profile_without_token() {
+    let container = Container::new();
+    let app = init_test_app!(&container);
+
+    let req = test::TestRequest::get()
+        .uri("/api/public/profile")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert!(resp.status().is_success());
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_scheduled_blog_hidden_until_published() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let category = create_blog(&app, &token, "DRAFT").await.category;
+    let publish_at = Utc::now() + chrono::Duration::seconds(1);
+    let create_dto = CreateBlogRequestDto {
+        title: format!("Scheduled Blog {}", Utc::now().timestamp_micros()),
+        slug: None,
+        content: "Content".to_string(),
+        category_id: category.id,
+        tag_ids: None,
+        excerpt: "Excerpt".to_string(),
+        thumbnail: None,
+        status: "SCHEDULED".to_string(),
+        published_at: Some(publish_at.to_rfc3339()),
+    };
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&create_dto)
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let blog = resp.data.unwrap();
+    assert_eq!(blog.status, "SCHEDULED");
+    assert!(blog.published_at.is_some());
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/blogs/{}", blog.slug))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+
+    // Nothing is due yet
+    let published = container
+        .publish_scheduled_blogs_usecase
+        .execute()
+        .await
+        .unwrap();
+    assert!(!published.iter().any(|b| b.id == blog.id));
+
+    actix_web::rt::time::sleep(std::time::Duration::from_millis(1200)).await;
+
+    let published = container
+        .publish_scheduled_blogs_usecase
+        .execute()
+        .await
+        .unwrap();
+    assert!(published.iter().any(|b| b.id == blog.id));
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/blogs/{}", blog.slug))
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().status, "PUBLISHED");
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_scheduled_blog_requires_future_time() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let category = create_blog(&app, &token, "DRAFT").await.category;
+    for published_at in [
+        None,
+        Some("not a date".to_string()),
+        Some("2020-01-01 00:00:00".to_string()),
+    ] {
+        let create_dto = CreateBlogRequestDto {
+            title: format!("Bad Schedule {}", Utc::now().timestamp_micros()),
+            slug: None,
+            content: "Content".to_string(),
+            category_id: category.id,
+            tag_ids: None,
+            excerpt: "Excerpt".to_string(),
+            thumbnail: None,
+            status: "SCHEDULED".to_string(),
+            published_at,
+        };
+        let req = test::TestRequest::post()
+            .uri("/app/blogs")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(&create_dto)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+    }
+}
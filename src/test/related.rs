@@ -0,0 +1,177 @@
+use crate::app::features::blog::interface::dto::{
+    BlogResponseDto, CategoryResponseDto, CreateBlogRequestDto, CreateCategoryRequestDto,
+    CreateTagRequestDto, RelatedBlogResponseDto, TagResponseDto,
+};
+use crate::init_test_app;
+use crate::test::helpers::{login_admin, seed_user};
+use crate::utils::di::Container;
+use crate::utils::success_response::SuccessResponse;
+use actix_web::http::StatusCode;
+use actix_web::test;
+use chrono::Utc;
+use serial_test::serial;
+
+async fn create_category(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+) -> i32 {
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateCategoryRequestDto {
+            name: format!("Related Cat {}", Utc::now().timestamp_micros()),
+            parent_id: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap().id
+}
+
+async fn create_tag(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+) -> i32 {
+    let req = test::TestRequest::post()
+        .uri("/app/tags")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateTagRequestDto {
+            name: format!("Related Tag {}", Utc::now().timestamp_micros()),
+        })
+        .to_request();
+    let resp: SuccessResponse<TagResponseDto> = test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap().id
+}
+
+async fn create_blog(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    category_id: i32,
+    tag_ids: Vec<i32>,
+    status: &str,
+) -> BlogResponseDto {
+    let create_dto = CreateBlogRequestDto {
+        title: format!("Related Blog {}", Utc::now().timestamp_micros()),
+        slug: None,
+        content: "Content".to_string(),
+        category_id,
+        tag_ids: Some(tag_ids),
+        excerpt: "Excerpt".to_string(),
+        thumbnail: None,
+        status: status.to_string(),
+        published_at: None,
+    };
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&create_dto)
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap()
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_related_blogs_ranked_by_tags_category_and_recency() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let cat_a = create_category(&app, &token).await;
+    let cat_b = create_category(&app, &token).await;
+    let tag_1 = create_tag(&app, &token).await;
+    let tag_2 = create_tag(&app, &token).await;
+
+    let source = create_blog(&app, &token, cat_a, vec![tag_1, tag_2], "PUBLISHED").await;
+    let both_tags = create_blog(&app, &token, cat_b, vec![tag_1, tag_2], "PUBLISHED").await;
+    let one_tag_same_cat = create_blog(&app, &token, cat_a, vec![tag_1], "PUBLISHED").await;
+    let same_cat = create_blog(&app, &token, cat_a, vec![], "PUBLISHED").await;
+    let draft = create_blog(&app, &token, cat_a, vec![tag_1, tag_2], "DRAFT").await;
+
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/api/public/blogs/{}/related?limit=3",
+            source.slug
+        ))
+        .to_request();
+    let resp: SuccessResponse<Vec<RelatedBlogResponseDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    let related = resp.data.unwrap();
+
+    let ids: Vec<i32> = related.iter().map(|r| r.id).collect();
+    assert_eq!(ids, vec![both_tags.id, one_tag_same_cat.id, same_cat.id]);
+    assert_eq!(related[0].shared_tags, 2);
+    assert!(!related[0].same_category);
+    assert_eq!(related[1].shared_tags, 1);
+    assert!(related[1].same_category);
+    assert!(related[0].score > related[1].score);
+    assert!(!ids.contains(&source.id));
+    assert!(!ids.contains(&draft.id));
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_admin_related_blogs_for_any_post() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let category_id = create_category(&app, &token).await;
+    let tag_1 = create_tag(&app, &token).await;
+    let tag_2 = create_tag(&app, &token).await;
+    create_blog(&app, &token, category_id, vec![tag_1, tag_2], "PUBLISHED").await;
+    let draft = create_blog(&app, &token, category_id, vec![tag_1, tag_2], "DRAFT").await;
+
+    // Admins can ask for recommendations for any post by id
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/blogs/{}/related?limit=1", draft.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<Vec<RelatedBlogResponseDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    let related = resp.data.unwrap();
+    assert_eq!(related.len(), 1);
+    assert_eq!(related[0].shared_tags, 2);
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_public_related_blogs_rejects_drafts_and_bad_limits() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let category_id = create_category(&app, &token).await;
+    let source = create_blog(&app, &token, category_id, vec![], "PUBLISHED").await;
+    let draft = create_blog(&app, &token, category_id, vec![], "DRAFT").await;
+
+    // Readers only get recommendations for published posts
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/blogs/{}/related", draft.slug))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/api/public/blogs/{}/related?limit=0",
+            source.slug
+        ))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,212 @@
+use crate::app::features::blog::interface::dto::{
+    BlogResponseDto, BlogRevisionDiffResponseDto, BlogRevisionResponseDto, CategoryResponseDto,
+    CreateBlogRequestDto, CreateCategoryRequestDto, PaginatedResponseDto, UpdateBlogRequestDto,
+};
+use crate::init_test_app;
+use crate::test::helpers::{login_admin, seed_user};
+use crate::utils::di::Container;
+use crate::utils::success_response::SuccessResponse;
+use actix_web::test;
+use chrono::Utc;
+use serial_test::serial;
+
+async fn create_blog_with_edits(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    contents: &[&str],
+) -> BlogResponseDto {
+    let category_dto = CreateCategoryRequestDto {
+        name: format!("Revision Cat {}", Utc::now().timestamp_micros()),
+        parent_id: None,
+    };
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&category_dto)
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(app, req).await;
+    let cat_id = resp.data.unwrap().id;
+
+    let title = format!("Revision Blog {}", Utc::now().timestamp_micros());
+    let create_dto = CreateBlogRequestDto {
+        title: title.clone(),
+        slug: None,
+        content: contents[0].to_string(),
+        category_id: cat_id,
+        tag_ids: None,
+        excerpt: "Excerpt".to_string(),
+        thumbnail: None,
+        status: "DRAFT".to_string(),
+        published_at: None,
+    };
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&create_dto)
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(app, req).await;
+    let mut blog = resp.data.unwrap();
+
+    for content in &contents[1..] {
+        let update_dto = UpdateBlogRequestDto {
+            title: title.clone(),
+            slug: None,
+            content: content.to_string(),
+            category_id: cat_id,
+            tag_ids: None,
+            excerpt: "Excerpt".to_string(),
+            thumbnail: None,
+            status: "DRAFT".to_string(),
+            published_at: None,
+        };
+        let req = test::TestRequest::put()
+            .uri(&format!("/app/blogs/{}", blog.id))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(&update_dto)
+            .to_request();
+        let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(app, req).await;
+        blog = resp.data.unwrap();
+    }
+
+    blog
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_blog_revisions_list_get_and_diff() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let blog = create_blog_with_edits(
+        &app,
+        &token,
+        &[
+            "line one\nline two\nline three",
+            "line one\nline 2\nline three",
+            "line one\nline 2\nline three\nline four",
+        ],
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/blogs/{}/revisions", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<PaginatedResponseDto<BlogRevisionResponseDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    let data = resp.data.unwrap();
+    assert_eq!(data.meta.total_items, 3);
+    let numbers: Vec<i32> = data.items.iter().map(|r| r.revision_number).collect();
+    assert_eq!(numbers, vec![3, 2, 1]);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/blogs/{}/revisions/1", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<BlogRevisionResponseDto> =
+        test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().content, "line one\nline two\nline three");
+
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/app/blogs/{}/revisions/diff?from=1&to=3",
+            blog.id
+        ))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<BlogRevisionDiffResponseDto> =
+        test::call_and_read_body_json(&app, req).await;
+    let diff = resp.data.unwrap();
+    assert_eq!(diff.additions, 2);
+    assert_eq!(diff.deletions, 1);
+    assert!(
+        diff.content
+            .iter()
+            .any(|l| l.tag == "delete" && l.value == "line two" && l.old_line == Some(2))
+    );
+    assert!(
+        diff.content
+            .iter()
+            .any(|l| l.tag == "insert" && l.value == "line four" && l.new_line == Some(4))
+    );
+    assert!(diff.title.iter().all(|l| l.tag == "equal"));
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_restore_blog_revision_creates_new_revision() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let blog = create_blog_with_edits(&app, &token, &["original text", "bad edit"]).await;
+
+    let req = test::TestRequest::post()
+        .uri(&format!("/app/blogs/{}/revisions/1/restore", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::CREATED);
+    let body: SuccessResponse<BlogRevisionResponseDto> = test::read_body_json(resp).await;
+    let restored = body.data.unwrap();
+    assert_eq!(restored.revision_number, 3);
+    assert_eq!(restored.content, "original text");
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/blogs/{}", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().content, "original text");
+
+    // The bad edit is still in history
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/blogs/{}/revisions/2", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<BlogRevisionResponseDto> =
+        test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().content, "bad edit");
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_blog_revision_not_found_and_diff_validation() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let blog = create_blog_with_edits(&app, &token, &["only version"]).await;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/blogs/{}/revisions/99", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+
+    let req = test::TestRequest::post()
+        .uri(&format!("/app/blogs/{}/revisions/99/restore", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/app/blogs/{}/revisions/diff?from=0&to=1",
+            blog.id
+        ))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+}
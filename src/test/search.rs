@@ -0,0 +1,241 @@
+use crate::app::features::blog::interface::dto::{
+    BlogResponseDto, BlogSearchResultDto, CategoryResponseDto, CreateBlogRequestDto,
+    CreateCategoryRequestDto, PaginatedResponseDto, UpdateBlogRequestDto,
+};
+use crate::init_test_app;
+use crate::test::helpers::{login_admin, seed_user};
+use crate::utils::di::Container;
+use crate::utils::success_response::SuccessResponse;
+use actix_web::test;
+use chrono::Utc;
+use serial_test::serial;
+
+async fn create_blog(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    title: &str,
+    content: &str,
+    status: &str,
+) -> BlogResponseDto {
+    let category_dto = CreateCategoryRequestDto {
+        name: format!("Search Cat {}", Utc::now().timestamp_micros()),
+        parent_id: None,
+    };
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&category_dto)
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(app, req).await;
+    let cat_id = resp.data.unwrap().id;
+
+    let create_dto = CreateBlogRequestDto {
+        title: title.to_string(),
+        slug: None,
+        content: content.to_string(),
+        category_id: cat_id,
+        tag_ids: None,
+        excerpt: "Excerpt".to_string(),
+        thumbnail: None,
+        status: status.to_string(),
+        published_at: None,
+    };
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&create_dto)
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap()
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_search_blogs_highlights_and_ranks() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let word = format!("zebra{}", Utc::now().timestamp_micros());
+    let in_title = create_blog(
+        &app,
+        &token,
+        &format!("About {} stripes", word),
+        "Nothing here",
+        "PUBLISHED",
+    )
+    .await;
+    let in_content = create_blog(
+        &app,
+        &token,
+        &format!("Plain title {}", Utc::now().timestamp_micros()),
+        &format!("Some <b>text</b> mentioning {} once", word),
+        "PUBLISHED",
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/blogs/search?q={}", word))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<PaginatedResponseDto<BlogSearchResultDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    let data = resp.data.unwrap();
+
+    assert_eq!(data.meta.total_items, 2);
+    assert_eq!(data.items[0].id, in_title.id);
+    assert_eq!(data.items[1].id, in_content.id);
+    assert!(
+        data.items[0]
+            .title_highlight
+            .contains(&format!("<mark>{}</mark>", word))
+    );
+    assert!(data.items[1].snippet.contains("&lt;b&gt;text&lt;/b&gt;"));
+    assert!(
+        data.items[1]
+            .snippet
+            .contains(&format!("<mark>{}</mark>", word))
+    );
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_search_blogs_prefix_and_reindex_on_update() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let stamp = Utc::now().timestamp_micros();
+    let blog = create_blog(
+        &app,
+        &token,
+        &format!("Prefix {}", stamp),
+        &format!("giraffe{} content", stamp),
+        "DRAFT",
+    )
+    .await;
+
+    // Prefix match on the last term
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/app/blogs/search?q=giraffe{}",
+            &stamp.to_string()[..4]
+        ))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<PaginatedResponseDto<BlogSearchResultDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    assert!(resp.data.unwrap().items.iter().any(|b| b.id == blog.id));
+
+    let update_dto = UpdateBlogRequestDto {
+        title: blog.title.clone(),
+        slug: None,
+        content: format!("okapi{} content", stamp),
+        category_id: blog.category.id,
+        tag_ids: None,
+        excerpt: "Excerpt".to_string(),
+        thumbnail: None,
+        status: "DRAFT".to_string(),
+        published_at: None,
+    };
+    let req = test::TestRequest::put()
+        .uri(&format!("/app/blogs/{}", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&update_dto)
+        .to_request();
+    test::call_service(&app, req).await;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/blogs/search?q=giraffe{}", stamp))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<PaginatedResponseDto<BlogSearchResultDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().meta.total_items, 0);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/blogs/search?q=okapi{}", stamp))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<PaginatedResponseDto<BlogSearchResultDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().items[0].id, blog.id);
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_search_public_blogs_only_published() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let word = format!("lemur{}", Utc::now().timestamp_micros());
+    let published = create_blog(&app, &token, &format!("Pub {}", word), "Body", "PUBLISHED").await;
+    create_blog(&app, &token, &format!("Draft {}", word), "Body", "DRAFT").await;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/blogs/search?q={}", word))
+        .to_request();
+    let resp: SuccessResponse<PaginatedResponseDto<BlogSearchResultDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    let data = resp.data.unwrap();
+
+    assert_eq!(data.meta.total_items, 1);
+    assert_eq!(data.items[0].id, published.id);
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_search_blogs_blank_query_validation() {
+    let container = Container::new();
+    let app = init_test_app!(&container);
+
+    let req = test::TestRequest::get()
+        .uri("/api/public/blogs/search?q=%20%20")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+
+    // Quotes and FTS operators are treated as plain words, not syntax
+    let req = test::TestRequest::get()
+        .uri("/api/public/blogs/search?q=%22foo%20AND%20(bar")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert!(resp.status().is_success());
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_search_public_blogs_pagination_validation() {
+    let container = Container::new();
+    let app = init_test_app!(&container);
+
+    for query in ["per_page=-1", "per_page=101", "page=0"] {
+        let req = test::TestRequest::get()
+            .uri(&format!("/api/public/blogs/search?q=rust&{}", query))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(
+            resp.status(),
+            actix_web::http::StatusCode::BAD_REQUEST,
+            "{}",
+            query
+        );
+    }
+
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/api/public/blogs/search?q=rust&page={}",
+            i64::MAX
+        ))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert!(resp.status().is_success());
+}
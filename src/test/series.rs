@@ -0,0 +1,332 @@
+use crate::app::features::blog::interface::dto::{
+    BlogResponseDto, CategoryResponseDto, CreateBlogRequestDto, CreateCategoryRequestDto,
+};
+use crate::app::features::series::interface::dto::{
+    CreateSeriesRequestDto, PaginatedResponseDto, SeriesResponseDto, UpdateSeriesRequestDto,
+};
+use crate::init_test_app;
+use crate::test::helpers::{login_admin, seed_user};
+use crate::utils::di::Container;
+use crate::utils::error_response::ErrorResponse;
+use crate::utils::success_response::SuccessResponse;
+use actix_web::http::StatusCode;
+use actix_web::test;
+use chrono::Utc;
+use serial_test::serial;
+
+async fn create_blog(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    category_id: i32,
+    title: &str,
+    status: &str,
+) -> BlogResponseDto {
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateBlogRequestDto {
+            title: title.to_string(),
+            slug: None,
+            content: "Content".to_string(),
+            category_id,
+            tag_ids: None,
+            excerpt: "Excerpt".to_string(),
+            thumbnail: None,
+            status: status.to_string(),
+            published_at: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap()
+}
+
+async fn get_blog(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    id: i32,
+) -> BlogResponseDto {
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/blogs/{}", id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap()
+}
+
+/// Three posts, the middle one a draft, gathered into one series.
+struct SeriesFixture {
+    stamp: i64,
+    first: BlogResponseDto,
+    draft: BlogResponseDto,
+    third: BlogResponseDto,
+    series: SeriesResponseDto,
+}
+
+async fn create_series_fixture(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+) -> SeriesFixture {
+    let stamp = Utc::now().timestamp_micros();
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateCategoryRequestDto {
+            name: format!("Series Cat {}", stamp),
+            parent_id: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(app, req).await;
+    let category_id = resp.data.unwrap().id;
+
+    let first = create_blog(app, token, category_id, "Rust Part One", "PUBLISHED").await;
+    let draft = create_blog(app, token, category_id, "Rust Part Two", "DRAFT").await;
+    let third = create_blog(app, token, category_id, "Rust Part Three", "PUBLISHED").await;
+    assert!(first.series.is_none());
+
+    let req = test::TestRequest::post()
+        .uri("/app/series")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateSeriesRequestDto {
+            title: format!("Learning Rust {}", stamp),
+            slug: None,
+            description: Some("From zero".to_string()),
+            blog_ids: vec![first.id, draft.id, third.id],
+        })
+        .to_request();
+    let resp = test::call_service(app, req).await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let body: SuccessResponse<SeriesResponseDto> = test::read_body_json(resp).await;
+    SeriesFixture {
+        stamp,
+        first,
+        draft,
+        third,
+        series: body.data.unwrap(),
+    }
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_create_series_numbers_the_parts() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+    let SeriesFixture {
+        stamp,
+        first,
+        draft,
+        third,
+        series,
+    } = create_series_fixture(&app, &token).await;
+
+    assert_eq!(series.slug, format!("learning-rust-{}", stamp));
+    let parts = series
+        .posts
+        .iter()
+        .map(|p| (p.part, p.id))
+        .collect::<Vec<_>>();
+    assert_eq!(parts, vec![(1, first.id), (2, draft.id), (3, third.id)]);
+
+    let req = test::TestRequest::get()
+        .uri("/app/series?per_page=100")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<PaginatedResponseDto<SeriesResponseDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().items[0].id, series.id);
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_blog_series_block() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+    let SeriesFixture {
+        first,
+        draft,
+        third,
+        series,
+        ..
+    } = create_series_fixture(&app, &token).await;
+
+    // Admins see every part
+    let block = get_blog(&app, &token, draft.id).await.series.unwrap();
+    assert_eq!(block.id, series.id);
+    assert_eq!((block.part, block.total_parts), (2, 3));
+    assert_eq!(block.previous.unwrap().id, first.id);
+    assert_eq!(block.next.unwrap().id, third.id);
+
+    let block = get_blog(&app, &token, first.id).await.series.unwrap();
+    assert!(block.previous.is_none());
+    assert_eq!(block.next.unwrap().slug, draft.slug);
+
+    // Readers skip the draft
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/blogs/{}", third.slug))
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let block = resp.data.unwrap().series.unwrap();
+    assert_eq!((block.part, block.total_parts), (2, 2));
+    assert_eq!(block.previous.unwrap().id, first.id);
+    assert!(block.next.is_none());
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public/series/{}", series.slug))
+        .to_request();
+    let resp: SuccessResponse<SeriesResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let public = resp.data.unwrap();
+    assert_eq!(
+        public.posts.iter().map(|p| p.id).collect::<Vec<_>>(),
+        vec![first.id, third.id]
+    );
+    assert_eq!(public.posts[1].part, 2);
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_create_series_validation() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+    let SeriesFixture {
+        stamp,
+        third,
+        series,
+        ..
+    } = create_series_fixture(&app, &token).await;
+
+    // A post belongs to one series only
+    let req = test::TestRequest::post()
+        .uri("/app/series")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateSeriesRequestDto {
+            title: format!("Other {}", stamp),
+            slug: None,
+            description: None,
+            blog_ids: vec![third.id],
+        })
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body: ErrorResponse = test::read_body_json(resp).await;
+    assert_eq!(
+        body.errors.unwrap().get("blog_ids").unwrap(),
+        &format!(
+            "Blog {} is already part of series \"{}\"",
+            third.id, series.title
+        )
+    );
+
+    let req = test::TestRequest::post()
+        .uri("/app/series")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateSeriesRequestDto {
+            title: String::new(),
+            slug: None,
+            description: None,
+            blog_ids: vec![i32::MAX],
+        })
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body: ErrorResponse = test::read_body_json(resp).await;
+    let errors = body.errors.unwrap();
+    assert!(errors.contains_key("title"));
+    assert_eq!(
+        errors.get("blog_ids").unwrap(),
+        &format!("Blog {} not found", i32::MAX)
+    );
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_update_series_reorders_and_drops_parts() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+    let SeriesFixture {
+        first,
+        draft,
+        third,
+        series,
+        ..
+    } = create_series_fixture(&app, &token).await;
+
+    // The slug stays with the title
+    let req = test::TestRequest::put()
+        .uri(&format!("/app/series/{}", series.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&UpdateSeriesRequestDto {
+            title: series.title.clone(),
+            slug: None,
+            description: None,
+            blog_ids: vec![third.id, first.id],
+        })
+        .to_request();
+    let resp: SuccessResponse<SeriesResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let updated = resp.data.unwrap();
+    assert_eq!(updated.slug, series.slug);
+    assert_eq!(
+        updated.posts.iter().map(|p| p.id).collect::<Vec<_>>(),
+        vec![third.id, first.id]
+    );
+    assert!(get_blog(&app, &token, draft.id).await.series.is_none());
+    let block = get_blog(&app, &token, first.id).await.series.unwrap();
+    assert_eq!((block.part, block.total_parts), (2, 2));
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/app/series/{}", series.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&UpdateSeriesRequestDto {
+            title: series.title.clone(),
+            slug: None,
+            description: None,
+            blog_ids: vec![first.id, first.id],
+        })
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_delete_series_keeps_its_posts() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+    let SeriesFixture { first, series, .. } = create_series_fixture(&app, &token).await;
+
+    let req = test::TestRequest::delete()
+        .uri(&format!("/app/series/{}", series.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+    assert!(get_blog(&app, &token, first.id).await.series.is_none());
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/series/{}", series.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::NOT_FOUND
+    );
+}
@@ -0,0 +1,176 @@
+use crate::app::features::blog::interface::dto::{
+    BlogResponseDto, CategoryResponseDto, CreateBlogRequestDto, CreateCategoryRequestDto,
+};
+use crate::app::features::portofolio::interface::dto::{
+    CreatePortofolioRequestDto, PortofolioResponseDto,
+};
+use crate::app::features::projects::interface::dto::{CreateProjectRequestDto, ProjectResponseDto};
+use crate::app::features::sitemap::application::usecase as sitemap_usecase;
+use crate::app::features::sitemap::infrastructure::repository_impl::SitemapRepositoryImpl;
+use crate::init_test_app;
+use crate::test::helpers::{login_admin, seed_user};
+use crate::utils::db::establish_connection;
+use crate::utils::di::Container;
+use crate::utils::success_response::SuccessResponse;
+use actix_web::http::{StatusCode, header};
+use actix_web::test;
+use chrono::Utc;
+use serial_test::serial;
+use std::sync::Arc;
+
+async fn create_blog(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    status: &str,
+) -> BlogResponseDto {
+    let category_dto = CreateCategoryRequestDto {
+        name: format!("Sitemap Cat {}", Utc::now().timestamp_micros()),
+        parent_id: None,
+    };
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&category_dto)
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(app, req).await;
+    let cat_id = resp.data.unwrap().id;
+
+    let create_dto = CreateBlogRequestDto {
+        title: format!("Sitemap Blog {}", Utc::now().timestamp_micros()),
+        slug: None,
+        content: "Content".to_string(),
+        category_id: cat_id,
+        tag_ids: None,
+        excerpt: "Excerpt".to_string(),
+        thumbnail: None,
+        status: status.to_string(),
+        published_at: None,
+    };
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&create_dto)
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap()
+}
+
+async fn get_text(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    uri: &str,
+) -> (StatusCode, String, String) {
+    let req = test::TestRequest::get().uri(uri).to_request();
+    let resp = test::call_service(app, req).await;
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .map(|v| v.to_str().unwrap().to_string())
+        .unwrap_or_default();
+    let body = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+    (status, content_type, body)
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_sitemap_lists_public_content() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let published = create_blog(&app, &token, "PUBLISHED").await;
+    let draft = create_blog(&app, &token, "DRAFT").await;
+
+    let create_project_dto = CreateProjectRequestDto {
+        nama_projek: format!("Sitemap Project {}", Utc::now().timestamp_micros()),
+        slug: None,
+        deskripsi: "Desc".to_string(),
+        status: "ONGOING".to_string(),
+        progress: 0,
+        link_demo: None,
+        repository: None,
+        tanggal_mulai: "2023-01-01".to_string(),
+        tanggal_selesai: None,
+        stack_ids: None,
+    };
+    let req = test::TestRequest::post()
+        .uri("/app/projects")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&create_project_dto)
+        .to_request();
+    let resp: SuccessResponse<ProjectResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let project = resp.data.unwrap();
+
+    let create_portofolio_dto = CreatePortofolioRequestDto {
+        project_id: project.id,
+        judul: format!("Sitemap Portofolio {}", Utc::now().timestamp_micros()),
+        slug: None,
+        deskripsi: "Portofolio Desc".to_string(),
+        is_active: true,
+    };
+    let req = test::TestRequest::post()
+        .uri("/app/portofolios")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&create_portofolio_dto)
+        .to_request();
+    let resp: SuccessResponse<PortofolioResponseDto> =
+        test::call_and_read_body_json(&app, req).await;
+    let portofolio = resp.data.unwrap();
+
+    let (status, content_type, body) = get_text(&app, "/sitemap.xml").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/xml; charset=utf-8");
+    assert!(body.contains("<urlset"));
+    assert!(body.contains(&format!("/blog/{}</loc>", published.slug)));
+    assert!(body.contains(&format!("/projects/{}</loc>", project.slug)));
+    assert!(body.contains(&format!("/portofolios/{}</loc>", portofolio.slug)));
+    assert!(!body.contains(&format!("/blog/{}</loc>", draft.slug)));
+    assert!(body.contains("<lastmod>"));
+
+    let (status, content_type, body) = get_text(&app, "/robots.txt").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "text/plain; charset=utf-8");
+    assert!(body.contains("User-agent: *"));
+    assert!(body.contains("Disallow: /app/"));
+    assert!(body.contains("Sitemap: http://localhost:8080/sitemap.xml"));
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_sitemap_splits_into_index() {
+    let mut container = Container::new();
+    seed_user(&container);
+    let pool = establish_connection(&container.config.database_url);
+    container.get_sitemap_usecase =
+        sitemap_usecase::get::Execute::new(Arc::new(SitemapRepositoryImpl::new(pool)), 1);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    create_blog(&app, &token, "PUBLISHED").await;
+    create_blog(&app, &token, "PUBLISHED").await;
+
+    let (status, _, body) = get_text(&app, "/sitemap.xml").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.contains("<sitemapindex"));
+    assert!(body.contains("/sitemap-1.xml</loc>"));
+    assert!(body.contains("/sitemap-2.xml</loc>"));
+
+    let (status, _, body) = get_text(&app, "/sitemap-2.xml").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.contains("<urlset"));
+    assert_eq!(body.matches("<url>").count(), 1);
+
+    let (status, _, _) = get_text(&app, "/sitemap-0.xml").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _, _) = get_text(&app, "/sitemap-999999.xml").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
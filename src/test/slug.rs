@@ -0,0 +1,202 @@
+use crate::app::features::blog::interface::dto::{
+    BlogResponseDto, CategoryResponseDto, CreateBlogRequestDto, CreateCategoryRequestDto,
+};
+use crate::app::features::portofolio::interface::dto::{
+    CreatePortofolioRequestDto, PortofolioResponseDto, UpdatePortofolioRequestDto,
+};
+use crate::app::features::projects::interface::dto::{CreateProjectRequestDto, ProjectResponseDto};
+use crate::init_test_app;
+use crate::test::helpers::{login_admin, seed_user};
+use crate::utils::di::Container;
+use crate::utils::error_response::ErrorResponse;
+use crate::utils::slug::MAX_SLUG_LENGTH;
+use crate::utils::success_response::SuccessResponse;
+use actix_web::http::StatusCode;
+use actix_web::test;
+use chrono::Utc;
+use serial_test::serial;
+
+async fn create_blog(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    category_id: i32,
+    title: &str,
+    slug: Option<&str>,
+) -> actix_web::dev::ServiceResponse {
+    let create_dto = CreateBlogRequestDto {
+        title: title.to_string(),
+        slug: slug.map(|s| s.to_string()),
+        content: "Content".to_string(),
+        category_id,
+        tag_ids: None,
+        excerpt: "Excerpt".to_string(),
+        thumbnail: None,
+        status: "DRAFT".to_string(),
+        published_at: None,
+    };
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&create_dto)
+        .to_request();
+    test::call_service(app, req).await
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_blog_slugs_are_transliterated_and_numbered() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateCategoryRequestDto {
+            name: format!("Slug Service Cat {}", Utc::now().timestamp_micros()),
+            parent_id: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let category_id = resp.data.unwrap().id;
+
+    let stamp = Utc::now().timestamp_micros();
+    let title = format!("Café  Déjà -- Vu!! {}", stamp);
+    let expected = format!("cafe-deja-vu-{}", stamp);
+
+    let resp = create_blog(&app, &token, category_id, &title, None).await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let first: SuccessResponse<BlogResponseDto> = test::read_body_json(resp).await;
+    assert_eq!(first.data.unwrap().slug, expected);
+
+    // Same title again gets a numbered slug instead of an error
+    let resp = create_blog(&app, &token, category_id, &title, None).await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let second: SuccessResponse<BlogResponseDto> = test::read_body_json(resp).await;
+    assert_eq!(second.data.unwrap().slug, format!("{}-2", expected));
+
+    let resp = create_blog(&app, &token, category_id, &title, None).await;
+    let third: SuccessResponse<BlogResponseDto> = test::read_body_json(resp).await;
+    assert_eq!(third.data.unwrap().slug, format!("{}-3", expected));
+
+    // Punctuation-only titles still get a usable slug
+    let resp = create_blog(&app, &token, category_id, "?!…", None).await;
+    let blog: SuccessResponse<BlogResponseDto> = test::read_body_json(resp).await;
+    assert!(blog.data.unwrap().slug.starts_with("post"));
+
+    // Long titles are capped, with room left for the suffix
+    let long_title = format!("{} {}", "word ".repeat(40), stamp);
+    let resp = create_blog(&app, &token, category_id, &long_title, None).await;
+    let blog: SuccessResponse<BlogResponseDto> = test::read_body_json(resp).await;
+    let long_slug = blog.data.unwrap().slug;
+    assert!(long_slug.len() <= MAX_SLUG_LENGTH);
+    assert!(!long_slug.ends_with('-'));
+    let resp = create_blog(&app, &token, category_id, &long_title, None).await;
+    let blog: SuccessResponse<BlogResponseDto> = test::read_body_json(resp).await;
+    let numbered = blog.data.unwrap().slug;
+    assert!(numbered.len() <= MAX_SLUG_LENGTH);
+    assert!(numbered.ends_with("-2"));
+
+    // Explicit slugs are normalized but never renumbered
+    let custom = format!("My Custom Slug {}", stamp);
+    let resp = create_blog(&app, &token, category_id, "Whatever", Some(&custom)).await;
+    let blog: SuccessResponse<BlogResponseDto> = test::read_body_json(resp).await;
+    assert_eq!(blog.data.unwrap().slug, format!("my-custom-slug-{}", stamp));
+
+    let resp = create_blog(&app, &token, category_id, "Whatever", Some(&custom)).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body: ErrorResponse = test::read_body_json(resp).await;
+    assert_eq!(
+        body.errors.unwrap().get("slug").unwrap(),
+        "Slug already exists"
+    );
+
+    let resp = create_blog(&app, &token, category_id, "Whatever", Some("---")).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_project_and_portofolio_slug_overrides() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let stamp = Utc::now().timestamp_micros();
+    let req = test::TestRequest::post()
+        .uri("/app/projects")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateProjectRequestDto {
+            nama_projek: format!("Proyek Ñandú {}", stamp),
+            slug: None,
+            deskripsi: "Desc".to_string(),
+            status: "ONGOING".to_string(),
+            progress: 0,
+            link_demo: None,
+            repository: None,
+            tanggal_mulai: "2023-01-01".to_string(),
+            tanggal_selesai: None,
+            stack_ids: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<ProjectResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let project = resp.data.unwrap();
+    assert_eq!(project.slug, format!("proyek-nandu-{}", stamp));
+
+    let req = test::TestRequest::post()
+        .uri("/app/portofolios")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreatePortofolioRequestDto {
+            project_id: project.id,
+            judul: format!("Portofolio {}", stamp),
+            slug: Some(format!("showcase-{}", stamp)),
+            deskripsi: "Desc".to_string(),
+            is_active: true,
+        })
+        .to_request();
+    let resp: SuccessResponse<PortofolioResponseDto> =
+        test::call_and_read_body_json(&app, req).await;
+    let portofolio = resp.data.unwrap();
+    assert_eq!(portofolio.slug, format!("showcase-{}", stamp));
+
+    // Editing other fields keeps a custom slug
+    let req = test::TestRequest::put()
+        .uri(&format!("/app/portofolios/{}", portofolio.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&UpdatePortofolioRequestDto {
+            project_id: project.id,
+            judul: portofolio.judul.clone(),
+            slug: None,
+            deskripsi: "New desc".to_string(),
+            is_active: true,
+        })
+        .to_request();
+    let resp: SuccessResponse<PortofolioResponseDto> =
+        test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().slug, portofolio.slug);
+
+    // ...while a new title moves it
+    let req = test::TestRequest::put()
+        .uri(&format!("/app/portofolios/{}", portofolio.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&UpdatePortofolioRequestDto {
+            project_id: project.id,
+            judul: format!("Portofolio Baru {}", stamp),
+            slug: None,
+            deskripsi: "New desc".to_string(),
+            is_active: true,
+        })
+        .to_request();
+    let resp: SuccessResponse<PortofolioResponseDto> =
+        test::call_and_read_body_json(&app, req).await;
+    assert_eq!(
+        resp.data.unwrap().slug,
+        format!("portofolio-baru-{}", stamp)
+    );
+}
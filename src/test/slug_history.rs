@@ -0,0 +1,459 @@
+use crate::app::features::blog::interface::dto::{
+    BlogResponseDto, CategoryResponseDto, CreateBlogRequestDto, CreateCategoryRequestDto,
+    UpdateBlogRequestDto,
+};
+use crate::app::features::portofolio::interface::dto::{
+    CreatePortofolioRequestDto, PortofolioResponseDto, UpdatePortofolioRequestDto,
+};
+use crate::app::features::projects::interface::dto::{
+    CreateProjectRequestDto, ProjectResponseDto, UpdateProjectRequestDto,
+};
+use crate::app::features::slug_history::interface::dto::{
+    PaginatedResponseDto, PruneSlugHistoryRequestDto, PruneSlugHistoryResponseDto,
+    SlugHistoryResponseDto,
+};
+use crate::init_test_app;
+use crate::test::helpers::{login_admin, seed_user};
+use crate::utils::di::Container;
+use crate::utils::success_response::SuccessResponse;
+use actix_web::http::{StatusCode, header};
+use actix_web::test;
+use chrono::Utc;
+use serial_test::serial;
+
+async fn get_location(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    uri: &str,
+) -> (StatusCode, Option<String>) {
+    let req = test::TestRequest::get().uri(uri).to_request();
+    let resp = test::call_service(app, req).await;
+    let location = resp
+        .headers()
+        .get(header::LOCATION)
+        .map(|v| v.to_str().unwrap().to_string());
+    (resp.status(), location)
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_renamed_slugs_redirect_to_current() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    // Blog
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateCategoryRequestDto {
+            name: format!("Slug Cat {}", Utc::now().timestamp_micros()),
+            parent_id: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let category_id = resp.data.unwrap().id;
+
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateBlogRequestDto {
+            title: format!("Slug Blog {}", Utc::now().timestamp_micros()),
+            slug: None,
+            content: "Content".to_string(),
+            category_id,
+            tag_ids: None,
+            excerpt: "Excerpt".to_string(),
+            thumbnail: None,
+            status: "PUBLISHED".to_string(),
+            published_at: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let blog = resp.data.unwrap();
+
+    let mut renamed_blog = None;
+    for suffix in ["Renamed", "Renamed Again"] {
+        let req = test::TestRequest::put()
+            .uri(&format!("/app/blogs/{}", blog.id))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(&UpdateBlogRequestDto {
+                title: format!("{} {}", blog.title, suffix),
+                slug: None,
+                content: "Content".to_string(),
+                category_id,
+                tag_ids: None,
+                excerpt: "Excerpt".to_string(),
+                thumbnail: None,
+                status: "PUBLISHED".to_string(),
+                published_at: None,
+            })
+            .to_request();
+        let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+        renamed_blog = resp.data;
+    }
+    let renamed_blog = renamed_blog.unwrap();
+    assert_ne!(renamed_blog.slug, blog.slug);
+
+    // Both retired slugs point straight at the newest one
+    let (status, location) = get_location(&app, &format!("/api/public/blogs/{}", blog.slug)).await;
+    assert_eq!(status, StatusCode::MOVED_PERMANENTLY);
+    assert_eq!(
+        location.unwrap(),
+        format!("/api/public/blogs/{}", renamed_blog.slug)
+    );
+    let (status, _) = get_location(&app, &format!("/api/public/blogs/{}", renamed_blog.slug)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Project
+    let req = test::TestRequest::post()
+        .uri("/app/projects")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateProjectRequestDto {
+            nama_projek: format!("Slug Project {}", Utc::now().timestamp_micros()),
+            slug: None,
+            deskripsi: "Desc".to_string(),
+            status: "ONGOING".to_string(),
+            progress: 0,
+            link_demo: None,
+            repository: None,
+            tanggal_mulai: "2023-01-01".to_string(),
+            tanggal_selesai: None,
+            stack_ids: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<ProjectResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let project = resp.data.unwrap();
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/app/projects/{}", project.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&UpdateProjectRequestDto {
+            nama_projek: format!("{} Renamed", project.nama_projek),
+            slug: None,
+            deskripsi: "Desc".to_string(),
+            status: "ONGOING".to_string(),
+            progress: Some(0),
+            link_demo: None,
+            repository: None,
+            tanggal_mulai: "2023-01-01".to_string(),
+            tanggal_selesai: None,
+            stack_ids: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<ProjectResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let renamed_project = resp.data.unwrap();
+
+    let (status, location) =
+        get_location(&app, &format!("/api/public/projects/{}", project.slug)).await;
+    assert_eq!(status, StatusCode::MOVED_PERMANENTLY);
+    assert_eq!(
+        location.unwrap(),
+        format!("/api/public/projects/{}", renamed_project.slug)
+    );
+
+    // Portofolio
+    let req = test::TestRequest::post()
+        .uri("/app/portofolios")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreatePortofolioRequestDto {
+            project_id: project.id,
+            judul: format!("Slug Portofolio {}", Utc::now().timestamp_micros()),
+            slug: None,
+            deskripsi: "Desc".to_string(),
+            is_active: true,
+        })
+        .to_request();
+    let resp: SuccessResponse<PortofolioResponseDto> =
+        test::call_and_read_body_json(&app, req).await;
+    let portofolio = resp.data.unwrap();
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/app/portofolios/{}", portofolio.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&UpdatePortofolioRequestDto {
+            project_id: project.id,
+            judul: format!("{} Renamed", portofolio.judul),
+            slug: None,
+            deskripsi: "Desc".to_string(),
+            is_active: true,
+        })
+        .to_request();
+    let resp: SuccessResponse<PortofolioResponseDto> =
+        test::call_and_read_body_json(&app, req).await;
+    let renamed_portofolio = resp.data.unwrap();
+
+    let (status, location) = get_location(
+        &app,
+        &format!("/api/public/portofolios/{}", portofolio.slug),
+    )
+    .await;
+    assert_eq!(status, StatusCode::MOVED_PERMANENTLY);
+    assert_eq!(
+        location.unwrap(),
+        format!("/api/public/portofolios/{}", renamed_portofolio.slug)
+    );
+
+    // Unknown slugs are still plain 404s
+    let (status, location) = get_location(&app, "/api/public/blogs/never-existed").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(location.is_none());
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_renamed_slugs_of_hidden_entities_do_not_redirect() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    // A published blog renamed while being taken back to draft
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateCategoryRequestDto {
+            name: format!("Hidden Slug Cat {}", Utc::now().timestamp_micros()),
+            parent_id: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let category_id = resp.data.unwrap().id;
+
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateBlogRequestDto {
+            title: format!("Hidden Slug Blog {}", Utc::now().timestamp_micros()),
+            slug: None,
+            content: "Content".to_string(),
+            category_id,
+            tag_ids: None,
+            excerpt: "Excerpt".to_string(),
+            thumbnail: None,
+            status: "PUBLISHED".to_string(),
+            published_at: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let blog = resp.data.unwrap();
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/app/blogs/{}", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&UpdateBlogRequestDto {
+            title: format!("{} Secret", blog.title),
+            slug: None,
+            content: "Content".to_string(),
+            category_id,
+            tag_ids: None,
+            excerpt: "Excerpt".to_string(),
+            thumbnail: None,
+            status: "DRAFT".to_string(),
+            published_at: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    assert_ne!(resp.data.unwrap().slug, blog.slug);
+
+    let (status, location) = get_location(&app, &format!("/api/public/blogs/{}", blog.slug)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(location.is_none());
+
+    // A project renamed while going back to draft
+    let req = test::TestRequest::post()
+        .uri("/app/projects")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateProjectRequestDto {
+            nama_projek: format!("Hidden Slug Project {}", Utc::now().timestamp_micros()),
+            slug: None,
+            deskripsi: "Desc".to_string(),
+            status: "ONGOING".to_string(),
+            progress: 0,
+            link_demo: None,
+            repository: None,
+            tanggal_mulai: "2023-01-01".to_string(),
+            tanggal_selesai: None,
+            stack_ids: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<ProjectResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let project = resp.data.unwrap();
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/app/projects/{}", project.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&UpdateProjectRequestDto {
+            nama_projek: format!("{} Secret", project.nama_projek),
+            slug: None,
+            deskripsi: "Desc".to_string(),
+            status: "DRAFT".to_string(),
+            progress: Some(0),
+            link_demo: None,
+            repository: None,
+            tanggal_mulai: "2023-01-01".to_string(),
+            tanggal_selesai: None,
+            stack_ids: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<ProjectResponseDto> = test::call_and_read_body_json(&app, req).await;
+    assert_ne!(resp.data.unwrap().slug, project.slug);
+
+    let (status, location) =
+        get_location(&app, &format!("/api/public/projects/{}", project.slug)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(location.is_none());
+
+    // A portofolio renamed while being deactivated
+    let req = test::TestRequest::post()
+        .uri("/app/portofolios")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreatePortofolioRequestDto {
+            project_id: project.id,
+            judul: format!("Hidden Slug Portofolio {}", Utc::now().timestamp_micros()),
+            slug: None,
+            deskripsi: "Desc".to_string(),
+            is_active: true,
+        })
+        .to_request();
+    let resp: SuccessResponse<PortofolioResponseDto> =
+        test::call_and_read_body_json(&app, req).await;
+    let portofolio = resp.data.unwrap();
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/app/portofolios/{}", portofolio.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&UpdatePortofolioRequestDto {
+            project_id: project.id,
+            judul: format!("{} Secret", portofolio.judul),
+            slug: None,
+            deskripsi: "Desc".to_string(),
+            is_active: false,
+        })
+        .to_request();
+    let resp: SuccessResponse<PortofolioResponseDto> =
+        test::call_and_read_body_json(&app, req).await;
+    assert_ne!(resp.data.unwrap().slug, portofolio.slug);
+
+    let (status, location) = get_location(
+        &app,
+        &format!("/api/public/portofolios/{}", portofolio.slug),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(location.is_none());
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_admin_lists_and_prunes_slug_history() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let req = test::TestRequest::post()
+        .uri("/app/projects")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateProjectRequestDto {
+            nama_projek: format!("History Project {}", Utc::now().timestamp_micros()),
+            slug: None,
+            deskripsi: "Desc".to_string(),
+            status: "ONGOING".to_string(),
+            progress: 0,
+            link_demo: None,
+            repository: None,
+            tanggal_mulai: "2023-01-01".to_string(),
+            tanggal_selesai: None,
+            stack_ids: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<ProjectResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let project = resp.data.unwrap();
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/app/projects/{}", project.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&UpdateProjectRequestDto {
+            nama_projek: format!("{} Moved", project.nama_projek),
+            slug: None,
+            deskripsi: "Desc".to_string(),
+            status: "ONGOING".to_string(),
+            progress: Some(0),
+            link_demo: None,
+            repository: None,
+            tanggal_mulai: "2023-01-01".to_string(),
+            tanggal_selesai: None,
+            stack_ids: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<ProjectResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let renamed = resp.data.unwrap();
+
+    let req = test::TestRequest::get()
+        .uri("/app/slug-history?entity_type=project&per_page=100")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<PaginatedResponseDto<SlugHistoryResponseDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    let page = resp.data.unwrap();
+    let entry = page
+        .items
+        .iter()
+        .find(|h| h.slug == project.slug)
+        .expect("retired slug is listed");
+    assert_eq!(entry.entity_id, project.id);
+    assert_eq!(entry.current_slug.as_deref(), Some(renamed.slug.as_str()));
+    assert!(page.items.iter().all(|h| h.entity_type == "project"));
+
+    // Nothing has been retired for a day yet
+    let req = test::TestRequest::post()
+        .uri("/app/slug-history/prune")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&PruneSlugHistoryRequestDto {
+            entity_type: Some("project".to_string()),
+            older_than_days: Some(1),
+        })
+        .to_request();
+    let resp: SuccessResponse<PruneSlugHistoryResponseDto> =
+        test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().deleted, 0);
+
+    let req = test::TestRequest::delete()
+        .uri(&format!("/app/slug-history/{}", entry.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert!(resp.status().is_success());
+
+    let (status, _) = get_location(&app, &format!("/api/public/projects/{}", project.slug)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let req = test::TestRequest::delete()
+        .uri(&format!("/app/slug-history/{}", entry.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    for (entity_type, older_than_days) in [
+        (Some("article".to_string()), None),
+        (None, Some(i64::MAX)),
+        (None, Some(-1)),
+    ] {
+        let req = test::TestRequest::post()
+            .uri("/app/slug-history/prune")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(&PruneSlugHistoryRequestDto {
+                entity_type,
+                older_than_days,
+            })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -1,10 +1,14 @@
 use crate::app::features::blog::interface::dto::{
-    CreateTagRequestDto, TagResponseDto, UpdateTagRequestDto,
+    BlogResponseDto, CategoryResponseDto, CreateBlogRequestDto, CreateCategoryRequestDto,
+    CreateTagRequestDto, MergeTagRequestDto, MergeTagResponseDto, PaginatedResponseDto,
+    TagCloudEntryDto, TagResponseDto, UpdateTagRequestDto,
 };
 use crate::init_test_app;
 use crate::test::helpers::{login_admin, seed_user};
 use crate::utils::di::Container;
+use crate::utils::error_response::ErrorResponse;
 use crate::utils::success_response::SuccessResponse;
+use actix_web::http::StatusCode;
 use actix_web::test;
 use chrono::Utc;
 use serial_test::serial;
@@ -198,3 +202,218 @@ async fn test_delete_tag() {
     let resp = test::call_service(&app, req).await;
     assert!(resp.status().is_success());
 }
+
+async fn create_tag(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    name: String,
+) -> TagResponseDto {
+    let req = test::TestRequest::post()
+        .uri("/app/tags")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateTagRequestDto { name })
+        .to_request();
+    let resp: SuccessResponse<TagResponseDto> = test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap()
+}
+
+async fn create_blog(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    category_id: i32,
+    tag_ids: Vec<i32>,
+    status: &str,
+) -> BlogResponseDto {
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateBlogRequestDto {
+            title: format!("Tagged {}", Utc::now().timestamp_micros()),
+            slug: None,
+            content: "Content".to_string(),
+            category_id,
+            tag_ids: Some(tag_ids),
+            excerpt: "Excerpt".to_string(),
+            thumbnail: None,
+            status: status.to_string(),
+            published_at: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap()
+}
+
+async fn tag_cloud(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+) -> Vec<TagCloudEntryDto> {
+    let req = test::TestRequest::get()
+        .uri("/api/public/tags?limit=200")
+        .to_request();
+    let resp: SuccessResponse<Vec<TagCloudEntryDto>> =
+        test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap()
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_merge_tags_counts_and_tag_cloud() {
+    let container = Container::new();
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+
+    let stamp = Utc::now().timestamp_micros();
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateCategoryRequestDto {
+            name: format!("Merge Cat {}", stamp),
+            parent_id: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let category_id = resp.data.unwrap().id;
+
+    let rust = create_tag(&app, &token, format!("rust {}", stamp)).await;
+    let rust_lang = create_tag(&app, &token, format!("Rust-lang {}", stamp)).await;
+    let drafts_only = create_tag(&app, &token, format!("drafts {}", stamp)).await;
+
+    let both = create_blog(
+        &app,
+        &token,
+        category_id,
+        vec![rust.id, rust_lang.id],
+        "PUBLISHED",
+    )
+    .await;
+    create_blog(&app, &token, category_id, vec![rust.id], "PUBLISHED").await;
+    create_blog(&app, &token, category_id, vec![rust_lang.id], "PUBLISHED").await;
+    create_blog(&app, &token, category_id, vec![rust.id], "DRAFT").await;
+    create_blog(&app, &token, category_id, vec![drafts_only.id], "DRAFT").await;
+
+    // Counts are opt-in and include drafts
+    let req = test::TestRequest::get()
+        .uri("/app/tags?per_page=1000&with_counts=true")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<PaginatedResponseDto<TagResponseDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    let items = resp.data.unwrap().items;
+    let count_of = |id: i32| items.iter().find(|t| t.id == id).unwrap().post_count;
+    assert_eq!(count_of(rust.id), Some(3));
+    assert_eq!(count_of(rust_lang.id), Some(2));
+    assert_eq!(count_of(drafts_only.id), Some(1));
+
+    let req = test::TestRequest::get()
+        .uri("/app/tags?per_page=1000")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<PaginatedResponseDto<TagResponseDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    assert!(
+        resp.data
+            .unwrap()
+            .items
+            .iter()
+            .all(|t| t.post_count.is_none())
+    );
+
+    // The cloud only sees published posts
+    let cloud = tag_cloud(&app).await;
+    let entry = |cloud: &[TagCloudEntryDto], id: i32| cloud.iter().find(|e| e.id == id).cloned();
+    assert_eq!(entry(&cloud, rust.id).unwrap().post_count, 2);
+    assert_eq!(entry(&cloud, rust_lang.id).unwrap().post_count, 2);
+    assert!(entry(&cloud, drafts_only.id).is_none());
+    assert!(cloud.iter().all(|e| (1..=5).contains(&e.weight)));
+
+    let req = test::TestRequest::post()
+        .uri(&format!("/app/tags/{}/merge", rust_lang.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&MergeTagRequestDto {
+            target_id: rust_lang.id,
+        })
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body: ErrorResponse = test::read_body_json(resp).await;
+    assert_eq!(
+        body.errors.unwrap().get("target_id").unwrap(),
+        "Cannot merge a tag into itself"
+    );
+
+    let req = test::TestRequest::post()
+        .uri(&format!("/app/tags/{}/merge", rust_lang.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&MergeTagRequestDto {
+            target_id: i32::MAX,
+        })
+        .to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::BAD_REQUEST
+    );
+
+    // The post carrying both tags must not trip the (blog_id, tag_id) key
+    let req = test::TestRequest::post()
+        .uri(&format!("/app/tags/{}/merge", rust_lang.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&MergeTagRequestDto { target_id: rust.id })
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: SuccessResponse<MergeTagResponseDto> = test::read_body_json(resp).await;
+    let merged = body.data.unwrap();
+    assert_eq!(merged.tag.id, rust.id);
+    assert_eq!(merged.tag.post_count, Some(4));
+    assert_eq!((merged.moved_posts, merged.already_tagged), (1, 1));
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/blogs/{}", both.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(
+        resp.data
+            .unwrap()
+            .tags
+            .iter()
+            .map(|t| t.id)
+            .collect::<Vec<_>>(),
+        vec![rust.id]
+    );
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/tags/{}", rust_lang.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::NOT_FOUND
+    );
+
+    let req = test::TestRequest::post()
+        .uri(&format!("/app/tags/{}/merge", rust_lang.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&MergeTagRequestDto { target_id: rust.id })
+        .to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::NOT_FOUND
+    );
+
+    let cloud = tag_cloud(&app).await;
+    assert_eq!(entry(&cloud, rust.id).unwrap().post_count, 3);
+    assert!(entry(&cloud, rust_lang.id).is_none());
+}
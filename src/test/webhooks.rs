@@ -0,0 +1,326 @@
+use crate::app::features::blog::interface::dto::{
+    BlogResponseDto, CategoryResponseDto, CreateBlogRequestDto, CreateCategoryRequestDto,
+    UpdateBlogRequestDto,
+};
+use crate::app::features::webhooks::interface::dto::{
+    CreateWebhookDto, PaginatedResponseDto, UpdateWebhookDto, WebhookDeliveryDto,
+    WebhookResponseDto,
+};
+use crate::config::Config;
+use crate::init_test_app;
+use crate::test::helpers::{CapturedRequest, HttpReceiver, login_admin, seed_user};
+use crate::utils::di::Container;
+use crate::utils::success_response::SuccessResponse;
+use actix_web::http::StatusCode;
+use actix_web::test;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use serial_test::serial;
+use sha2::Sha256;
+use std::net::TcpListener;
+
+const SECRET: &str = "a-long-enough-webhook-secret";
+
+fn assert_signed(request: &CapturedRequest, secret: &str) {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+    mac.update(request.body.as_bytes());
+    let expected = format!("sha256={:x}", mac.finalize().into_bytes());
+    assert_eq!(
+        request.header("X-Webhook-Signature"),
+        Some(expected.as_str())
+    );
+}
+
+async fn get_deliveries(
+    app: &impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    token: &str,
+    webhook_id: i32,
+) -> Vec<WebhookDeliveryDto> {
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/webhooks/{}/deliveries", webhook_id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<PaginatedResponseDto<WebhookDeliveryDto>> =
+        test::call_and_read_body_json(app, req).await;
+    resp.data.unwrap().items
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_webhooks_sign_retry_and_redeliver() {
+    let receiver = HttpReceiver::start(vec![500]);
+    let mut config = Config::new();
+    config.webhook_backoff_secs = 0;
+    config.webhook_max_attempts = 2;
+    let container = Container::with_config(config);
+    seed_user(&container);
+    let app = init_test_app!(&container);
+    let token = login_admin(&app, &container).await;
+    let stamp = Utc::now().timestamp_micros();
+
+    // Unknown events and non-HTTP URLs are rejected
+    for (url, event) in [
+        (receiver.url("/hook"), "blog.exploded"),
+        ("ftp://example.com/hook".to_string(), "blog.published"),
+    ] {
+        let req = test::TestRequest::post()
+            .uri("/app/webhooks")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(&CreateWebhookDto {
+                url,
+                secret: SECRET.to_string(),
+                events: vec![event.to_string()],
+                is_active: None,
+            })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    let req = test::TestRequest::post()
+        .uri("/app/webhooks")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateWebhookDto {
+            url: receiver.url("/hook"),
+            secret: SECRET.to_string(),
+            events: vec!["blog.published".to_string(), "blog.deleted".to_string()],
+            is_active: None,
+        })
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let body = test::read_body(resp).await;
+    assert!(!String::from_utf8_lossy(&body).contains(SECRET));
+    let resp: SuccessResponse<WebhookResponseDto> = serde_json::from_slice(&body).unwrap();
+    let webhook = resp.data.unwrap();
+    assert_eq!(webhook.events, vec!["blog.deleted", "blog.published"]);
+    assert!(webhook.is_active);
+
+    let req = test::TestRequest::post()
+        .uri("/app/categories")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateCategoryRequestDto {
+            name: format!("Webhook Cat {}", stamp),
+            parent_id: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<CategoryResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let category_id = resp.data.unwrap().id;
+
+    // Creating a draft fires blog.created, which this webhook doesn't want
+    let req = test::TestRequest::post()
+        .uri("/app/blogs")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&CreateBlogRequestDto {
+            title: format!("Webhook Post {}", stamp),
+            slug: None,
+            content: "Content".to_string(),
+            category_id,
+            tag_ids: None,
+            excerpt: "Excerpt".to_string(),
+            thumbnail: None,
+            status: "DRAFT".to_string(),
+            published_at: None,
+        })
+        .to_request();
+    let resp: SuccessResponse<BlogResponseDto> = test::call_and_read_body_json(&app, req).await;
+    let blog = resp.data.unwrap();
+    assert_eq!(container.deliver_webhooks_usecase.execute().unwrap(), 0);
+    assert!(get_deliveries(&app, &token, webhook.id).await.is_empty());
+
+    let publish = UpdateBlogRequestDto {
+        title: blog.title.clone(),
+        slug: None,
+        content: "Content".to_string(),
+        category_id,
+        tag_ids: None,
+        excerpt: "Excerpt".to_string(),
+        thumbnail: None,
+        status: "PUBLISHED".to_string(),
+        published_at: None,
+    };
+    let req = test::TestRequest::put()
+        .uri(&format!("/app/blogs/{}", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&publish)
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+
+    // Saving an already published post is not a second blog.published
+    let req = test::TestRequest::put()
+        .uri(&format!("/app/blogs/{}", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&publish)
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+
+    // The receiver answers 500 first, so the delivery is retried
+    assert_eq!(container.deliver_webhooks_usecase.execute().unwrap(), 1);
+    let deliveries = get_deliveries(&app, &token, webhook.id).await;
+    assert_eq!(deliveries.len(), 1);
+    assert_eq!(deliveries[0].status, "PENDING");
+    assert_eq!(deliveries[0].attempts, 1);
+    assert_eq!(deliveries[0].response_status, Some(500));
+    assert!(deliveries[0].next_attempt_at.is_some());
+
+    assert_eq!(container.deliver_webhooks_usecase.execute().unwrap(), 1);
+    let deliveries = get_deliveries(&app, &token, webhook.id).await;
+    assert_eq!(deliveries[0].status, "SUCCEEDED");
+    assert_eq!(deliveries[0].attempts, 2);
+    assert_eq!(deliveries[0].response_status, Some(200));
+    assert_eq!(deliveries[0].response_body.as_deref(), Some("status 200"));
+    assert_eq!(deliveries[0].next_attempt_at, None);
+
+    let requests = receiver.requests();
+    assert_eq!(requests.len(), 2);
+    for request in &requests {
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/hook");
+        assert_eq!(request.header("X-Webhook-Event"), Some("blog.published"));
+        assert_eq!(
+            request.header("X-Webhook-Delivery"),
+            Some(deliveries[0].id.to_string().as_str())
+        );
+        assert_signed(request, SECRET);
+    }
+    let payload: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+    assert_eq!(payload["event"], "blog.published");
+    assert_eq!(payload["data"]["id"], blog.id);
+    assert_eq!(payload["data"]["status"], "PUBLISHED");
+
+    // Point the webhook at a closed port; the secret is kept when left out
+    let closed_port = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let req = test::TestRequest::put()
+        .uri(&format!("/app/webhooks/{}", webhook.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&UpdateWebhookDto {
+            url: format!("http://127.0.0.1:{}/hook", closed_port),
+            secret: None,
+            events: vec!["blog.deleted".to_string()],
+            is_active: true,
+        })
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+
+    let req = test::TestRequest::delete()
+        .uri(&format!("/app/blogs/{}", blog.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+
+    // Two attempts without a response use up max_attempts
+    container.deliver_webhooks_usecase.execute().unwrap();
+    container.deliver_webhooks_usecase.execute().unwrap();
+    let deliveries = get_deliveries(&app, &token, webhook.id).await;
+    assert_eq!(deliveries.len(), 2);
+    let failed = deliveries[0].clone();
+    assert_eq!(failed.event, "blog.deleted");
+    assert_eq!(failed.status, "FAILED");
+    assert_eq!(failed.attempts, 2);
+    assert_eq!(failed.response_status, None);
+    assert!(failed.error.is_some());
+    assert_eq!(container.deliver_webhooks_usecase.execute().unwrap(), 0);
+
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/app/webhooks/{}/deliveries?status=FAILED",
+            webhook.id
+        ))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp: SuccessResponse<PaginatedResponseDto<WebhookDeliveryDto>> =
+        test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.data.unwrap().meta.total_items, 1);
+
+    // Back to the receiver, then send the failed payload again
+    let req = test::TestRequest::put()
+        .uri(&format!("/app/webhooks/{}", webhook.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(&UpdateWebhookDto {
+            url: receiver.url("/hook"),
+            secret: None,
+            events: vec!["blog.deleted".to_string()],
+            is_active: true,
+        })
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+
+    let req = test::TestRequest::post()
+        .uri(&format!("/app/webhooks/deliveries/{}/redeliver", failed.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let resp: SuccessResponse<WebhookDeliveryDto> = test::read_body_json(resp).await;
+    let redelivered = resp.data.unwrap();
+    assert_ne!(redelivered.id, failed.id);
+    assert_eq!(redelivered.status, "PENDING");
+    assert_eq!(redelivered.attempts, 0);
+    assert_eq!(redelivered.payload, failed.payload);
+    // Queued only; the deliverer sends it
+    assert_eq!(receiver.requests().len(), 2);
+
+    assert_eq!(container.deliver_webhooks_usecase.execute().unwrap(), 1);
+    let deliveries = get_deliveries(&app, &token, webhook.id).await;
+    let redelivered = deliveries.iter().find(|d| d.id == redelivered.id).unwrap();
+    assert_eq!(redelivered.status, "SUCCEEDED");
+    assert_eq!(redelivered.attempts, 1);
+
+    let requests = receiver.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[2].header("X-Webhook-Event"), Some("blog.deleted"));
+    assert_eq!(requests[2].body, failed.payload);
+    assert_signed(&requests[2], SECRET);
+
+    // The original entry is left as it was
+    let deliveries = get_deliveries(&app, &token, webhook.id).await;
+    assert_eq!(deliveries.len(), 3);
+    assert!(
+        deliveries
+            .iter()
+            .any(|d| d.id == failed.id && d.status == "FAILED")
+    );
+
+    let req = test::TestRequest::post()
+        .uri("/app/webhooks/deliveries/999999/redeliver")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::NOT_FOUND
+    );
+
+    let req = test::TestRequest::delete()
+        .uri(&format!("/app/webhooks/{}", webhook.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/app/webhooks/{}", webhook.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::NOT_FOUND
+    );
+}
+
+#[actix_web::test]
+#[serial]
+async fn test_webhooks_require_auth() {
+    let container = Container::new();
+    let app = init_test_app!(&container);
+
+    let req = test::TestRequest::get().uri("/app/webhooks").to_request();
+    let err = test::try_call_service(&app, req).await.unwrap_err();
+    assert_eq!(err.error_response().status(), StatusCode::UNAUTHORIZED);
+}
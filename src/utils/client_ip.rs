@@ -0,0 +1,36 @@
+use actix_web::HttpRequest;
+use std::net::IpAddr;
+
+/// Address of the client that sent `req`.
+///
+/// This is the connection's peer address, unless the peer is one of
+/// `trusted_proxies`. In that case `X-Forwarded-For` is read from the right,
+/// skipping further trusted proxies, because anything to their left was
+/// supplied by the client.
+pub fn client_ip(req: &HttpRequest, trusted_proxies: &[IpAddr]) -> Option<IpAddr> {
+    let peer = req.peer_addr()?.ip();
+    if !trusted_proxies.contains(&peer) {
+        return Some(peer);
+    }
+
+    let forwarded = req
+        .headers()
+        .get_all("X-Forwarded-For")
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .map(|ip| ip.trim().parse::<IpAddr>())
+        .collect::<Vec<_>>();
+    let mut client = peer;
+    for hop in forwarded.iter().rev() {
+        match hop {
+            Ok(ip) => {
+                client = *ip;
+                if !trusted_proxies.contains(ip) {
+                    break;
+                }
+            }
+            Err(_) => break,
+        }
+    }
+    Some(client)
+}
@@ -1,16 +1,65 @@
+use crate::app::features::analytics::application::usecase as analytics_usecase;
+use crate::app::features::analytics::domain::repository::AnalyticsRepository;
+use crate::app::features::analytics::infrastructure::repository_impl::AnalyticsRepositoryImpl;
 use crate::app::features::auth::domain::repository::UserRepository;
 use crate::app::features::auth::infrastructure::repository_impl::UserRepositoryImpl;
 use crate::app::features::home::application::usecase as home_usecase;
 
 use crate::app::features::auth::application::usecase as auth_usecase;
+use crate::app::features::authors::application::usecase as author_usecase;
+use crate::app::features::authors::domain::repository::AuthorRepository;
+use crate::app::features::authors::infrastructure::repository_impl::AuthorRepositoryImpl;
 use crate::app::features::blog::application::blog_usecase;
 use crate::app::features::blog::application::category_usecase;
+use crate::app::features::blog::application::revision_usecase;
 use crate::app::features::blog::application::tag_usecase;
+use crate::app::features::blog::domain::entity::RelatedWeights;
 use crate::app::features::blog::domain::repository::BlogRepository;
 use crate::app::features::blog::infrastructure::repository_impl::BlogRepositoryImpl;
+use crate::app::features::comments::application::usecase as comment_usecase;
+use crate::app::features::comments::domain::repository::CommentRepository;
+use crate::app::features::comments::infrastructure::repository_impl::CommentRepositoryImpl;
+use crate::app::features::dashboard::application::usecase as dashboard_usecase;
+use crate::app::features::dashboard::domain::repository::DashboardRepository;
+use crate::app::features::dashboard::infrastructure::repository_impl::DashboardRepositoryImpl;
+use crate::app::features::feed::application::usecase as feed_usecase;
+use crate::app::features::feed::domain::repository::FeedRepository;
+use crate::app::features::feed::infrastructure::repository_impl::FeedRepositoryImpl;
 use crate::app::features::home::domain::repository::CountRepository;
+use crate::app::features::media::application::usecase as media_usecase;
+use crate::app::features::media::domain::image::ImageProcessor;
+use crate::app::features::media::domain::repository::MediaRepository;
+use crate::app::features::media::domain::storage::MediaStorage;
+use crate::app::features::media::infrastructure::image_impl::LocalImageProcessor;
+use crate::app::features::media::infrastructure::repository_impl::MediaRepositoryImpl;
+use crate::app::features::media::infrastructure::storage_impl::LocalMediaStorage;
+use crate::app::features::previews::application::usecase as preview_usecase;
+use crate::app::features::previews::domain::repository::PreviewRepository;
+use crate::app::features::previews::infrastructure::repository_impl::PreviewRepositoryImpl;
+use crate::app::features::series::application::usecase as series_usecase;
+use crate::app::features::series::domain::repository::SeriesRepository;
+use crate::app::features::series::infrastructure::repository_impl::SeriesRepositoryImpl;
+use crate::app::features::sitemap::application::usecase as sitemap_usecase;
+use crate::app::features::sitemap::domain::repository::SitemapRepository;
+use crate::app::features::sitemap::infrastructure::repository_impl::SitemapRepositoryImpl;
+use crate::app::features::slug_history::application::usecase as slug_history_usecase;
+use crate::app::features::slug_history::domain::repository::SlugHistoryRepository;
+use crate::app::features::slug_history::infrastructure::repository_impl::SlugHistoryRepositoryImpl;
+use crate::app::features::subscribers::application::usecase as subscriber_usecase;
+use crate::app::features::subscribers::domain::repository::SubscriberRepository;
+use crate::app::features::subscribers::infrastructure::repository_impl::SubscriberRepositoryImpl;
+use crate::app::features::webhooks::application::usecase as webhook_usecase;
+use crate::app::features::webhooks::domain::repository::WebhookRepository;
+use crate::app::features::webhooks::domain::sender::WebhookSender;
+use crate::app::features::webhooks::infrastructure::repository_impl::WebhookRepositoryImpl;
+use crate::app::features::webhooks::infrastructure::sender_impl::HttpWebhookSender;
 
 use crate::app::features::home::infrastructure::repository_impl::CountRepositoryImpl;
+use crate::app::features::importer::application::usecase as import_usecase;
+use crate::app::features::importer::domain::parser::ImportParser;
+use crate::app::features::importer::domain::repository::ImportRepository;
+use crate::app::features::importer::infrastructure::parser_impl::FileImportParser;
+use crate::app::features::importer::infrastructure::repository_impl::ImportRepositoryImpl;
 use crate::app::features::portofolio::application::usecase as portofolio_usecase;
 use crate::app::features::portofolio::domain::repository::PortofolioRepository;
 use crate::app::features::portofolio::infrastructure::repository_impl::PortofolioRepositoryImpl;
@@ -39,21 +88,87 @@ pub struct Container {
     pub get_category_usecase: category_usecase::get::Execute,
     pub update_category_usecase: category_usecase::update::Execute,
     pub delete_category_usecase: category_usecase::delete::Execute,
+    pub get_category_tree_usecase: category_usecase::tree::Execute,
     pub create_tag_usecase: tag_usecase::create::Execute,
     pub get_tags_usecase: tag_usecase::get_all::Execute,
     pub get_tag_usecase: tag_usecase::get::Execute,
     pub update_tag_usecase: tag_usecase::update::Execute,
     pub delete_tag_usecase: tag_usecase::delete::Execute,
+    pub merge_tag_usecase: tag_usecase::merge::Execute,
+    pub get_tag_cloud_usecase: tag_usecase::cloud::Execute,
     pub create_blog_usecase: blog_usecase::create::Execute,
     pub get_blogs_usecase: blog_usecase::get_all::Execute,
     pub get_blog_usecase: blog_usecase::get::Execute,
     pub update_blog_usecase: blog_usecase::update::Execute,
     pub delete_blog_usecase: blog_usecase::delete::Execute,
+    pub bulk_blogs_usecase: blog_usecase::bulk::Execute,
+    pub get_published_blogs_usecase: blog_usecase::get_all_published::Execute,
+    pub get_blog_by_slug_usecase: blog_usecase::get_by_slug::Execute,
+    pub search_blogs_usecase: blog_usecase::search::Execute,
+    pub related_blogs_usecase: blog_usecase::related::Execute,
+    pub publish_scheduled_blogs_usecase: blog_usecase::publish_scheduled::Execute,
+    pub render_missing_blogs_usecase: blog_usecase::render_missing::Execute,
+    pub get_blog_revisions_usecase: revision_usecase::get_all::Execute,
+    pub get_blog_revision_usecase: revision_usecase::get::Execute,
+    pub diff_blog_revisions_usecase: revision_usecase::diff::Execute,
+    pub restore_blog_revision_usecase: revision_usecase::restore::Execute,
+    pub create_comment_usecase: comment_usecase::create::Execute,
+    pub get_public_comments_usecase: comment_usecase::get_public::Execute,
+    pub get_comments_usecase: comment_usecase::get_all::Execute,
+    pub moderate_comments_usecase: comment_usecase::moderate::Execute,
+    pub delete_comment_usecase: comment_usecase::delete::Execute,
+    pub get_feed_usecase: feed_usecase::get::Execute,
+    pub get_sitemap_usecase: sitemap_usecase::get::Execute,
+    pub resolve_slug_redirect_usecase: slug_history_usecase::resolve::Execute,
+    pub get_slug_history_usecase: slug_history_usecase::get_all::Execute,
+    pub prune_slug_history_usecase: slug_history_usecase::prune::Execute,
+    pub delete_slug_history_usecase: slug_history_usecase::delete::Execute,
+    pub upload_media_usecase: media_usecase::upload::Execute,
+    pub get_all_media_usecase: media_usecase::get_all::Execute,
+    pub get_media_usecase: media_usecase::get::Execute,
+    pub delete_media_usecase: media_usecase::delete::Execute,
+    pub serve_media_usecase: media_usecase::serve::Execute,
+    pub create_series_usecase: series_usecase::create::Execute,
+    pub get_all_series_usecase: series_usecase::get_all::Execute,
+    pub get_series_usecase: series_usecase::get::Execute,
+    pub update_series_usecase: series_usecase::update::Execute,
+    pub delete_series_usecase: series_usecase::delete::Execute,
+    pub get_public_series_usecase: series_usecase::get_public::Execute,
+    pub get_public_authors_usecase: author_usecase::get_all::Execute,
+    pub get_public_author_usecase: author_usecase::get::Execute,
+    pub update_author_usecase: author_usecase::update::Execute,
+    pub create_preview_token_usecase: preview_usecase::create::Execute,
+    pub get_preview_tokens_usecase: preview_usecase::get_all::Execute,
+    pub revoke_preview_token_usecase: preview_usecase::revoke::Execute,
+    pub get_preview_usecase: preview_usecase::view::Execute,
+    pub record_blog_view_usecase: analytics_usecase::record_view::Execute,
+    pub get_blog_views_usecase: analytics_usecase::daily_views::Execute,
+    pub get_top_posts_usecase: analytics_usecase::top_posts::Execute,
+    pub get_dashboard_usecase: dashboard_usecase::summary::Execute,
+    pub subscribe_usecase: subscriber_usecase::subscribe::Execute,
+    pub confirm_subscription_usecase: subscriber_usecase::confirm::Execute,
+    pub unsubscribe_usecase: subscriber_usecase::unsubscribe::Execute,
+    pub get_subscription_status_usecase: subscriber_usecase::status::Execute,
+    pub get_subscribers_usecase: subscriber_usecase::get_all::Execute,
+    pub notify_subscribers_usecase: subscriber_usecase::notify::Execute,
+    pub send_newsletter_emails_usecase: subscriber_usecase::send::Execute,
+    pub create_webhook_usecase: webhook_usecase::create::Execute,
+    pub get_webhooks_usecase: webhook_usecase::get_all::Execute,
+    pub get_webhook_usecase: webhook_usecase::get::Execute,
+    pub update_webhook_usecase: webhook_usecase::update::Execute,
+    pub delete_webhook_usecase: webhook_usecase::delete::Execute,
+    pub enqueue_webhook_usecase: webhook_usecase::enqueue::Execute,
+    pub deliver_webhooks_usecase: webhook_usecase::deliver::Execute,
+    pub get_webhook_deliveries_usecase: webhook_usecase::get_deliveries::Execute,
+    pub redeliver_webhook_usecase: webhook_usecase::redeliver::Execute,
+    pub import_posts_usecase: import_usecase::import::Execute,
     pub create_project_usecase: project_usecase::create::Execute,
     pub get_all_projects_usecase: project_usecase::get_all::Execute,
     pub get_project_usecase: project_usecase::get::Execute,
     pub update_project_usecase: project_usecase::update::Execute,
     pub delete_project_usecase: project_usecase::delete::Execute,
+    pub get_public_projects_usecase: project_usecase::get_all_public::Execute,
+    pub get_project_by_slug_usecase: project_usecase::get_by_slug::Execute,
     pub create_stack_usecase: stack_usecase::create::Execute,
     pub get_all_stacks_usecase: stack_usecase::get_all::Execute,
     pub get_stack_usecase: stack_usecase::get::Execute,
@@ -64,14 +179,19 @@ pub struct Container {
     pub portofolio_get_usecase: portofolio_usecase::get::Execute,
     pub portofolio_update_usecase: portofolio_usecase::update::Execute,
     pub portofolio_delete_usecase: portofolio_usecase::delete::Execute,
+    pub portofolio_get_all_active_usecase: portofolio_usecase::get_all_active::Execute,
+    pub portofolio_get_by_slug_usecase: portofolio_usecase::get_by_slug::Execute,
     pub get_profile_usecase: profile_usecase::get::Execute,
     pub upsert_profile_usecase: profile_usecase::upsert::Execute,
 }
 
 impl Container {
     pub fn new() -> Self {
-        let config = Config::new();
+        Self::with_config(Config::new())
+    }
 
+    /// Same as `new`, for callers that need to adjust the configuration first.
+    pub fn with_config(config: Config) -> Self {
         let pool = establish_connection(&config.database_url);
         let email = Email::new(config.clone());
 
@@ -102,18 +222,48 @@ impl Container {
             category_usecase::update::Execute::new(blog_repository.clone());
         let delete_category_usecase =
             category_usecase::delete::Execute::new(blog_repository.clone());
+        let get_category_tree_usecase =
+            category_usecase::tree::Execute::new(blog_repository.clone());
 
         let create_tag_usecase = tag_usecase::create::Execute::new(blog_repository.clone());
         let get_tags_usecase = tag_usecase::get_all::Execute::new(blog_repository.clone());
         let get_tag_usecase = tag_usecase::get::Execute::new(blog_repository.clone());
         let update_tag_usecase = tag_usecase::update::Execute::new(blog_repository.clone());
         let delete_tag_usecase = tag_usecase::delete::Execute::new(blog_repository.clone());
+        let merge_tag_usecase = tag_usecase::merge::Execute::new(blog_repository.clone());
+        let get_tag_cloud_usecase = tag_usecase::cloud::Execute::new(blog_repository.clone());
 
         let create_blog_usecase = blog_usecase::create::Execute::new(blog_repository.clone());
         let get_blogs_usecase = blog_usecase::get_all::Execute::new(blog_repository.clone());
         let get_blog_usecase = blog_usecase::get::Execute::new(blog_repository.clone());
         let update_blog_usecase = blog_usecase::update::Execute::new(blog_repository.clone());
         let delete_blog_usecase = blog_usecase::delete::Execute::new(blog_repository.clone());
+        let bulk_blogs_usecase = blog_usecase::bulk::Execute::new(blog_repository.clone());
+        let get_published_blogs_usecase =
+            blog_usecase::get_all_published::Execute::new(blog_repository.clone());
+        let get_blog_by_slug_usecase =
+            blog_usecase::get_by_slug::Execute::new(blog_repository.clone());
+        let search_blogs_usecase = blog_usecase::search::Execute::new(blog_repository.clone());
+        let related_blogs_usecase = blog_usecase::related::Execute::new(
+            blog_repository.clone(),
+            RelatedWeights {
+                tag: config.related_tag_weight,
+                category: config.related_category_weight,
+                recency: config.related_recency_weight,
+            },
+        );
+        let publish_scheduled_blogs_usecase =
+            blog_usecase::publish_scheduled::Execute::new(blog_repository.clone());
+        let render_missing_blogs_usecase =
+            blog_usecase::render_missing::Execute::new(blog_repository.clone());
+        let get_blog_revisions_usecase =
+            revision_usecase::get_all::Execute::new(blog_repository.clone());
+        let get_blog_revision_usecase =
+            revision_usecase::get::Execute::new(blog_repository.clone());
+        let diff_blog_revisions_usecase =
+            revision_usecase::diff::Execute::new(blog_repository.clone());
+        let restore_blog_revision_usecase =
+            revision_usecase::restore::Execute::new(blog_repository.clone());
 
         let project_repository: Arc<dyn ProjectRepository + Send + Sync> =
             Arc::new(ProjectRepositoryImpl::new(pool.clone()));
@@ -127,6 +277,10 @@ impl Container {
             project_usecase::update::Execute::new(project_repository.clone());
         let delete_project_usecase =
             project_usecase::delete::Execute::new(project_repository.clone());
+        let get_public_projects_usecase =
+            project_usecase::get_all_public::Execute::new(project_repository.clone());
+        let get_project_by_slug_usecase =
+            project_usecase::get_by_slug::Execute::new(project_repository.clone());
 
         let create_stack_usecase = stack_usecase::create::Execute::new(project_repository.clone());
         let get_all_stacks_usecase =
@@ -135,6 +289,179 @@ impl Container {
         let update_stack_usecase = stack_usecase::update::Execute::new(project_repository.clone());
         let delete_stack_usecase = stack_usecase::delete::Execute::new(project_repository.clone());
 
+        let comment_repository: Arc<dyn CommentRepository + Send + Sync> =
+            Arc::new(CommentRepositoryImpl::new(pool.clone()));
+
+        let create_comment_usecase =
+            comment_usecase::create::Execute::new(comment_repository.clone());
+        let get_public_comments_usecase =
+            comment_usecase::get_public::Execute::new(comment_repository.clone());
+        let get_comments_usecase =
+            comment_usecase::get_all::Execute::new(comment_repository.clone());
+        let moderate_comments_usecase =
+            comment_usecase::moderate::Execute::new(comment_repository.clone());
+        let delete_comment_usecase =
+            comment_usecase::delete::Execute::new(comment_repository.clone());
+
+        let feed_repository: Arc<dyn FeedRepository + Send + Sync> =
+            Arc::new(FeedRepositoryImpl::new(pool.clone()));
+        let get_feed_usecase = feed_usecase::get::Execute::new(feed_repository.clone());
+
+        let sitemap_repository: Arc<dyn SitemapRepository + Send + Sync> =
+            Arc::new(SitemapRepositoryImpl::new(pool.clone()));
+        let get_sitemap_usecase =
+            sitemap_usecase::get::Execute::new(sitemap_repository, config.sitemap_max_urls);
+
+        let slug_history_repository: Arc<dyn SlugHistoryRepository + Send + Sync> =
+            Arc::new(SlugHistoryRepositoryImpl::new(pool.clone()));
+        let resolve_slug_redirect_usecase =
+            slug_history_usecase::resolve::Execute::new(slug_history_repository.clone());
+        let get_slug_history_usecase =
+            slug_history_usecase::get_all::Execute::new(slug_history_repository.clone());
+        let prune_slug_history_usecase =
+            slug_history_usecase::prune::Execute::new(slug_history_repository.clone());
+        let delete_slug_history_usecase =
+            slug_history_usecase::delete::Execute::new(slug_history_repository.clone());
+
+        let media_repository: Arc<dyn MediaRepository + Send + Sync> =
+            Arc::new(MediaRepositoryImpl::new(pool.clone()));
+        let media_storage: Arc<dyn MediaStorage + Send + Sync> =
+            Arc::new(LocalMediaStorage::new(config.media_dir.clone()));
+        let image_processor: Arc<dyn ImageProcessor + Send + Sync> =
+            Arc::new(LocalImageProcessor::new(
+                config.media_variant_widths.clone(),
+                config.media_webp_quality,
+            ));
+        let upload_media_usecase = media_usecase::upload::Execute::new(
+            media_repository.clone(),
+            media_storage.clone(),
+            image_processor,
+            config.media_max_bytes,
+            config.media_allowed_types.clone(),
+            config.site_url.clone(),
+        );
+        let get_all_media_usecase =
+            media_usecase::get_all::Execute::new(media_repository.clone(), config.site_url.clone());
+        let get_media_usecase =
+            media_usecase::get::Execute::new(media_repository.clone(), config.site_url.clone());
+        let delete_media_usecase =
+            media_usecase::delete::Execute::new(media_repository.clone(), media_storage.clone());
+        let serve_media_usecase =
+            media_usecase::serve::Execute::new(media_repository.clone(), media_storage.clone());
+
+        let series_repository: Arc<dyn SeriesRepository + Send + Sync> =
+            Arc::new(SeriesRepositoryImpl::new(pool.clone()));
+        let create_series_usecase = series_usecase::create::Execute::new(series_repository.clone());
+        let get_all_series_usecase =
+            series_usecase::get_all::Execute::new(series_repository.clone());
+        let get_series_usecase = series_usecase::get::Execute::new(series_repository.clone());
+        let update_series_usecase = series_usecase::update::Execute::new(series_repository.clone());
+        let delete_series_usecase = series_usecase::delete::Execute::new(series_repository.clone());
+        let get_public_series_usecase =
+            series_usecase::get_public::Execute::new(series_repository.clone());
+
+        let author_repository: Arc<dyn AuthorRepository + Send + Sync> =
+            Arc::new(AuthorRepositoryImpl::new(pool.clone()));
+        let get_public_authors_usecase =
+            author_usecase::get_all::Execute::new(author_repository.clone());
+        let get_public_author_usecase =
+            author_usecase::get::Execute::new(author_repository.clone());
+        let update_author_usecase = author_usecase::update::Execute::new(author_repository.clone());
+
+        let preview_repository: Arc<dyn PreviewRepository + Send + Sync> =
+            Arc::new(PreviewRepositoryImpl::new(pool.clone()));
+        let create_preview_token_usecase = preview_usecase::create::Execute::new(
+            preview_repository.clone(),
+            config.jwt_secret.clone(),
+        );
+        let get_preview_tokens_usecase =
+            preview_usecase::get_all::Execute::new(preview_repository.clone());
+        let revoke_preview_token_usecase =
+            preview_usecase::revoke::Execute::new(preview_repository.clone());
+        let get_preview_usecase = preview_usecase::view::Execute::new(
+            preview_repository.clone(),
+            get_blog_usecase.clone(),
+            config.jwt_secret.clone(),
+        );
+
+        let analytics_repository: Arc<dyn AnalyticsRepository + Send + Sync> =
+            Arc::new(AnalyticsRepositoryImpl::new(pool.clone()));
+        let record_blog_view_usecase = analytics_usecase::record_view::Execute::new(
+            analytics_repository.clone(),
+            config.jwt_secret.clone(),
+            config.view_window_minutes,
+        );
+        let get_blog_views_usecase =
+            analytics_usecase::daily_views::Execute::new(analytics_repository.clone());
+        let get_top_posts_usecase =
+            analytics_usecase::top_posts::Execute::new(analytics_repository.clone());
+
+        let dashboard_repository: Arc<dyn DashboardRepository + Send + Sync> =
+            Arc::new(DashboardRepositoryImpl::new(pool.clone()));
+        let get_dashboard_usecase =
+            dashboard_usecase::summary::Execute::new(dashboard_repository.clone());
+
+        let subscriber_repository: Arc<dyn SubscriberRepository + Send + Sync> =
+            Arc::new(SubscriberRepositoryImpl::new(pool.clone()));
+        let subscribe_usecase = subscriber_usecase::subscribe::Execute::new(
+            subscriber_repository.clone(),
+            config.newsletter_resend_secs,
+        );
+        let confirm_subscription_usecase = subscriber_usecase::confirm::Execute::new(
+            subscriber_repository.clone(),
+            config.jwt_secret.clone(),
+        );
+        let unsubscribe_usecase = subscriber_usecase::unsubscribe::Execute::new(
+            subscriber_repository.clone(),
+            config.jwt_secret.clone(),
+        );
+        let get_subscription_status_usecase = subscriber_usecase::status::Execute::new(
+            subscriber_repository.clone(),
+            config.jwt_secret.clone(),
+        );
+        let get_subscribers_usecase =
+            subscriber_usecase::get_all::Execute::new(subscriber_repository.clone());
+        let notify_subscribers_usecase =
+            subscriber_usecase::notify::Execute::new(subscriber_repository.clone());
+        let send_newsletter_emails_usecase = subscriber_usecase::send::Execute::new(
+            subscriber_repository.clone(),
+            email.clone(),
+            config.site_url.clone(),
+            config.jwt_secret.clone(),
+        );
+
+        let webhook_repository: Arc<dyn WebhookRepository + Send + Sync> =
+            Arc::new(WebhookRepositoryImpl::new(pool.clone()));
+        let webhook_sender: Arc<dyn WebhookSender + Send + Sync> =
+            Arc::new(HttpWebhookSender::new(config.webhook_timeout_secs));
+        let create_webhook_usecase =
+            webhook_usecase::create::Execute::new(webhook_repository.clone());
+        let get_webhooks_usecase =
+            webhook_usecase::get_all::Execute::new(webhook_repository.clone());
+        let get_webhook_usecase = webhook_usecase::get::Execute::new(webhook_repository.clone());
+        let update_webhook_usecase =
+            webhook_usecase::update::Execute::new(webhook_repository.clone());
+        let delete_webhook_usecase =
+            webhook_usecase::delete::Execute::new(webhook_repository.clone());
+        let enqueue_webhook_usecase =
+            webhook_usecase::enqueue::Execute::new(webhook_repository.clone());
+        let deliver_webhooks_usecase = webhook_usecase::deliver::Execute::new(
+            webhook_repository.clone(),
+            webhook_sender,
+            config.webhook_max_attempts,
+            config.webhook_backoff_secs,
+        );
+        let get_webhook_deliveries_usecase =
+            webhook_usecase::get_deliveries::Execute::new(webhook_repository.clone());
+        let redeliver_webhook_usecase =
+            webhook_usecase::redeliver::Execute::new(webhook_repository.clone());
+
+        let import_repository: Arc<dyn ImportRepository + Send + Sync> =
+            Arc::new(ImportRepositoryImpl::new(pool.clone()));
+        let import_parser: Arc<dyn ImportParser + Send + Sync> = Arc::new(FileImportParser::new());
+        let import_posts_usecase =
+            import_usecase::import::Execute::new(import_repository, import_parser);
+
         let portofolio_repository: Arc<dyn PortofolioRepository + Send + Sync> =
             Arc::new(PortofolioRepositoryImpl::new(pool.clone()));
 
@@ -148,6 +475,10 @@ impl Container {
             portofolio_usecase::update::Execute::new(portofolio_repository.clone());
         let portofolio_delete_usecase =
             portofolio_usecase::delete::Execute::new(portofolio_repository.clone());
+        let portofolio_get_all_active_usecase =
+            portofolio_usecase::get_all_active::Execute::new(portofolio_repository.clone());
+        let portofolio_get_by_slug_usecase =
+            portofolio_usecase::get_by_slug::Execute::new(portofolio_repository.clone());
 
         let profile_repository: Arc<dyn ProfileRepository + Send + Sync> =
             Arc::new(ProfileRepositoryImpl::new(pool.clone()));
@@ -167,21 +498,87 @@ impl Container {
             get_category_usecase,
             update_category_usecase,
             delete_category_usecase,
+            get_category_tree_usecase,
             create_tag_usecase,
             get_tags_usecase,
             get_tag_usecase,
             update_tag_usecase,
             delete_tag_usecase,
+            merge_tag_usecase,
+            get_tag_cloud_usecase,
             create_blog_usecase,
             get_blogs_usecase,
             get_blog_usecase,
             update_blog_usecase,
             delete_blog_usecase,
+            bulk_blogs_usecase,
+            get_published_blogs_usecase,
+            get_blog_by_slug_usecase,
+            search_blogs_usecase,
+            related_blogs_usecase,
+            publish_scheduled_blogs_usecase,
+            render_missing_blogs_usecase,
+            get_blog_revisions_usecase,
+            get_blog_revision_usecase,
+            diff_blog_revisions_usecase,
+            restore_blog_revision_usecase,
+            create_comment_usecase,
+            get_public_comments_usecase,
+            get_comments_usecase,
+            moderate_comments_usecase,
+            delete_comment_usecase,
+            get_feed_usecase,
+            get_sitemap_usecase,
+            resolve_slug_redirect_usecase,
+            get_slug_history_usecase,
+            prune_slug_history_usecase,
+            delete_slug_history_usecase,
+            upload_media_usecase,
+            get_all_media_usecase,
+            get_media_usecase,
+            delete_media_usecase,
+            serve_media_usecase,
+            create_series_usecase,
+            get_all_series_usecase,
+            get_series_usecase,
+            update_series_usecase,
+            delete_series_usecase,
+            get_public_series_usecase,
+            get_public_authors_usecase,
+            get_public_author_usecase,
+            update_author_usecase,
+            create_preview_token_usecase,
+            get_preview_tokens_usecase,
+            revoke_preview_token_usecase,
+            get_preview_usecase,
+            record_blog_view_usecase,
+            get_blog_views_usecase,
+            get_top_posts_usecase,
+            get_dashboard_usecase,
+            subscribe_usecase,
+            confirm_subscription_usecase,
+            unsubscribe_usecase,
+            get_subscription_status_usecase,
+            get_subscribers_usecase,
+            notify_subscribers_usecase,
+            send_newsletter_emails_usecase,
+            create_webhook_usecase,
+            get_webhooks_usecase,
+            get_webhook_usecase,
+            update_webhook_usecase,
+            delete_webhook_usecase,
+            enqueue_webhook_usecase,
+            deliver_webhooks_usecase,
+            get_webhook_deliveries_usecase,
+            redeliver_webhook_usecase,
+            import_posts_usecase,
             create_project_usecase,
             get_all_projects_usecase,
             get_project_usecase,
             update_project_usecase,
             delete_project_usecase,
+            get_public_projects_usecase,
+            get_project_by_slug_usecase,
             create_stack_usecase,
             get_all_stacks_usecase,
             get_stack_usecase,
@@ -192,6 +589,8 @@ impl Container {
             portofolio_get_usecase,
             portofolio_update_usecase,
             portofolio_delete_usecase,
+            portofolio_get_all_active_usecase,
+            portofolio_get_by_slug_usecase,
             get_profile_usecase,
             upsert_profile_usecase,
         }
@@ -1,7 +1,45 @@
 use crate::config::Config;
 use crate::app::features::auth::domain::entity::User;
+use lettre::message::header::{Header, HeaderName, HeaderValue};
 use lettre::message::{Mailbox, header};
 use lettre::{Message, SmtpTransport, Transport};
+use std::error::Error;
+
+/// `List-Unsubscribe`, so mail clients can offer their own unsubscribe button.
+#[derive(Clone)]
+struct ListUnsubscribe(String);
+
+impl Header for ListUnsubscribe {
+    fn name() -> HeaderName {
+        HeaderName::new_from_ascii_str("List-Unsubscribe")
+    }
+
+    fn parse(s: &str) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        Ok(Self(s.trim_matches(|c| c == '<' || c == '>').to_string()))
+    }
+
+    fn display(&self) -> HeaderValue {
+        HeaderValue::new(Self::name(), format!("<{}>", self.0))
+    }
+}
+
+/// RFC 8058 one-click unsubscribe: the client POSTs to the `List-Unsubscribe` URL.
+#[derive(Clone)]
+struct ListUnsubscribePost;
+
+impl Header for ListUnsubscribePost {
+    fn name() -> HeaderName {
+        HeaderName::new_from_ascii_str("List-Unsubscribe-Post")
+    }
+
+    fn parse(_: &str) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        Ok(Self)
+    }
+
+    fn display(&self) -> HeaderValue {
+        HeaderValue::new(Self::name(), "List-Unsubscribe=One-Click".to_string())
+    }
+}
 
 
 #[derive(Clone)]
@@ -42,12 +80,37 @@ impl Email {
         self.send_email(email)
     }
 
+    pub fn send_html_email(&self, to: &str, subject: String, body: String) -> Result<String, String> {
+        let email = Message::builder()
+            .from(Mailbox::new(None, self.config.smtp_from.parse().map_err(|e| format!("{:?}", e))?))
+            .to(to.parse().map_err(|e| format!("{:?}", e))?)
+            .subject(subject)
+            .header(header::ContentType::TEXT_HTML)
+            .body(body)
+            .map_err(|e| e.to_string())?;
+        self.send_email(email)
+    }
+
+    /// Like `send_html_email`, with the headers mail clients use to unsubscribe.
+    pub fn send_newsletter_email(&self, to: &str, subject: String, body: String, unsubscribe_url: &str) -> Result<String, String> {
+        let email = Message::builder()
+            .from(Mailbox::new(None, self.config.smtp_from.parse().map_err(|e| format!("{:?}", e))?))
+            .to(to.parse().map_err(|e| format!("{:?}", e))?)
+            .subject(subject)
+            .header(header::ContentType::TEXT_HTML)
+            .header(ListUnsubscribe(unsubscribe_url.to_string()))
+            .header(ListUnsubscribePost)
+            .body(body)
+            .map_err(|e| e.to_string())?;
+        self.send_email(email)
+    }
+
     fn send_email(&self,email: Message) -> Result<String, String> {
         let mailer = SmtpTransport::builder_dangerous(self.config.smtp_host.clone())
             .port(self.config.smtp_port as u16)
             .build();
 
-        mailer.send(&email).unwrap();
+        mailer.send(&email).map_err(|e| e.to_string())?;
         Ok("Email sent successfully".to_string())
     }
 }
@@ -0,0 +1,102 @@
+use comrak::html::collect_text;
+use comrak::nodes::{AstNode, NodeValue};
+use comrak::{Anchorizer, Arena, Options, format_html, parse_document};
+use serde::{Deserialize, Serialize};
+
+/// Average adult silent reading speed used for `reading_time_minutes`.
+const WORDS_PER_MINUTE: usize = 200;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TocEntry {
+    pub level: u8,
+    pub text: String,
+    pub anchor: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct RenderedMarkdown {
+    pub html: String,
+    pub toc: Vec<TocEntry>,
+    pub word_count: i32,
+    pub reading_time_minutes: i32,
+}
+
+impl RenderedMarkdown {
+    pub fn toc_json(&self) -> String {
+        serde_json::to_string(&self.toc).unwrap_or_else(|_| "[]".to_string())
+    }
+}
+
+pub fn toc_from_json(json: Option<&str>) -> Vec<TocEntry> {
+    json.and_then(|j| serde_json::from_str(j).ok())
+        .unwrap_or_default()
+}
+
+fn options() -> Options<'static> {
+    let mut options = Options::default();
+    options.extension.strikethrough = true;
+    options.extension.table = true;
+    options.extension.autolink = true;
+    options.extension.tasklist = true;
+    options.extension.footnotes = true;
+    options.extension.header_ids = Some(String::new());
+    options
+}
+
+/// Renders CommonMark + GFM to sanitized HTML and extracts the table of
+/// contents, word count and reading time in a single parse.
+pub fn render(content: &str) -> RenderedMarkdown {
+    let arena = Arena::new();
+    let options = options();
+    let root = parse_document(&arena, content, &options);
+
+    // Walk headings in document order with a fresh anchorizer so the anchors
+    // match the ids comrak writes into the HTML.
+    let mut anchorizer = Anchorizer::new();
+    let mut toc = Vec::new();
+    for node in root.descendants() {
+        if let NodeValue::Heading(heading) = node.data.borrow().value {
+            let text = node_text(node);
+            let anchor = anchorizer.anchorize(text.clone());
+            toc.push(TocEntry {
+                level: heading.level,
+                text: text.trim().to_string(),
+                anchor,
+            });
+        }
+    }
+
+    let word_count = node_text(root).split_whitespace().count();
+    let reading_time_minutes = word_count.div_ceil(WORDS_PER_MINUTE);
+
+    let mut html = Vec::new();
+    format_html(root, &options, &mut html).expect("writing to a Vec cannot fail");
+    let html = sanitize(&String::from_utf8_lossy(&html));
+
+    RenderedMarkdown {
+        html,
+        toc,
+        word_count: word_count as i32,
+        reading_time_minutes: reading_time_minutes as i32,
+    }
+}
+
+fn node_text<'a>(node: &'a AstNode<'a>) -> String {
+    let mut text = Vec::new();
+    collect_text(node, &mut text);
+    String::from_utf8_lossy(&text).into_owned()
+}
+
+fn sanitize(html: &str) -> String {
+    ammonia::Builder::default()
+        .add_tags(&["input"])
+        .add_tag_attributes("input", &["type", "checked", "disabled"])
+        .add_tag_attributes("a", &["id", "class", "aria-hidden"])
+        .add_tag_attributes("code", &["class"])
+        .add_tag_attributes("li", &["id"])
+        .add_tag_attributes("sup", &["class", "id"])
+        .add_tag_attributes("section", &["class"])
+        .add_tags(&["section"])
+        .clean(html)
+        .to_string()
+}
@@ -1,6 +1,9 @@
+pub mod client_ip;
 pub mod db;
 pub mod di;
 pub mod email;
 pub mod error_response;
+pub mod markdown;
+pub mod slug;
 pub mod success_response;
 pub mod token;
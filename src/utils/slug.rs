@@ -0,0 +1,82 @@
+use deunicode::deunicode;
+
+/// Longest slug we hand out; collision suffixes are fitted inside it.
+pub const MAX_SLUG_LENGTH: usize = 80;
+
+pub enum SlugError<E> {
+    /// The requested slug cannot be used; the message suits a `slug` field error.
+    Invalid(&'static str),
+    Lookup(E),
+}
+
+/// Lowercase ASCII slug for `input`: transliterated, every run of other
+/// characters collapsed into one dash, trimmed and capped at `MAX_SLUG_LENGTH`.
+pub fn slugify(input: &str) -> String {
+    let mut slug = String::with_capacity(input.len());
+    for c in deunicode(input).chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    truncate(&slug, MAX_SLUG_LENGTH)
+}
+
+fn truncate(slug: &str, max_len: usize) -> String {
+    slug[..slug.len().min(max_len)]
+        .trim_end_matches('-')
+        .to_string()
+}
+
+/// First of `base`, `base-2`, `base-3`, ... that `is_taken` reports as free.
+pub fn unique_slug<E>(
+    base: &str,
+    mut is_taken: impl FnMut(&str) -> Result<bool, E>,
+) -> Result<String, E> {
+    if !is_taken(base)? {
+        return Ok(base.to_string());
+    }
+    let mut n = 2;
+    loop {
+        let suffix = format!("-{}", n);
+        let candidate = format!(
+            "{}{}",
+            truncate(base, MAX_SLUG_LENGTH - suffix.len()),
+            suffix
+        );
+        if !is_taken(&candidate)? {
+            return Ok(candidate);
+        }
+        n += 1;
+    }
+}
+
+/// Slug for a new or renamed entity.
+///
+/// A `requested` slug is normalized and must be free. Otherwise the slug is
+/// derived from `source` (or `fallback` when `source` has nothing usable) and
+/// numbered on collision.
+pub fn resolve_slug<E>(
+    requested: Option<&str>,
+    source: &str,
+    fallback: &str,
+    mut is_taken: impl FnMut(&str) -> Result<bool, E>,
+) -> Result<String, SlugError<E>> {
+    if let Some(requested) = requested {
+        let slug = slugify(requested);
+        if slug.is_empty() {
+            return Err(SlugError::Invalid("Slug must contain letters or digits"));
+        }
+        if is_taken(&slug).map_err(SlugError::Lookup)? {
+            return Err(SlugError::Invalid("Slug already exists"));
+        }
+        return Ok(slug);
+    }
+
+    let mut base = slugify(source);
+    if base.is_empty() {
+        base = fallback.to_string();
+    }
+    unique_slug(&base, is_taken).map_err(SlugError::Lookup)
+}
@@ -3,7 +3,7 @@ use jsonwebtoken::{
 };
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: String, // username
     pub exp: usize,
@@ -35,3 +35,99 @@ pub fn verify_token(token: &str, secret: &str) -> Result<Claims, String> {
     .map(|data| data.claims)
     .map_err(|e| e.to_string())
 }
+
+/// Audience of draft preview links. Login tokens carry no audience, so neither
+/// kind is accepted in place of the other.
+pub const PREVIEW_AUDIENCE: &str = "blog-preview";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreviewClaims {
+    pub sub: String, // blog id
+    pub aud: String,
+    pub jti: String, // preview token id, checked for revocation
+    pub exp: usize,
+}
+
+pub fn create_preview_token(blog_id: i32, token_id: i32, expires_at: u64, secret: &str) -> String {
+    let claims = PreviewClaims {
+        sub: blog_id.to_string(),
+        aud: PREVIEW_AUDIENCE.to_owned(),
+        jti: token_id.to_string(),
+        exp: expires_at as usize,
+    };
+
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(secret.as_ref()),
+    )
+    .expect("Token creation failed")
+}
+
+pub fn verify_preview_token(token: &str, secret: &str) -> Result<PreviewClaims, String> {
+    let mut validation = Validation::new(Algorithm::HS256);
+    validation.set_audience(&[PREVIEW_AUDIENCE]);
+    decode::<PreviewClaims>(
+        token,
+        &DecodingKey::from_secret(secret.as_ref()),
+        &validation,
+    )
+    .map(|data| data.claims)
+    .map_err(|e| e.to_string())
+}
+
+/// Audience of newsletter confirmation links (double opt-in).
+pub const SUBSCRIBE_AUDIENCE: &str = "newsletter-confirm";
+/// Audience of the unsubscribe link in every newsletter email.
+pub const UNSUBSCRIBE_AUDIENCE: &str = "newsletter-unsubscribe";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubscriberClaims {
+    pub sub: String, // subscriber id
+    pub aud: String,
+    pub email: String, // must still match the subscriber's address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp: Option<usize>,
+}
+
+/// Without `expires_at` the token never expires; unsubscribe links in old
+/// emails have to keep working.
+pub fn create_subscriber_token(
+    subscriber_id: i32,
+    email: &str,
+    audience: &str,
+    expires_at: Option<u64>,
+    secret: &str,
+) -> String {
+    let claims = SubscriberClaims {
+        sub: subscriber_id.to_string(),
+        aud: audience.to_owned(),
+        email: email.to_owned(),
+        exp: expires_at.map(|exp| exp as usize),
+    };
+
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(secret.as_ref()),
+    )
+    .expect("Token creation failed")
+}
+
+pub fn verify_subscriber_token(
+    token: &str,
+    audience: &str,
+    secret: &str,
+) -> Result<SubscriberClaims, String> {
+    let mut validation = Validation::new(Algorithm::HS256);
+    validation.set_audience(&[audience]);
+    // `exp` is still checked whenever the token carries one
+    validation.required_spec_claims.clear();
+    decode::<SubscriberClaims>(
+        token,
+        &DecodingKey::from_secret(secret.as_ref()),
+        &validation,
+    )
+    .map(|data| data.claims)
+    .map_err(|e| e.to_string())
+}